@@ -0,0 +1,149 @@
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use hangul_cd::block::all_syllables;
+use hangul_cd::generate::HangulGenerator;
+use hangul_cd::jamo::{Character, JamoUnicodeType};
+use hangul_cd::string::{CharClass, StringComposer, classify_str, compose, decompose};
+use hangul_cd::word::HangulWordComposer;
+
+// Roughly 10,000 syllables of generated text, plus the same text as loose
+// jamo and interleaved with ASCII.
+fn inputs() -> (String, String, String) {
+    let mut generator = HangulGenerator::new(2024);
+    let text = generator.text(3000, 1..=5);
+    let jamo = decompose(&text);
+    let mixed = text
+        .split(' ')
+        .map(|word| format!("{word} (the quick brown fox)"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (text, jamo, mixed)
+}
+
+fn classification(c: &mut Criterion) {
+    let (_, _, mixed) = inputs();
+    let mut group = c.benchmark_group("classify");
+    group.throughput(Throughput::Bytes(mixed.len() as u64));
+    group.bench_function("per_char", |b| {
+        b.iter(|| {
+            black_box(&mixed)
+                .chars()
+                .map(CharClass::of)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("classify_str", |b| {
+        b.iter(|| classify_str(black_box(&mixed)))
+    });
+    group.finish();
+
+    // The lookups done for every character pushed to a composer
+    let (_, jamo, _) = inputs();
+    let mut group = c.benchmark_group("classify_jamo");
+    group.throughput(Throughput::Bytes(jamo.len() as u64));
+    group.bench_function("unicode_type", |b| {
+        b.iter(|| {
+            black_box(&jamo)
+                .chars()
+                .map(JamoUnicodeType::evaluate)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("character", |b| {
+        b.iter(|| {
+            black_box(&jamo)
+                .chars()
+                .map(Character::from_char)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn composition(c: &mut Criterion) {
+    let (text, jamo, _) = inputs();
+    let mut group = c.benchmark_group("compose");
+    group.throughput(Throughput::Bytes(jamo.len() as u64));
+    group.bench_function("compose", |b| b.iter(|| compose(black_box(&jamo))));
+    group.bench_function("feed_str", |b| {
+        b.iter(|| {
+            let mut composer = StringComposer::new();
+            composer.feed_str(black_box(&jamo)).unwrap();
+            composer.as_string()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("decompose");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("decompose", |b| b.iter(|| decompose(black_box(&text))));
+    group.finish();
+
+    // Every modern syllable, decomposed and composed back
+    let all: String = all_syllables().collect();
+    let mut group = c.benchmark_group("roundtrip");
+    group.throughput(Throughput::Elements(all.chars().count() as u64));
+    group.bench_function("all_syllables", |b| {
+        b.iter(|| compose(&decompose(black_box(&all))))
+    });
+    group.finish();
+}
+
+// Types a single long word a jamo at a time, reading the composed text back
+// after every keystroke as an editor would.
+fn keystrokes(c: &mut Criterion) {
+    let mut generator = HangulGenerator::new(2024);
+    let word = decompose(&generator.text(500, 1..=5).replace(' ', ""));
+    let mut group = c.benchmark_group("keystroke");
+    group.throughput(Throughput::Elements(word.chars().count() as u64));
+    group.bench_function("word_composer", |b| {
+        b.iter(|| {
+            let mut composer = HangulWordComposer::new();
+            for c in black_box(&word).chars() {
+                composer.push_char(c).unwrap();
+                black_box(composer.as_string().unwrap());
+            }
+        })
+    });
+    group.bench_function("string_composer", |b| {
+        b.iter(|| {
+            let mut composer = StringComposer::new();
+            for c in black_box(&word).chars() {
+                composer.push_char(c).unwrap();
+                black_box(composer.as_string().unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+// Compares sequential and parallel decomposition on a few megabytes of
+// generated text.
+#[cfg(feature = "rayon")]
+fn parallel(c: &mut Criterion) {
+    use hangul_cd::string::{map_blocks, par_decompose, par_map_blocks};
+
+    let mut generator = HangulGenerator::new(2024);
+    let text = generator.text(600_000, 1..=5);
+    let mut group = c.benchmark_group("parallel");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.sample_size(20);
+    group.bench_function("decompose", |b| b.iter(|| decompose(black_box(&text))));
+    group.bench_function("par_decompose", |b| {
+        b.iter(|| par_decompose(black_box(&text)))
+    });
+    group.bench_function("map_blocks", |b| {
+        b.iter(|| map_blocks(black_box(&text), |block| block))
+    });
+    group.bench_function("par_map_blocks", |b| {
+        b.iter(|| par_map_blocks(black_box(&text), |block| block))
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "rayon"))]
+criterion_group!(benches, classification, composition, keystrokes);
+#[cfg(feature = "rayon")]
+criterion_group!(benches, classification, composition, keystrokes, parallel);
+criterion_main!(benches);
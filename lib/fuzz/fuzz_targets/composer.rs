@@ -0,0 +1,38 @@
+#![no_main]
+
+use hangul_cd::string::{ComposerMode, ComposerOptions, StringComposer};
+use libfuzzer_sys::fuzz_target;
+
+// Feeds arbitrary text through the composer in every mode, interleaving
+// pops, undo, and redo. Errors are fine; panics are not.
+fuzz_target!(|data: &[u8]| {
+    let Some((&control, text)) = data.split_first() else {
+        return;
+    };
+    let text = String::from_utf8_lossy(text);
+    let mode = match control % 3 {
+        0 => ComposerMode::Strict,
+        1 => ComposerMode::Lenient,
+        _ => ComposerMode::Replace,
+    };
+
+    let mut composer = StringComposer::with_options(ComposerOptions::new().with_mode(mode));
+    let _ = composer.feed_str(&text);
+    let _ = composer.as_string();
+
+    for (i, c) in text.chars().enumerate() {
+        if i % 4 == 0 {
+            composer.checkpoint();
+        }
+        let _ = composer.push_char(c);
+        if control & 0x80 != 0 && i % 3 == 0 {
+            let _ = composer.pop();
+        }
+        if control & 0x40 != 0 && i % 5 == 0 {
+            composer.undo();
+        }
+    }
+    while composer.redo() {}
+    let _ = composer.as_string();
+    while let Ok(Some(_)) = composer.pop() {}
+});
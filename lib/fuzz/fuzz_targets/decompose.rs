@@ -0,0 +1,19 @@
+#![no_main]
+
+use hangul_cd::block::HangulBlock;
+use hangul_cd::string::{compose, decompose};
+use libfuzzer_sys::fuzz_target;
+
+// Every precomposed syllable must survive decomposition and recomposition,
+// and no input may panic.
+fuzz_target!(|text: &str| {
+    let _ = compose(text);
+    let decomposed = decompose(text);
+    let _ = compose(&decomposed);
+
+    for c in text.chars() {
+        if let Ok(block) = HangulBlock::from_char(c) {
+            assert_eq!(block.to_char(), c);
+        }
+    }
+});
@@ -0,0 +1,35 @@
+/*
+ * C interface to hangul-cd, built with the `ffi` feature:
+ *
+ *     cargo rustc --release --lib --features ffi --crate-type cdylib
+ *
+ * All strings are UTF-8. See src/ffi.rs for the full documentation.
+ */
+#ifndef HANGUL_H
+#define HANGUL_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define HANGUL_OK 0
+#define HANGUL_ERR_NULL (-1)
+#define HANGUL_ERR_INVALID_CHAR (-2)
+#define HANGUL_ERR_COMPOSE (-3)
+
+typedef struct HangulComposer HangulComposer;
+
+HangulComposer *hangul_composer_new(void);
+int32_t hangul_composer_push(HangulComposer *composer, uint32_t codepoint);
+uint32_t hangul_composer_pop(HangulComposer *composer);
+char *hangul_composer_text(const HangulComposer *composer);
+void hangul_composer_free(HangulComposer *composer);
+void hangul_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HANGUL_H */
@@ -0,0 +1,627 @@
+use std::collections::HashMap;
+
+use crate::block::{HangulBlock, syllable_indices};
+use crate::jamo::{Character, Choseong, Jamo, JamoVowelSingular, Jongseong, Jungseong};
+
+/// Counts of characters observed in a text, returned by `jamo_frequency`
+/// and `syllable_frequency`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FrequencyTable {
+    counts: HashMap<char, usize>,
+    total: usize,
+}
+
+impl FrequencyTable {
+    /// Returns the number of times `c` was observed.
+    pub fn count(&self, c: char) -> usize {
+        self.counts.get(&c).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of characters observed.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of distinct characters observed.
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns the share of all observations that were `c`, from `0.0` to
+    /// `1.0`. Returns `0.0` if nothing was observed.
+    pub fn relative_frequency(&self, c: char) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.count(c) as f64 / self.total as f64
+        }
+    }
+
+    /// Returns every observed character with its count, most common first.
+    /// Ties are broken by codepoint so the result is deterministic.
+    pub fn most_common(&self) -> Vec<(char, usize)> {
+        let mut entries: Vec<(char, usize)> = self.counts.iter().map(|(c, n)| (*c, *n)).collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        entries
+    }
+
+    fn record(&mut self, c: char) {
+        *self.counts.entry(c).or_insert(0) += 1;
+        self.total += 1;
+    }
+}
+
+/// Counts the compatibility jamo in `text`. Precomposed syllables are split
+/// into their initial, vowel, and final jamo; composite jamo such as ㅘ or
+/// ㄳ are counted as a single jamo. Standalone modern and compatibility jamo
+/// are counted as well, while non-Hangul characters are ignored.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::jamo_frequency;
+///
+/// let freq = jamo_frequency("한국 사람");
+/// assert_eq!(freq.count('ㅏ'), 3);
+/// assert_eq!(freq.count('ㄱ'), 2);
+/// assert_eq!(freq.total(), 11);
+/// ```
+pub fn jamo_frequency(text: &str) -> FrequencyTable {
+    let mut table = FrequencyTable::default();
+    for c in text.chars() {
+        if let Ok(block) = HangulBlock::from_char(c) {
+            table.record(block.initial.char_compatibility());
+            table.record(block.vowel.char_compatibility());
+            if let Some(f) = &block.final_optional {
+                table.record(f.char_compatibility());
+            }
+        } else if let Ok(Character::Hangul(jamo)) = Character::from_char(c) {
+            table.record(jamo.char_compatibility());
+        }
+    }
+    table
+}
+
+/// Counts the precomposed Hangul syllables in `text`, ignoring everything
+/// else.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::syllable_frequency;
+///
+/// let freq = syllable_frequency("하나, 둘, 하나!");
+/// assert_eq!(freq.count('하'), 2);
+/// assert_eq!(freq.most_common()[0], ('나', 2));
+/// assert_eq!(freq.total(), 5);
+/// ```
+pub fn syllable_frequency(text: &str) -> FrequencyTable {
+    let mut table = FrequencyTable::default();
+    for c in text.chars() {
+        if HangulBlock::from_char(c).is_ok() {
+            table.record(c);
+        }
+    }
+    table
+}
+
+/// A histogram of the structural shapes of the Hangul syllables in a text,
+/// returned by `block_shape_histogram`. Each syllable is counted once in
+/// `open`/`closed` and once in one of the vowel orientation fields.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BlockShapeHistogram {
+    /// The total number of syllables counted.
+    pub total: usize,
+
+    /// Syllables with no final consonant (받침), e.g. 가.
+    pub open: usize,
+
+    /// Syllables with a final consonant, e.g. 각.
+    pub closed: usize,
+
+    /// Syllables whose initial is a double consonant, e.g. 까.
+    pub composite_initial: usize,
+
+    /// Syllables whose vowel is composite, e.g. 과.
+    pub composite_vowel: usize,
+
+    /// Syllables whose final is a double or cluster consonant, e.g. 값.
+    pub composite_final: usize,
+
+    /// Syllables with a vertical vowel placed to the right of the initial,
+    /// e.g. 가, 개, 기.
+    pub vertical_vowel: usize,
+
+    /// Syllables with a horizontal vowel placed below the initial, e.g.
+    /// 고, 구, 그.
+    pub horizontal_vowel: usize,
+
+    /// Syllables with a vowel combining horizontal and vertical strokes,
+    /// e.g. 과, 귀, 의.
+    pub mixed_vowel: usize,
+}
+
+/// Tallies the shapes of the precomposed Hangul syllables in `text`,
+/// ignoring everything else.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::block_shape_histogram;
+///
+/// let histogram = block_shape_histogram("값진 과일");
+/// assert_eq!(histogram.total, 4);
+/// assert_eq!(histogram.closed, 3);
+/// assert_eq!(histogram.open, 1);
+/// assert_eq!(histogram.composite_final, 1);
+/// assert_eq!(histogram.mixed_vowel, 1);
+/// ```
+pub fn block_shape_histogram(text: &str) -> BlockShapeHistogram {
+    let mut histogram = BlockShapeHistogram::default();
+    for c in text.chars() {
+        let Ok(block) = HangulBlock::from_char(c) else {
+            continue;
+        };
+        histogram.total += 1;
+
+        match block.final_jamo() {
+            None => histogram.open += 1,
+            Some(f) => {
+                histogram.closed += 1;
+                if matches!(f, Jamo::CompositeConsonant(_)) {
+                    histogram.composite_final += 1;
+                }
+            }
+        }
+
+        if matches!(block.initial.jamo(), Jamo::CompositeConsonant(_)) {
+            histogram.composite_initial += 1;
+        }
+
+        match block.vowel.jamo() {
+            Jamo::Vowel(v) => match v {
+                JamoVowelSingular::O
+                | JamoVowelSingular::Yo
+                | JamoVowelSingular::U
+                | JamoVowelSingular::Yu
+                | JamoVowelSingular::Eu => histogram.horizontal_vowel += 1,
+                _ => histogram.vertical_vowel += 1,
+            },
+            Jamo::CompositeVowel(_) => {
+                histogram.composite_vowel += 1;
+                histogram.mixed_vowel += 1;
+            }
+            _ => {}
+        }
+    }
+    histogram
+}
+
+/// Returns an iterator over the syllable n-grams of `text`: every run of
+/// `n` consecutive precomposed Hangul syllables, in order. N-grams do not
+/// span other characters, so words separated by spaces or punctuation do
+/// not form n-grams together. Yields nothing if `n` is 0.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::ngrams;
+///
+/// let bigrams: Vec<String> = ngrams("대한민국 만세", 2).collect();
+/// assert_eq!(bigrams, ["대한", "한민", "민국", "만세"]);
+/// ```
+pub fn ngrams(text: &str, n: usize) -> impl Iterator<Item = String> {
+    windows(
+        hangul_runs(text, |c, run| {
+            if HangulBlock::from_char(c).is_ok() {
+                run.push(c);
+                true
+            } else {
+                false
+            }
+        }),
+        n,
+    )
+}
+
+/// Returns an iterator over the jamo n-grams of `text`: every run of `n`
+/// consecutive compatibility jamo within a word, in order. Syllables are
+/// split into jamo as by `jamo_frequency`, and loose jamo are included, so
+/// n-grams span syllable boundaries but not other characters. Yields
+/// nothing if `n` is 0.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::jamo_ngrams;
+///
+/// let trigrams: Vec<String> = jamo_ngrams("한글", 3).collect();
+/// assert_eq!(trigrams, ["ㅎㅏㄴ", "ㅏㄴㄱ", "ㄴㄱㅡ", "ㄱㅡㄹ"]);
+/// ```
+pub fn jamo_ngrams(text: &str, n: usize) -> impl Iterator<Item = String> {
+    windows(
+        hangul_runs(text, |c, run| {
+            if let Ok(block) = HangulBlock::from_char(c) {
+                run.push(block.initial.char_compatibility());
+                run.push(block.vowel.char_compatibility());
+                run.extend(block.final_optional.map(|f| f.char_compatibility()));
+                true
+            } else if let Ok(Character::Hangul(jamo)) = Character::from_char(c) {
+                run.push(jamo.char_compatibility());
+                true
+            } else {
+                false
+            }
+        }),
+        n,
+    )
+}
+
+/// Counts how often each n-gram produced by `ngrams` or `jamo_ngrams`
+/// occurs.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::{ngram_frequency, ngrams};
+///
+/// let freq = ngram_frequency(ngrams("하나 하나 하나둘", 2));
+/// assert_eq!(freq["하나"], 3);
+/// assert_eq!(freq["나둘"], 1);
+/// ```
+pub fn ngram_frequency<I: IntoIterator<Item = String>>(ngrams: I) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for ngram in ngrams {
+        *counts.entry(ngram).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Splits `text` into runs of the characters that `push` appends for each
+// character it accepts, ending a run at every character it rejects.
+fn hangul_runs(text: &str, push: impl Fn(char, &mut Vec<char>) -> bool) -> Vec<Vec<char>> {
+    let mut runs = vec![Vec::new()];
+    for c in text.chars() {
+        // Infallible: `runs` always has a current run
+        if !push(c, runs.last_mut().unwrap()) && !runs.last().unwrap().is_empty() {
+            runs.push(Vec::new());
+        }
+    }
+    runs
+}
+
+// Returns an iterator over every window of `n` characters within each run,
+// as strings.
+fn windows(runs: Vec<Vec<char>>, n: usize) -> impl Iterator<Item = String> {
+    runs.into_iter().flat_map(move |run| {
+        let count = if n == 0 {
+            0
+        } else {
+            (run.len() + 1).saturating_sub(n)
+        };
+        (0..count).map(move |i| run[i..i + n].iter().collect())
+    })
+}
+
+/// The length of the vectors returned by `jamo_vector`: one entry for each
+/// of the 51 modern compatibility jamo, ㄱ (U+3131) to ㅣ (U+3163).
+pub const JAMO_VECTOR_LEN: usize = 51;
+
+/// The length of the vectors returned by `syllable_one_hot`: one entry for
+/// each of the 19 initial consonants, the 21 vowels, and the 27 final
+/// consonants or no final consonant.
+pub const SYLLABLE_ONE_HOT_LEN: usize = Choseong::COUNT + Jungseong::COUNT + Jongseong::COUNT + 1;
+
+/// Counts the jamo in `text` as `jamo_frequency` does, returning the counts
+/// as a vector in a fixed order, for use as features in machine learning
+/// models. Entry `i` is the count of the compatibility jamo U+3131 + `i`,
+/// so ㄱ is entry 0 and ㅣ is entry 50.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::{JAMO_VECTOR_LEN, jamo_vector};
+///
+/// let vector = jamo_vector("한국 ㅋㅋ");
+/// assert_eq!(vector.len(), JAMO_VECTOR_LEN);
+/// assert_eq!(vector[0], 2); // ㄱ
+/// assert_eq!(vector['ㅋ' as usize - 'ㄱ' as usize], 2);
+/// assert_eq!(vector.iter().sum::<u32>(), 8);
+/// ```
+pub fn jamo_vector(text: &str) -> [u32; JAMO_VECTOR_LEN] {
+    let mut vector = [0; JAMO_VECTOR_LEN];
+    let mut record = |c: char| {
+        if let Some(count) = (c as usize)
+            .checked_sub(0x3131)
+            .and_then(|i| vector.get_mut(i))
+        {
+            *count += 1;
+        }
+    };
+    for c in text.chars() {
+        if let Ok(block) = HangulBlock::from_char(c) {
+            record(block.initial.char_compatibility());
+            record(block.vowel.char_compatibility());
+            if let Some(f) = &block.final_optional {
+                record(f.char_compatibility());
+            }
+        } else if let Ok(Character::Hangul(jamo)) = Character::from_char(c) {
+            record(jamo.char_compatibility());
+        }
+    }
+    vector
+}
+
+/// Encodes the precomposed syllable `c` as the concatenation of one-hot
+/// vectors for its initial consonant, vowel, and final consonant, in the
+/// order used to compute syllable codepoints, or returns `None` if `c` is
+/// not a precomposed Hangul syllable. The final consonant part has an
+/// extra first entry for syllables without one, so exactly three entries
+/// are 1.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::{SYLLABLE_ONE_HOT_LEN, syllable_one_hot};
+///
+/// let one_hot = syllable_one_hot('가').unwrap();
+/// assert_eq!(one_hot.len(), SYLLABLE_ONE_HOT_LEN);
+/// // ㄱ is the first initial, ㅏ the first vowel, and 가 has no final
+/// assert_eq!((one_hot[0], one_hot[19], one_hot[40]), (1, 1, 1));
+/// assert_eq!(one_hot.iter().sum::<u8>(), 3);
+/// assert_eq!(syllable_one_hot('a'), None);
+/// ```
+pub fn syllable_one_hot(c: char) -> Option<[u8; SYLLABLE_ONE_HOT_LEN]> {
+    let (initial, vowel, final_index) = syllable_indices(c)?;
+    let mut one_hot = [0; SYLLABLE_ONE_HOT_LEN];
+    one_hot[initial] = 1;
+    one_hot[Choseong::COUNT + vowel] = 1;
+    one_hot[Choseong::COUNT + Jungseong::COUNT + final_index] = 1;
+    Some(one_hot)
+}
+
+/// A run of standalone jamo used expressively in chat text, found by
+/// `detect_fillers`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Filler {
+    /// The byte offset of the run in the text.
+    pub offset: usize,
+
+    /// The length in bytes of the run.
+    pub len: usize,
+
+    /// What the run expresses.
+    pub kind: FillerKind,
+}
+
+/// What a `Filler` expresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillerKind {
+    /// Laughter written with ㅋ or ㅎ, from 크크 and 하하, e.g. ㅋㅋㅋ.
+    Laughter,
+
+    /// Crying written with ㅠ or ㅜ, which look like streaming tears, e.g.
+    /// ㅠㅠ.
+    Crying,
+
+    /// Agreement written with ㅇ, from 응, e.g. ㅇㅇ.
+    Yes,
+
+    /// Disagreement written with ㄴ, from 노 or 아니, e.g. ㄴㄴ.
+    No,
+}
+
+impl FillerKind {
+    /// Returns the usual two-jamo spelling of this kind of filler, which
+    /// `normalize_fillers` replaces each filler with.
+    pub fn canonical(&self) -> &'static str {
+        match self {
+            FillerKind::Laughter => "ㅋㅋ",
+            FillerKind::Crying => "ㅠㅠ",
+            FillerKind::Yes => "ㅇㅇ",
+            FillerKind::No => "ㄴㄴ",
+        }
+    }
+
+    // Returns the kind of filler the compatibility jamo `c` can be part of.
+    fn of(c: char) -> Option<Self> {
+        match c {
+            'ㅋ' | 'ㅎ' => Some(FillerKind::Laughter),
+            'ㅠ' | 'ㅜ' => Some(FillerKind::Crying),
+            'ㅇ' => Some(FillerKind::Yes),
+            'ㄴ' => Some(FillerKind::No),
+            _ => None,
+        }
+    }
+}
+
+/// Finds the runs of standalone jamo in `text` used to express laughter,
+/// crying, agreement, or disagreement, in the order they appear.
+///
+/// Each filler is at least two jamo long, and adjacent fillers of different
+/// kinds are reported separately, as in ㅋㅋㅠㅠ. A run of standalone jamo
+/// containing any other jamo is an abbreviation rather than a filler, so
+/// ㄱㅅ (감사) and ㅇㅋ (오케이) are not reported.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::{Filler, FillerKind, detect_fillers};
+///
+/// let fillers = detect_fillers("ㅇㅇ 알겠어ㅋㅋㅋ");
+/// assert_eq!(
+///     fillers,
+///     [
+///         Filler { offset: 0, len: 6, kind: FillerKind::Yes },
+///         Filler { offset: 16, len: 9, kind: FillerKind::Laughter },
+///     ]
+/// );
+/// assert!(detect_fillers("ㄱㅅ ㅇㅋ").is_empty());
+/// ```
+pub fn detect_fillers(text: &str) -> Vec<Filler> {
+    let mut fillers = Vec::new();
+    let mut run: Vec<(usize, usize, Option<FillerKind>)> = Vec::new();
+    for (offset, c) in text.char_indices().chain([(text.len(), '\0')]) {
+        if let Ok(Character::Hangul(jamo)) = Character::from_char(c) {
+            run.push((
+                offset,
+                c.len_utf8(),
+                FillerKind::of(jamo.char_compatibility()),
+            ));
+            continue;
+        }
+        if run.iter().all(|&(_, _, kind)| kind.is_some()) {
+            for segment in run.chunk_by(|a, b| a.2 == b.2) {
+                if let [(offset, _, Some(kind)), .., (last, len, _)] = *segment {
+                    fillers.push(Filler {
+                        offset,
+                        len: last + len - offset,
+                        kind,
+                    });
+                }
+            }
+        }
+        run.clear();
+    }
+    fillers
+}
+
+/// Replaces each filler found by `detect_fillers` with its kind's
+/// canonical spelling, so that ㅋㅋㅋㅋ and ㅎㅎ both become ㅋㅋ.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::normalize_fillers;
+///
+/// assert_eq!(normalize_fillers("ㅎㅎㅎ 좋아ㅜㅠㅠㅠ"), "ㅋㅋ 좋아ㅠㅠ");
+/// ```
+pub fn normalize_fillers(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    for filler in detect_fillers(text) {
+        result.push_str(&text[last..filler.offset]);
+        result.push_str(filler.kind.canonical());
+        last = filler.offset + filler.len;
+    }
+    result.push_str(&text[last..]);
+    result
+}
+
+/// Removes each filler found by `detect_fillers` from `text`, along with
+/// the whitespace separating it from the surrounding text, so that no
+/// doubled or trailing spaces are left behind.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::analysis::remove_fillers;
+///
+/// assert_eq!(remove_fillers("ㅋㅋㅋ 진짜 웃기다ㅋㅋ ㅠㅠ"), "진짜 웃기다");
+/// assert_eq!(remove_fillers("ㅇㅇ 내일 봐 ㅎㅎ"), "내일 봐");
+/// ```
+pub fn remove_fillers(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    for filler in detect_fillers(text) {
+        result.push_str(&text[last..filler.offset]);
+        let end = filler.offset + filler.len;
+        let rest = &text[end..];
+        // Drop the whitespace after the filler if the filler was at the
+        // start of the text or already followed whitespace.
+        last = if result.is_empty() || result.ends_with(char::is_whitespace) {
+            end + rest.len() - rest.trim_start().len()
+        } else {
+            end
+        };
+    }
+    result.push_str(&text[last..]);
+    result.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jamo_frequency_counts_blocks_and_standalone_jamo() {
+        let freq = jamo_frequency("닭 ㅋㅋ \u{1100}");
+        assert_eq!(freq.count('ㄷ'), 1);
+        assert_eq!(freq.count('ㅏ'), 1);
+        assert_eq!(freq.count('ㄺ'), 1);
+        assert_eq!(freq.count('ㅋ'), 2);
+        assert_eq!(freq.count('ㄱ'), 1);
+        assert_eq!(freq.total(), 6);
+        assert_eq!(freq.most_common()[0], ('ㅋ', 2));
+    }
+
+    #[test]
+    fn empty_text_has_no_frequencies() {
+        let freq = syllable_frequency("hello");
+        assert_eq!(freq.total(), 0);
+        assert_eq!(freq.distinct(), 0);
+        assert_eq!(freq.relative_frequency('가'), 0.0);
+        assert_eq!(block_shape_histogram(""), BlockShapeHistogram::default());
+    }
+
+    #[test]
+    fn relative_frequency() {
+        let freq = syllable_frequency("가가나다");
+        assert_eq!(freq.relative_frequency('가'), 0.5);
+        assert_eq!(freq.relative_frequency('다'), 0.25);
+    }
+
+    #[test]
+    fn vowel_orientation() {
+        let histogram = block_shape_histogram("가개고과의");
+        assert_eq!(histogram.vertical_vowel, 2);
+        assert_eq!(histogram.horizontal_vowel, 1);
+        assert_eq!(histogram.mixed_vowel, 2);
+        assert_eq!(histogram.composite_vowel, 2);
+        assert_eq!(histogram.open, 5);
+    }
+
+    #[test]
+    fn fillers_of_mixed_kinds() {
+        let kinds: Vec<FillerKind> = detect_fillers("ㅋㅎㅋ ㅠㅠㅋㅋ ㄴㄴ ㅋ ㅇ")
+            .iter()
+            .map(|f| f.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                FillerKind::Laughter,
+                FillerKind::Crying,
+                FillerKind::Laughter,
+                FillerKind::No
+            ]
+        );
+        assert!(detect_fillers("ㅋㅋㄱ 닭").is_empty());
+        assert_eq!(detect_fillers("\u{110F}\u{110F}")[0].len, 6);
+    }
+
+    #[test]
+    fn removing_fillers() {
+        assert_eq!(remove_fillers("ㅋㅋㅋ"), "");
+        assert_eq!(remove_fillers("아 ㅋㅋ 진짜"), "아 진짜");
+        assert_eq!(remove_fillers("ㅇㅋ ㅋ"), "ㅇㅋ ㅋ");
+        assert_eq!(normalize_fillers("ㄴㄴㄴㄴ"), "ㄴㄴ");
+    }
+
+    #[test]
+    fn ngram_edge_cases() {
+        assert_eq!(ngrams("가나다", 0).count(), 0);
+        assert_eq!(ngrams("가나", 3).count(), 0);
+        assert_eq!(ngrams("", 1).count(), 0);
+        let unigrams: Vec<String> = ngrams("가, 나ㄱ다", 1).collect();
+        assert_eq!(unigrams, ["가", "나", "다"]);
+        let bigrams: Vec<String> = jamo_ngrams("ㅋㅋ 와!", 2).collect();
+        assert_eq!(bigrams, ["ㅋㅋ", "ㅇㅘ"]);
+    }
+
+    #[test]
+    fn vectors_match_frequencies() {
+        let text = "값진 과일, ㅋㅋ \u{1100}";
+        let freq = jamo_frequency(text);
+        let vector = jamo_vector(text);
+        for (i, count) in vector.iter().enumerate() {
+            let c = char::from_u32(0x3131 + i as u32).unwrap();
+            assert_eq!(*count as usize, freq.count(c), "{c}");
+        }
+        assert_eq!(vector.iter().sum::<u32>() as usize, freq.total());
+
+        let one_hot = syllable_one_hot('힣').unwrap();
+        assert_eq!(one_hot[18], 1);
+        assert_eq!(one_hot[39], 1);
+        assert_eq!(one_hot[SYLLABLE_ONE_HOT_LEN - 1], 1);
+    }
+}
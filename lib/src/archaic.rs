@@ -0,0 +1,1141 @@
+use thiserror::Error;
+
+use crate::block::{CompositionRules, HangulBlock, StandardRules};
+use crate::jamo::*;
+
+/// An error enum for errors related to archaic Jamo.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ArchaicError {
+    /// Character could not be converted to an archaic or modern Jamo.
+    #[error("Could not convert character '{0}' to archaic Jamo")]
+    FromCharError(char),
+
+    /// Occurs when a Jamo letter has no conjoining codepoint for the
+    /// requested position; for example, ㆍ in the initial position.
+    #[error("Jamo '{0}' is not valid in position '{1:?}'")]
+    InvalidJamoContext(char, JamoPosition),
+
+    /// Occurs when a sequence of conjoining Jamo does not form exactly
+    /// one syllable block.
+    #[error("Could not parse conjoining Jamo sequence '{0}' as a single block")]
+    InvalidSequence(String),
+}
+
+/// An enum representing archaic (obsolete) Hangul jamo that have a
+/// compatibility codepoint in the Hangul Compatibility Jamo block
+/// (U+3165 to U+318E).
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArchaicJamo {
+    /// ㅥ
+    SsangNieun,
+    /// ㅦ
+    NieunDigeut,
+    /// ㅧ
+    NieunSiot,
+    /// ㅨ
+    NieunBanSiot,
+    /// ㅩ
+    RieulGiyeokSiot,
+    /// ㅪ
+    RieulDigeut,
+    /// ㅫ
+    RieulBieupSiot,
+    /// ㅬ
+    RieulBanSiot,
+    /// ㅭ
+    RieulYeorinHieut,
+    /// ㅮ
+    MieumBieup,
+    /// ㅯ
+    MieumSiot,
+    /// ㅰ
+    MieumBanSiot,
+    /// ㅱ
+    GabyeounMieum,
+    /// ㅲ
+    BieupGiyeok,
+    /// ㅳ
+    BieupDigeut,
+    /// ㅴ
+    BieupSiotGiyeok,
+    /// ㅵ
+    BieupSiotDigeut,
+    /// ㅶ
+    BieupJieut,
+    /// ㅷ
+    BieupTieut,
+    /// ㅸ
+    GabyeounBieup,
+    /// ㅹ
+    GabyeounSsangBieup,
+    /// ㅺ
+    SiotGiyeok,
+    /// ㅻ
+    SiotNieun,
+    /// ㅼ
+    SiotDigeut,
+    /// ㅽ
+    SiotBieup,
+    /// ㅾ
+    SiotJieut,
+    /// ㅿ
+    BanSiot,
+    /// ㆀ
+    SsangIeung,
+    /// ㆁ
+    YetIeung,
+    /// ㆂ
+    YetIeungSiot,
+    /// ㆃ
+    YetIeungBanSiot,
+    /// ㆄ
+    GabyeounPieup,
+    /// ㆅ
+    SsangHieut,
+    /// ㆆ
+    YeorinHieut,
+    /// ㆇ
+    YoYa,
+    /// ㆈ
+    YoYae,
+    /// ㆉ
+    YoI,
+    /// ㆊ
+    YuYeo,
+    /// ㆋ
+    YuYe,
+    /// ㆌ
+    YuI,
+    /// ㆍ
+    AraeA,
+    /// ㆎ
+    AraeAe,
+}
+impl ArchaicJamo {
+    /// Creates an `ArchaicJamo` from either a compatibility jamo character
+    /// or a conjoining jamo character in any position. Conjoining jamo are
+    /// taken from the Hangul Jamo block (U+1100 to U+11FF) as well as the
+    /// Hangul Jamo Extended-A and Extended-B blocks, which hold a handful
+    /// of archaic clusters.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::archaic::ArchaicJamo;
+    ///
+    /// assert_eq!(ArchaicJamo::from_char('ㅿ'), Ok(ArchaicJamo::BanSiot));
+    /// assert_eq!(ArchaicJamo::from_char('\u{1140}'), Ok(ArchaicJamo::BanSiot)); // initial ᅀ
+    /// assert_eq!(ArchaicJamo::from_char('\u{11EB}'), Ok(ArchaicJamo::BanSiot)); // final ᇫ
+    /// ```
+    pub fn from_char(c: char) -> Result<Self, ArchaicError> {
+        match Self::from_modern_jamo_with_position(c) {
+            Some((jamo, _)) => Ok(jamo),
+            None => Self::from_compatibility_jamo(c),
+        }
+    }
+
+    /// Creates an `ArchaicJamo` from a conjoining jamo character in any
+    /// position.
+    pub fn from_modern_jamo(c: char) -> Result<Self, ArchaicError> {
+        match Self::from_modern_jamo_with_position(c) {
+            Some((jamo, _)) => Ok(jamo),
+            None => Err(ArchaicError::FromCharError(c)),
+        }
+    }
+
+    /// Returns the conjoining jamo character for the given position.
+    /// Returns `None` if the archaic jamo has no conjoining form in that
+    /// position; for example, ㅲ is only encoded as an initial, and vowels
+    /// are only encoded in the vowel position.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::archaic::ArchaicJamo;
+    /// use hangul_cd::jamo::JamoPosition;
+    ///
+    /// let yet_ieung = ArchaicJamo::YetIeung;
+    /// assert_eq!(yet_ieung.char_modern(JamoPosition::Initial), Some('\u{114C}'));
+    /// assert_eq!(yet_ieung.char_modern(JamoPosition::Final), Some('\u{11F0}'));
+    /// assert_eq!(yet_ieung.char_modern(JamoPosition::Vowel), None);
+    /// ```
+    pub fn char_modern(&self, position: JamoPosition) -> Option<char> {
+        match position {
+            JamoPosition::Initial => self.char_modern_initial(),
+            JamoPosition::Vowel => self.char_modern_vowel(),
+            JamoPosition::Final => self.char_modern_final(),
+        }
+    }
+
+    /// Returns the compatibility jamo character for this archaic jamo.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::archaic::ArchaicJamo;
+    ///
+    /// assert_eq!(ArchaicJamo::BanSiot.char_compatibility(), 'ㅿ');
+    /// ```
+    pub fn char_compatibility(&self) -> char {
+        match self {
+            ArchaicJamo::SsangNieun => '\u{3165}',         // ㅥ
+            ArchaicJamo::NieunDigeut => '\u{3166}',        // ㅦ
+            ArchaicJamo::NieunSiot => '\u{3167}',          // ㅧ
+            ArchaicJamo::NieunBanSiot => '\u{3168}',       // ㅨ
+            ArchaicJamo::RieulGiyeokSiot => '\u{3169}',    // ㅩ
+            ArchaicJamo::RieulDigeut => '\u{316A}',        // ㅪ
+            ArchaicJamo::RieulBieupSiot => '\u{316B}',     // ㅫ
+            ArchaicJamo::RieulBanSiot => '\u{316C}',       // ㅬ
+            ArchaicJamo::RieulYeorinHieut => '\u{316D}',   // ㅭ
+            ArchaicJamo::MieumBieup => '\u{316E}',         // ㅮ
+            ArchaicJamo::MieumSiot => '\u{316F}',          // ㅯ
+            ArchaicJamo::MieumBanSiot => '\u{3170}',       // ㅰ
+            ArchaicJamo::GabyeounMieum => '\u{3171}',      // ㅱ
+            ArchaicJamo::BieupGiyeok => '\u{3172}',        // ㅲ
+            ArchaicJamo::BieupDigeut => '\u{3173}',        // ㅳ
+            ArchaicJamo::BieupSiotGiyeok => '\u{3174}',    // ㅴ
+            ArchaicJamo::BieupSiotDigeut => '\u{3175}',    // ㅵ
+            ArchaicJamo::BieupJieut => '\u{3176}',         // ㅶ
+            ArchaicJamo::BieupTieut => '\u{3177}',         // ㅷ
+            ArchaicJamo::GabyeounBieup => '\u{3178}',      // ㅸ
+            ArchaicJamo::GabyeounSsangBieup => '\u{3179}', // ㅹ
+            ArchaicJamo::SiotGiyeok => '\u{317A}',         // ㅺ
+            ArchaicJamo::SiotNieun => '\u{317B}',          // ㅻ
+            ArchaicJamo::SiotDigeut => '\u{317C}',         // ㅼ
+            ArchaicJamo::SiotBieup => '\u{317D}',          // ㅽ
+            ArchaicJamo::SiotJieut => '\u{317E}',          // ㅾ
+            ArchaicJamo::BanSiot => '\u{317F}',            // ㅿ
+            ArchaicJamo::SsangIeung => '\u{3180}',         // ㆀ
+            ArchaicJamo::YetIeung => '\u{3181}',           // ㆁ
+            ArchaicJamo::YetIeungSiot => '\u{3182}',       // ㆂ
+            ArchaicJamo::YetIeungBanSiot => '\u{3183}',    // ㆃ
+            ArchaicJamo::GabyeounPieup => '\u{3184}',      // ㆄ
+            ArchaicJamo::SsangHieut => '\u{3185}',         // ㆅ
+            ArchaicJamo::YeorinHieut => '\u{3186}',        // ㆆ
+            ArchaicJamo::YoYa => '\u{3187}',               // ㆇ
+            ArchaicJamo::YoYae => '\u{3188}',              // ㆈ
+            ArchaicJamo::YoI => '\u{3189}',                // ㆉ
+            ArchaicJamo::YuYeo => '\u{318A}',              // ㆊ
+            ArchaicJamo::YuYe => '\u{318B}',               // ㆋ
+            ArchaicJamo::YuI => '\u{318C}',                // ㆌ
+            ArchaicJamo::AraeA => '\u{318D}',              // ㆍ
+            ArchaicJamo::AraeAe => '\u{318E}',             // ㆎ
+        }
+    }
+
+    fn char_modern_initial(&self) -> Option<char> {
+        match self {
+            ArchaicJamo::SsangNieun => Some('\u{1114}'),
+            ArchaicJamo::NieunDigeut => Some('\u{1115}'),
+            ArchaicJamo::NieunSiot => Some('\u{115B}'),
+            ArchaicJamo::RieulDigeut => Some('\u{A966}'),
+            ArchaicJamo::MieumBieup => Some('\u{111C}'),
+            ArchaicJamo::MieumSiot => Some('\u{A971}'),
+            ArchaicJamo::GabyeounMieum => Some('\u{111D}'),
+            ArchaicJamo::BieupGiyeok => Some('\u{111E}'),
+            ArchaicJamo::BieupDigeut => Some('\u{1120}'),
+            ArchaicJamo::BieupSiotGiyeok => Some('\u{1122}'),
+            ArchaicJamo::BieupSiotDigeut => Some('\u{1123}'),
+            ArchaicJamo::BieupJieut => Some('\u{1127}'),
+            ArchaicJamo::BieupTieut => Some('\u{1129}'),
+            ArchaicJamo::GabyeounBieup => Some('\u{112B}'),
+            ArchaicJamo::GabyeounSsangBieup => Some('\u{112C}'),
+            ArchaicJamo::SiotGiyeok => Some('\u{112D}'),
+            ArchaicJamo::SiotNieun => Some('\u{112E}'),
+            ArchaicJamo::SiotDigeut => Some('\u{112F}'),
+            ArchaicJamo::SiotBieup => Some('\u{1132}'),
+            ArchaicJamo::SiotJieut => Some('\u{1136}'),
+            ArchaicJamo::BanSiot => Some('\u{1140}'),
+            ArchaicJamo::SsangIeung => Some('\u{1147}'),
+            ArchaicJamo::YetIeung => Some('\u{114C}'),
+            ArchaicJamo::GabyeounPieup => Some('\u{1157}'),
+            ArchaicJamo::SsangHieut => Some('\u{1158}'),
+            ArchaicJamo::YeorinHieut => Some('\u{1159}'),
+            _ => None,
+        }
+    }
+
+    fn char_modern_vowel(&self) -> Option<char> {
+        match self {
+            ArchaicJamo::YoYa => Some('\u{1184}'),
+            ArchaicJamo::YoYae => Some('\u{1185}'),
+            ArchaicJamo::YoI => Some('\u{1188}'),
+            ArchaicJamo::YuYeo => Some('\u{1191}'),
+            ArchaicJamo::YuYe => Some('\u{1192}'),
+            ArchaicJamo::YuI => Some('\u{1194}'),
+            ArchaicJamo::AraeA => Some('\u{119E}'),
+            ArchaicJamo::AraeAe => Some('\u{11A1}'),
+            _ => None,
+        }
+    }
+
+    fn char_modern_final(&self) -> Option<char> {
+        match self {
+            ArchaicJamo::SsangNieun => Some('\u{11FF}'),
+            ArchaicJamo::NieunDigeut => Some('\u{11C6}'),
+            ArchaicJamo::NieunSiot => Some('\u{11C7}'),
+            ArchaicJamo::NieunBanSiot => Some('\u{11C8}'),
+            ArchaicJamo::RieulGiyeokSiot => Some('\u{11CC}'),
+            ArchaicJamo::RieulDigeut => Some('\u{11CE}'),
+            ArchaicJamo::RieulBieupSiot => Some('\u{11D3}'),
+            ArchaicJamo::RieulBanSiot => Some('\u{11D7}'),
+            ArchaicJamo::RieulYeorinHieut => Some('\u{11D9}'),
+            ArchaicJamo::MieumBieup => Some('\u{11DC}'),
+            ArchaicJamo::MieumSiot => Some('\u{11DD}'),
+            ArchaicJamo::MieumBanSiot => Some('\u{11DF}'),
+            ArchaicJamo::GabyeounMieum => Some('\u{11E2}'),
+            ArchaicJamo::BieupDigeut => Some('\u{D7E3}'),
+            ArchaicJamo::BieupSiotDigeut => Some('\u{D7E7}'),
+            ArchaicJamo::BieupJieut => Some('\u{D7E8}'),
+            ArchaicJamo::GabyeounBieup => Some('\u{11E6}'),
+            ArchaicJamo::SiotGiyeok => Some('\u{11E7}'),
+            ArchaicJamo::SiotDigeut => Some('\u{11E8}'),
+            ArchaicJamo::SiotBieup => Some('\u{11EA}'),
+            ArchaicJamo::SiotJieut => Some('\u{D7EF}'),
+            ArchaicJamo::BanSiot => Some('\u{11EB}'),
+            ArchaicJamo::SsangIeung => Some('\u{11EE}'),
+            ArchaicJamo::YetIeung => Some('\u{11F0}'),
+            ArchaicJamo::YetIeungSiot => Some('\u{11F1}'),
+            ArchaicJamo::YetIeungBanSiot => Some('\u{11F2}'),
+            ArchaicJamo::GabyeounPieup => Some('\u{11F4}'),
+            ArchaicJamo::YeorinHieut => Some('\u{11F9}'),
+            _ => None,
+        }
+    }
+
+    /// Creates an `ArchaicJamo` from a compatibility jamo character.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::archaic::ArchaicJamo;
+    ///
+    /// assert_eq!(ArchaicJamo::from_compatibility_jamo('ㆍ'), Ok(ArchaicJamo::AraeA));
+    /// assert!(ArchaicJamo::from_compatibility_jamo('ㄱ').is_err());
+    /// ```
+    pub fn from_compatibility_jamo(c: char) -> Result<Self, ArchaicError> {
+        match c {
+            '\u{3165}' => Ok(ArchaicJamo::SsangNieun),         // ㅥ
+            '\u{3166}' => Ok(ArchaicJamo::NieunDigeut),        // ㅦ
+            '\u{3167}' => Ok(ArchaicJamo::NieunSiot),          // ㅧ
+            '\u{3168}' => Ok(ArchaicJamo::NieunBanSiot),       // ㅨ
+            '\u{3169}' => Ok(ArchaicJamo::RieulGiyeokSiot),    // ㅩ
+            '\u{316A}' => Ok(ArchaicJamo::RieulDigeut),        // ㅪ
+            '\u{316B}' => Ok(ArchaicJamo::RieulBieupSiot),     // ㅫ
+            '\u{316C}' => Ok(ArchaicJamo::RieulBanSiot),       // ㅬ
+            '\u{316D}' => Ok(ArchaicJamo::RieulYeorinHieut),   // ㅭ
+            '\u{316E}' => Ok(ArchaicJamo::MieumBieup),         // ㅮ
+            '\u{316F}' => Ok(ArchaicJamo::MieumSiot),          // ㅯ
+            '\u{3170}' => Ok(ArchaicJamo::MieumBanSiot),       // ㅰ
+            '\u{3171}' => Ok(ArchaicJamo::GabyeounMieum),      // ㅱ
+            '\u{3172}' => Ok(ArchaicJamo::BieupGiyeok),        // ㅲ
+            '\u{3173}' => Ok(ArchaicJamo::BieupDigeut),        // ㅳ
+            '\u{3174}' => Ok(ArchaicJamo::BieupSiotGiyeok),    // ㅴ
+            '\u{3175}' => Ok(ArchaicJamo::BieupSiotDigeut),    // ㅵ
+            '\u{3176}' => Ok(ArchaicJamo::BieupJieut),         // ㅶ
+            '\u{3177}' => Ok(ArchaicJamo::BieupTieut),         // ㅷ
+            '\u{3178}' => Ok(ArchaicJamo::GabyeounBieup),      // ㅸ
+            '\u{3179}' => Ok(ArchaicJamo::GabyeounSsangBieup), // ㅹ
+            '\u{317A}' => Ok(ArchaicJamo::SiotGiyeok),         // ㅺ
+            '\u{317B}' => Ok(ArchaicJamo::SiotNieun),          // ㅻ
+            '\u{317C}' => Ok(ArchaicJamo::SiotDigeut),         // ㅼ
+            '\u{317D}' => Ok(ArchaicJamo::SiotBieup),          // ㅽ
+            '\u{317E}' => Ok(ArchaicJamo::SiotJieut),          // ㅾ
+            '\u{317F}' => Ok(ArchaicJamo::BanSiot),            // ㅿ
+            '\u{3180}' => Ok(ArchaicJamo::SsangIeung),         // ㆀ
+            '\u{3181}' => Ok(ArchaicJamo::YetIeung),           // ㆁ
+            '\u{3182}' => Ok(ArchaicJamo::YetIeungSiot),       // ㆂ
+            '\u{3183}' => Ok(ArchaicJamo::YetIeungBanSiot),    // ㆃ
+            '\u{3184}' => Ok(ArchaicJamo::GabyeounPieup),      // ㆄ
+            '\u{3185}' => Ok(ArchaicJamo::SsangHieut),         // ㆅ
+            '\u{3186}' => Ok(ArchaicJamo::YeorinHieut),        // ㆆ
+            '\u{3187}' => Ok(ArchaicJamo::YoYa),               // ㆇ
+            '\u{3188}' => Ok(ArchaicJamo::YoYae),              // ㆈ
+            '\u{3189}' => Ok(ArchaicJamo::YoI),                // ㆉ
+            '\u{318A}' => Ok(ArchaicJamo::YuYeo),              // ㆊ
+            '\u{318B}' => Ok(ArchaicJamo::YuYe),               // ㆋ
+            '\u{318C}' => Ok(ArchaicJamo::YuI),                // ㆌ
+            '\u{318D}' => Ok(ArchaicJamo::AraeA),              // ㆍ
+            '\u{318E}' => Ok(ArchaicJamo::AraeAe),             // ㆎ
+            _ => Err(ArchaicError::FromCharError(c)),
+        }
+    }
+
+    fn from_modern_jamo_with_position(c: char) -> Option<(Self, JamoPosition)> {
+        match c {
+            '\u{1114}' => Some((ArchaicJamo::SsangNieun, JamoPosition::Initial)),
+            '\u{1115}' => Some((ArchaicJamo::NieunDigeut, JamoPosition::Initial)),
+            '\u{111C}' => Some((ArchaicJamo::MieumBieup, JamoPosition::Initial)),
+            '\u{111D}' => Some((ArchaicJamo::GabyeounMieum, JamoPosition::Initial)),
+            '\u{111E}' => Some((ArchaicJamo::BieupGiyeok, JamoPosition::Initial)),
+            '\u{1120}' => Some((ArchaicJamo::BieupDigeut, JamoPosition::Initial)),
+            '\u{1122}' => Some((ArchaicJamo::BieupSiotGiyeok, JamoPosition::Initial)),
+            '\u{1123}' => Some((ArchaicJamo::BieupSiotDigeut, JamoPosition::Initial)),
+            '\u{1127}' => Some((ArchaicJamo::BieupJieut, JamoPosition::Initial)),
+            '\u{1129}' => Some((ArchaicJamo::BieupTieut, JamoPosition::Initial)),
+            '\u{112B}' => Some((ArchaicJamo::GabyeounBieup, JamoPosition::Initial)),
+            '\u{112C}' => Some((ArchaicJamo::GabyeounSsangBieup, JamoPosition::Initial)),
+            '\u{112D}' => Some((ArchaicJamo::SiotGiyeok, JamoPosition::Initial)),
+            '\u{112E}' => Some((ArchaicJamo::SiotNieun, JamoPosition::Initial)),
+            '\u{112F}' => Some((ArchaicJamo::SiotDigeut, JamoPosition::Initial)),
+            '\u{1132}' => Some((ArchaicJamo::SiotBieup, JamoPosition::Initial)),
+            '\u{1136}' => Some((ArchaicJamo::SiotJieut, JamoPosition::Initial)),
+            '\u{1140}' => Some((ArchaicJamo::BanSiot, JamoPosition::Initial)),
+            '\u{1147}' => Some((ArchaicJamo::SsangIeung, JamoPosition::Initial)),
+            '\u{114C}' => Some((ArchaicJamo::YetIeung, JamoPosition::Initial)),
+            '\u{1157}' => Some((ArchaicJamo::GabyeounPieup, JamoPosition::Initial)),
+            '\u{1158}' => Some((ArchaicJamo::SsangHieut, JamoPosition::Initial)),
+            '\u{1159}' => Some((ArchaicJamo::YeorinHieut, JamoPosition::Initial)),
+            '\u{115B}' => Some((ArchaicJamo::NieunSiot, JamoPosition::Initial)),
+            '\u{1184}' => Some((ArchaicJamo::YoYa, JamoPosition::Vowel)),
+            '\u{1185}' => Some((ArchaicJamo::YoYae, JamoPosition::Vowel)),
+            '\u{1188}' => Some((ArchaicJamo::YoI, JamoPosition::Vowel)),
+            '\u{1191}' => Some((ArchaicJamo::YuYeo, JamoPosition::Vowel)),
+            '\u{1192}' => Some((ArchaicJamo::YuYe, JamoPosition::Vowel)),
+            '\u{1194}' => Some((ArchaicJamo::YuI, JamoPosition::Vowel)),
+            '\u{119E}' => Some((ArchaicJamo::AraeA, JamoPosition::Vowel)),
+            '\u{11A1}' => Some((ArchaicJamo::AraeAe, JamoPosition::Vowel)),
+            '\u{11C6}' => Some((ArchaicJamo::NieunDigeut, JamoPosition::Final)),
+            '\u{11C7}' => Some((ArchaicJamo::NieunSiot, JamoPosition::Final)),
+            '\u{11C8}' => Some((ArchaicJamo::NieunBanSiot, JamoPosition::Final)),
+            '\u{11CC}' => Some((ArchaicJamo::RieulGiyeokSiot, JamoPosition::Final)),
+            '\u{11CE}' => Some((ArchaicJamo::RieulDigeut, JamoPosition::Final)),
+            '\u{11D3}' => Some((ArchaicJamo::RieulBieupSiot, JamoPosition::Final)),
+            '\u{11D7}' => Some((ArchaicJamo::RieulBanSiot, JamoPosition::Final)),
+            '\u{11D9}' => Some((ArchaicJamo::RieulYeorinHieut, JamoPosition::Final)),
+            '\u{11DC}' => Some((ArchaicJamo::MieumBieup, JamoPosition::Final)),
+            '\u{11DD}' => Some((ArchaicJamo::MieumSiot, JamoPosition::Final)),
+            '\u{11DF}' => Some((ArchaicJamo::MieumBanSiot, JamoPosition::Final)),
+            '\u{11E2}' => Some((ArchaicJamo::GabyeounMieum, JamoPosition::Final)),
+            '\u{11E6}' => Some((ArchaicJamo::GabyeounBieup, JamoPosition::Final)),
+            '\u{11E7}' => Some((ArchaicJamo::SiotGiyeok, JamoPosition::Final)),
+            '\u{11E8}' => Some((ArchaicJamo::SiotDigeut, JamoPosition::Final)),
+            '\u{11EA}' => Some((ArchaicJamo::SiotBieup, JamoPosition::Final)),
+            '\u{11EB}' => Some((ArchaicJamo::BanSiot, JamoPosition::Final)),
+            '\u{11EE}' => Some((ArchaicJamo::SsangIeung, JamoPosition::Final)),
+            '\u{11F0}' => Some((ArchaicJamo::YetIeung, JamoPosition::Final)),
+            '\u{11F1}' => Some((ArchaicJamo::YetIeungSiot, JamoPosition::Final)),
+            '\u{11F2}' => Some((ArchaicJamo::YetIeungBanSiot, JamoPosition::Final)),
+            '\u{11F4}' => Some((ArchaicJamo::GabyeounPieup, JamoPosition::Final)),
+            '\u{11F9}' => Some((ArchaicJamo::YeorinHieut, JamoPosition::Final)),
+            '\u{11FF}' => Some((ArchaicJamo::SsangNieun, JamoPosition::Final)),
+            '\u{A966}' => Some((ArchaicJamo::RieulDigeut, JamoPosition::Initial)),
+            '\u{A971}' => Some((ArchaicJamo::MieumSiot, JamoPosition::Initial)),
+            '\u{D7E3}' => Some((ArchaicJamo::BieupDigeut, JamoPosition::Final)),
+            '\u{D7E7}' => Some((ArchaicJamo::BieupSiotDigeut, JamoPosition::Final)),
+            '\u{D7E8}' => Some((ArchaicJamo::BieupJieut, JamoPosition::Final)),
+            '\u{D7EF}' => Some((ArchaicJamo::SiotJieut, JamoPosition::Final)),
+            _ => None,
+        }
+    }
+
+    /// Decomposes the archaic jamo into its constituent letters as
+    /// compatibility jamo characters. Clusters such as ㅴ decompose into
+    /// their component consonants (ㅂ, ㅅ, ㄱ), while singular archaic
+    /// letters such as ㅿ decompose into themselves.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::archaic::ArchaicJamo;
+    ///
+    /// assert_eq!(ArchaicJamo::BieupSiotGiyeok.decompose(), &['ㅂ', 'ㅅ', 'ㄱ']);
+    /// assert_eq!(ArchaicJamo::RieulBanSiot.decompose(), &['ㄹ', 'ㅿ']);
+    /// assert_eq!(ArchaicJamo::AraeA.decompose(), &['ㆍ']);
+    /// ```
+    pub fn decompose(&self) -> &'static [char] {
+        match self {
+            ArchaicJamo::SsangNieun => &['ㄴ', 'ㄴ'],
+            ArchaicJamo::NieunDigeut => &['ㄴ', 'ㄷ'],
+            ArchaicJamo::NieunSiot => &['ㄴ', 'ㅅ'],
+            ArchaicJamo::NieunBanSiot => &['ㄴ', 'ㅿ'],
+            ArchaicJamo::RieulGiyeokSiot => &['ㄹ', 'ㄱ', 'ㅅ'],
+            ArchaicJamo::RieulDigeut => &['ㄹ', 'ㄷ'],
+            ArchaicJamo::RieulBieupSiot => &['ㄹ', 'ㅂ', 'ㅅ'],
+            ArchaicJamo::RieulBanSiot => &['ㄹ', 'ㅿ'],
+            ArchaicJamo::RieulYeorinHieut => &['ㄹ', 'ㆆ'],
+            ArchaicJamo::MieumBieup => &['ㅁ', 'ㅂ'],
+            ArchaicJamo::MieumSiot => &['ㅁ', 'ㅅ'],
+            ArchaicJamo::MieumBanSiot => &['ㅁ', 'ㅿ'],
+            ArchaicJamo::GabyeounMieum => &['ㅁ', 'ㅇ'],
+            ArchaicJamo::BieupGiyeok => &['ㅂ', 'ㄱ'],
+            ArchaicJamo::BieupDigeut => &['ㅂ', 'ㄷ'],
+            ArchaicJamo::BieupSiotGiyeok => &['ㅂ', 'ㅅ', 'ㄱ'],
+            ArchaicJamo::BieupSiotDigeut => &['ㅂ', 'ㅅ', 'ㄷ'],
+            ArchaicJamo::BieupJieut => &['ㅂ', 'ㅈ'],
+            ArchaicJamo::BieupTieut => &['ㅂ', 'ㅌ'],
+            ArchaicJamo::GabyeounBieup => &['ㅂ', 'ㅇ'],
+            ArchaicJamo::GabyeounSsangBieup => &['ㅂ', 'ㅂ', 'ㅇ'],
+            ArchaicJamo::SiotGiyeok => &['ㅅ', 'ㄱ'],
+            ArchaicJamo::SiotNieun => &['ㅅ', 'ㄴ'],
+            ArchaicJamo::SiotDigeut => &['ㅅ', 'ㄷ'],
+            ArchaicJamo::SiotBieup => &['ㅅ', 'ㅂ'],
+            ArchaicJamo::SiotJieut => &['ㅅ', 'ㅈ'],
+            ArchaicJamo::BanSiot => &['ㅿ'],
+            ArchaicJamo::SsangIeung => &['ㅇ', 'ㅇ'],
+            ArchaicJamo::YetIeung => &['ㆁ'],
+            ArchaicJamo::YetIeungSiot => &['ㆁ', 'ㅅ'],
+            ArchaicJamo::YetIeungBanSiot => &['ㆁ', 'ㅿ'],
+            ArchaicJamo::GabyeounPieup => &['ㅍ', 'ㅇ'],
+            ArchaicJamo::SsangHieut => &['ㅎ', 'ㅎ'],
+            ArchaicJamo::YeorinHieut => &['ㆆ'],
+            ArchaicJamo::YoYa => &['ㅛ', 'ㅑ'],
+            ArchaicJamo::YoYae => &['ㅛ', 'ㅒ'],
+            ArchaicJamo::YoI => &['ㅛ', 'ㅣ'],
+            ArchaicJamo::YuYeo => &['ㅠ', 'ㅕ'],
+            ArchaicJamo::YuYe => &['ㅠ', 'ㅖ'],
+            ArchaicJamo::YuI => &['ㅠ', 'ㅣ'],
+            ArchaicJamo::AraeA => &['ㆍ'],
+            ArchaicJamo::AraeAe => &['ㆍ', 'ㅣ'],
+        }
+    }
+
+    /// Returns `true` if this archaic jamo is a vowel.
+    pub fn is_vowel(&self) -> bool {
+        matches!(
+            self,
+            ArchaicJamo::YoYa
+                | ArchaicJamo::YoYae
+                | ArchaicJamo::YoI
+                | ArchaicJamo::YuYeo
+                | ArchaicJamo::YuYe
+                | ArchaicJamo::YuI
+                | ArchaicJamo::AraeA
+                | ArchaicJamo::AraeAe
+        )
+    }
+}
+
+// The conjoining initial ᄡ, which `Jamo::char_modern` does not give since
+// ㅄ is only a final in modern Korean.
+const BIEUP_SIOT_INITIAL: char = '\u{1121}';
+
+/// An enum representing any letter usable in a historical syllable block:
+/// either a modern `Jamo` or an `ArchaicJamo`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HistoricalJamo {
+    Modern(Jamo),
+    Archaic(ArchaicJamo),
+}
+
+impl HistoricalJamo {
+    /// Classifies a compatibility or conjoining jamo character as either a
+    /// modern or an archaic jamo.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::archaic::{ArchaicJamo, HistoricalJamo};
+    /// use hangul_cd::jamo::{Jamo, JamoConsonantSingular};
+    ///
+    /// assert_eq!(
+    ///     HistoricalJamo::from_char('ㄱ'),
+    ///     Ok(HistoricalJamo::Modern(Jamo::Consonant(JamoConsonantSingular::Giyeok)))
+    /// );
+    /// assert_eq!(
+    ///     HistoricalJamo::from_char('ㆆ'),
+    ///     Ok(HistoricalJamo::Archaic(ArchaicJamo::YeorinHieut))
+    /// );
+    /// assert!(HistoricalJamo::from_char('A').is_err());
+    /// ```
+    pub fn from_char(c: char) -> Result<Self, ArchaicError> {
+        match Character::from_char(c) {
+            Ok(Character::Hangul(jamo)) => Ok(HistoricalJamo::Modern(jamo)),
+            _ => Ok(HistoricalJamo::Archaic(ArchaicJamo::from_char(c)?)),
+        }
+    }
+
+    /// Returns the compatibility jamo character for this letter.
+    pub fn char_compatibility(&self) -> char {
+        match self {
+            HistoricalJamo::Modern(jamo) => jamo.char_compatibility(),
+            HistoricalJamo::Archaic(jamo) => jamo.char_compatibility(),
+        }
+    }
+
+    /// Returns the conjoining jamo character for this letter in the given
+    /// position, or `None` if it cannot appear in that position. Unlike
+    /// `Jamo::char_modern`, this includes ㅄ as an initial (U+1121), as in
+    /// the 15th-century spelling ᄡᆞᆯ for 쌀.
+    pub fn char_modern(&self, position: JamoPosition) -> Option<char> {
+        match self {
+            HistoricalJamo::Modern(Jamo::CompositeConsonant(JamoConsonantComposite::BieupSiot))
+                if position == JamoPosition::Initial =>
+            {
+                Some(BIEUP_SIOT_INITIAL)
+            }
+            HistoricalJamo::Modern(jamo) => jamo.char_modern(position),
+            HistoricalJamo::Archaic(jamo) => jamo.char_modern(position),
+        }
+    }
+
+    /// Returns `true` if this letter is a vowel.
+    pub fn is_vowel(&self) -> bool {
+        match self {
+            HistoricalJamo::Modern(jamo) => {
+                matches!(jamo, Jamo::Vowel(_) | Jamo::CompositeVowel(_))
+            }
+            HistoricalJamo::Archaic(jamo) => jamo.is_vowel(),
+        }
+    }
+
+    fn from_modern_jamo_with_position(c: char) -> Option<(Self, JamoPosition)> {
+        let position = match c as u32 {
+            0x1100..=0x1112 => JamoPosition::Initial,
+            0x1161..=0x1175 => JamoPosition::Vowel,
+            0x11A8..=0x11C2 => JamoPosition::Final,
+            _ if c == BIEUP_SIOT_INITIAL => {
+                return Some((
+                    HistoricalJamo::Modern(Jamo::CompositeConsonant(
+                        JamoConsonantComposite::BieupSiot,
+                    )),
+                    JamoPosition::Initial,
+                ));
+            }
+            _ => {
+                return ArchaicJamo::from_modern_jamo_with_position(c)
+                    .map(|(jamo, position)| (HistoricalJamo::Archaic(jamo), position));
+            }
+        };
+        Jamo::from_modern_jamo(c)
+            .ok()
+            .map(|jamo| (HistoricalJamo::Modern(jamo), position))
+    }
+}
+
+/// A struct representing a syllable block which may contain archaic jamo.
+/// Archaic syllables have no precomposed Unicode codepoint, so they are
+/// represented as a sequence of conjoining jamo (Unicode NFD form).
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::archaic::{ArchaicBlock, ArchaicJamo, HistoricalJamo};
+///
+/// // ᄒᆞᆫ (an archaic spelling of 한)
+/// let block = ArchaicBlock {
+///     initial: HistoricalJamo::from_char('ㅎ').unwrap(),
+///     vowel: HistoricalJamo::Archaic(ArchaicJamo::AraeA),
+///     final_optional: Some(HistoricalJamo::from_char('ㄴ').unwrap()),
+/// };
+/// assert_eq!(block.to_conjoining().unwrap(), "\u{1112}\u{119E}\u{11AB}");
+///
+/// // Conjoining sequences can be parsed back into blocks
+/// let parsed = ArchaicBlock::from_conjoining("\u{1112}\u{119E}\u{11AB}").unwrap();
+/// assert_eq!(parsed, block);
+///
+/// // Blocks made only of modern jamo are composed into a precomposed syllable
+/// let modern = ArchaicBlock::from_conjoining("\u{1112}\u{1161}\u{11AB}").unwrap();
+/// assert_eq!(modern.as_string().unwrap(), "한");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArchaicBlock {
+    pub initial: HistoricalJamo,
+    pub vowel: HistoricalJamo,
+    pub final_optional: Option<HistoricalJamo>,
+}
+
+impl ArchaicBlock {
+    /// Returns `true` if every letter in the block is a modern jamo, meaning
+    /// the block can be represented as a precomposed Hangul syllable.
+    pub fn is_modern(&self) -> bool {
+        matches!(self.initial, HistoricalJamo::Modern(_))
+            && matches!(self.vowel, HistoricalJamo::Modern(_))
+            && !matches!(self.final_optional, Some(HistoricalJamo::Archaic(_)))
+    }
+
+    /// Converts the block into a sequence of conjoining jamo (NFD form).
+    /// Returns an error if any letter cannot appear in its position.
+    pub fn to_conjoining(&self) -> Result<String, ArchaicError> {
+        let mut result = String::new();
+        result.push(Self::conjoining_char(&self.initial, JamoPosition::Initial)?);
+        result.push(Self::conjoining_char(&self.vowel, JamoPosition::Vowel)?);
+        if let Some(final_jamo) = &self.final_optional {
+            result.push(Self::conjoining_char(final_jamo, JamoPosition::Final)?);
+        }
+        Ok(result)
+    }
+
+    /// Converts the block into a string, using a precomposed Hangul syllable
+    /// if the block is made only of modern jamo, and a conjoining jamo
+    /// sequence otherwise.
+    pub fn as_string(&self) -> Result<String, ArchaicError> {
+        match self.to_modern_block() {
+            Some(block) => Ok(block.to_char().to_string()),
+            None => self.to_conjoining(),
+        }
+    }
+
+    /// Parses a sequence of conjoining jamo (an initial, a vowel, and an
+    /// optional final) into an `ArchaicBlock`.
+    pub fn from_conjoining(s: &str) -> Result<Self, ArchaicError> {
+        let mut letters = Vec::new();
+        for c in s.chars() {
+            match HistoricalJamo::from_modern_jamo_with_position(c) {
+                Some(letter) => letters.push(letter),
+                None => return Err(ArchaicError::FromCharError(c)),
+            }
+        }
+
+        let mut letters = letters.into_iter();
+        match (
+            letters.next(),
+            letters.next(),
+            letters.next(),
+            letters.next(),
+        ) {
+            (
+                Some((initial, JamoPosition::Initial)),
+                Some((vowel, JamoPosition::Vowel)),
+                final_optional,
+                None,
+            ) => match final_optional {
+                None => Ok(ArchaicBlock {
+                    initial,
+                    vowel,
+                    final_optional: None,
+                }),
+                Some((final_jamo, JamoPosition::Final)) => Ok(ArchaicBlock {
+                    initial,
+                    vowel,
+                    final_optional: Some(final_jamo),
+                }),
+                Some(_) => Err(ArchaicError::InvalidSequence(s.to_string())),
+            },
+            _ => Err(ArchaicError::InvalidSequence(s.to_string())),
+        }
+    }
+
+    fn to_modern_block(&self) -> Option<HangulBlock> {
+        let initial = match &self.initial {
+            HistoricalJamo::Modern(jamo) => *jamo,
+            HistoricalJamo::Archaic(_) => return None,
+        };
+        let vowel = match &self.vowel {
+            HistoricalJamo::Modern(jamo) => *jamo,
+            HistoricalJamo::Archaic(_) => return None,
+        };
+        let final_optional = match &self.final_optional {
+            Some(HistoricalJamo::Modern(jamo)) => Some(*jamo),
+            Some(HistoricalJamo::Archaic(_)) => return None,
+            None => None,
+        };
+        HangulBlock::try_new(initial, vowel, final_optional).ok()
+    }
+
+    fn conjoining_char(
+        letter: &HistoricalJamo,
+        position: JamoPosition,
+    ) -> Result<char, ArchaicError> {
+        match letter.char_modern(position.clone()) {
+            Some(c) => Ok(c),
+            None => Err(ArchaicError::InvalidJamoContext(
+                letter.char_compatibility(),
+                position,
+            )),
+        }
+    }
+}
+
+/// The `CompositionRules` of 15th-century orthography, as in the
+/// 훈민정음 and early printed texts. Besides the standard rules, these allow:
+///
+/// - initial clusters with ㅂ and ㅅ, such as ㅄ, ㅲ, ㅺ, and the
+///   three-letter ㅴ and ㅵ, along with the doubles ㅥ, ㆀ, and ㆅ and the
+///   light labials (순경음) ㅱ, ㅸ, ㅹ, and ㆄ;
+/// - the vowel ㆎ (ㆍ + ㅣ) and the composites of ㅛ and ㅠ, such as ㆇ;
+/// - archaic final clusters, such as ㅧ, ㅩ, and ㆃ.
+///
+/// Used with `compose_archaic` to write historical text, or with
+/// `HangulWordComposer::with_rules`, which only composes modern syllables.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArchaicRules;
+
+// The combinations `ArchaicRules` adds to the standard rules, as
+// (first, second, result) compatibility jamo.
+const ARCHAIC_INITIALS: [(char, char, char); 19] = [
+    ('ㄴ', 'ㄴ', 'ㅥ'),
+    ('ㅁ', 'ㅇ', 'ㅱ'),
+    ('ㅂ', 'ㄱ', 'ㅲ'),
+    ('ㅂ', 'ㄷ', 'ㅳ'),
+    ('ㅂ', 'ㅅ', 'ㅄ'),
+    ('ㅄ', 'ㄱ', 'ㅴ'),
+    ('ㅄ', 'ㄷ', 'ㅵ'),
+    ('ㅂ', 'ㅈ', 'ㅶ'),
+    ('ㅂ', 'ㅌ', 'ㅷ'),
+    ('ㅂ', 'ㅇ', 'ㅸ'),
+    ('ㅃ', 'ㅇ', 'ㅹ'),
+    ('ㅅ', 'ㄱ', 'ㅺ'),
+    ('ㅅ', 'ㄴ', 'ㅻ'),
+    ('ㅅ', 'ㄷ', 'ㅼ'),
+    ('ㅅ', 'ㅂ', 'ㅽ'),
+    ('ㅅ', 'ㅈ', 'ㅾ'),
+    ('ㅇ', 'ㅇ', 'ㆀ'),
+    ('ㅍ', 'ㅇ', 'ㆄ'),
+    ('ㅎ', 'ㅎ', 'ㆅ'),
+];
+
+const ARCHAIC_VOWELS: [(char, char, char); 7] = [
+    ('ㅛ', 'ㅑ', 'ㆇ'),
+    ('ㅛ', 'ㅒ', 'ㆈ'),
+    ('ㅛ', 'ㅣ', 'ㆉ'),
+    ('ㅠ', 'ㅕ', 'ㆊ'),
+    ('ㅠ', 'ㅖ', 'ㆋ'),
+    ('ㅠ', 'ㅣ', 'ㆌ'),
+    ('ㆍ', 'ㅣ', 'ㆎ'),
+];
+
+const ARCHAIC_FINALS: [(char, char, char); 15] = [
+    ('ㄴ', 'ㄷ', 'ㅦ'),
+    ('ㄴ', 'ㅅ', 'ㅧ'),
+    ('ㄴ', 'ㅿ', 'ㅨ'),
+    ('ㄺ', 'ㅅ', 'ㅩ'),
+    ('ㄹ', 'ㄷ', 'ㅪ'),
+    ('ㄼ', 'ㅅ', 'ㅫ'),
+    ('ㄹ', 'ㅿ', 'ㅬ'),
+    ('ㄹ', 'ㆆ', 'ㅭ'),
+    ('ㅁ', 'ㅂ', 'ㅮ'),
+    ('ㅁ', 'ㅅ', 'ㅯ'),
+    ('ㅁ', 'ㅿ', 'ㅰ'),
+    ('ㅁ', 'ㅇ', 'ㅱ'),
+    ('ㅂ', 'ㅇ', 'ㅸ'),
+    ('ㆁ', 'ㅅ', 'ㆂ'),
+    ('ㆁ', 'ㅿ', 'ㆃ'),
+];
+
+// Looks up the combination of `first` and `second` in `table`.
+fn archaic_combination(table: &[(char, char, char)], first: char, second: char) -> Option<char> {
+    table
+        .iter()
+        .find(|&&(a, b, _)| a == first && b == second)
+        .map(|&(_, _, result)| result)
+}
+
+impl CompositionRules for ArchaicRules {
+    fn combine_initial(&self, first: char, second: char) -> Option<char> {
+        archaic_combination(&ARCHAIC_INITIALS, first, second)
+            .or_else(|| StandardRules.combine_initial(first, second))
+    }
+
+    fn combine_vowel(&self, first: char, second: char) -> Option<char> {
+        archaic_combination(&ARCHAIC_VOWELS, first, second)
+            .or_else(|| StandardRules.combine_vowel(first, second))
+    }
+
+    fn combine_final(&self, first: char, second: char) -> Option<char> {
+        archaic_combination(&ARCHAIC_FINALS, first, second)
+            .or_else(|| StandardRules.combine_final(first, second))
+    }
+}
+
+/// Composes the compatibility jamo in `text`, modern or archaic, into
+/// syllable blocks using `ArchaicRules`. See `compose_archaic_with_rules`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::archaic::compose_archaic;
+///
+/// // 나랏말ᄊᆞ미, from the preface of the 훈민정음
+/// assert_eq!(
+///     compose_archaic("ㄴㅏㄹㅏㅅㅁㅏㄹㅆㆍㅁㅣ"),
+///     "\u{1102}\u{1161}\u{1105}\u{1161}\u{11BA}\u{1106}\u{1161}\u{11AF}\u{110A}\u{119E}\u{1106}\u{1175}"
+/// );
+///
+/// // ᄡᆞᆯ (쌀), with the initial cluster ㅄ
+/// assert_eq!(compose_archaic("ㅂㅅㆍㄹ"), "\u{1121}\u{119E}\u{11AF}");
+/// ```
+pub fn compose_archaic(text: &str) -> String {
+    compose_archaic_with_rules(text, &ArchaicRules)
+}
+
+/// Composes the compatibility jamo in `text`, modern or archaic, into
+/// syllable blocks using `rules`, the way they would be typed: a consonant
+/// followed by a vowel starts a new block, taking the last consonant from
+/// the final of the block before it. Letters are only combined when the
+/// result has a conjoining jamo for its position.
+///
+/// Every block is written as a sequence of conjoining jamo (U+1100 to
+/// U+11FF and the extended blocks), since archaic syllables have no
+/// precomposed form; `string::normalize_hangul` recomposes the blocks that
+/// are modern syllables. Jamo that do not form a block, and every other
+/// character, are kept as they are.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::archaic::compose_archaic_with_rules;
+/// use hangul_cd::block::StandardRules;
+///
+/// // Standard rules do not allow ㅂ and ㅅ to begin a syllable
+/// assert_eq!(
+///     compose_archaic_with_rules("ㅂㅅㆍㄹ!", &StandardRules),
+///     "ㅂ\u{1109}\u{119E}\u{11AF}!"
+/// );
+/// ```
+pub fn compose_archaic_with_rules<R: CompositionRules + ?Sized>(text: &str, rules: &R) -> String {
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut block = PendingBlock::default();
+    for c in text.chars() {
+        match HistoricalJamo::from_char(c) {
+            Ok(letter) if is_compatibility_jamo(c) => {
+                if letter.is_vowel() {
+                    block.push_vowel(c, rules, &mut result);
+                } else {
+                    block.push_consonant(c, rules, &mut result);
+                }
+            }
+            _ => {
+                block.flush(&mut result);
+                result.push(c);
+            }
+        }
+    }
+    block.flush(&mut result);
+    result
+}
+
+// Returns whether `c` is in the Hangul Compatibility Jamo block.
+fn is_compatibility_jamo(c: char) -> bool {
+    ('\u{3131}'..='\u{318E}').contains(&c)
+}
+
+// Returns the conjoining jamo for the compatibility jamo `c` in `position`.
+fn conjoining_jamo(c: char, position: JamoPosition) -> Option<char> {
+    HistoricalJamo::from_char(c).ok()?.char_modern(position)
+}
+
+// A block being composed by `compose_archaic_with_rules`, as compatibility
+// jamo. Each letter added to the final is kept along with the final it
+// formed, so that the last one can move to the next block.
+#[derive(Default)]
+struct PendingBlock {
+    initial: Option<char>,
+    vowel: Option<char>,
+    finals: Vec<(char, char)>,
+}
+
+impl PendingBlock {
+    fn push_consonant<R: CompositionRules + ?Sized>(
+        &mut self,
+        c: char,
+        rules: &R,
+        result: &mut String,
+    ) {
+        match (self.initial, self.vowel, self.finals.last()) {
+            (Some(initial), None, _) => {
+                let combined = rules
+                    .combine_initial(initial, c)
+                    .filter(|&i| conjoining_jamo(i, JamoPosition::Initial).is_some());
+                if combined.is_some() {
+                    self.initial = combined;
+                    return;
+                }
+            }
+            (Some(_), Some(_), last) => {
+                let combined = match last {
+                    Some(&(last, _)) => rules.combine_final(last, c),
+                    None => Some(c),
+                }
+                .filter(|&f| conjoining_jamo(f, JamoPosition::Final).is_some());
+                if let Some(f) = combined {
+                    self.finals.push((f, c));
+                    return;
+                }
+            }
+            _ => {}
+        }
+        self.flush(result);
+        if conjoining_jamo(c, JamoPosition::Initial).is_some() {
+            self.initial = Some(c);
+        } else {
+            result.push(c);
+        }
+    }
+
+    fn push_vowel<R: CompositionRules + ?Sized>(
+        &mut self,
+        c: char,
+        rules: &R,
+        result: &mut String,
+    ) {
+        match (self.initial, self.vowel, self.finals.last()) {
+            (_, Some(vowel), None) => {
+                let combined = rules
+                    .combine_vowel(vowel, c)
+                    .filter(|&v| conjoining_jamo(v, JamoPosition::Vowel).is_some());
+                if combined.is_some() {
+                    self.vowel = combined;
+                    return;
+                }
+                self.flush(result);
+            }
+            (_, Some(_), Some(&(_, moved))) => {
+                if conjoining_jamo(moved, JamoPosition::Initial).is_some() {
+                    self.finals.pop();
+                    self.flush(result);
+                    self.initial = Some(moved);
+                } else {
+                    self.flush(result);
+                }
+            }
+            (_, None, _) => {}
+        }
+        self.vowel = Some(c);
+    }
+
+    // Writes the block to `result` and clears it. A block with an initial
+    // and a vowel is written as conjoining jamo, and a lone letter as its
+    // compatibility jamo.
+    fn flush(&mut self, result: &mut String) {
+        let final_optional = self.finals.last().map(|&(f, _)| f);
+        match (self.initial.take(), self.vowel.take()) {
+            (Some(initial), Some(vowel)) => {
+                let letters = [
+                    (Some(initial), JamoPosition::Initial),
+                    (Some(vowel), JamoPosition::Vowel),
+                    (final_optional, JamoPosition::Final),
+                ];
+                for (letter, position) in letters {
+                    // Infallible: letters are only added to a block in a
+                    // position they have a conjoining jamo for
+                    result.extend(letter.map(|c| conjoining_jamo(c, position).unwrap()));
+                }
+            }
+            (initial, vowel) => result.extend(initial.or(vowel)),
+        }
+        self.finals.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_char_compatibility_and_conjoining_agree() {
+        for cp in 0x3165..=0x318E {
+            let c = char::from_u32(cp).unwrap();
+            let jamo = ArchaicJamo::from_compatibility_jamo(c).unwrap();
+            assert_eq!(jamo.char_compatibility(), c);
+            for position in [
+                JamoPosition::Initial,
+                JamoPosition::Vowel,
+                JamoPosition::Final,
+            ] {
+                if let Some(m) = jamo.char_modern(position) {
+                    assert_eq!(
+                        ArchaicJamo::from_char(m),
+                        Ok(jamo.clone()),
+                        "Failed on conjoining form U+{:04X} of {}",
+                        m as u32,
+                        c
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_archaic_jamo_has_a_conjoining_form() {
+        for cp in 0x3165..=0x318E {
+            let jamo = ArchaicJamo::from_char(char::from_u32(cp).unwrap()).unwrap();
+            assert!(
+                jamo.char_modern(JamoPosition::Initial).is_some()
+                    || jamo.char_modern(JamoPosition::Vowel).is_some()
+                    || jamo.char_modern(JamoPosition::Final).is_some(),
+                "No conjoining form for {:?}",
+                jamo
+            );
+        }
+    }
+
+    #[test]
+    fn modern_jamo_are_not_archaic() {
+        for c in "ㄱㄲㅏㅘㄳ\u{1100}\u{1161}\u{11A8}A".chars() {
+            assert_eq!(
+                ArchaicJamo::from_char(c),
+                Err(ArchaicError::FromCharError(c))
+            );
+        }
+    }
+
+    #[test]
+    fn cluster_initial_block_to_conjoining() {
+        // ᄢᅳᆷ (an archaic spelling of 꿈)
+        let block = ArchaicBlock {
+            initial: HistoricalJamo::Archaic(ArchaicJamo::BieupSiotGiyeok),
+            vowel: HistoricalJamo::from_char('ㅡ').unwrap(),
+            final_optional: Some(HistoricalJamo::from_char('ㅁ').unwrap()),
+        };
+        assert!(!block.is_modern());
+        assert_eq!(block.to_conjoining().unwrap(), "\u{1122}\u{1173}\u{11B7}");
+        assert_eq!(block.as_string().unwrap(), "\u{1122}\u{1173}\u{11B7}");
+    }
+
+    #[test]
+    fn invalid_position_is_an_error() {
+        let block = ArchaicBlock {
+            initial: HistoricalJamo::Archaic(ArchaicJamo::AraeA),
+            vowel: HistoricalJamo::from_char('ㅏ').unwrap(),
+            final_optional: None,
+        };
+        assert_eq!(
+            block.to_conjoining(),
+            Err(ArchaicError::InvalidJamoContext(
+                'ㆍ',
+                JamoPosition::Initial
+            ))
+        );
+    }
+
+    #[test]
+    fn from_conjoining_rejects_malformed_sequences() {
+        assert!(ArchaicBlock::from_conjoining("\u{119E}").is_err());
+        assert!(ArchaicBlock::from_conjoining("\u{1100}\u{11A8}").is_err());
+        assert!(ArchaicBlock::from_conjoining("\u{1100}\u{1161}\u{11A8}\u{11A8}").is_err());
+        assert!(ArchaicBlock::from_conjoining("가").is_err());
+    }
+
+    #[test]
+    fn archaic_rules_form_conjoining_jamo() {
+        let tables = [
+            (&ARCHAIC_INITIALS[..], JamoPosition::Initial),
+            (&ARCHAIC_VOWELS[..], JamoPosition::Vowel),
+            (&ARCHAIC_FINALS[..], JamoPosition::Final),
+        ];
+        for (table, position) in tables {
+            for &(first, second, result) in table {
+                assert!(
+                    conjoining_jamo(result, position.clone()).is_some(),
+                    "{first} + {second} = {result} has no conjoining form"
+                );
+            }
+        }
+        assert_eq!(ArchaicRules.combine_initial('ㄱ', 'ㄱ'), Some('ㄲ'));
+        assert_eq!(ArchaicRules.combine_final('ㄹ', 'ㄱ'), Some('ㄺ'));
+        assert_eq!(ArchaicRules.combine_vowel('ㆍ', 'ㅏ'), None);
+    }
+
+    #[test]
+    fn compose_archaic_moves_finals() {
+        // ᄠᅢ, with the initial cluster ㅼ typed as ㅅ and ㄷ
+        assert_eq!(compose_archaic("ㅅㄷㅐ"), "\u{112F}\u{1162}");
+        // ᄞᆞᆯ, with ㅵ typed as ㅂ, ㅅ, and ㄷ
+        assert_eq!(compose_archaic("ㅂㅅㄷㆍㄹ"), "\u{1123}\u{119E}\u{11AF}");
+        // The last letter of a final cluster moves to the next block
+        assert_eq!(
+            compose_archaic("ㄷㅏㄹㄱㅏ"),
+            "\u{1103}\u{1161}\u{11AF}\u{1100}\u{1161}"
+        );
+        // A three-letter final, ㄹ + ㄱ + ㅅ
+        assert_eq!(compose_archaic("ㄷㅏㄹㄱㅅ"), "\u{1103}\u{1161}\u{11CC}");
+        // ㅸ as an initial, and the vowel ㆎ
+        assert_eq!(compose_archaic("ㅂㅇㆍㅣ"), "\u{112B}\u{11A1}");
+        // Lone jamo and other characters are kept
+        assert_eq!(compose_archaic("ㆍ ㄳ, ㅋㅋ"), "ㆍ ㄳ, ㅋㅋ");
+        assert_eq!(compose_archaic(""), "");
+    }
+
+    #[test]
+    fn bieup_siot_initial_round_trips() {
+        let block = ArchaicBlock::from_conjoining("\u{1121}\u{119E}\u{11AF}").unwrap();
+        assert_eq!(block.initial.char_compatibility(), 'ㅄ');
+        assert_eq!(block.to_conjoining().unwrap(), "\u{1121}\u{119E}\u{11AF}");
+    }
+}
@@ -0,0 +1,291 @@
+use crate::jamo::{Character, modern_to_compatibility_jamo};
+use crate::string::{ComposerOptions, StringComposer, StringError};
+
+/// A correction made by an `AutocorrectComposer`: a sequence of jamo
+/// keystrokes that is replaced as soon as it is typed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorrectionRule {
+    /// The keystrokes to replace, as compatibility jamo, such as `"ㅐㅣ"`.
+    pub typed: String,
+
+    /// The keystrokes typed in their place, such as `"ㅒ"`.
+    pub replacement: String,
+}
+
+impl CorrectionRule {
+    /// Creates a rule replacing the keystrokes `typed` with `replacement`.
+    pub fn new(typed: &str, replacement: &str) -> Self {
+        CorrectionRule {
+            typed: typed.to_string(),
+            replacement: replacement.to_string(),
+        }
+    }
+
+    /// Returns the rules used by `AutocorrectComposer::new`, for mistakes
+    /// common when typing on a physical keyboard:
+    ///
+    /// - a vowel tapped twice, such as ㅏㅏ for ㅏ. ㅜㅜ, ㅠㅠ, and ㅡㅡ are
+    ///   left alone, since they are often typed on purpose after a word, as
+    ///   in 너무ㅜㅜ;
+    /// - ㅒ and ㅖ typed as ㅐ or ㅑ and ㅔ or ㅕ followed by ㅣ, as they are
+    ///   drawn, rather than with Shift.
+    pub fn common() -> Vec<CorrectionRule> {
+        const RULES: [(&str, &str); 13] = [
+            ("ㅏㅏ", "ㅏ"),
+            ("ㅑㅑ", "ㅑ"),
+            ("ㅓㅓ", "ㅓ"),
+            ("ㅕㅕ", "ㅕ"),
+            ("ㅗㅗ", "ㅗ"),
+            ("ㅛㅛ", "ㅛ"),
+            ("ㅣㅣ", "ㅣ"),
+            ("ㅐㅐ", "ㅐ"),
+            ("ㅔㅔ", "ㅔ"),
+            ("ㅐㅣ", "ㅒ"),
+            ("ㅑㅣ", "ㅒ"),
+            ("ㅔㅣ", "ㅖ"),
+            ("ㅕㅣ", "ㅖ"),
+        ];
+        RULES
+            .iter()
+            .map(|&(typed, replacement)| CorrectionRule::new(typed, replacement))
+            .collect()
+    }
+
+    // Returns whether typing `letter` after `keystrokes` completes `typed`.
+    fn matches(&self, keystrokes: &[char], letter: char) -> bool {
+        let mut typed = self.typed.chars().rev();
+        typed.next() == Some(letter)
+            && typed.clone().count() <= keystrokes.len()
+            && typed.zip(keystrokes.iter().rev()).all(|(a, &b)| a == b)
+    }
+}
+
+/// An event emitted by an `AutocorrectComposer` when it corrects what was
+/// typed, so that a UI can show or offer to undo the change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Corrected {
+    /// The keystrokes that were replaced, including the one just typed.
+    pub typed: String,
+
+    /// The keystrokes typed in their place.
+    pub replacement: String,
+}
+
+/// A `StringComposer` that fixes common typing mistakes as they are typed,
+/// using a list of `CorrectionRule`s. When the jamo typed into the word
+/// being composed end with the keystrokes of a rule, they are replaced by
+/// the rule's replacement, and `push_char` returns a `Corrected` event.
+///
+/// Rules only apply within the word being composed, so jamo typed on their
+/// own, such as ㅠㅠ, are never corrected.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::autocorrect::{AutocorrectComposer, CorrectionRule};
+///
+/// let mut composer = AutocorrectComposer::new();
+/// let mut events = Vec::new();
+/// for c in "ㅇㅏㅏㄴㄴㅕㅇ ㄱㅐㅣ".chars() {
+///     events.extend(composer.push_char(c).unwrap());
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "안녕 걔");
+/// assert_eq!(events.len(), 2);
+/// assert_eq!((events[0].typed.as_str(), events[0].replacement.as_str()), ("ㅏㅏ", "ㅏ"));
+/// assert_eq!(events[1].replacement, "ㅒ");
+///
+/// // Custom rules replace the common ones
+/// let rules = vec![CorrectionRule::new("ㅗㅓ", "ㅝ")];
+/// let mut composer = AutocorrectComposer::with_rules(rules);
+/// composer.feed_str("ㅁㅗㅓ").unwrap();
+/// assert_eq!(composer.as_string().unwrap(), "뭐");
+/// ```
+#[derive(Debug)]
+pub struct AutocorrectComposer {
+    composer: StringComposer,
+    rules: Vec<CorrectionRule>,
+    // The compatibility jamo typed into the word being composed, in order.
+    keystrokes: Vec<char>,
+}
+
+impl Default for AutocorrectComposer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutocorrectComposer {
+    /// Creates a new, empty `AutocorrectComposer` with the rules of
+    /// `CorrectionRule::common`.
+    pub fn new() -> Self {
+        Self::with_rules(CorrectionRule::common())
+    }
+
+    /// Creates a new, empty `AutocorrectComposer` with `rules`. When more
+    /// than one rule matches, the first in the list is used.
+    pub fn with_rules(rules: Vec<CorrectionRule>) -> Self {
+        Self::with_options(ComposerOptions::new(), rules)
+    }
+
+    /// Creates a new, empty `AutocorrectComposer` with `rules`, composing
+    /// with a `StringComposer` configured by `options`.
+    pub fn with_options(options: ComposerOptions, rules: Vec<CorrectionRule>) -> Self {
+        AutocorrectComposer {
+            composer: StringComposer::with_options(options),
+            rules,
+            keystrokes: Vec::new(),
+        }
+    }
+
+    /// Returns the rules this composer corrects with.
+    pub fn rules(&self) -> &[CorrectionRule] {
+        &self.rules
+    }
+
+    /// Pushes a character like `StringComposer::push_char`, first
+    /// correcting it along with the keystrokes before it if they complete a
+    /// rule. Returns the correction made, if any.
+    ///
+    /// If pushing the replacement fails, as it can in
+    /// `ComposerMode::Strict`, the composer is left as it was before the
+    /// call.
+    pub fn push_char(&mut self, c: char) -> Result<Option<Corrected>, StringError> {
+        let rule = compatibility_jamo(c).and_then(|letter| {
+            self.rules
+                .iter()
+                .find(|rule| rule.matches(&self.keystrokes, letter))
+                .cloned()
+        });
+        let Some(rule) = rule else {
+            self.push_uncorrected(c)?;
+            return Ok(None);
+        };
+
+        let snapshot = self.composer.state();
+        let keystrokes = self.keystrokes.clone();
+        if let Err(e) = self.replace(&rule) {
+            self.composer.restore(&snapshot);
+            self.keystrokes = keystrokes;
+            return Err(e);
+        }
+        Ok(Some(Corrected {
+            typed: rule.typed,
+            replacement: rule.replacement,
+        }))
+    }
+
+    /// Pushes each character of `s` in order, stopping at the first error,
+    /// and returns the corrections made.
+    pub fn feed_str(&mut self, s: &str) -> Result<Vec<Corrected>, StringError> {
+        let mut corrections = Vec::new();
+        for c in s.chars() {
+            corrections.extend(self.push_char(c)?);
+        }
+        Ok(corrections)
+    }
+
+    /// Pops the last character like `StringComposer::pop`.
+    pub fn pop(&mut self) -> Result<Option<char>, StringError> {
+        let popped = self.composer.pop()?;
+        self.sync_keystrokes(None);
+        Ok(popped)
+    }
+
+    /// Returns the composed string, with jamo that are not part of a
+    /// syllable given as compatibility jamo like `TextComposer::preedit`.
+    pub fn as_string(&self) -> Result<String, StringError> {
+        Ok(self
+            .composer
+            .as_string()?
+            .chars()
+            .map(modern_to_compatibility_jamo)
+            .collect())
+    }
+
+    /// Returns the `StringComposer` this composer corrects input for.
+    pub fn composer(&self) -> &StringComposer {
+        &self.composer
+    }
+
+    // Replaces the keystrokes of `rule` before the one being typed with its
+    // replacement.
+    fn replace(&mut self, rule: &CorrectionRule) -> Result<(), StringError> {
+        for _ in 1..rule.typed.chars().count() {
+            self.pop()?;
+        }
+        for c in rule.replacement.chars() {
+            self.push_uncorrected(c)?;
+        }
+        Ok(())
+    }
+
+    fn push_uncorrected(&mut self, c: char) -> Result<(), StringError> {
+        self.composer.push_char(c)?;
+        self.sync_keystrokes(compatibility_jamo(c));
+        Ok(())
+    }
+
+    // Records `pushed` as typed into the word being composed, or removes the
+    // last keystroke if nothing was pushed, clearing the keystrokes if the
+    // word was committed or emptied.
+    fn sync_keystrokes(&mut self, pushed: Option<char>) {
+        if !self.composer.is_composing() {
+            self.keystrokes.clear();
+            return;
+        }
+        match pushed {
+            Some(letter) => self.keystrokes.push(letter),
+            None => {
+                self.keystrokes.pop();
+            }
+        }
+    }
+}
+
+// Returns `c` as a compatibility jamo, if it is a jamo.
+fn compatibility_jamo(c: char) -> Option<char> {
+    match Character::from_char(c) {
+        Ok(Character::Hangul(jamo)) => Some(jamo.char_compatibility()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::ComposerMode;
+
+    #[test]
+    fn corrects_only_within_a_word() {
+        let mut composer = AutocorrectComposer::new();
+        let corrections = composer.feed_str("ㄱㅏㅏㅂㅅ ㅠㅠ ㄴㅓㅁㅜㅜㅜ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "값 ㅠㅠ 너무ㅜㅜ");
+        assert_eq!(corrections.len(), 1);
+        assert_eq!(corrections[0].typed, "ㅏㅏ");
+
+        // Corrections are undone a keystroke at a time
+        let mut composer = AutocorrectComposer::new();
+        composer.feed_str("ㅇㅔㅣㄴ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "옌");
+        composer.pop().unwrap();
+        composer.pop().unwrap();
+        assert_eq!(composer.as_string().unwrap(), "ㅇ");
+        composer.feed_str("ㅕㅣ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "예");
+    }
+
+    #[test]
+    fn failed_corrections_leave_the_composer_unchanged() {
+        let options = ComposerOptions::new().with_mode(ComposerMode::Strict);
+        let rules = vec![CorrectionRule::new("ㅏㅏ", "ㅏㅏ")];
+        let mut composer = AutocorrectComposer::with_options(options, rules);
+        composer.feed_str("ㄱㅏ").unwrap();
+        assert_eq!(
+            composer.push_char('ㅏ'),
+            Err(StringError::InvalidHangul('ㅏ'))
+        );
+        assert_eq!(composer.as_string().unwrap(), "가");
+        composer.push_char('ㄴ').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "간");
+    }
+}
@@ -0,0 +1,110 @@
+//! The `hangul` command-line tool, built with the `cli` feature.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process::ExitCode;
+
+use hangul_cd::romanize::{RomanizationSystem, romanize};
+use hangul_cd::string::{ComposingWriter, decompose, to_chosung};
+use hangul_cd::word::attach_josa;
+
+const USAGE: &str = "\
+Usage: hangul <COMMAND> [FILE]...
+       hangul josa <JOSA> [FILE]...
+
+Reads each FILE in order, or standard input if none are given, and writes
+the result to standard output.
+
+Commands:
+  compose    Compose jamo into syllables (ㅎㅏㄴㄱㅡㄹ → 한글)
+  decompose  Decompose syllables into jamo (한글 → ㅎㅏㄴㄱㅡㄹ)
+  chosung    Replace syllables with their initial consonants (한글 → ㅎㄱ)
+  romanize   Write Hangul in Revised Romanization (한국어 → hangugeo)
+  josa       Attach JOSA to each line in the form it takes (책 + 를 → 책을)";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(command) = args.next() else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    if command == "-h" || command == "--help" {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let josa = if command == "josa" {
+        match args.next() {
+            Some(josa) => josa,
+            None => {
+                eprintln!("hangul: josa needs a particle to attach\n\n{USAGE}");
+                return ExitCode::from(2);
+            }
+        }
+    } else {
+        String::new()
+    };
+    let files: Vec<String> = args.collect();
+    let stdout = io::stdout();
+    let output = BufWriter::new(stdout.lock());
+    let result = match command.as_str() {
+        "compose" => compose_files(&files, output),
+        "decompose" => map_lines(&files, output, |line| Ok(decompose(line))),
+        "chosung" => map_lines(&files, output, |line| Ok(to_chosung(line))),
+        "romanize" => map_lines(&files, output, |line| {
+            Ok(romanize(line, RomanizationSystem::RevisedRomanization))
+        }),
+        "josa" => map_lines(&files, output, |line| Ok(attach_josa(line, &josa))),
+        _ => {
+            eprintln!("hangul: unknown command '{command}'\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("hangul: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Calls `f` with a reader for each file in `files`, or for standard input
+// if there are none.
+fn for_each_input(
+    files: &[String],
+    mut f: impl FnMut(&mut dyn BufRead) -> io::Result<()>,
+) -> io::Result<()> {
+    if files.is_empty() {
+        return f(&mut io::stdin().lock());
+    }
+    for path in files {
+        let file =
+            File::open(path).map_err(|e| io::Error::new(e.kind(), format!("{path}: {e}")))?;
+        f(&mut BufReader::new(file))?;
+    }
+    Ok(())
+}
+
+fn compose_files(files: &[String], output: impl Write) -> io::Result<()> {
+    let mut writer = ComposingWriter::new(output);
+    for_each_input(files, |input| {
+        io::copy(input, &mut writer)?;
+        Ok(())
+    })?;
+    writer.into_inner()?.flush()
+}
+
+fn map_lines(
+    files: &[String],
+    mut output: impl Write,
+    f: impl Fn(&str) -> io::Result<String>,
+) -> io::Result<()> {
+    for_each_input(files, |input| {
+        for line in input.lines() {
+            writeln!(output, "{}", f(&line?)?)?;
+        }
+        Ok(())
+    })?;
+    output.flush()
+}
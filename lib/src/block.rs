@@ -1,7 +1,8 @@
 use thiserror::Error;
 
 use crate::jamo::*;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
+use std::str::FromStr;
 
 /// Errors that can occur when working with Hangul syllable blocks.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -32,6 +33,16 @@ pub enum BlockError {
     /// for example, a vowel in the initial position.
     #[error("Jamo '{0:?}' is in invalid position; expected '{1:?}'")]
     JamoInInvalidPosition(Jamo, JamoPosition),
+
+    /// Occurs when parsing a string that is not exactly one precomposed
+    /// Hangul syllable.
+    #[error("Could not parse \"{0}\" as a single Hangul syllable")]
+    FromStrError(String),
+
+    /// Occurs when parsing a syllable pattern that is not of the form
+    /// `C?G?VC?`, such as `CVVC`.
+    #[error("Invalid syllable pattern \"{0}\"")]
+    InvalidPattern(String),
 }
 
 /// A struct representing a composed Hangul syllable block,
@@ -42,23 +53,26 @@ pub enum BlockError {
 /// ```rust
 /// use hangul_cd::block::{HangulBlock, HangulBlockDecompositionOptions};
 /// use hangul_cd::jamo::{
+///     Choseong,
 ///     Jamo,
 ///     JamoConsonantSingular,
 ///     JamoVowelSingular,
 ///     JamoUnicodeType,
+///     Jungseong,
 /// };
 ///
-/// // `HangulBlock`s can be directly constructed; to ensure validity,
-/// // use a `BlockComposer` to build them instead. See `BlockComposer`
-/// // documentation for more details.
+/// // `HangulBlock`s can be directly constructed from jamo checked for their
+/// // positions; `HangulBlock::try_new` or a `BlockComposer` can build them
+/// // from unchecked jamo instead. See `BlockComposer` documentation for more
+/// // details.
 /// let block = HangulBlock {
-///     initial: Jamo::from_compatibility_jamo('ㄱ').unwrap(),
-///     vowel: Jamo::from_compatibility_jamo('ㅏ').unwrap(),
+///     initial: Choseong::try_from('ㄱ').unwrap(),
+///     vowel: Jungseong::try_from('ㅏ').unwrap(),
 ///     final_optional: None,
 /// };
 ///
 /// // Convert the block to a Hangul syllable character
-/// let syllable = block.to_char().unwrap();
+/// let syllable = block.to_char();
 /// assert_eq!(syllable, '가');
 ///
 /// // Decompose the block into its constituent Jamo characters as a tuple
@@ -80,11 +94,12 @@ pub enum BlockError {
 /// let decomposed_vec = block.decomposed_vec(&options).unwrap();
 /// assert_eq!(decomposed_vec, vec!['ᄀ', 'ᅡ']);
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HangulBlock {
-    pub initial: Jamo,
-    pub vowel: Jamo,
-    pub final_optional: Option<Jamo>,
+    pub initial: Choseong,
+    pub vowel: Jungseong,
+    pub final_optional: Option<Jongseong>,
 }
 
 /// A tuple representing the decomposed Jamo characters of a `HangulBlock`.
@@ -105,66 +120,107 @@ pub type DecomposedTuple = (
 );
 
 impl HangulBlock {
-    /// Converts the `HangulBlock` into a composed Hangul syllable unicode
-    /// character. Assumes all chars are valid Jamo.
-    pub fn to_char(&self) -> Result<char, BlockError> {
-        // Ensure the initial, vowel, and final are modern Jamo and not
-        // compatibility jamo
-        let initial = match self.initial.char_modern(JamoPosition::Initial) {
-            Some(c) => c,
-            None => {
-                return Err(BlockError::InvalidJamoContext(
-                    self.initial.clone(),
-                    JamoPosition::Initial,
-                    JamoUnicodeType::Modern,
-                ));
-            }
-        };
-        let vowel = match self.vowel.char_modern(JamoPosition::Vowel) {
-            Some(c) => c,
-            None => {
-                return Err(BlockError::InvalidJamoContext(
-                    self.vowel.clone(),
-                    JamoPosition::Vowel,
-                    JamoUnicodeType::Modern,
-                ));
-            }
+    /// Creates a `HangulBlock`, checking that `initial` is a valid initial
+    /// consonant (choseong), `vowel` a valid vowel (jungseong), and
+    /// `final_optional`, if present, a valid final consonant (jongseong).
+    /// Returns `BlockError::InvalidJamoContext` for the first jamo that is
+    /// not valid in its position.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::block::{BlockError, HangulBlock};
+    /// use hangul_cd::jamo::{Jamo, JamoPosition, JamoUnicodeType};
+    ///
+    /// let jamo = |c| Jamo::from_compatibility_jamo(c).unwrap();
+    ///
+    /// let block = HangulBlock::try_new(jamo('ㄲ'), jamo('ㅘ'), Some(jamo('ㄺ'))).unwrap();
+    /// assert_eq!(block.to_char(), '꽑');
+    ///
+    /// // ㄸ cannot be a final consonant
+    /// assert_eq!(
+    ///     HangulBlock::try_new(jamo('ㄱ'), jamo('ㅏ'), Some(jamo('ㄸ'))),
+    ///     Err(BlockError::InvalidJamoContext(
+    ///         jamo('ㄸ'),
+    ///         JamoPosition::Final,
+    ///         JamoUnicodeType::Modern,
+    ///     ))
+    /// );
+    /// ```
+    pub fn try_new(
+        initial: Jamo,
+        vowel: Jamo,
+        final_optional: Option<Jamo>,
+    ) -> Result<Self, BlockError> {
+        let invalid = |jamo, position| {
+            BlockError::InvalidJamoContext(jamo, position, JamoUnicodeType::Modern)
         };
-        let final_optional = match &self.final_optional {
-            Some(c) => c.char_modern(JamoPosition::Final),
+        Ok(HangulBlock {
+            initial: Choseong::try_from(initial)
+                .map_err(|_| invalid(initial, JamoPosition::Initial))?,
+            vowel: Jungseong::try_from(vowel).map_err(|_| invalid(vowel, JamoPosition::Vowel))?,
+            final_optional: match final_optional {
+                Some(last) => Some(
+                    Jongseong::try_from(last).map_err(|_| invalid(last, JamoPosition::Final))?,
+                ),
+                None => None,
+            },
+        })
+    }
+
+    /// Creates a `HangulBlock` from jamo already known to be valid in their
+    /// positions. Unlike `try_new`, this cannot fail.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::block::HangulBlock;
+    /// use hangul_cd::jamo::{Choseong, Jongseong, Jungseong};
+    ///
+    /// let block = HangulBlock::from_parts(
+    ///     Choseong::try_from('ㅎ').unwrap(),
+    ///     Jungseong::try_from('ㅏ').unwrap(),
+    ///     Some(Jongseong::try_from('ㄴ').unwrap()),
+    /// );
+    /// assert_eq!(block.to_char(), '한');
+    /// ```
+    pub const fn from_parts(
+        initial: Choseong,
+        vowel: Jungseong,
+        final_optional: Option<Jongseong>,
+    ) -> Self {
+        HangulBlock {
+            initial,
+            vowel,
+            final_optional,
+        }
+    }
+
+    /// Returns the final consonant of the block as a `Jamo`, if it has one.
+    pub const fn final_jamo(&self) -> Option<&Jamo> {
+        match &self.final_optional {
+            Some(last) => Some(last.jamo()),
             None => None,
-        };
+        }
+    }
 
-        // Get u32 representation of chars
-        let initial_num = initial as u32;
-        let vowel_num = vowel as u32;
-        let final_num = match final_optional {
-            Some(c) => c as u32,
+    /// Converts the `HangulBlock` into a composed Hangul syllable unicode
+    /// character.
+    pub const fn to_char(&self) -> char {
+        let l_index = self.initial.index() as u32;
+        let v_index = self.vowel.index() as u32;
+        let t_index = match &self.final_optional {
+            Some(last) => last.index() as u32,
             None => 0,
         };
-
-        // Calculate indices
-        let l_index = initial_num - L_BASE;
-        let v_index = vowel_num - V_BASE;
-        let t_index = if final_num == 0 {
-            0
-        } else {
-            final_num - T_BASE
-        };
         let s_index = (l_index * N_COUNT) + (v_index * T_COUNT) + t_index;
 
-        // Unwrapping because this should only ever be called with valid Hangul
-        if let Some(c) = std::char::from_u32(S_BASE + s_index) {
-            Ok(c)
-        } else {
-            Err(BlockError::InvalidBlockRepresentation(S_BASE + s_index))
-        }
+        // Infallible: the indices of valid jamo always give a syllable
+        std::char::from_u32(S_BASE + s_index).unwrap()
     }
 
     /// Creates a `HangulBlock` from a composed Hangul syllable unicode character.
     pub fn from_char(c: char) -> Result<Self, BlockError> {
         let codepoint = c as u32;
-        if !(S_BASE..=S_BASE + S_COUNT).contains(&codepoint) {
+        if !(S_BASE..S_BASE + S_COUNT).contains(&codepoint) {
             return Err(BlockError::InvalidBlockRepresentation(codepoint));
         }
 
@@ -173,19 +229,15 @@ impl HangulBlock {
         let v_index = (s_index % N_COUNT) / T_COUNT;
         let t_index = s_index % T_COUNT;
 
-        let initial = Jamo::from_modern_jamo(
-            std::char::from_u32(L_BASE + l_index)
-                .ok_or(BlockError::InvalidComponentRepresentation(L_BASE + l_index))?,
-        )?;
-        let vowel = Jamo::from_modern_jamo(
-            std::char::from_u32(V_BASE + v_index)
-                .ok_or(BlockError::InvalidComponentRepresentation(V_BASE + v_index))?,
-        )?;
+        let initial = Choseong::from_index(l_index as usize)
+            .ok_or(BlockError::InvalidComponentRepresentation(L_BASE + l_index))?;
+        let vowel = Jungseong::from_index(v_index as usize)
+            .ok_or(BlockError::InvalidComponentRepresentation(V_BASE + v_index))?;
         let final_optional = if t_index > 0 {
-            Some(Jamo::from_modern_jamo(
-                std::char::from_u32(T_BASE + t_index)
+            Some(
+                Jongseong::from_index(t_index as usize)
                     .ok_or(BlockError::InvalidComponentRepresentation(T_BASE + t_index))?,
-            )?)
+            )
         } else {
             None
         };
@@ -207,30 +259,30 @@ impl HangulBlock {
     /// - First final consonant (if any)
     /// - Second final consonant (if composite)
     pub fn decomposed_tuple(&self) -> Result<DecomposedTuple, BlockError> {
-        let (i1, i2) = match &self.initial {
+        let (i1, i2) = match self.initial.jamo() {
             Jamo::CompositeConsonant(c) => {
                 let (a, b) = c.decompose();
                 (Some(a), Some(b))
             }
-            Jamo::Consonant(c) => (Some(Jamo::Consonant(c.clone())), None),
+            Jamo::Consonant(c) => (Some(Jamo::Consonant(*c)), None),
             _ => (None, None),
         };
 
-        let (v1, v2) = match &self.vowel {
+        let (v1, v2) = match self.vowel.jamo() {
             Jamo::CompositeVowel(c) => {
                 let (a, b) = c.decompose();
                 (Some(a), Some(b))
             }
-            Jamo::Vowel(c) => (Some(Jamo::Vowel(c.clone())), None),
+            Jamo::Vowel(c) => (Some(Jamo::Vowel(*c)), None),
             _ => (None, None),
         };
 
-        let (f1, f2) = match &self.final_optional {
+        let (f1, f2) = match self.final_jamo() {
             Some(Jamo::CompositeConsonant(c)) => {
                 let (a, b) = c.decompose();
                 (Some(a), Some(b))
             }
-            Some(Jamo::Consonant(c)) => (Some(Jamo::Consonant(c.clone())), None),
+            Some(Jamo::Consonant(c)) => (Some(Jamo::Consonant(*c)), None),
             _ => (None, None),
         };
 
@@ -245,7 +297,7 @@ impl HangulBlock {
     ) -> Result<Vec<char>, BlockError> {
         let mut result = Vec::new();
 
-        match (&self.initial, &options.jamo_era) {
+        match (self.initial.jamo(), &options.jamo_era) {
             (Jamo::CompositeConsonant(c), JamoUnicodeType::Modern) => {
                 if options.decompose_composites {
                     let (a, b) = c.decompose();
@@ -266,7 +318,7 @@ impl HangulBlock {
                 } else {
                     result.push(c.char_modern(JamoPosition::Initial).ok_or(
                         BlockError::InvalidJamoContext(
-                            Jamo::CompositeConsonant(c.clone()),
+                            Jamo::CompositeConsonant(*c),
                             JamoPosition::Initial,
                             JamoUnicodeType::Modern,
                         ),
@@ -285,7 +337,7 @@ impl HangulBlock {
             (Jamo::Consonant(c), JamoUnicodeType::Modern) => {
                 result.push(c.char_modern(JamoPosition::Initial).ok_or(
                     BlockError::InvalidJamoContext(
-                        Jamo::Consonant(c.clone()),
+                        Jamo::Consonant(*c),
                         JamoPosition::Initial,
                         JamoUnicodeType::Modern,
                     ),
@@ -295,27 +347,24 @@ impl HangulBlock {
                 result.push(c.char_compatibility());
             }
             (j, _) => {
-                return Err(BlockError::JamoInInvalidPosition(
-                    j.clone(),
-                    JamoPosition::Initial,
-                ));
+                return Err(BlockError::JamoInInvalidPosition(*j, JamoPosition::Initial));
             }
         }
 
-        match (&self.vowel, &options.jamo_era) {
+        match (self.vowel.jamo(), &options.jamo_era) {
             (Jamo::CompositeVowel(c), JamoUnicodeType::Modern) => {
                 if options.decompose_composites {
                     let (a, b) = c.decompose();
                     result.push(a.char_modern(JamoPosition::Vowel).ok_or(
                         BlockError::InvalidJamoContext(
-                            Jamo::CompositeVowel(c.clone()),
+                            Jamo::CompositeVowel(*c),
                             JamoPosition::Vowel,
                             JamoUnicodeType::Modern,
                         ),
                     )?);
                     result.push(b.char_modern(JamoPosition::Vowel).ok_or(
                         BlockError::InvalidJamoContext(
-                            Jamo::CompositeVowel(c.clone()),
+                            Jamo::CompositeVowel(*c),
                             JamoPosition::Vowel,
                             JamoUnicodeType::Modern,
                         ),
@@ -341,27 +390,27 @@ impl HangulBlock {
             }
             _ => {
                 return Err(BlockError::JamoInInvalidPosition(
-                    self.vowel.clone(),
+                    *self.vowel.jamo(),
                     JamoPosition::Vowel,
                 ));
             }
         }
 
-        if let Some(final_jamo) = &self.final_optional {
-            match (&final_jamo, &options.jamo_era) {
+        if let Some(final_jamo) = self.final_jamo() {
+            match (final_jamo, &options.jamo_era) {
                 (Jamo::CompositeConsonant(c), JamoUnicodeType::Modern) => {
                     if options.decompose_composites {
                         let (a, b) = c.decompose();
                         result.push(a.char_modern(JamoPosition::Final).ok_or(
                             BlockError::InvalidJamoContext(
-                                Jamo::CompositeConsonant(c.clone()),
+                                Jamo::CompositeConsonant(*c),
                                 JamoPosition::Final,
                                 JamoUnicodeType::Modern,
                             ),
                         )?);
                         result.push(b.char_modern(JamoPosition::Final).ok_or(
                             BlockError::InvalidJamoContext(
-                                Jamo::CompositeConsonant(c.clone()),
+                                Jamo::CompositeConsonant(*c),
                                 JamoPosition::Final,
                                 JamoUnicodeType::Modern,
                             ),
@@ -369,7 +418,7 @@ impl HangulBlock {
                     } else {
                         result.push(c.char_modern(JamoPosition::Final).ok_or(
                             BlockError::InvalidJamoContext(
-                                Jamo::CompositeConsonant(c.clone()),
+                                Jamo::CompositeConsonant(*c),
                                 JamoPosition::Final,
                                 JamoUnicodeType::Modern,
                             ),
@@ -388,7 +437,7 @@ impl HangulBlock {
                 (Jamo::Consonant(c), JamoUnicodeType::Modern) => {
                     result.push(c.char_modern(JamoPosition::Final).ok_or(
                         BlockError::InvalidJamoContext(
-                            Jamo::Consonant(c.clone()),
+                            Jamo::Consonant(*c),
                             JamoPosition::Final,
                             JamoUnicodeType::Modern,
                         ),
@@ -399,7 +448,7 @@ impl HangulBlock {
                 }
                 _ => {
                     return Err(BlockError::JamoInInvalidPosition(
-                        final_jamo.clone(),
+                        *final_jamo,
                         JamoPosition::Final,
                     ));
                 }
@@ -410,17 +459,55 @@ impl HangulBlock {
     }
 }
 
+impl fmt::Display for HangulBlock {
+    /// Writes the composed syllable.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::block::HangulBlock;
+    ///
+    /// let block: HangulBlock = "값".parse().unwrap();
+    /// assert_eq!(format!("{block}!"), "값!");
+    /// assert_eq!(HangulBlock::try_from('닭').unwrap().to_string(), "닭");
+    /// assert!("가나".parse::<HangulBlock>().is_err());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl TryFrom<char> for HangulBlock {
+    type Error = BlockError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        HangulBlock::from_char(c)
+    }
+}
+
+impl FromStr for HangulBlock {
+    type Err = BlockError;
+
+    /// Parses a string containing exactly one precomposed Hangul syllable.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => HangulBlock::from_char(c),
+            _ => Err(BlockError::FromStrError(s.to_string())),
+        }
+    }
+}
+
 /// Options for decomposing a `HangulBlock` into its constituent Jamo characters.
 ///
 /// **Example:**
 /// ```rust
 /// use hangul_cd::block::{HangulBlock, HangulBlockDecompositionOptions};
-/// use hangul_cd::jamo::{Jamo, JamoUnicodeType};
+/// use hangul_cd::jamo::{Choseong, Jongseong, JamoUnicodeType, Jungseong};
 ///
 /// let block = HangulBlock {
-///    initial: Jamo::from_compatibility_jamo('ㄱ').unwrap(),
-///   vowel: Jamo::from_compatibility_jamo('ㅘ').unwrap(),
-///   final_optional: Some(Jamo::from_compatibility_jamo('ㄳ').unwrap()),
+///     initial: Choseong::try_from('ㄱ').unwrap(),
+///     vowel: Jungseong::try_from('ㅘ').unwrap(),
+///     final_optional: Some(Jongseong::try_from('ㄳ').unwrap()),
 /// };
 ///
 /// let options = HangulBlockDecompositionOptions {
@@ -466,7 +553,7 @@ pub enum BlockPushResult {
     NonHangul,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)] // Names improve clarity here
 enum BlockCompositionState {
     /// nothing, waiting for first consonant
@@ -491,6 +578,167 @@ enum BlockCompositionState {
     ExpectingNextBlock,
 }
 
+/// The rules a composer follows to combine two jamo pushed one after the
+/// other: two initial consonants into a double consonant (ㄱ + ㄱ → ㄲ), two
+/// vowels into a composite vowel (ㅗ + ㅏ → ㅘ), and two final consonants
+/// into a cluster (ㄹ + ㄱ → ㄺ). Jamo are given and returned as
+/// compatibility jamo, and `None` means the two do not combine. The same
+/// rules decide whether a composite jamo pushed whole, such as ㄳ, is
+/// allowed in a position.
+///
+/// `StandardRules` follows standard modern Korean. Other rules can describe
+/// keyboard layouts where, for example, ㄲ has its own key and ㄱ + ㄱ should
+/// not combine, or orthographies that allow other clusters. `BlockComposer`
+/// and `HangulWordComposer` can only hold modern jamo, so they only use
+/// combinations that give the composite jamo made of the two, such as ㄳ
+/// from ㄱ and ㅅ; blocks that do not form a modern syllable, such as one
+/// with ㄳ as its initial, are written as compatibility jamo.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{CompositionRules, StandardRules};
+/// use hangul_cd::word::{HangulWordComposer, WordPushResult};
+///
+/// // Standard rules, but ㄲ, ㄸ, ㅃ, ㅆ, and ㅉ must be typed directly
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// struct NoDoubling;
+///
+/// impl CompositionRules for NoDoubling {
+///     fn combine_initial(&self, first: char, second: char) -> Option<char> {
+///         None
+///     }
+///
+///     fn combine_vowel(&self, first: char, second: char) -> Option<char> {
+///         StandardRules.combine_vowel(first, second)
+///     }
+///
+///     fn combine_final(&self, first: char, second: char) -> Option<char> {
+///         StandardRules.combine_final(first, second).filter(|&c| c != 'ㄲ' && c != 'ㅆ')
+///     }
+/// }
+///
+/// let mut composer = HangulWordComposer::with_rules(NoDoubling);
+/// assert_eq!(composer.push_char('ㄱ'), Ok(WordPushResult::Continue));
+/// assert_eq!(composer.push_char('ㄱ'), Ok(WordPushResult::InvalidHangul));
+/// for c in "ㅏㅅㅅ".chars() {
+///     assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+/// }
+/// // The ㅅ starts a new block instead of forming ㅆ
+/// assert_eq!(composer.as_string().unwrap(), "갓\u{1109}");
+/// ```
+pub trait CompositionRules {
+    /// Returns the double consonant that `first` and `second` form at the
+    /// start of a syllable, if any.
+    fn combine_initial(&self, first: char, second: char) -> Option<char>;
+
+    /// Returns the composite vowel that `first` and `second` form, if any.
+    fn combine_vowel(&self, first: char, second: char) -> Option<char>;
+
+    /// Returns the consonant cluster that `first` and `second` form at the
+    /// end of a syllable, if any.
+    fn combine_final(&self, first: char, second: char) -> Option<char>;
+}
+
+/// The `CompositionRules` of standard modern Korean, used by default: the
+/// double consonants ㄲ, ㄸ, ㅃ, ㅆ, and ㅉ as initials, the seven composite
+/// vowels, and the eleven final clusters along with ㄲ and ㅆ as finals.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StandardRules;
+
+impl CompositionRules for StandardRules {
+    fn combine_initial(&self, first: char, second: char) -> Option<char> {
+        let (first, second) = consonant_pair(first, second)?;
+        first
+            .combine_for_initial(&second)
+            .map(|c| c.char_compatibility())
+    }
+
+    fn combine_vowel(&self, first: char, second: char) -> Option<char> {
+        match (
+            Jamo::from_compatibility_jamo(first).ok()?,
+            Jamo::from_compatibility_jamo(second).ok()?,
+        ) {
+            (Jamo::Vowel(first), Jamo::Vowel(second)) => {
+                first.combine(&second).map(|c| c.char_compatibility())
+            }
+            _ => None,
+        }
+    }
+
+    fn combine_final(&self, first: char, second: char) -> Option<char> {
+        let (first, second) = consonant_pair(first, second)?;
+        first
+            .combine_for_final(&second)
+            .map(|c| c.char_compatibility())
+    }
+}
+
+// Returns `first` and `second` as singular consonants, if both are.
+fn consonant_pair(
+    first: char,
+    second: char,
+) -> Option<(JamoConsonantSingular, JamoConsonantSingular)> {
+    match (
+        Jamo::from_compatibility_jamo(first).ok()?,
+        Jamo::from_compatibility_jamo(second).ok()?,
+    ) {
+        (Jamo::Consonant(first), Jamo::Consonant(second)) => Some((first, second)),
+        _ => None,
+    }
+}
+
+// Returns the composite jamo made of the singular jamo `first` and `second`,
+// if there is one, whether or not it is valid in any position.
+fn composite_of(first: &Jamo, second: &Jamo) -> Option<Jamo> {
+    match (first, second) {
+        (Jamo::Consonant(first), Jamo::Consonant(second)) => first
+            .combine_for_initial(second)
+            .or_else(|| first.combine_for_final(second))
+            .map(Jamo::CompositeConsonant),
+        (Jamo::Vowel(first), Jamo::Vowel(second)) => {
+            first.combine(second).map(Jamo::CompositeVowel)
+        }
+        _ => None,
+    }
+}
+
+// Returns whether `rules` combine `first` and `second` in `position` into
+// the composite jamo made of the two, the only result a `BlockComposer` can
+// hold.
+fn combines<R: CompositionRules + ?Sized>(
+    rules: &R,
+    position: JamoPosition,
+    first: &Jamo,
+    second: &Jamo,
+) -> bool {
+    let Some(composite) = composite_of(first, second) else {
+        return false;
+    };
+    let (first, second) = (first.char_compatibility(), second.char_compatibility());
+    let combined = match position {
+        JamoPosition::Initial => rules.combine_initial(first, second),
+        JamoPosition::Vowel => rules.combine_vowel(first, second),
+        JamoPosition::Final => rules.combine_final(first, second),
+    };
+    combined == Some(composite.char_compatibility())
+}
+
+// Returns whether `rules` allow the composite jamo `composite`, pushed
+// whole, in `position`.
+pub(crate) fn allows_composite<R: CompositionRules + ?Sized>(
+    rules: &R,
+    position: JamoPosition,
+    composite: &Jamo,
+) -> bool {
+    let (first, second) = match composite {
+        Jamo::CompositeConsonant(c) => c.decompose(),
+        Jamo::CompositeVowel(c) => c.decompose(),
+        Jamo::Consonant(_) | Jamo::Vowel(_) => return false,
+    };
+    combines(rules, position, &first, &second)
+}
+
 /// A composer for a single Hangul syllable block. Used to build a block
 /// by pushing and popping Jamo letters.
 ///
@@ -525,8 +773,15 @@ enum BlockCompositionState {
 /// let block_char = composer.block_as_string().unwrap();
 /// assert_eq!(block_char, Some('강'));
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "BlockComposerFields")
+)]
 pub struct BlockComposer {
+    // Follows from the jamo, so it is recomputed when deserializing
+    #[cfg_attr(feature = "serde", serde(skip))]
     state: BlockCompositionState,
     initial_first: Option<Jamo>,
     initial_second: Option<Jamo>,
@@ -536,6 +791,74 @@ pub struct BlockComposer {
     final_second: Option<Jamo>,
 }
 
+// The jamo of a deserialized `BlockComposer`, before they are checked
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct BlockComposerFields {
+    initial_first: Option<Jamo>,
+    initial_second: Option<Jamo>,
+    vowel_first: Option<Jamo>,
+    vowel_second: Option<Jamo>,
+    final_first: Option<Jamo>,
+    final_second: Option<Jamo>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BlockComposerFields> for BlockComposer {
+    type Error = BlockError;
+
+    /// Accepts only jamo that pushing could have left in a composer: each
+    /// jamo needs the one before it, and each pair must combine into a jamo
+    /// valid in its position.
+    fn try_from(fields: BlockComposerFields) -> Result<Self, Self::Error> {
+        let mut result = BlockComposer {
+            state: BlockCompositionState::ExpectingInitial,
+            initial_first: fields.initial_first,
+            initial_second: fields.initial_second,
+            vowel_first: fields.vowel_first,
+            vowel_second: fields.vowel_second,
+            final_first: fields.final_first,
+            final_second: fields.final_second,
+        };
+        let dependencies = [
+            (
+                result.initial_second,
+                result.initial_first,
+                JamoPosition::Initial,
+            ),
+            (
+                result.vowel_first,
+                result.initial_first,
+                JamoPosition::Vowel,
+            ),
+            (result.vowel_second, result.vowel_first, JamoPosition::Vowel),
+            (result.final_first, result.vowel_first, JamoPosition::Final),
+            (result.final_second, result.final_first, JamoPosition::Final),
+        ];
+        for (jamo, needed, position) in dependencies {
+            if let (Some(jamo), None) = (jamo, needed) {
+                return Err(BlockError::JamoInInvalidPosition(jamo, position));
+            }
+        }
+
+        let positions = [
+            JamoPosition::Initial,
+            JamoPosition::Vowel,
+            JamoPosition::Final,
+        ];
+        for (jamo, position) in result.combined_jamo()?.into_iter().zip(positions) {
+            if let Some(jamo) = jamo
+                && jamo.char_modern(position.clone()).is_none()
+            {
+                return Err(BlockError::JamoInInvalidPosition(jamo, position));
+            }
+        }
+
+        result.reset_state();
+        Ok(result)
+    }
+}
+
 impl Default for BlockComposer {
     fn default() -> Self {
         Self::new()
@@ -587,17 +910,30 @@ impl BlockComposer {
     /// If the letter could not be pushed, the state of the current block will
     /// remain unchanged.
     pub fn push(&mut self, letter: &Jamo) -> BlockPushResult {
+        self.push_with_rules(letter, &StandardRules)
+    }
+
+    /// Tries to push a Jamo letter into the `BlockComposer` as `push` does,
+    /// combining jamo according to `rules` rather than `StandardRules`. Use
+    /// the same rules for every letter pushed into a block.
+    pub fn push_with_rules<R: CompositionRules + ?Sized>(
+        &mut self,
+        letter: &Jamo,
+        rules: &R,
+    ) -> BlockPushResult {
         match self.state {
-            BlockCompositionState::ExpectingInitial => self.try_push_initial(letter),
+            BlockCompositionState::ExpectingInitial => self.try_push_initial(letter, rules),
             BlockCompositionState::ExpectingDoubleInitialOrVowel => {
-                self.try_push_double_initial_or_vowel(letter)
+                self.try_push_double_initial_or_vowel(letter, rules)
             }
-            BlockCompositionState::ExpectingVowel => self.try_push_vowel(letter),
+            BlockCompositionState::ExpectingVowel => self.try_push_vowel(letter, rules),
             BlockCompositionState::ExpectingCompositeVowelOrFinal => {
-                self.try_push_composite_vowel_or_final(letter)
+                self.try_push_composite_vowel_or_final(letter, rules)
+            }
+            BlockCompositionState::ExpectingFinal => self.try_push_final(letter, rules),
+            BlockCompositionState::ExpectingCompositeFinal => {
+                self.try_push_composite_final(letter, rules)
             }
-            BlockCompositionState::ExpectingFinal => self.try_push_final(letter),
-            BlockCompositionState::ExpectingCompositeFinal => self.try_push_composite_final(letter),
             BlockCompositionState::ExpectingNextBlock => self.try_push_next_block(letter),
         }
     }
@@ -698,6 +1034,26 @@ impl BlockComposer {
         }
     }
 
+    // Adds `letter` as the second half of the initial if the block is only
+    // that letter so far, forming a double consonant whatever the rules.
+    // Returns whether it was added.
+    pub(crate) fn double_initial(&mut self, letter: &Jamo) -> bool {
+        if self.state != BlockCompositionState::ExpectingDoubleInitialOrVowel
+            || self.initial_first.as_ref() != Some(letter)
+        {
+            return false;
+        }
+        self.initial_second = Some(*letter);
+        self.state = BlockCompositionState::ExpectingVowel;
+        true
+    }
+
+    // Returns whether the block ends with `letter` as a final on its own.
+    pub(crate) fn ends_with_final(&self, letter: &Jamo) -> bool {
+        self.state == BlockCompositionState::ExpectingCompositeFinal
+            && self.final_first.as_ref() == Some(letter)
+    }
+
     pub(crate) fn pop_end_consonant(&mut self) -> Option<Jamo> {
         if let Some(c) = self.final_second.take() {
             Some(c)
@@ -706,16 +1062,20 @@ impl BlockComposer {
         }
     }
 
-    fn try_push_initial(&mut self, letter: &Jamo) -> BlockPushResult {
+    fn try_push_initial<R: CompositionRules + ?Sized>(
+        &mut self,
+        letter: &Jamo,
+        rules: &R,
+    ) -> BlockPushResult {
         match letter {
             Jamo::Consonant(_) => {
-                self.initial_first = Some(letter.clone());
+                self.initial_first = Some(*letter);
                 self.state = BlockCompositionState::ExpectingDoubleInitialOrVowel;
                 BlockPushResult::Success
             }
-            Jamo::CompositeConsonant(c) => {
-                if c.is_valid_initial() {
-                    self.initial_first = Some(letter.clone());
+            Jamo::CompositeConsonant(_) => {
+                if allows_composite(rules, JamoPosition::Initial, letter) {
+                    self.initial_first = Some(*letter);
                     self.state = BlockCompositionState::ExpectingVowel;
                     BlockPushResult::Success
                 } else {
@@ -726,44 +1086,37 @@ impl BlockComposer {
         }
     }
 
-    fn try_push_double_initial_or_vowel(&mut self, letter: &Jamo) -> BlockPushResult {
+    fn try_push_double_initial_or_vowel<R: CompositionRules + ?Sized>(
+        &mut self,
+        letter: &Jamo,
+        rules: &R,
+    ) -> BlockPushResult {
         match letter {
-            Jamo::Consonant(c) => match &self.initial_first {
-                Some(Jamo::Consonant(i1)) => {
-                    if i1.combine_for_initial(c).is_some() {
-                        self.initial_second = Some(letter.clone());
-                        self.state = BlockCompositionState::ExpectingVowel;
-                        BlockPushResult::Success
-                    } else {
-                        BlockPushResult::InvalidHangul
-                    }
+            Jamo::Consonant(_) => match &self.initial_first {
+                Some(i1) if combines(rules, JamoPosition::Initial, i1, letter) => {
+                    self.initial_second = Some(*letter);
+                    self.state = BlockCompositionState::ExpectingVowel;
+                    BlockPushResult::Success
                 }
                 _ => BlockPushResult::InvalidHangul,
             },
-            Jamo::Vowel(_) => {
-                self.vowel_first = Some(letter.clone());
-                self.state = BlockCompositionState::ExpectingCompositeVowelOrFinal;
-                BlockPushResult::Success
-            }
-            Jamo::CompositeVowel(c) => {
-                let (v1, v2) = c.decompose();
-                self.vowel_first = Some(v1);
-                self.vowel_second = Some(v2);
-                self.state = BlockCompositionState::ExpectingFinal;
-                BlockPushResult::Success
-            }
+            Jamo::Vowel(_) | Jamo::CompositeVowel(_) => self.try_push_vowel(letter, rules),
             Jamo::CompositeConsonant(_) => BlockPushResult::InvalidHangul,
         }
     }
 
-    fn try_push_vowel(&mut self, letter: &Jamo) -> BlockPushResult {
+    fn try_push_vowel<R: CompositionRules + ?Sized>(
+        &mut self,
+        letter: &Jamo,
+        rules: &R,
+    ) -> BlockPushResult {
         match letter {
             Jamo::Vowel(_) => {
-                self.vowel_first = Some(letter.clone());
+                self.vowel_first = Some(*letter);
                 self.state = BlockCompositionState::ExpectingCompositeVowelOrFinal;
                 BlockPushResult::Success
             }
-            Jamo::CompositeVowel(c) => {
+            Jamo::CompositeVowel(c) if allows_composite(rules, JamoPosition::Vowel, letter) => {
                 let (v1, v2) = c.decompose();
                 self.vowel_first = Some(v1);
                 self.vowel_second = Some(v2);
@@ -774,57 +1127,43 @@ impl BlockComposer {
         }
     }
 
-    fn try_push_composite_vowel_or_final(&mut self, letter: &Jamo) -> BlockPushResult {
+    fn try_push_composite_vowel_or_final<R: CompositionRules + ?Sized>(
+        &mut self,
+        letter: &Jamo,
+        rules: &R,
+    ) -> BlockPushResult {
         match letter {
-            Jamo::Vowel(c) => match &self.vowel_first {
-                Some(Jamo::Vowel(v1)) => {
-                    if v1.combine(c).is_some() {
-                        self.vowel_second = Some(letter.clone());
-                        self.state = BlockCompositionState::ExpectingFinal;
-                        BlockPushResult::Success
-                    } else {
-                        BlockPushResult::InvalidHangul
-                    }
+            Jamo::Vowel(_) => match &self.vowel_first {
+                Some(v1) if combines(rules, JamoPosition::Vowel, v1, letter) => {
+                    self.vowel_second = Some(*letter);
+                    self.state = BlockCompositionState::ExpectingFinal;
+                    BlockPushResult::Success
                 }
                 _ => BlockPushResult::InvalidHangul,
             },
-            Jamo::Consonant(_) => {
-                self.final_first = Some(letter.clone());
-                self.state = BlockCompositionState::ExpectingCompositeFinal;
-                BlockPushResult::Success
-            }
-            Jamo::CompositeConsonant(c) => {
-                if c.is_valid_final() {
-                    let (f1, f2) = c.decompose();
-                    self.final_first = Some(f1);
-                    self.final_second = Some(f2);
-                    self.state = BlockCompositionState::ExpectingNextBlock;
-                    BlockPushResult::Success
-                } else if c.is_valid_initial() {
-                    BlockPushResult::StartNewBlockNoPop
-                } else {
-                    BlockPushResult::InvalidHangul
-                }
-            }
-            _ => BlockPushResult::InvalidHangul,
+            _ => self.try_push_final(letter, rules),
         }
     }
 
-    fn try_push_final(&mut self, letter: &Jamo) -> BlockPushResult {
+    fn try_push_final<R: CompositionRules + ?Sized>(
+        &mut self,
+        letter: &Jamo,
+        rules: &R,
+    ) -> BlockPushResult {
         match letter {
             Jamo::Consonant(_) => {
-                self.final_first = Some(letter.clone());
+                self.final_first = Some(*letter);
                 self.state = BlockCompositionState::ExpectingCompositeFinal;
                 BlockPushResult::Success
             }
             Jamo::CompositeConsonant(c) => {
-                if c.is_valid_final() {
+                if allows_composite(rules, JamoPosition::Final, letter) {
                     let (f1, f2) = c.decompose();
                     self.final_first = Some(f1);
                     self.final_second = Some(f2);
                     self.state = BlockCompositionState::ExpectingNextBlock;
                     BlockPushResult::Success
-                } else if c.is_valid_initial() {
+                } else if allows_composite(rules, JamoPosition::Initial, letter) {
                     BlockPushResult::StartNewBlockNoPop
                 } else {
                     BlockPushResult::InvalidHangul
@@ -834,22 +1173,23 @@ impl BlockComposer {
         }
     }
 
-    fn try_push_composite_final(&mut self, letter: &Jamo) -> BlockPushResult {
+    fn try_push_composite_final<R: CompositionRules + ?Sized>(
+        &mut self,
+        letter: &Jamo,
+        rules: &R,
+    ) -> BlockPushResult {
         match letter {
-            Jamo::Consonant(c) => match &self.final_first {
-                Some(Jamo::Consonant(f1)) => {
-                    if f1.combine_for_final(c).is_some() {
-                        self.final_second = Some(letter.clone());
-                        self.state = BlockCompositionState::ExpectingNextBlock;
-                        BlockPushResult::Success
-                    } else {
-                        BlockPushResult::StartNewBlockNoPop
-                    }
+            Jamo::Consonant(_) => match &self.final_first {
+                Some(f1) if combines(rules, JamoPosition::Final, f1, letter) => {
+                    self.final_second = Some(*letter);
+                    self.state = BlockCompositionState::ExpectingNextBlock;
+                    BlockPushResult::Success
                 }
-                _ => BlockPushResult::InvalidHangul,
+                Some(_) => BlockPushResult::StartNewBlockNoPop,
+                None => BlockPushResult::InvalidHangul,
             },
-            Jamo::CompositeConsonant(c) => {
-                if c.is_valid_initial() {
+            Jamo::CompositeConsonant(_) => {
+                if allows_composite(rules, JamoPosition::Initial, letter) {
                     BlockPushResult::StartNewBlockNoPop
                 } else {
                     BlockPushResult::InvalidHangul
@@ -874,7 +1214,7 @@ impl BlockComposer {
     /// **Example:**
     /// ```rust
     /// use hangul_cd::block::{BlockComposer, BlockCompletionStatus, HangulBlock};
-    /// use hangul_cd::jamo::{Jamo, JamoConsonantSingular, JamoVowelSingular};
+    /// use hangul_cd::jamo::{Choseong, Jamo, JamoConsonantSingular, Jungseong};
     ///
     /// let mut composer = BlockComposer::new();
     ///
@@ -892,63 +1232,18 @@ impl BlockComposer {
     /// assert_eq!(
     ///    composer.try_as_complete_block(),
     ///    Ok(BlockCompletionStatus::Complete(HangulBlock {
-    ///        initial: Jamo::Consonant(JamoConsonantSingular::Giyeok),
-    ///        vowel: Jamo::Vowel(JamoVowelSingular::A),
+    ///        initial: Choseong::try_from('ㄱ').unwrap(),
+    ///        vowel: Jungseong::try_from('ㅏ').unwrap(),
     ///        final_optional: None,
     ///    }))
     /// );
     /// ```
     pub fn try_as_complete_block(&self) -> Result<BlockCompletionStatus, BlockError> {
-        let initial_optional = match (&self.initial_first, &self.initial_second) {
-            (Some(Jamo::Consonant(i1)), Some(Jamo::Consonant(i2))) => {
-                match i1.combine_for_initial(i2) {
-                    Some(composite) => Some(Jamo::CompositeConsonant(composite)),
-                    None => {
-                        return Err(BlockError::JamoInInvalidPosition(
-                            Jamo::Consonant(i2.clone()),
-                            JamoPosition::Initial,
-                        ));
-                    }
-                }
-            }
-            (Some(i1), None) => Some(i1.clone()),
-            _ => None,
-        };
-        let vowel_optional = match (&self.vowel_first, &self.vowel_second) {
-            (Some(Jamo::Vowel(v1)), Some(Jamo::Vowel(v2))) => match v1.combine(v2) {
-                Some(composite) => Some(Jamo::CompositeVowel(composite)),
-                None => {
-                    return Err(BlockError::JamoInInvalidPosition(
-                        Jamo::Vowel(v2.clone()),
-                        JamoPosition::Vowel,
-                    ));
-                }
-            },
-            (Some(v1), None) => Some(v1.clone()),
-            _ => None,
-        };
-        let final_optional = match (&self.final_first, &self.final_second) {
-            (Some(Jamo::Consonant(f1)), Some(Jamo::Consonant(f2))) => {
-                match f1.combine_for_final(f2) {
-                    Some(composite) => Some(Jamo::CompositeConsonant(composite)),
-                    None => {
-                        return Err(BlockError::JamoInInvalidPosition(
-                            Jamo::Consonant(f2.clone()),
-                            JamoPosition::Final,
-                        ));
-                    }
-                }
-            }
-            (Some(f1), None) => Some(f1.clone()),
-            _ => None,
-        };
-
+        let [initial_optional, vowel_optional, final_optional] = self.combined_jamo()?;
         match (initial_optional, vowel_optional) {
-            (Some(initial), Some(vowel)) => Ok(BlockCompletionStatus::Complete(HangulBlock {
-                initial,
-                vowel,
-                final_optional,
-            })),
+            (Some(initial), Some(vowel)) => Ok(BlockCompletionStatus::Complete(
+                HangulBlock::try_new(initial, vowel, final_optional)?,
+            )),
             (Some(initial), None) => Ok(BlockCompletionStatus::Incomplete(initial)),
             (None, Some(vowel)) => Ok(BlockCompletionStatus::Incomplete(vowel)),
             (None, None) => match final_optional {
@@ -958,6 +1253,30 @@ impl BlockComposer {
         }
     }
 
+    // Returns the initial, vowel, and final of the block, each combined from
+    // the pair of jamo pushed for it. Unlike `try_as_complete_block`, this
+    // does not require them to form a modern syllable.
+    pub(crate) fn combined_jamo(&self) -> Result<[Option<Jamo>; 3], BlockError> {
+        // The rules were checked as each jamo was pushed, so pairs only need
+        // to be combined into the composite jamo made of them
+        let combine = |first: &Option<Jamo>, second: &Option<Jamo>, position| match (first, second)
+        {
+            (Some(first), Some(second)) => match composite_of(first, second) {
+                Some(composite) => Ok(Some(composite)),
+                None => Err(BlockError::JamoInInvalidPosition(*second, position)),
+            },
+            (first, _) => Ok(*first),
+        };
+        let initial_optional = combine(
+            &self.initial_first,
+            &self.initial_second,
+            JamoPosition::Initial,
+        )?;
+        let vowel_optional = combine(&self.vowel_first, &self.vowel_second, JamoPosition::Vowel)?;
+        let final_optional = combine(&self.final_first, &self.final_second, JamoPosition::Final)?;
+        Ok([initial_optional, vowel_optional, final_optional])
+    }
+
     /// Returns the composed Hangul syllable character as an `Option<char>`
     /// wrapped in a `Result`. If the block is complete, it returns the composed
     /// character. If the block is incomplete, it returns the Jamo currently in
@@ -965,7 +1284,7 @@ impl BlockComposer {
     /// it returns `None`.
     pub fn block_as_string(&self) -> Result<Option<char>, BlockError> {
         match self.try_as_complete_block()? {
-            BlockCompletionStatus::Complete(block) => Ok(Some(block.to_char()?)),
+            BlockCompletionStatus::Complete(block) => Ok(Some(block.to_char())),
             BlockCompletionStatus::Incomplete(c) => Ok(c.char_modern(match c {
                 Jamo::Consonant(_) | Jamo::CompositeConsonant(_) => JamoPosition::Initial,
                 Jamo::Vowel(_) | Jamo::CompositeVowel(_) => JamoPosition::Vowel,
@@ -974,6 +1293,28 @@ impl BlockComposer {
         }
     }
 
+    // Sets the state to the one `push` would have left the block in after
+    // pushing its jamo, for blocks whose jamo were set or removed directly.
+    pub(crate) fn reset_state(&mut self) {
+        self.state = if self.final_second.is_some() {
+            BlockCompositionState::ExpectingNextBlock
+        } else if self.final_first.is_some() {
+            BlockCompositionState::ExpectingCompositeFinal
+        } else if self.vowel_second.is_some() {
+            BlockCompositionState::ExpectingFinal
+        } else if self.vowel_first.is_some() {
+            BlockCompositionState::ExpectingCompositeVowelOrFinal
+        } else if self.initial_second.is_some()
+            || matches!(self.initial_first, Some(Jamo::CompositeConsonant(_)))
+        {
+            BlockCompositionState::ExpectingVowel
+        } else if self.initial_first.is_some() {
+            BlockCompositionState::ExpectingDoubleInitialOrVowel
+        } else {
+            BlockCompositionState::ExpectingInitial
+        };
+    }
+
     /// Creates a `BlockComposer` from an existing `HangulBlock`,
     /// decomposing it into its constituent Jamo characters.
     /// Returns an error if decomposition fails.
@@ -981,102 +1322,854 @@ impl BlockComposer {
         let mut result = BlockComposer::new();
         let (i1, i2, v1, v2, f1, f2) = block.decomposed_tuple()?;
 
-        if f2.is_some() {
-            result.state = BlockCompositionState::ExpectingNextBlock;
-        } else if f1.is_some() {
-            result.state = BlockCompositionState::ExpectingCompositeFinal;
-        } else if v2.is_some() {
-            result.state = BlockCompositionState::ExpectingFinal;
-        } else if v1.is_some() {
-            result.state = BlockCompositionState::ExpectingCompositeVowelOrFinal;
-        }
-        // Anything after this shouldn't happen. But this won't return an error
-        // because it's conceivable that a manually constructed HangulBlock
-        // leads to one of these states occuring. This may lead to undefined
-        // behavior.
-        else if i2.is_some() {
-            result.state = BlockCompositionState::ExpectingVowel;
-        } else if i1.is_some() {
-            result.state = BlockCompositionState::ExpectingDoubleInitialOrVowel;
-        } else {
-            result.state = BlockCompositionState::ExpectingInitial;
-        }
-
         result.initial_first = i1;
         result.initial_second = i2;
         result.vowel_first = v1;
         result.vowel_second = v2;
         result.final_first = f1;
         result.final_second = f2;
+        result.reset_state();
 
         Ok(result)
     }
 }
 
 /// Converts a vector of `HangulBlock` structs into a composed Hangul string.
-/// Returns an `Err` if any block cannot be converted into a valid Hangul syllable.
-pub fn hangul_blocks_vec_to_string(blocks: &Vec<HangulBlock>) -> Result<String, BlockError> {
-    let mut result = String::new();
-    for block in blocks {
-        result.push(block.to_char()?);
+pub fn hangul_blocks_vec_to_string(blocks: &[HangulBlock]) -> String {
+    blocks.iter().map(HangulBlock::to_char).collect()
+}
+
+/// Returns the syllable after `c` in Unicode (and dictionary) order, or
+/// `None` if `c` is not a precomposed Hangul syllable or is the last one
+/// (힣).
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{next_syllable, prev_syllable};
+///
+/// assert_eq!(next_syllable('가'), Some('각'));
+/// assert_eq!(next_syllable('갛'), Some('개'));
+/// assert_eq!(next_syllable('힣'), None);
+/// assert_eq!(prev_syllable('나'), Some('낗'));
+/// assert_eq!(prev_syllable('가'), None);
+/// ```
+pub fn next_syllable(c: char) -> Option<char> {
+    let codepoint = c as u32;
+    if !is_syllable_codepoint(codepoint) {
+        return None;
+    }
+    char::from_u32(codepoint + 1).filter(|next| is_syllable_codepoint(*next as u32))
+}
+
+/// Returns the syllable before `c` in Unicode (and dictionary) order, or
+/// `None` if `c` is not a precomposed Hangul syllable or is the first one
+/// (가).
+pub fn prev_syllable(c: char) -> Option<char> {
+    let codepoint = c as u32;
+    if !is_syllable_codepoint(codepoint) || codepoint == S_BASE {
+        return None;
+    }
+    char::from_u32(codepoint - 1)
+}
+
+/// Returns an iterator over all 11,172 precomposed modern Hangul syllables
+/// in Unicode (and dictionary) order, from 가 to 힣.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::all_syllables;
+///
+/// assert_eq!(all_syllables().count(), 11172);
+/// assert_eq!(all_syllables().nth(28), Some('개'));
+/// assert_eq!(all_syllables().last(), Some('힣'));
+/// ```
+pub fn all_syllables() -> impl Iterator<Item = char> {
+    (S_BASE..S_BASE + S_COUNT).filter_map(char::from_u32)
+}
+
+/// Returns the index of the syllable `c` among all precomposed Hangul
+/// syllables, from 0 for 가 to 11171 for 힣, or `None` if `c` is not a
+/// precomposed Hangul syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{syllable_from_index, syllable_index};
+///
+/// assert_eq!(syllable_index('가'), Some(0));
+/// assert_eq!(syllable_index('한'), Some(10588));
+/// assert_eq!(syllable_index('ㄱ'), None);
+/// assert_eq!(syllable_from_index(10588), Some('한'));
+/// assert_eq!(syllable_from_index(11172), None);
+/// ```
+pub const fn syllable_index(c: char) -> Option<u16> {
+    let codepoint = c as u32;
+    if is_syllable_codepoint(codepoint) {
+        Some((codepoint - S_BASE) as u16)
+    } else {
+        None
+    }
+}
+
+/// Returns the precomposed Hangul syllable at `index`, as numbered by
+/// `syllable_index`, or `None` if `index` is 11172 or more.
+pub const fn syllable_from_index(index: u16) -> Option<char> {
+    let index = index as u32;
+    if index >= S_COUNT {
+        return None;
+    }
+    char::from_u32(S_BASE + index)
+}
+
+/// Splits the precomposed Hangul syllable `c` into the indices of its
+/// initial, vowel, and final, as numbered by `Choseong::index`,
+/// `Jungseong::index`, and `Jongseong::index`, with a final index of 0 for
+/// syllables without a final consonant. Returns `None` if `c` is not a
+/// precomposed Hangul syllable.
+///
+/// Like the index conversions of `Choseong`, `Jungseong`, and `Jongseong`,
+/// this and `syllable_from_indices` are `const`, so tables of syllables can
+/// be built at compile time.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{syllable_from_indices, syllable_indices};
+/// use hangul_cd::jamo::Choseong;
+///
+/// assert_eq!(syllable_indices('한'), Some((18, 0, 4)));
+/// assert_eq!(syllable_from_indices(18, 0, 4), Some('한'));
+/// assert_eq!(syllable_from_indices(19, 0, 0), None);
+///
+/// // The initial consonant of every syllable, computed at compile time
+/// const INITIALS: [char; 11172] = {
+///     let mut table = ['\0'; 11172];
+///     let mut i = 0;
+///     while i < table.len() {
+///         let c = char::from_u32(0xAC00 + i as u32).unwrap();
+///         let (initial, _, _) = syllable_indices(c).unwrap();
+///         table[i] = Choseong::from_index(initial).unwrap().char_compatibility();
+///         i += 1;
+///     }
+///     table
+/// };
+/// assert_eq!(INITIALS[10588], 'ㅎ');
+/// ```
+pub const fn syllable_indices(c: char) -> Option<(usize, usize, usize)> {
+    let codepoint = c as u32;
+    if !is_syllable_codepoint(codepoint) {
+        return None;
+    }
+    let s_index = codepoint - S_BASE;
+    Some((
+        (s_index / N_COUNT) as usize,
+        ((s_index % N_COUNT) / T_COUNT) as usize,
+        (s_index % T_COUNT) as usize,
+    ))
+}
+
+/// Returns the precomposed Hangul syllable with the given initial, vowel,
+/// and final indices, as returned by `syllable_indices`, or `None` if any
+/// index is out of range.
+pub const fn syllable_from_indices(
+    initial: usize,
+    vowel: usize,
+    final_index: usize,
+) -> Option<char> {
+    if initial >= Choseong::COUNT || vowel >= Jungseong::COUNT || final_index > Jongseong::COUNT {
+        return None;
+    }
+    char::from_u32(S_BASE + initial as u32 * N_COUNT + vowel as u32 * T_COUNT + final_index as u32)
+}
+
+/// Returns the syllable `c` with its initial consonant replaced by
+/// `initial`, or `None` if `c` is not a precomposed Hangul syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{with_final, with_initial, with_vowel};
+/// use hangul_cd::jamo::{Choseong, Jongseong, Jungseong};
+///
+/// assert_eq!(with_initial('한', Choseong::try_from('ㄲ').unwrap()), Some('깐'));
+/// assert_eq!(with_vowel('한', Jungseong::try_from('ㅘ').unwrap()), Some('환'));
+/// assert_eq!(with_final('한', Some(Jongseong::try_from('ㄺ').unwrap())), Some('핡'));
+/// assert_eq!(with_final('한', None), Some('하'));
+/// assert_eq!(with_final('a', None), None);
+/// ```
+pub fn with_initial(c: char, initial: Choseong) -> Option<char> {
+    let (_, vowel, final_optional) = syllable_indices(c)?;
+    syllable_from_indices(initial.index(), vowel, final_optional)
+}
+
+/// Returns the syllable `c` with its vowel replaced by `vowel`, or `None` if
+/// `c` is not a precomposed Hangul syllable.
+pub fn with_vowel(c: char, vowel: Jungseong) -> Option<char> {
+    let (initial, _, final_optional) = syllable_indices(c)?;
+    syllable_from_indices(initial, vowel.index(), final_optional)
+}
+
+/// Returns the syllable `c` with its final consonant replaced by
+/// `final_optional`, or removed if `final_optional` is `None`. Returns
+/// `None` if `c` is not a precomposed Hangul syllable.
+pub fn with_final(c: char, final_optional: Option<Jongseong>) -> Option<char> {
+    let (initial, vowel, _) = syllable_indices(c)?;
+    syllable_from_indices(initial, vowel, final_optional.map_or(0, |f| f.index()))
+}
+
+/// The phonological structure of a Hangul syllable: whether it has an
+/// onset consonant (C), a glide (G) before its vowel (V), and a coda
+/// consonant (C). Written as a string such as `CV`, `CVC`, or `CGVC`.
+///
+/// The initial ㅇ is silent, so it does not count as an onset. The glide
+/// vowels are the y-diphthongs ㅑ, ㅒ, ㅕ, ㅖ, ㅛ, and ㅠ, the w-diphthongs
+/// ㅘ, ㅙ, ㅝ, and ㅞ, and ㅢ; ㅚ and ㅟ are treated as monophthongs, as in
+/// the standard pronunciation rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyllablePattern {
+    /// Whether the syllable starts with a pronounced consonant.
+    pub onset: bool,
+    /// Whether the vowel starts with a glide.
+    pub glide: bool,
+    /// Whether the syllable ends with a consonant.
+    pub coda: bool,
+}
+
+impl fmt::Display for SyllablePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (present, symbol) in [
+            (self.onset, "C"),
+            (self.glide, "G"),
+            (true, "V"),
+            (self.coda, "C"),
+        ] {
+            if present {
+                f.write_str(symbol)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SyllablePattern {
+    type Err = BlockError;
+
+    /// Parses a pattern of the form `C?G?VC?`, such as `CVC` or `GV`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (onset, rest) = s.strip_prefix('C').map_or((false, s), |rest| (true, rest));
+        let (glide, rest) = rest
+            .strip_prefix('G')
+            .map_or((false, rest), |rest| (true, rest));
+        match rest {
+            "V" => Ok(SyllablePattern {
+                onset,
+                glide,
+                coda: false,
+            }),
+            "VC" => Ok(SyllablePattern {
+                onset,
+                glide,
+                coda: true,
+            }),
+            _ => Err(BlockError::InvalidPattern(s.to_string())),
+        }
+    }
+}
+
+/// Returns the phonological structure of the precomposed Hangul syllable
+/// `c`, or `None` if `c` is not a precomposed Hangul syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::pattern;
+///
+/// assert_eq!(pattern('아').unwrap().to_string(), "V");
+/// assert_eq!(pattern('가').unwrap().to_string(), "CV");
+/// assert_eq!(pattern('관').unwrap().to_string(), "CGVC");
+/// assert_eq!(pattern('약').unwrap().to_string(), "GVC");
+/// assert_eq!(pattern('a'), None);
+/// ```
+pub fn pattern(c: char) -> Option<SyllablePattern> {
+    let (initial, vowel, final_index) = syllable_indices(c)?;
+    // ㅇ, the silent initial
+    const IEUNG: usize = 11;
+    // ㅑ, ㅒ, ㅕ, ㅖ, ㅘ, ㅙ, ㅛ, ㅝ, ㅞ, ㅠ, and ㅢ
+    const GLIDES: [usize; 11] = [2, 3, 6, 7, 9, 10, 12, 14, 15, 17, 19];
+    Some(SyllablePattern {
+        onset: initial != IEUNG,
+        glide: GLIDES.contains(&vowel),
+        coda: final_index != 0,
+    })
+}
+
+/// A region of a syllable block occupied by a jamo, as described by
+/// `BlockLayout`. The block is divided into three rows (top, middle, and
+/// bottom) of two columns (left and right); `Left`, `Right`, `Top`, and
+/// `Bottom` span the whole side of the block, except that `Top` leaves the
+/// bottom row to a vowel or final below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlockSlot {
+    /// The left column, top to bottom.
+    Left,
+
+    /// The right column, top to bottom.
+    Right,
+
+    /// The top and middle rows.
+    Top,
+
+    /// The middle row.
+    Middle,
+
+    /// The bottom row.
+    Bottom,
+
+    /// The left column of the top and middle rows.
+    TopLeft,
+
+    /// The right column of the top and middle rows.
+    TopRight,
+
+    /// The left column of the middle row.
+    MiddleLeft,
+
+    /// The left column of the bottom row.
+    BottomLeft,
+}
+
+impl BlockSlot {
+    // The (row, column) cells of the 3×2 block grid covered by this slot.
+    // Slots placed later in a layout take over cells from earlier ones, so
+    // `Top` followed by `Middle` leaves only the top row to `Top`.
+    fn cells(self) -> &'static [(usize, usize)] {
+        match self {
+            BlockSlot::Left => &[(0, 0), (1, 0), (2, 0)],
+            BlockSlot::Right => &[(0, 1), (1, 1), (2, 1)],
+            BlockSlot::Top => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+            BlockSlot::Middle => &[(1, 0), (1, 1)],
+            BlockSlot::Bottom => &[(2, 0), (2, 1)],
+            BlockSlot::TopLeft => &[(0, 0), (1, 0)],
+            BlockSlot::TopRight => &[(0, 1), (1, 1)],
+            BlockSlot::MiddleLeft => &[(1, 0)],
+            BlockSlot::BottomLeft => &[(2, 0)],
+        }
+    }
+}
+
+/// A compatibility jamo and the region of a syllable block it occupies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JamoPlacement {
+    /// The jamo, as a compatibility jamo.
+    pub jamo: char,
+
+    /// The region of the block the jamo occupies.
+    pub slot: BlockSlot,
+}
+
+/// The arrangement of the jamo within a syllable block, as used by Hangul
+/// fonts. There are six layout types, depending on the shape of the vowel
+/// and whether there is a final consonant:
+///
+/// | Type | Vowel | Final | Example | Initial | Vowel | Final |
+/// |------|-------|-------|---------|---------|-------|-------|
+/// | 1 | vertical (ㅏ) | no | 가 | `Left` | `Right` | |
+/// | 2 | horizontal (ㅗ) | no | 고 | `Top` | `Bottom` | |
+/// | 3 | combined (ㅘ) | no | 과 | `TopLeft` | `BottomLeft`, `Right` | |
+/// | 4 | vertical | yes | 각 | `TopLeft` | `TopRight` | `Bottom` |
+/// | 5 | horizontal | yes | 곡 | `Top` | `Middle` | `Bottom` |
+/// | 6 | combined | yes | 곽 | `TopLeft` | `MiddleLeft`, `TopRight` | `Bottom` |
+///
+/// Combined vowels are placed as their horizontal and vertical parts (ㅘ
+/// as ㅗ and ㅏ), in that order. Composite initials and finals are placed
+/// whole.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{BlockSlot, JamoPlacement, layout_grid};
+///
+/// let layout = layout_grid('곽').unwrap();
+/// assert_eq!(layout.layout_type, 6);
+/// assert_eq!(
+///     layout.placements[1],
+///     JamoPlacement { jamo: 'ㅗ', slot: BlockSlot::MiddleLeft }
+/// );
+/// assert_eq!(layout.to_string(), "ㄱㅏ\nㅗㅏ\nㄱㄱ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockLayout {
+    /// The layout type, from 1 to 6.
+    pub layout_type: u8,
+
+    /// The jamo of the block and their slots, in writing order.
+    pub placements: Vec<JamoPlacement>,
+}
+
+impl BlockLayout {
+    /// Returns the block as a grid of three rows of two cells, each holding
+    /// the jamo that covers that part of the block.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::block::layout_grid;
+    ///
+    /// let layout = layout_grid('각').unwrap();
+    /// assert_eq!(layout.grid(), [['ㄱ', 'ㅏ'], ['ㄱ', 'ㅏ'], ['ㄱ', 'ㄱ']]);
+    /// ```
+    pub fn grid(&self) -> [[char; 2]; 3] {
+        let mut grid = [[' '; 2]; 3];
+        for placement in &self.placements {
+            for &(row, column) in placement.slot.cells() {
+                grid[row][column] = placement.jamo;
+            }
+        }
+        grid
+    }
+}
+
+impl fmt::Display for BlockLayout {
+    /// Draws the grid returned by `grid`, one row per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.grid().iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}{}", row[0], row[1])?;
+        }
+        Ok(())
     }
-    Ok(result)
+}
+
+/// Returns the layout of the jamo within the precomposed Hangul syllable
+/// `c`, or `None` if `c` is not a precomposed Hangul syllable. See
+/// `BlockLayout` for the layout types.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::layout_grid;
+///
+/// assert_eq!(layout_grid('가').unwrap().to_string(), "ㄱㅏ\nㄱㅏ\nㄱㅏ");
+/// assert_eq!(layout_grid('곡').unwrap().to_string(), "ㄱㄱ\nㅗㅗ\nㄱㄱ");
+/// assert!(layout_grid('ㄱ').is_none());
+/// ```
+pub fn layout_grid(c: char) -> Option<BlockLayout> {
+    let block = HangulBlock::from_char(c).ok()?;
+    let initial = block.initial.char_compatibility();
+    let final_optional = block.final_optional.map(|f| f.char_compatibility());
+    let is_horizontal = |vowel: char| matches!(vowel, 'ㅗ' | 'ㅛ' | 'ㅜ' | 'ㅠ' | 'ㅡ');
+
+    let place = |jamo, slot| JamoPlacement { jamo, slot };
+    let mut placements = Vec::with_capacity(4);
+    let layout_type = match (block.vowel.jamo(), final_optional) {
+        (Jamo::CompositeVowel(composite), _) => {
+            let (horizontal, vertical) = composite.decompose();
+            let with_final = final_optional.is_some();
+            placements.extend([
+                place(initial, BlockSlot::TopLeft),
+                place(
+                    horizontal.char_compatibility(),
+                    if with_final {
+                        BlockSlot::MiddleLeft
+                    } else {
+                        BlockSlot::BottomLeft
+                    },
+                ),
+                place(
+                    vertical.char_compatibility(),
+                    if with_final {
+                        BlockSlot::TopRight
+                    } else {
+                        BlockSlot::Right
+                    },
+                ),
+            ]);
+            if with_final { 6 } else { 3 }
+        }
+        (vowel, None) => {
+            let vowel = vowel.char_compatibility();
+            if is_horizontal(vowel) {
+                placements.extend([
+                    place(initial, BlockSlot::Top),
+                    place(vowel, BlockSlot::Bottom),
+                ]);
+                2
+            } else {
+                placements.extend([
+                    place(initial, BlockSlot::Left),
+                    place(vowel, BlockSlot::Right),
+                ]);
+                1
+            }
+        }
+        (vowel, Some(_)) => {
+            let vowel = vowel.char_compatibility();
+            if is_horizontal(vowel) {
+                placements.extend([
+                    place(initial, BlockSlot::Top),
+                    place(vowel, BlockSlot::Middle),
+                ]);
+                5
+            } else {
+                placements.extend([
+                    place(initial, BlockSlot::TopLeft),
+                    place(vowel, BlockSlot::TopRight),
+                ]);
+                4
+            }
+        }
+    };
+    if let Some(final_jamo) = final_optional {
+        placements.push(place(final_jamo, BlockSlot::Bottom));
+    }
+    Some(BlockLayout {
+        layout_type,
+        placements,
+    })
+}
+
+/// A jamo of a syllable block and the box it is drawn in, as returned by
+/// `render_plan`. Coordinates are in the unit em square, with `(0.0, 0.0)`
+/// at the top left and `y` increasing downward, as for `jamo::strokes`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JamoBox {
+    /// The jamo, as a compatibility jamo.
+    pub jamo: char,
+
+    /// The region of the block the jamo occupies.
+    pub slot: BlockSlot,
+
+    /// The left edge of the box.
+    pub x: f32,
+
+    /// The top edge of the box.
+    pub y: f32,
+
+    /// The width of the box.
+    pub width: f32,
+
+    /// The height of the box.
+    pub height: f32,
+}
+
+impl JamoBox {
+    /// Maps a point in the unit em square, such as a point of a stroke from
+    /// `jamo::strokes`, to the same place within the box.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::block::render_plan;
+    ///
+    /// let plan = render_plan('가').unwrap();
+    /// assert_eq!(plan.boxes[0].transform((0.0, 0.0)), (0.05, 0.1));
+    /// let (x, y) = plan.boxes[1].transform((1.0, 0.5));
+    /// assert!((x - 0.95).abs() < 1e-6 && (y - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn transform(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        (self.x + x * self.width, self.y + y * self.height)
+    }
+}
+
+/// A description of how to draw a precomposed syllable from its jamo, as
+/// returned by `render_plan`: the box each jamo is scaled into, for the
+/// syllable's layout type. This is enough to draw Hangul from a bitmap or
+/// stroke set for each of the 51 compatibility jamo, without a font.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderPlan {
+    /// The layout type, from 1 to 6, as described for `BlockLayout`.
+    pub layout_type: u8,
+
+    /// The jamo of the block and their boxes, in writing order.
+    pub boxes: Vec<JamoBox>,
+}
+
+/// Returns how to draw the precomposed Hangul syllable `c` from its jamo,
+/// or `None` if `c` is not a precomposed Hangul syllable. Each jamo of
+/// `layout_grid` is given a box within the em square sized for the layout
+/// type, so that, for example, the initial of 가 is tall and narrow while
+/// the initial of 곡 is short and wide. Boxes may touch but do not overlap,
+/// and leave a small margin at the edges of the em square.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::{BlockSlot, render_plan};
+///
+/// let plan = render_plan('곡').unwrap();
+/// assert_eq!(plan.layout_type, 5);
+/// let jamo: Vec<char> = plan.boxes.iter().map(|b| b.jamo).collect();
+/// assert_eq!(jamo, ['ㄱ', 'ㅗ', 'ㄱ']);
+/// let initial = plan.boxes[0];
+/// assert_eq!(initial.slot, BlockSlot::Top);
+/// assert!(initial.width > initial.height);
+/// assert!(render_plan('ㄱ').is_none());
+/// ```
+pub fn render_plan(c: char) -> Option<RenderPlan> {
+    let layout = layout_grid(c)?;
+    let boxes = layout
+        .placements
+        .iter()
+        .map(|placement| {
+            let (x, y, width, height) = slot_bounds(layout.layout_type, placement.slot);
+            JamoBox {
+                jamo: placement.jamo,
+                slot: placement.slot,
+                x,
+                y,
+                width,
+                height,
+            }
+        })
+        .collect();
+    Some(RenderPlan {
+        layout_type: layout.layout_type,
+        boxes,
+    })
+}
+
+// Returns the box, as (x, y, width, height) in the em square, of `slot` in
+// a block of layout type `layout_type`.
+fn slot_bounds(layout_type: u8, slot: BlockSlot) -> (f32, f32, f32, f32) {
+    match (layout_type, slot) {
+        (1, BlockSlot::Left) => (0.05, 0.1, 0.55, 0.8),
+        (1, _) => (0.6, 0.05, 0.35, 0.9),
+        (2, BlockSlot::Top) => (0.15, 0.05, 0.7, 0.5),
+        (2, _) => (0.05, 0.55, 0.9, 0.4),
+        (3, BlockSlot::TopLeft) => (0.05, 0.05, 0.55, 0.45),
+        (3, BlockSlot::BottomLeft) => (0.05, 0.5, 0.6, 0.4),
+        (3, _) => (0.65, 0.05, 0.3, 0.9),
+        (4, BlockSlot::TopLeft) => (0.05, 0.05, 0.55, 0.5),
+        (4, BlockSlot::TopRight) => (0.6, 0.05, 0.35, 0.55),
+        (5, BlockSlot::Top) => (0.15, 0.05, 0.7, 0.3),
+        (5, BlockSlot::Middle) => (0.05, 0.35, 0.9, 0.25),
+        (6, BlockSlot::TopLeft) => (0.05, 0.05, 0.55, 0.3),
+        (6, BlockSlot::MiddleLeft) => (0.05, 0.35, 0.6, 0.2),
+        (6, BlockSlot::TopRight) => (0.65, 0.05, 0.3, 0.55),
+        // The final, below the rest of the block
+        _ => (0.1, 0.6, 0.8, 0.35),
+    }
+}
+
+const fn is_syllable_codepoint(codepoint: u32) -> bool {
+    codepoint >= S_BASE && codepoint < S_BASE + S_COUNT
+}
+
+/// Returns the number of strokes used to write the precomposed Hangul
+/// syllable `c`, summing its initial, vowel, and final jamo. Returns `None`
+/// if `c` is not a precomposed Hangul syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::stroke_count;
+///
+/// assert_eq!(stroke_count('한'), Some(6)); // ㅎ (3) + ㅏ (2) + ㄴ (1)
+/// assert_eq!(stroke_count('ㅎ'), None);
+/// ```
+pub fn stroke_count(c: char) -> Option<u8> {
+    stroke_sequence(c).map(|strokes| strokes.len() as u8)
+}
+
+/// Returns the strokes used to write the precomposed Hangul syllable `c`,
+/// in stroke order: the initial, then the vowel, then the final. Returns
+/// `None` if `c` is not a precomposed Hangul syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::stroke_sequence;
+/// use hangul_cd::jamo::StrokeKind;
+///
+/// assert_eq!(
+///     stroke_sequence('그'),
+///     Some(vec![StrokeKind::HorizontalVertical, StrokeKind::Horizontal])
+/// );
+/// ```
+pub fn stroke_sequence(c: char) -> Option<Vec<StrokeKind>> {
+    let block = HangulBlock::from_char(c).ok()?;
+    let mut strokes = block.initial.jamo().strokes();
+    strokes.extend(block.vowel.jamo().strokes());
+    if let Some(f) = block.final_jamo() {
+        strokes.extend(f.strokes());
+    }
+    Some(strokes)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_try_new_validates_positions() {
+        let jamo = |c| Jamo::from_compatibility_jamo(c).unwrap();
+        let invalid = |c, position| {
+            BlockError::InvalidJamoContext(jamo(c), position, JamoUnicodeType::Modern)
+        };
+
+        assert_eq!(
+            HangulBlock::try_new(jamo('ㅏ'), jamo('ㅏ'), None),
+            Err(invalid('ㅏ', JamoPosition::Initial))
+        );
+        assert_eq!(
+            HangulBlock::try_new(jamo('ㄳ'), jamo('ㅏ'), None),
+            Err(invalid('ㄳ', JamoPosition::Initial))
+        );
+        assert_eq!(
+            HangulBlock::try_new(jamo('ㄱ'), jamo('ㄱ'), None),
+            Err(invalid('ㄱ', JamoPosition::Vowel))
+        );
+        assert_eq!(
+            HangulBlock::try_new(jamo('ㄱ'), jamo('ㅏ'), Some(jamo('ㅃ'))),
+            Err(invalid('ㅃ', JamoPosition::Final))
+        );
+
+        // Directly constructed blocks can only hold jamo valid in their
+        // positions
+        assert!(Jongseong::try_from(jamo('ㅉ')).is_err());
+        assert!(Choseong::try_from(jamo('ㅏ')).is_err());
+
+        let block = HangulBlock::try_new(jamo('ㅇ'), jamo('ㅢ'), Some(jamo('ㅆ'))).unwrap();
+        assert_eq!(block.to_char(), '읬');
+    }
+
+    #[test]
+    fn test_standard_rules_allow_modern_composites() {
+        for c in 'ㄱ'..='ㅣ' {
+            let jamo = Jamo::from_compatibility_jamo(c).unwrap();
+            let allows = |position| allows_composite(&StandardRules, position, &jamo);
+            match &jamo {
+                Jamo::CompositeConsonant(composite) => {
+                    assert_eq!(allows(JamoPosition::Initial), composite.is_valid_initial());
+                    assert_eq!(allows(JamoPosition::Final), composite.is_valid_final());
+                }
+                Jamo::CompositeVowel(_) => assert!(allows(JamoPosition::Vowel), "{c}"),
+                _ => assert!(!allows(JamoPosition::Initial), "{c}"),
+            }
+        }
+        assert_eq!(StandardRules.combine_initial('ㅂ', 'ㅂ'), Some('ㅃ'));
+        assert_eq!(StandardRules.combine_final('ㅂ', 'ㅂ'), None);
+        assert_eq!(StandardRules.combine_vowel('ㅜ', 'ㅔ'), Some('ㅞ'));
+        assert_eq!(StandardRules.combine_vowel('ㄱ', 'ㅏ'), None);
+    }
+
+    #[test]
+    fn test_from_parts_covers_all_syllables() {
+        let mut count = 0;
+        for initial in Choseong::all() {
+            for vowel in Jungseong::all() {
+                let finals = std::iter::once(None).chain(Jongseong::all().map(Some));
+                for final_optional in finals {
+                    let expected = S_BASE as usize
+                        + initial.index() * N_COUNT as usize
+                        + vowel.index() * T_COUNT as usize
+                        + final_optional.as_ref().map_or(0, Jongseong::index);
+                    let block = HangulBlock::from_parts(initial, vowel, final_optional);
+                    assert_eq!(block.to_char() as usize, expected);
+                    count += 1;
+                }
+            }
+        }
+        assert_eq!(count, S_COUNT);
+    }
+
+    #[test]
+    fn test_from_char_rejects_codepoint_after_last_syllable() {
+        assert!(HangulBlock::from_char('힣').is_ok());
+        assert_eq!(
+            HangulBlock::from_char('\u{D7A4}'),
+            Err(BlockError::InvalidBlockRepresentation(0xD7A4))
+        );
+    }
+
+    #[test]
+    fn test_syllable_arithmetic() {
+        let mut count = 1;
+        let mut c = '가';
+        while let Some(next) = next_syllable(c) {
+            assert_eq!(prev_syllable(next), Some(c));
+            c = next;
+            count += 1;
+        }
+        assert_eq!(c, '힣');
+        assert_eq!(count, S_COUNT);
+        assert_eq!(next_syllable('\u{D7A4}'), None);
+        assert_eq!(prev_syllable('ㄱ'), None);
+
+        let last = |c| Some(Jongseong::try_from(c).unwrap());
+        assert_eq!(with_final('가', last('ㅎ')), Some('갛'));
+        assert_eq!(with_final('갛', None), Some('가'));
+        assert_eq!(
+            with_initial('힣', Choseong::try_from('ㄱ').unwrap()),
+            Some('깋')
+        );
+        assert_eq!(
+            with_vowel('힣', Jungseong::try_from('ㅏ').unwrap()),
+            Some('핳')
+        );
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        let block: HangulBlock = "꿿".parse().unwrap();
+        assert_eq!(block.to_string(), "꿿");
+        assert_eq!(
+            "".parse::<HangulBlock>(),
+            Err(BlockError::FromStrError(String::new()))
+        );
+        assert_eq!(
+            "ㄱ".parse::<HangulBlock>(),
+            Err(BlockError::InvalidBlockRepresentation('ㄱ' as u32))
+        );
+    }
+
     #[test]
     fn test_hangul_block_to_char() {
         let block = HangulBlock {
-            initial: Jamo::from_compatibility_jamo('ㄱ').unwrap(),
-            vowel: Jamo::from_compatibility_jamo('ㅏ').unwrap(),
-            final_optional: Some(Jamo::from_compatibility_jamo('ㄴ').unwrap()),
+            initial: Choseong::try_from('ㄱ').unwrap(),
+            vowel: Jungseong::try_from('ㅏ').unwrap(),
+            final_optional: Some(Jongseong::try_from('ㄴ').unwrap()),
         };
         let result = block.to_char();
-        assert_eq!(result, Ok('간'));
+        assert_eq!(result, '간');
 
         let block_no_final = HangulBlock {
-            initial: Jamo::from_compatibility_jamo('ㅂ').unwrap(),
-            vowel: Jamo::from_compatibility_jamo('ㅗ').unwrap(),
+            initial: Choseong::try_from('ㅂ').unwrap(),
+            vowel: Jungseong::try_from('ㅗ').unwrap(),
             final_optional: None,
         };
         let result_no_final = block_no_final.to_char();
-        assert_eq!(result_no_final, Ok('보'));
+        assert_eq!(result_no_final, '보');
     }
 
     #[test]
     fn test_hangul_blocks_vec_to_string() {
         let blocks = vec![
             HangulBlock {
-                initial: Jamo::from_compatibility_jamo('ㅇ').unwrap(),
-                vowel: Jamo::from_compatibility_jamo('ㅏ').unwrap(),
-                final_optional: Some(Jamo::from_compatibility_jamo('ㄴ').unwrap()),
+                initial: Choseong::try_from('ㅇ').unwrap(),
+                vowel: Jungseong::try_from('ㅏ').unwrap(),
+                final_optional: Some(Jongseong::try_from('ㄴ').unwrap()),
             },
             HangulBlock {
-                initial: Jamo::from_compatibility_jamo('ㄴ').unwrap(),
-                vowel: Jamo::from_compatibility_jamo('ㅕ').unwrap(),
-                final_optional: Some(Jamo::from_compatibility_jamo('ㅇ').unwrap()),
+                initial: Choseong::try_from('ㄴ').unwrap(),
+                vowel: Jungseong::try_from('ㅕ').unwrap(),
+                final_optional: Some(Jongseong::try_from('ㅇ').unwrap()),
             },
             HangulBlock {
-                initial: Jamo::from_compatibility_jamo('ㅎ').unwrap(),
-                vowel: Jamo::from_compatibility_jamo('ㅏ').unwrap(),
+                initial: Choseong::try_from('ㅎ').unwrap(),
+                vowel: Jungseong::try_from('ㅏ').unwrap(),
                 final_optional: None,
             },
             HangulBlock {
-                initial: Jamo::from_compatibility_jamo('ㅅ').unwrap(),
-                vowel: Jamo::from_compatibility_jamo('ㅔ').unwrap(),
+                initial: Choseong::try_from('ㅅ').unwrap(),
+                vowel: Jungseong::try_from('ㅔ').unwrap(),
                 final_optional: None,
             },
             HangulBlock {
-                initial: Jamo::from_compatibility_jamo('ㅇ').unwrap(),
-                vowel: Jamo::from_compatibility_jamo('ㅛ').unwrap(),
+                initial: Choseong::try_from('ㅇ').unwrap(),
+                vowel: Jungseong::try_from('ㅛ').unwrap(),
                 final_optional: None,
             },
         ];
         let result = hangul_blocks_vec_to_string(&blocks);
-        assert_eq!(result, Ok("안녕하세요".to_string()));
+        assert_eq!(result, "안녕하세요");
     }
 
     struct BlockComposerPushTestCase {
@@ -1289,20 +2382,20 @@ mod tests {
         let from_block_char = HangulBlock::from_char(case.0.3).unwrap();
         assert_eq!(
             from_block_char.initial,
-            Jamo::from_compatibility_jamo(case.0.0).unwrap(),
+            Choseong::try_from(case.0.0).unwrap(),
             "Initial consonant did not match expected for case {:?}",
             case
         );
         assert_eq!(
             from_block_char.vowel,
-            Jamo::from_compatibility_jamo(case.0.1).unwrap(),
+            Jungseong::try_from(case.0.1).unwrap(),
             "Vowel did not match expected for case {:?}",
             case
         );
         if case.0.2 != '\0' {
             assert_eq!(
                 from_block_char.final_optional.unwrap(),
-                Jamo::from_compatibility_jamo(case.0.2).unwrap(),
+                Jongseong::try_from(case.0.2).unwrap(),
                 "Final consonant did not match expected for case {:?}",
                 case
             );
@@ -1404,4 +2497,169 @@ mod tests {
         let expected = vec!['ㄱ', 'ㅏ', 'ㅄ'];
         assert_eq!(decomposed, expected);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_hangul_block() {
+        let block = HangulBlock::from_char('값').unwrap();
+        let json = serde_json::to_string(&block).unwrap();
+        let restored: HangulBlock = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, block);
+        assert_eq!(restored.to_char(), '값');
+    }
+
+    #[test]
+    fn test_stroke_count() {
+        assert_eq!(stroke_count('가'), Some(3));
+        assert_eq!(stroke_count('값'), Some(9));
+        assert_eq!(stroke_count('뷁'), Some(13));
+        assert_eq!(stroke_count('a'), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_checks_block_composer_jamo() {
+        let mut composer = BlockComposer::new();
+        for c in "ㄷㅏㄹ".chars() {
+            composer.push_char(c).unwrap();
+        }
+        let json = serde_json::to_string(&composer).unwrap();
+        let mut restored: BlockComposer = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, composer);
+        assert_eq!(restored.push_char('ㄱ'), Ok(BlockPushResult::Success));
+        assert_eq!(restored.block_as_string(), Ok(Some('닭')));
+
+        let jamo = |c| serde_json::to_value(Jamo::from_compatibility_jamo(c).unwrap()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let invalid = [
+            // A vowel with no initial
+            ("initial_first", serde_json::Value::Null),
+            // A vowel as the initial
+            ("initial_first", jamo('ㅗ')),
+            // A pair that does not combine
+            ("initial_second", jamo('ㄴ')),
+            // A double consonant as a final
+            ("final_second", jamo('ㄷ')),
+        ];
+        for (field, jamo) in invalid {
+            let mut value = value.clone();
+            if field == "final_second" {
+                value["final_first"] = jamo.clone();
+            }
+            value[field] = jamo;
+            assert!(
+                serde_json::from_value::<BlockComposer>(value).is_err(),
+                "{field}"
+            );
+        }
+    }
+
+    #[test]
+    fn syllable_patterns() {
+        let cases = [
+            ('이', "V"),
+            ('외', "V"),
+            ('의', "GV"),
+            ('앙', "VC"),
+            ('뭐', "CGV"),
+            ('쥐', "CV"),
+            ('닭', "CVC"),
+            ('꿹', "CGVC"),
+        ];
+        for (c, expected) in cases {
+            let p = pattern(c).unwrap();
+            assert_eq!(p.to_string(), expected);
+            assert_eq!(expected.parse::<SyllablePattern>(), Ok(p));
+        }
+        for invalid in ["", "C", "CC", "GCV", "VV", "CVCC", "cv"] {
+            assert_eq!(
+                invalid.parse::<SyllablePattern>(),
+                Err(BlockError::InvalidPattern(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn layout_grids() {
+        let cases = [
+            ('의', 3, "ㅇㅣ\nㅇㅣ\nㅡㅣ"),
+            ('쇄', 3, "ㅅㅐ\nㅅㅐ\nㅗㅐ"),
+            ('뷁', 6, "ㅂㅔ\nㅜㅔ\nㄺㄺ"),
+            ('흙', 5, "ㅎㅎ\nㅡㅡ\nㄺㄺ"),
+            ('했', 4, "ㅎㅐ\nㅎㅐ\nㅆㅆ"),
+        ];
+        for (c, layout_type, drawing) in cases {
+            let layout = layout_grid(c).unwrap();
+            assert_eq!(layout.layout_type, layout_type);
+            assert_eq!(layout.to_string(), drawing);
+        }
+        // Every cell of every block is covered by a jamo
+        for c in '가'..='힣' {
+            let layout = layout_grid(c).unwrap();
+            assert!(layout.grid().iter().flatten().all(|&cell| cell != ' '));
+        }
+    }
+
+    #[test]
+    fn render_plans() {
+        let plan = render_plan('곽').unwrap();
+        let boxes: Vec<(char, f32, f32)> = plan.boxes.iter().map(|b| (b.jamo, b.x, b.y)).collect();
+        assert_eq!(
+            boxes,
+            [
+                ('ㄱ', 0.05, 0.05),
+                ('ㅗ', 0.05, 0.35),
+                ('ㅏ', 0.65, 0.05),
+                ('ㄱ', 0.1, 0.6)
+            ]
+        );
+
+        // Boxes stay within the em square and do not overlap
+        let overlap = |a: &JamoBox, b: &JamoBox| {
+            a.x < b.x + b.width - 1e-6
+                && b.x < a.x + a.width - 1e-6
+                && a.y < b.y + b.height - 1e-6
+                && b.y < a.y + a.height - 1e-6
+        };
+        for c in '가'..='힣' {
+            let plan = render_plan(c).unwrap();
+            assert_eq!(plan.boxes.len(), layout_grid(c).unwrap().placements.len());
+            for (i, a) in plan.boxes.iter().enumerate() {
+                assert!(a.x >= 0.0 && a.y >= 0.0, "{c}");
+                assert!(a.x + a.width <= 1.0 && a.y + a.height <= 1.0, "{c}");
+                assert!(plan.boxes[i + 1..].iter().all(|b| !overlap(a, b)), "{c}");
+            }
+        }
+    }
+
+    #[test]
+    fn syllable_indices_round_trip() {
+        for (i, c) in all_syllables().enumerate() {
+            assert_eq!(syllable_index(c), Some(i as u16));
+            assert_eq!(syllable_from_index(i as u16), Some(c));
+        }
+        assert_eq!(syllable_index('\u{D7A4}'), None);
+    }
+
+    #[test]
+    fn conversions_evaluate_at_compile_time() {
+        const HAN: HangulBlock = HangulBlock::from_parts(
+            Choseong::from_index(18).unwrap(),
+            Jungseong::from_index(0).unwrap(),
+            Jongseong::from_index(4),
+        );
+        const INDICES: Option<(usize, usize, usize)> = syllable_indices('한');
+        const CHAR: Option<char> = match INDICES {
+            Some((initial, vowel, final_index)) => {
+                syllable_from_indices(initial, vowel, final_index)
+            }
+            None => None,
+        };
+        const FINAL: char = modern_to_compatibility_jamo('\u{11AB}');
+        assert_eq!(HAN.to_char(), '한');
+        assert_eq!(CHAR, Some('한'));
+        assert_eq!(FINAL, 'ㄴ');
+        assert_eq!(syllable_from_indices(0, 21, 0), None);
+        assert_eq!(syllable_from_indices(0, 0, 28), None);
+    }
 }
@@ -0,0 +1,666 @@
+use thiserror::Error;
+
+use crate::block::HangulBlock;
+use crate::jamo::Jamo;
+
+/// An error type for Korean braille decoding.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum BrailleError {
+    /// Occurs when the braille cells starting at the given index (counted
+    /// in `char`s) do not form valid Korean braille.
+    #[error("Invalid Korean braille sequence at cell {0}")]
+    InvalidSequence(usize),
+}
+
+// 된소리표: written before an initial consonant to double it.
+const FORTIS_MARK: char = '⠠';
+
+// 구분표: separates two vowels that would otherwise read as one.
+const SEPARATOR: char = '⠤';
+
+// Initial consonants. Initial ㅇ is not written in braille.
+const INITIALS: [(char, char); 13] = [
+    ('ㄱ', '⠈'),
+    ('ㄴ', '⠉'),
+    ('ㄷ', '⠊'),
+    ('ㄹ', '⠐'),
+    ('ㅁ', '⠑'),
+    ('ㅂ', '⠘'),
+    ('ㅅ', '⠠'),
+    ('ㅈ', '⠨'),
+    ('ㅊ', '⠰'),
+    ('ㅋ', '⠋'),
+    ('ㅌ', '⠓'),
+    ('ㅍ', '⠙'),
+    ('ㅎ', '⠚'),
+];
+
+// Double initial consonants and the consonant they double.
+const DOUBLE_INITIALS: [(char, char); 5] = [
+    ('ㄲ', 'ㄱ'),
+    ('ㄸ', 'ㄷ'),
+    ('ㅃ', 'ㅂ'),
+    ('ㅆ', 'ㅅ'),
+    ('ㅉ', 'ㅈ'),
+];
+
+// Final consonants. Other finals are written as a sequence of these; ㅆ
+// has its own abbreviated cell.
+const FINALS: [(char, char); 15] = [
+    ('ㄱ', '⠁'),
+    ('ㄴ', '⠒'),
+    ('ㄷ', '⠔'),
+    ('ㄹ', '⠂'),
+    ('ㅁ', '⠢'),
+    ('ㅂ', '⠃'),
+    ('ㅅ', '⠄'),
+    ('ㅇ', '⠶'),
+    ('ㅈ', '⠅'),
+    ('ㅊ', '⠆'),
+    ('ㅋ', '⠖'),
+    ('ㅌ', '⠦'),
+    ('ㅍ', '⠲'),
+    ('ㅎ', '⠴'),
+    ('ㅆ', '⠌'),
+];
+
+const VOWELS: [(char, &str); 21] = [
+    ('ㅏ', "⠣"),
+    ('ㅑ', "⠜"),
+    ('ㅓ', "⠎"),
+    ('ㅕ', "⠱"),
+    ('ㅗ', "⠥"),
+    ('ㅛ', "⠬"),
+    ('ㅜ', "⠍"),
+    ('ㅠ', "⠩"),
+    ('ㅡ', "⠪"),
+    ('ㅣ', "⠕"),
+    ('ㅐ', "⠗"),
+    ('ㅔ', "⠝"),
+    ('ㅒ', "⠜⠗"),
+    ('ㅖ', "⠌"),
+    ('ㅘ', "⠧"),
+    ('ㅙ', "⠧⠗"),
+    ('ㅚ', "⠽"),
+    ('ㅝ', "⠏"),
+    ('ㅞ', "⠏⠗"),
+    ('ㅟ', "⠍⠗"),
+    ('ㅢ', "⠺"),
+];
+
+// Vowels written as a second cell after ⠗ (ㅐ) to form a composite vowel.
+const AE_COMPOSITES: [(char, char); 4] = [('ㅑ', 'ㅒ'), ('ㅘ', 'ㅙ'), ('ㅜ', 'ㅟ'), ('ㅝ', 'ㅞ')];
+
+// Abbreviations (약자) for a vowel followed by a final consonant.
+const RHYME_ABBREVIATIONS: [(char, char, char); 14] = [
+    ('ㅓ', 'ㄱ', '⠹'),
+    ('ㅓ', 'ㄴ', '⠾'),
+    ('ㅓ', 'ㄹ', '⠞'),
+    ('ㅕ', 'ㄴ', '⠡'),
+    ('ㅕ', 'ㄹ', '⠳'),
+    ('ㅕ', 'ㅇ', '⠻'),
+    ('ㅗ', 'ㄱ', '⠭'),
+    ('ㅗ', 'ㄴ', '⠷'),
+    ('ㅗ', 'ㅇ', '⠿'),
+    ('ㅜ', 'ㄴ', '⠛'),
+    ('ㅜ', 'ㄹ', '⠯'),
+    ('ㅡ', 'ㄴ', '⠵'),
+    ('ㅡ', 'ㄹ', '⠮'),
+    ('ㅣ', 'ㄴ', '⠟'),
+];
+
+// Initials (and their doubled forms) whose ㅏ is omitted, so that e.g. 나
+// is written with the ㄴ cell alone.
+const A_OMITTED: [char; 9] = ['ㄴ', 'ㄷ', 'ㅁ', 'ㅂ', 'ㅈ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ'];
+
+// Abbreviations for 가, 사, and 것.
+const GA: char = '⠫';
+const SA: char = '⠇';
+const GEOT: [char; 2] = ['⠸', '⠎'];
+
+// Abbreviations for words beginning with 그.
+const WORD_ABBREVIATIONS: [(&str, [char; 2]); 7] = [
+    ("그래서", ['⠁', '⠎']),
+    ("그러나", ['⠁', '⠉']),
+    ("그러면", ['⠁', '⠒']),
+    ("그러므로", ['⠁', '⠢']),
+    ("그런데", ['⠁', '⠝']),
+    ("그리고", ['⠁', '⠥']),
+    ("그리하여", ['⠁', '⠱']),
+];
+
+/// Converts Hangul text to Unicode Korean braille (한국 점자), following
+/// the contraction rules of the Korean Braille Standard (한국 점자 규정):
+/// - Initial ㅇ is omitted, and double initials are written with the
+///   된소리표 ⠠ (ㄲ → ⠠⠈).
+/// - 가 and 사 have their own cells (⠫, ⠇), and the ㅏ of 나, 다, 마, 바, 자,
+///   카, 타, 파, and 하 is omitted unless a vowel follows.
+/// - 억, 언, 얼, 연, 열, 영, 옥, 온, 옹, 운, 울, 은, 을, 인, and 것 are
+///   abbreviated, with 영 read as 엉 after ㅅ, ㅆ, ㅈ, ㅉ, and ㅊ.
+/// - Words starting with 그래서, 그러나, 그러면, 그러므로, 그런데, 그리고,
+///   or 그리하여 are abbreviated.
+/// - The separator ⠤ is written between a vowel and a following 예, and
+///   between ㅑ, ㅘ, ㅜ, or ㅝ and a following 애.
+///
+/// Characters other than precomposed Hangul syllables, including spaces and
+/// standalone jamo, are passed through unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::braille::encode;
+///
+/// assert_eq!(encode("한글"), "⠚⠒⠈⠮");
+/// assert_eq!(encode("점자"), "⠨⠎⠢⠨");
+/// assert_eq!(encode("그래서 나이"), "⠁⠎ ⠉⠣⠕");
+/// ```
+pub fn encode(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let word_start = i == 0 || Syllable::from_char(chars[i - 1]).is_none();
+        if word_start
+            && let Some((word, cells)) = WORD_ABBREVIATIONS.iter().find(|(word, _)| {
+                chars[i..]
+                    .iter()
+                    .copied()
+                    .take(word.chars().count())
+                    .eq(word.chars())
+            })
+        {
+            result.extend(cells);
+            i += word.chars().count();
+            continue;
+        }
+
+        match Syllable::from_char(chars[i]) {
+            Some(syllable) => {
+                let next = chars.get(i + 1).and_then(|c| Syllable::from_char(*c));
+                encode_syllable(&syllable, next.as_ref(), &mut result);
+            }
+            None => result.push(chars[i]),
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Converts Unicode Korean braille back to Hangul text, reversing `encode`.
+/// Characters that are not Korean braille cells, including spaces and the
+/// blank braille cell ⠀, are passed through unchanged and end the current
+/// word.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::braille::decode;
+///
+/// assert_eq!(decode("⠚⠒⠈⠮").unwrap(), "한글");
+/// assert_eq!(decode("⠁⠎ ⠉⠣⠕").unwrap(), "그래서 나이");
+/// assert!(decode("⠈").is_err());
+/// ```
+pub fn decode(braille: &str) -> Result<String, BrailleError> {
+    let cells: Vec<char> = braille.chars().collect();
+    let mut decoder = Decoder::default();
+    let mut word_start = true;
+    let mut i = 0;
+    while i < cells.len() {
+        let c = cells[i];
+        if !is_korean_cell(c) {
+            decoder.flush(i)?;
+            decoder.result.push(c);
+            word_start = true;
+            i += 1;
+            continue;
+        }
+
+        if word_start
+            && let Some((word, _)) = WORD_ABBREVIATIONS
+                .iter()
+                .find(|(_, abbreviation)| cells[i..].starts_with(abbreviation))
+        {
+            decoder.result.push_str(word);
+            word_start = false;
+            i += 2;
+            continue;
+        }
+        word_start = false;
+
+        if c == GEOT[0] {
+            if cells.get(i + 1) != Some(&GEOT[1]) {
+                return Err(BrailleError::InvalidSequence(i));
+            }
+            let initial = decoder.take_fortis('ㄱ', i)?;
+            decoder.start_syllable(Syllable::new(initial, 'ㅓ', Some('ㅅ')), i)?;
+            i += 2;
+            continue;
+        }
+
+        decoder.push_cell(c, cells.get(i + 1).copied(), i)?;
+        i += 1;
+    }
+    decoder.flush(cells.len())?;
+    Ok(decoder.result)
+}
+
+fn encode_syllable(syllable: &Syllable, next: Option<&Syllable>, result: &mut String) {
+    let (fortis, base) = split_double_initial(syllable.initial);
+    if fortis {
+        result.push(FORTIS_MARK);
+    }
+
+    if base == 'ㄱ' && syllable.vowel == 'ㅓ' && syllable.final_optional == Some('ㅅ') {
+        result.extend(GEOT);
+        return;
+    }
+
+    if syllable.vowel == 'ㅏ' {
+        match base {
+            'ㄱ' => result.push(GA),
+            'ㅅ' => result.push(SA),
+            'ㅇ' => result.push_str(vowel_cells('ㅏ')),
+            b => {
+                result.push(initial_cell(b));
+                let vowel_follows = match syllable.final_optional {
+                    Some(f) => f == 'ㅆ',
+                    None => next.is_some_and(|n| n.initial == 'ㅇ'),
+                };
+                if !A_OMITTED.contains(&b) || vowel_follows {
+                    result.push_str(vowel_cells('ㅏ'));
+                }
+            }
+        }
+        if let Some(f) = syllable.final_optional {
+            push_final_cells(f, result);
+        }
+    } else {
+        if base != 'ㅇ' {
+            result.push(initial_cell(base));
+        }
+        match rhyme_abbreviation(syllable) {
+            Some((cell, rest)) => {
+                result.push(cell);
+                if let Some(f) = rest {
+                    push_final_cells(f, result);
+                }
+            }
+            None => {
+                result.push_str(vowel_cells(syllable.vowel));
+                if let Some(f) = syllable.final_optional {
+                    push_final_cells(f, result);
+                }
+            }
+        }
+    }
+
+    if syllable.final_optional.is_none()
+        && let Some(next) = next
+        && next.initial == 'ㅇ'
+        && (next.vowel == 'ㅖ'
+            || (next.vowel == 'ㅐ' && AE_COMPOSITES.iter().any(|(v, _)| *v == syllable.vowel)))
+    {
+        result.push(SEPARATOR);
+    }
+}
+
+// Returns the abbreviation cell covering the syllable's vowel and (the
+// first part of) its final, along with any remaining final consonant.
+fn rhyme_abbreviation(syllable: &Syllable) -> Option<(char, Option<char>)> {
+    let finals = final_parts(syllable.final_optional?);
+    let (first, rest) = (finals[0], finals.get(1).copied());
+    let vowel = match (is_sibilant(syllable.initial), syllable.vowel, first) {
+        (true, 'ㅓ', 'ㅇ') => 'ㅕ',
+        (true, 'ㅕ', 'ㅇ') => return None,
+        (_, v, _) => v,
+    };
+    RHYME_ABBREVIATIONS
+        .iter()
+        .find(|(v, f, _)| *v == vowel && *f == first)
+        .map(|(_, _, cell)| (*cell, rest))
+}
+
+fn push_final_cells(f: char, result: &mut String) {
+    for part in final_parts(f) {
+        if let Some((_, cell)) = FINALS.iter().find(|(jamo, _)| *jamo == part) {
+            result.push(*cell);
+        }
+    }
+}
+
+// Splits a final consonant into the finals it is written with.
+fn final_parts(f: char) -> Vec<char> {
+    if f == 'ㅆ' {
+        return vec![f];
+    }
+    match Jamo::from_compatibility_jamo(f) {
+        Ok(Jamo::CompositeConsonant(c)) => {
+            let (a, b) = c.decompose();
+            vec![a.char_compatibility(), b.char_compatibility()]
+        }
+        _ => vec![f],
+    }
+}
+
+fn combine_finals(a: char, b: char) -> Option<char> {
+    match (
+        Jamo::from_compatibility_jamo(a).ok()?,
+        Jamo::from_compatibility_jamo(b).ok()?,
+    ) {
+        (Jamo::Consonant(a), Jamo::Consonant(b)) => {
+            Some(a.combine_for_final(&b)?.char_compatibility())
+        }
+        _ => None,
+    }
+}
+
+fn split_double_initial(initial: char) -> (bool, char) {
+    match DOUBLE_INITIALS
+        .iter()
+        .find(|(double, _)| *double == initial)
+    {
+        Some((_, base)) => (true, *base),
+        None => (false, initial),
+    }
+}
+
+fn double_initial(base: char) -> Option<char> {
+    DOUBLE_INITIALS
+        .iter()
+        .find(|(_, b)| *b == base)
+        .map(|(double, _)| *double)
+}
+
+fn is_sibilant(initial: char) -> bool {
+    matches!(initial, 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅉ' | 'ㅊ')
+}
+
+fn initial_cell(initial: char) -> char {
+    INITIALS
+        .iter()
+        .find(|(jamo, _)| *jamo == initial)
+        .map_or(initial, |(_, cell)| *cell)
+}
+
+fn vowel_cells(vowel: char) -> &'static str {
+    VOWELS
+        .iter()
+        .find(|(jamo, _)| *jamo == vowel)
+        .map_or("", |(_, cells)| cells)
+}
+
+fn is_korean_cell(c: char) -> bool {
+    c == FORTIS_MARK
+        || c == SEPARATOR
+        || c == GA
+        || c == SA
+        || GEOT.contains(&c)
+        || INITIALS.iter().any(|(_, cell)| *cell == c)
+        || FINALS.iter().any(|(_, cell)| *cell == c)
+        || VOWELS.iter().any(|(_, cells)| cells.starts_with(c))
+        || RHYME_ABBREVIATIONS.iter().any(|(_, _, cell)| *cell == c)
+}
+
+/// A Hangul syllable as compatibility jamo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Syllable {
+    initial: char,
+    vowel: char,
+    final_optional: Option<char>,
+}
+
+impl Syllable {
+    fn new(initial: char, vowel: char, final_optional: Option<char>) -> Self {
+        Self {
+            initial,
+            vowel,
+            final_optional,
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        let block = HangulBlock::from_char(c).ok()?;
+        Some(Self::new(
+            block.initial.char_compatibility(),
+            block.vowel.char_compatibility(),
+            block.final_optional.map(|f| f.char_compatibility()),
+        ))
+    }
+
+    fn to_char(self) -> Option<char> {
+        let final_optional = match self.final_optional {
+            Some(f) => Some(Jamo::from_compatibility_jamo(f).ok()?),
+            None => None,
+        };
+        HangulBlock::try_new(
+            Jamo::from_compatibility_jamo(self.initial).ok()?,
+            Jamo::from_compatibility_jamo(self.vowel).ok()?,
+            final_optional,
+        )
+        .ok()
+        .map(|block| block.to_char())
+    }
+}
+
+/// Decoding state: a syllable whose vowel has been read and which may still
+/// take a final, an initial consonant waiting for its vowel, and the
+/// position of a pending 된소리표.
+#[derive(Debug, Default)]
+struct Decoder {
+    result: String,
+    syllable: Option<Syllable>,
+    initial: Option<(char, usize)>,
+    fortis: Option<usize>,
+}
+
+impl Decoder {
+    fn push_cell(&mut self, c: char, next: Option<char>, at: usize) -> Result<(), BrailleError> {
+        if c == SEPARATOR {
+            return self.flush(at);
+        }
+
+        if c == FORTIS_MARK && self.fortis.is_none() {
+            let doubles = next.is_some_and(|n| {
+                n == GA
+                    || n == SA
+                    || n == GEOT[0]
+                    || DOUBLE_INITIALS
+                        .iter()
+                        .any(|(_, base)| initial_cell(*base) == n)
+            });
+            if doubles {
+                self.flush(at)?;
+                self.fortis = Some(at);
+                return Ok(());
+            }
+        }
+
+        if c == GA || c == SA {
+            let initial = self.take_fortis(if c == GA { 'ㄱ' } else { 'ㅅ' }, at)?;
+            return self.start_syllable(Syllable::new(initial, 'ㅏ', None), at);
+        }
+
+        if let Some((jamo, _)) = INITIALS.iter().find(|(_, cell)| *cell == c) {
+            let initial = self.take_fortis(*jamo, at)?;
+            self.flush(at)?;
+            self.initial = Some((initial, at));
+            return Ok(());
+        }
+
+        if self.fortis.is_some() {
+            return Err(BrailleError::InvalidSequence(at));
+        }
+
+        if let Some((vowel, final_consonant, _)) =
+            RHYME_ABBREVIATIONS.iter().find(|(_, _, cell)| *cell == c)
+        {
+            let initial = self.initial.take().map_or('ㅇ', |(initial, _)| initial);
+            let vowel = if is_sibilant(initial) && c == '⠻' {
+                'ㅓ'
+            } else {
+                *vowel
+            };
+            return self.start_syllable(Syllable::new(initial, vowel, Some(*final_consonant)), at);
+        }
+
+        if let Some((vowel, _)) = VOWELS.iter().find(|(_, cells)| cells.starts_with(c)) {
+            if let Some((initial, _)) = self.initial.take() {
+                return self.start_syllable(Syllable::new(initial, *vowel, None), at);
+            }
+            if let Some(syllable) = &mut self.syllable
+                && syllable.final_optional.is_none()
+            {
+                if *vowel == 'ㅖ' {
+                    syllable.final_optional = Some('ㅆ');
+                    return Ok(());
+                }
+                if *vowel == 'ㅐ'
+                    && let Some((_, composite)) =
+                        AE_COMPOSITES.iter().find(|(v, _)| *v == syllable.vowel)
+                {
+                    syllable.vowel = *composite;
+                    return Ok(());
+                }
+            }
+            return self.start_syllable(Syllable::new('ㅇ', *vowel, None), at);
+        }
+
+        if let Some((final_consonant, _)) = FINALS.iter().find(|(_, cell)| *cell == c) {
+            if let Some((initial, _)) = self.initial.take() {
+                if !A_OMITTED.contains(&split_double_initial(initial).1) {
+                    return Err(BrailleError::InvalidSequence(at));
+                }
+                return self
+                    .start_syllable(Syllable::new(initial, 'ㅏ', Some(*final_consonant)), at);
+            }
+            let syllable = self
+                .syllable
+                .as_mut()
+                .ok_or(BrailleError::InvalidSequence(at))?;
+            syllable.final_optional = match syllable.final_optional {
+                None => Some(*final_consonant),
+                Some(prev) => Some(
+                    combine_finals(prev, *final_consonant)
+                        .ok_or(BrailleError::InvalidSequence(at))?,
+                ),
+            };
+            return Ok(());
+        }
+
+        Err(BrailleError::InvalidSequence(at))
+    }
+
+    // Doubles `base` if a 된소리표 is pending.
+    fn take_fortis(&mut self, base: char, at: usize) -> Result<char, BrailleError> {
+        match self.fortis.take() {
+            Some(_) => double_initial(base).ok_or(BrailleError::InvalidSequence(at)),
+            None => Ok(base),
+        }
+    }
+
+    fn start_syllable(&mut self, syllable: Syllable, at: usize) -> Result<(), BrailleError> {
+        self.flush(at)?;
+        self.syllable = Some(syllable);
+        Ok(())
+    }
+
+    // Writes out any syllable in progress, expanding a lone initial whose ㅏ
+    // was omitted.
+    fn flush(&mut self, at: usize) -> Result<(), BrailleError> {
+        if let Some(position) = self.fortis {
+            return Err(BrailleError::InvalidSequence(position));
+        }
+        if let Some(syllable) = self.syllable.take() {
+            let c = syllable
+                .to_char()
+                .ok_or(BrailleError::InvalidSequence(at))?;
+            self.result.push(c);
+        }
+        if let Some((initial, position)) = self.initial.take() {
+            if !A_OMITTED.contains(&split_double_initial(initial).1) {
+                return Err(BrailleError::InvalidSequence(position));
+            }
+            let c = Syllable::new(initial, 'ㅏ', None)
+                .to_char()
+                .ok_or(BrailleError::InvalidSequence(at))?;
+            self.result.push(c);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roundtrip(text: &str, braille: &str) {
+        assert_eq!(encode(text), braille, "encoding {}", text);
+        assert_eq!(decode(braille).unwrap(), text, "decoding {}", braille);
+    }
+
+    #[test]
+    fn basic_syllables() {
+        assert_roundtrip("한글", "⠚⠒⠈⠮");
+        assert_roundtrip("점자", "⠨⠎⠢⠨");
+        assert_roundtrip("라면", "⠐⠣⠑⠡");
+        assert_roundtrip("했다", "⠚⠗⠌⠊");
+        assert_roundtrip("의사", "⠺⠇");
+    }
+
+    #[test]
+    fn double_initials_and_finals() {
+        assert_roundtrip("까치", "⠠⠫⠰⠕");
+        assert_roundtrip("싸다", "⠠⠇⠊");
+        assert_roundtrip("써", "⠠⠠⠎");
+        assert_roundtrip("닭", "⠊⠂⠁");
+        assert_roundtrip("밖", "⠘⠁⠁");
+        assert_roundtrip("얽", "⠞⠁");
+    }
+
+    #[test]
+    fn abbreviations() {
+        assert_roundtrip("것", "⠸⠎");
+        assert_roundtrip("껏", "⠠⠸⠎");
+        assert_roundtrip("영어", "⠻⠎");
+        assert_roundtrip("성", "⠠⠻");
+        assert_roundtrip("셩", "⠠⠱⠶");
+        assert_roundtrip("운동", "⠛⠊⠿");
+        assert_roundtrip("인간", "⠟⠫⠒");
+    }
+
+    #[test]
+    fn a_omitted_before_vowel() {
+        assert_roundtrip("나이", "⠉⠣⠕");
+        assert_roundtrip("나무", "⠉⠑⠍");
+        assert_roundtrip("났다", "⠉⠣⠌⠊");
+        assert_roundtrip("녜", "⠉⠌");
+    }
+
+    #[test]
+    fn separator_between_vowels() {
+        assert_roundtrip("서예", "⠠⠎⠤⠌");
+        assert_roundtrip("우애", "⠍⠤⠗");
+        assert_roundtrip("위", "⠍⠗");
+        assert_roundtrip("있예", "⠕⠌⠌");
+    }
+
+    #[test]
+    fn word_abbreviations() {
+        assert_roundtrip("그래서", "⠁⠎");
+        assert_roundtrip("그리고 그런데도", "⠁⠥ ⠁⠝⠊⠥");
+        // Only applied at the start of a word
+        assert_roundtrip("아그래서", "⠣⠈⠪⠐⠗⠠⠎");
+    }
+
+    #[test]
+    fn non_hangul_passes_through() {
+        assert_roundtrip("ABC 1!", "ABC 1!");
+        assert_eq!(encode("ㄱ"), "ㄱ");
+    }
+
+    #[test]
+    fn invalid_sequences() {
+        assert_eq!(decode("⠠"), Err(BrailleError::InvalidSequence(0)));
+        assert_eq!(decode("⠸"), Err(BrailleError::InvalidSequence(0)));
+        assert_eq!(decode("⠣⠈ "), Err(BrailleError::InvalidSequence(1)));
+        assert_eq!(decode("⠁"), Err(BrailleError::InvalidSequence(0)));
+    }
+}
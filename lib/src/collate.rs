@@ -0,0 +1,298 @@
+use std::cmp::Ordering;
+
+use crate::block::HangulBlock;
+use crate::jamo::*;
+
+// Leading byte of each character's key; non-Hangul text sorts before Hangul.
+const NON_HANGUL_KEY: u8 = 0x01;
+const HANGUL_KEY: u8 = 0x02;
+
+// Trailing byte of a Hangul character's key; a standalone jamo sorts
+// immediately before the syllables it begins.
+const STANDALONE_JAMO: u8 = 0x00;
+const SYLLABLE: u8 = 0x01;
+
+// Jamo orderings used by `CollationOrder::NorthKorean`, as compatibility
+// jamo. ㅇ is sorted after all other consonants, and the double consonants
+// follow the plain ones.
+const NORTH_KOREAN_CONSONANTS: [char; 30] = [
+    'ㄱ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ',
+    'ㅄ', 'ㅅ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ', 'ㄲ', 'ㄸ', 'ㅃ', 'ㅆ', 'ㅉ', 'ㅇ',
+];
+const NORTH_KOREAN_VOWELS: [char; 21] = [
+    'ㅏ', 'ㅑ', 'ㅓ', 'ㅕ', 'ㅗ', 'ㅛ', 'ㅜ', 'ㅠ', 'ㅡ', 'ㅣ', 'ㅐ', 'ㅒ', 'ㅔ', 'ㅖ', 'ㅚ', 'ㅟ',
+    'ㅢ', 'ㅘ', 'ㅝ', 'ㅙ', 'ㅞ',
+];
+const NORTH_KOREAN_FINALS: [char; 27] = [
+    'ㄱ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ',
+    'ㅄ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ', 'ㄲ', 'ㅆ',
+];
+
+/// The jamo ordering used when collating Hangul.
+///
+/// - `SouthKorean`: the standard South Korean dictionary order (가나다순),
+///   which follows Unicode order for modern syllables.
+/// - `NorthKorean`: the DPRK (Cultured Language) order, in which the double
+///   consonants follow ㅎ, ㅇ as an initial sorts after all other consonants,
+///   and the vowels ㅐ, ㅒ, ㅔ, ㅖ, ㅚ, ㅟ, ㅢ, ㅘ, ㅝ, ㅙ, ㅞ follow ㅣ.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollationOrder {
+    #[default]
+    SouthKorean,
+    NorthKorean,
+}
+
+impl CollationOrder {
+    /// Returns a sort key for `s` such that comparing two keys byte-wise
+    /// orders the original strings in this collation order.
+    ///
+    /// Hangul syllables are ordered by initial, then vowel, then final
+    /// consonant. Standalone jamo sort immediately before the syllables they
+    /// begin (ㄱ sorts before 가, and the vowel ㅏ sorts before 아), and
+    /// non-Hangul characters sort before all Hangul, by codepoint.
+    ///
+    /// Generating keys once and sorting by them is much cheaper than
+    /// repeatedly calling `compare` when sorting large lists.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::collate::CollationOrder;
+    ///
+    /// let mut words = vec!["아이", "까치", "하늘", "가방"];
+    /// words.sort_by_cached_key(|w| CollationOrder::NorthKorean.sort_key(w));
+    /// assert_eq!(words, vec!["가방", "하늘", "까치", "아이"]);
+    /// ```
+    pub fn sort_key(&self, s: &str) -> Vec<u8> {
+        let mut key = Vec::with_capacity(s.len() * 2);
+        for c in s.chars() {
+            match self.hangul_key(c) {
+                Some((initial, vowel, final_rank, kind)) => {
+                    key.extend_from_slice(&[HANGUL_KEY, initial, vowel, final_rank, kind]);
+                }
+                None => {
+                    let [_, b1, b2, b3] = (c as u32).to_be_bytes();
+                    key.extend_from_slice(&[NON_HANGUL_KEY, b1, b2, b3]);
+                }
+            }
+        }
+        key
+    }
+
+    /// Compares two strings in this collation order.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use hangul_cd::collate::CollationOrder;
+    ///
+    /// assert_eq!(CollationOrder::SouthKorean.compare("개", "거"), Ordering::Less);
+    /// assert_eq!(CollationOrder::NorthKorean.compare("개", "거"), Ordering::Greater);
+    /// ```
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.sort_key(a).cmp(&self.sort_key(b))
+    }
+
+    /// Sorts a slice of strings in place in this collation order.
+    /// Sort keys are computed once per element.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::collate::CollationOrder;
+    ///
+    /// let mut words = vec!["오리", "토끼", "꿩"];
+    /// CollationOrder::NorthKorean.sort(&mut words);
+    /// assert_eq!(words, vec!["토끼", "꿩", "오리"]);
+    /// ```
+    pub fn sort<S: AsRef<str>>(&self, items: &mut [S]) {
+        items.sort_by_cached_key(|s| self.sort_key(s.as_ref()));
+    }
+
+    /// Returns the (initial, vowel, final, kind) ranks of a Hangul character,
+    /// or `None` if the character is not Hangul.
+    fn hangul_key(&self, c: char) -> Option<(u8, u8, u8, u8)> {
+        if let Ok(block) = HangulBlock::from_char(c) {
+            let final_rank = match block.final_jamo() {
+                Some(f) => self.final_rank(f)?,
+                None => 0,
+            };
+            return Some((
+                self.consonant_rank(block.initial.jamo()),
+                self.vowel_rank(block.vowel.jamo()),
+                final_rank,
+                SYLLABLE,
+            ));
+        }
+
+        match Character::from_char(c).ok()? {
+            Character::Hangul(jamo) => match jamo {
+                Jamo::Consonant(_) | Jamo::CompositeConsonant(_) => {
+                    Some((self.consonant_rank(&jamo), 0, 0, STANDALONE_JAMO))
+                }
+                Jamo::Vowel(_) | Jamo::CompositeVowel(_) => Some((
+                    self.consonant_rank(&Jamo::Consonant(JamoConsonantSingular::Ieung)),
+                    self.vowel_rank(&jamo),
+                    0,
+                    STANDALONE_JAMO,
+                )),
+            },
+            Character::NonHangul(_) => None,
+        }
+    }
+
+    // Compatibility jamo consonants (U+3131 to U+314E) are laid out in South
+    // Korean dictionary order, so their offset doubles as a rank.
+    fn consonant_rank(&self, jamo: &Jamo) -> u8 {
+        let c = jamo.char_compatibility();
+        match self {
+            CollationOrder::SouthKorean => (c as u32 - 0x3130) as u8,
+            CollationOrder::NorthKorean => table_rank(&NORTH_KOREAN_CONSONANTS, c),
+        }
+    }
+
+    // Compatibility jamo vowels (U+314F to U+3163) are likewise in South
+    // Korean dictionary order.
+    fn vowel_rank(&self, jamo: &Jamo) -> u8 {
+        let c = jamo.char_compatibility();
+        match self {
+            CollationOrder::SouthKorean => (c as u32 - 0x314E) as u8,
+            CollationOrder::NorthKorean => table_rank(&NORTH_KOREAN_VOWELS, c),
+        }
+    }
+
+    fn final_rank(&self, jamo: &Jamo) -> Option<u8> {
+        let c = jamo.char_modern(JamoPosition::Final)?;
+        match self {
+            CollationOrder::SouthKorean => Some((c as u32 - T_BASE) as u8),
+            CollationOrder::NorthKorean => {
+                Some(table_rank(&NORTH_KOREAN_FINALS, jamo.char_compatibility()))
+            }
+        }
+    }
+}
+
+/// Returns a sort key for `s` such that comparing two keys byte-wise orders
+/// the original strings in South Korean dictionary order (가나다순). See
+/// `CollationOrder::sort_key` for details on how characters are ordered.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::collate::sort_key;
+///
+/// let mut words = vec!["나무", "가방", "ㄱ", "각", "abc", "까치"];
+/// words.sort_by_cached_key(|w| sort_key(w));
+/// assert_eq!(words, vec!["abc", "ㄱ", "가방", "각", "까치", "나무"]);
+/// ```
+pub fn sort_key(s: &str) -> Vec<u8> {
+    CollationOrder::SouthKorean.sort_key(s)
+}
+
+/// Compares two strings in South Korean dictionary order.
+///
+/// **Example:**
+/// ```rust
+/// use std::cmp::Ordering;
+/// use hangul_cd::collate::compare;
+///
+/// assert_eq!(compare("간", "갈"), Ordering::Less);
+/// assert_eq!(compare("ㅎ", "하"), Ordering::Less);
+/// assert_eq!(compare("한글", "한글"), Ordering::Equal);
+/// ```
+pub fn compare(a: &str, b: &str) -> Ordering {
+    CollationOrder::SouthKorean.compare(a, b)
+}
+
+/// Sorts a slice of strings in place in South Korean dictionary order.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::collate::sort;
+///
+/// let mut words = vec!["하늘", "바다", "사랑"];
+/// sort(&mut words);
+/// assert_eq!(words, vec!["바다", "사랑", "하늘"]);
+/// ```
+pub fn sort<S: AsRef<str>>(items: &mut [S]) {
+    CollationOrder::SouthKorean.sort(items)
+}
+
+// 1-based position of `c` in `table`. Every modern compatibility jamo of the
+// relevant kind appears in each table.
+fn table_rank(table: &[char], c: char) -> u8 {
+    table
+        .iter()
+        .position(|&t| t == c)
+        .map_or(0, |i| i as u8 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syllables_follow_unicode_order() {
+        let mut syllables: Vec<String> = "힣가각갂갃간하까나"
+            .chars()
+            .map(|c| c.to_string())
+            .collect();
+        sort(&mut syllables);
+        assert_eq!(
+            syllables.concat(),
+            "가각갂갃간까나하힣".to_string(),
+            "Modern syllables should sort in Unicode order"
+        );
+    }
+
+    #[test]
+    fn standalone_jamo_precede_their_syllables() {
+        let mut words = vec!["가", "ㄲ", "ㄱ", "까", "ㅏ", "아", "ㅇ", "ㄳ"];
+        sort(&mut words);
+        assert_eq!(words, vec!["ㄱ", "가", "ㄲ", "까", "ㄳ", "ㅇ", "ㅏ", "아"]);
+    }
+
+    #[test]
+    fn modern_jamo_sort_like_compatibility_jamo() {
+        assert_eq!(compare("\u{1100}", "ㄱ"), Ordering::Equal);
+        assert_eq!(compare("\u{1161}", "ㅏ"), Ordering::Equal);
+    }
+
+    #[test]
+    fn prefixes_sort_first() {
+        assert_eq!(compare("한", "한글"), Ordering::Less);
+        assert_eq!(compare("한글", "한국"), Ordering::Greater);
+        assert_eq!(compare("", "가"), Ordering::Less);
+    }
+
+    #[test]
+    fn non_hangul_sorts_before_hangul() {
+        let mut words = vec!["가", "Z", "1", "가1", "가a"];
+        sort(&mut words);
+        assert_eq!(words, vec!["1", "Z", "가", "가1", "가a"]);
+    }
+
+    #[test]
+    fn north_korean_consonant_order() {
+        let mut words = vec!["아", "까", "하", "가", "나", "ㅇ", "ㄲ", "ㅎ"];
+        CollationOrder::NorthKorean.sort(&mut words);
+        assert_eq!(words, vec!["가", "나", "ㅎ", "하", "ㄲ", "까", "ㅇ", "아"]);
+    }
+
+    #[test]
+    fn north_korean_vowel_and_final_order() {
+        let mut words = vec!["과", "개", "기", "괴", "구"];
+        CollationOrder::NorthKorean.sort(&mut words);
+        assert_eq!(words, vec!["구", "기", "개", "괴", "과"]);
+
+        let mut words = vec!["갂", "갛", "각", "갔", "강", "갓"];
+        CollationOrder::NorthKorean.sort(&mut words);
+        assert_eq!(words, vec!["각", "갓", "강", "갛", "갂", "갔"]);
+    }
+
+    #[test]
+    fn free_functions_use_south_korean_order() {
+        assert_eq!(
+            sort_key("까치"),
+            CollationOrder::SouthKorean.sort_key("까치")
+        );
+        assert_eq!(CollationOrder::default(), CollationOrder::SouthKorean);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::number::{to_native_korean_determiner, to_sino_korean};
+
+/// How `format_date` and `format_time` write numbers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumeralStyle {
+    /// Arabic digits, as usually written: 2024년 3월 5일, 오후 3시 30분.
+    #[default]
+    Digits,
+
+    /// Spelled out in Hangul, as read aloud: 이천이십사 년 삼월 오 일,
+    /// 오후 세 시 반. Hours use native Korean numerals and everything else
+    /// Sino-Korean numerals.
+    Hangul,
+}
+
+/// Formats a date in Korean, or returns `None` if `month` is not between 1
+/// and 12 or `day` is not a day of that month, such as February 29 in a
+/// year that is not a leap year. Years before 1 CE are written with 기원전,
+/// counting 0 as 1 BCE, and leap years follow the Gregorian calendar
+/// throughout.
+///
+/// When spelled out in Hangul, 6월 and 10월 are read 유월 and 시월.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::datetime::{NumeralStyle, format_date};
+///
+/// assert_eq!(
+///     format_date(2024, 3, 5, NumeralStyle::Digits).as_deref(),
+///     Some("2024년 3월 5일")
+/// );
+/// assert_eq!(
+///     format_date(2024, 10, 9, NumeralStyle::Hangul).as_deref(),
+///     Some("이천이십사 년 시월 구 일")
+/// );
+/// assert_eq!(format_date(2024, 13, 1, NumeralStyle::Digits), None);
+/// ```
+pub fn format_date(year: i32, month: u32, day: u32, style: NumeralStyle) -> Option<String> {
+    if day == 0 || day > days_in_month(year, month)? {
+        return None;
+    }
+    let era = if year < 1 { "기원전 " } else { "" };
+    let year = if year < 1 {
+        1 - year as i64
+    } else {
+        year as i64
+    } as u64;
+    Some(match style {
+        NumeralStyle::Digits => format!("{era}{year}년 {month}월 {day}일"),
+        NumeralStyle::Hangul => format!(
+            "{era}{} 년 {} {} 일",
+            to_sino_korean(year),
+            MONTH_NAMES[month as usize - 1],
+            to_sino_korean(day as u64)
+        ),
+    })
+}
+
+/// Formats a time of day in Korean on the 12-hour clock with 오전 or 오후,
+/// or returns `None` if `hour` is not below 24 or `minute` is not below 60.
+/// Whole hours are written without minutes.
+///
+/// When spelled out in Hangul, hours are read with native Korean numerals
+/// and minutes with Sino-Korean numerals, and half past is read 반.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::datetime::{NumeralStyle, format_time};
+///
+/// assert_eq!(
+///     format_time(15, 30, NumeralStyle::Digits).as_deref(),
+///     Some("오후 3시 30분")
+/// );
+/// assert_eq!(
+///     format_time(15, 30, NumeralStyle::Hangul).as_deref(),
+///     Some("오후 세 시 반")
+/// );
+/// assert_eq!(
+///     format_time(9, 5, NumeralStyle::Hangul).as_deref(),
+///     Some("오전 아홉 시 오 분")
+/// );
+/// assert_eq!(format_time(0, 0, NumeralStyle::Digits).as_deref(), Some("오전 12시"));
+/// ```
+pub fn format_time(hour: u32, minute: u32, style: NumeralStyle) -> Option<String> {
+    if hour >= 24 || minute >= 60 {
+        return None;
+    }
+    let period = if hour < 12 { "오전" } else { "오후" };
+    let hour = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    let mut result = match style {
+        NumeralStyle::Digits => format!("{period} {hour}시"),
+        // Infallible: `hour` is between 1 and 12
+        NumeralStyle::Hangul => {
+            format!("{period} {} 시", to_native_korean_determiner(hour).unwrap())
+        }
+    };
+    match (style, minute) {
+        (_, 0) => {}
+        (NumeralStyle::Digits, _) => result.push_str(&format!(" {minute}분")),
+        (NumeralStyle::Hangul, 30) => result.push_str(" 반"),
+        (NumeralStyle::Hangul, _) => {
+            result.push_str(&format!(" {} 분", to_sino_korean(minute as u64)));
+        }
+    }
+    Some(result)
+}
+
+/// Formats a `chrono` date in Korean, as `format_date` does.
+///
+/// **Example:**
+/// ```rust
+/// use chrono::NaiveDate;
+/// use hangul_cd::datetime::{NumeralStyle, format_chrono_date};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 25).unwrap();
+/// assert_eq!(format_chrono_date(&date, NumeralStyle::Digits), "2024년 6월 25일");
+/// assert_eq!(
+///     format_chrono_date(&date, NumeralStyle::Hangul),
+///     "이천이십사 년 유월 이십오 일"
+/// );
+/// ```
+#[cfg(feature = "chrono")]
+pub fn format_chrono_date<D: chrono::Datelike>(date: &D, style: NumeralStyle) -> String {
+    // Infallible: chrono months and days are always in range
+    format_date(date.year(), date.month(), date.day(), style).unwrap()
+}
+
+/// Formats a `chrono` time in Korean, as `format_time` does. Seconds are
+/// ignored.
+///
+/// **Example:**
+/// ```rust
+/// use chrono::NaiveTime;
+/// use hangul_cd::datetime::{NumeralStyle, format_chrono_time};
+///
+/// let time = NaiveTime::from_hms_opt(12, 30, 15).unwrap();
+/// assert_eq!(format_chrono_time(&time, NumeralStyle::Hangul), "오후 열두 시 반");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn format_chrono_time<T: chrono::Timelike>(time: &T, style: NumeralStyle) -> String {
+    // Infallible: chrono hours and minutes are always in range
+    format_time(time.hour(), time.minute(), style).unwrap()
+}
+
+// Returns the name of `month` spelled out in Hangul, or `None` if it is not
+// between 1 and 12.
+pub(crate) fn month_name(month: u32) -> Option<&'static str> {
+    MONTH_NAMES.get(month.checked_sub(1)? as usize).copied()
+}
+
+// Returns the number of days in `month` of `year`, or `None` if `month` is
+// not between 1 and 12.
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    Some(match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        _ => return None,
+    })
+}
+
+// The names of the months spelled out in Hangul, with the irregular 유월 and
+// 시월.
+const MONTH_NAMES: [&str; 12] = [
+    "일월",
+    "이월",
+    "삼월",
+    "사월",
+    "오월",
+    "유월",
+    "칠월",
+    "팔월",
+    "구월",
+    "시월",
+    "십일월",
+    "십이월",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        assert_eq!(
+            format_date(1, 6, 1, NumeralStyle::Hangul).as_deref(),
+            Some("일 년 유월 일 일")
+        );
+        assert_eq!(
+            format_date(-99, 12, 31, NumeralStyle::Digits).as_deref(),
+            Some("기원전 100년 12월 31일")
+        );
+        assert_eq!(format_date(2024, 1, 0, NumeralStyle::Digits), None);
+    }
+
+    #[test]
+    fn dates_must_exist() {
+        assert_eq!(
+            format_date(2024, 2, 29, NumeralStyle::Digits).as_deref(),
+            Some("2024년 2월 29일")
+        );
+        assert_eq!(
+            format_date(2000, 2, 29, NumeralStyle::Digits).as_deref(),
+            Some("2000년 2월 29일")
+        );
+        assert_eq!(format_date(2023, 2, 29, NumeralStyle::Digits), None);
+        assert_eq!(format_date(1900, 2, 29, NumeralStyle::Digits), None);
+        assert_eq!(format_date(2023, 2, 31, NumeralStyle::Digits), None);
+        assert_eq!(format_date(2024, 4, 31, NumeralStyle::Digits), None);
+        assert_eq!(
+            format_date(2024, 4, 30, NumeralStyle::Digits).as_deref(),
+            Some("2024년 4월 30일")
+        );
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(
+            format_time(12, 0, NumeralStyle::Hangul).as_deref(),
+            Some("오후 열두 시")
+        );
+        assert_eq!(
+            format_time(23, 59, NumeralStyle::Hangul).as_deref(),
+            Some("오후 열한 시 오십구 분")
+        );
+        assert_eq!(
+            format_time(11, 30, NumeralStyle::Digits).as_deref(),
+            Some("오전 11시 30분")
+        );
+        assert_eq!(format_time(24, 0, NumeralStyle::Digits), None);
+        assert_eq!(format_time(1, 60, NumeralStyle::Hangul), None);
+    }
+}
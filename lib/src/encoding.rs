@@ -0,0 +1,356 @@
+use encoding_rs::{DecoderResult, EUC_KR, WINDOWS_1252};
+use thiserror::Error;
+
+use crate::block::HangulBlock;
+use crate::jamo::Character;
+
+/// An error that can occur when converting text to or from a Korean
+/// encoding.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EncodingError {
+    /// Occurs when a character has no representation in the target
+    /// encoding.
+    #[error("Character '{0}' cannot be encoded")]
+    Unmappable(char),
+
+    /// Occurs when the bytes starting at the given offset are not a valid
+    /// character in the encoding.
+    #[error("Malformed bytes at offset {0}")]
+    Malformed(usize),
+}
+
+/// A legacy Korean text encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KoreanEncoding {
+    /// EUC-KR, the KS X 1001 character set in two bytes per character,
+    /// which covers only the 2,350 most common of the 11,172 modern
+    /// syllables.
+    EucKr,
+
+    /// CP949 (Unified Hangul Code), Microsoft's extension of EUC-KR with
+    /// every modern syllable. Most text labeled EUC-KR is actually CP949.
+    Cp949,
+}
+
+/// Encodes `text` in `encoding`, or returns the first character that the
+/// encoding cannot represent.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::encoding::{EncodingError, KoreanEncoding, encode};
+///
+/// assert_eq!(encode("한글", KoreanEncoding::EucKr).unwrap(), [0xC7, 0xD1, 0xB1, 0xDB]);
+///
+/// // 똠 is not one of the syllables in EUC-KR, but it is in CP949
+/// assert_eq!(
+///     encode("똠방각하", KoreanEncoding::EucKr),
+///     Err(EncodingError::Unmappable('똠'))
+/// );
+/// assert_eq!(encode("똠", KoreanEncoding::Cp949).unwrap(), [0x8C, 0x63]);
+/// ```
+pub fn encode(text: &str, encoding: KoreanEncoding) -> Result<Vec<u8>, EncodingError> {
+    let mut result = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        if !encode_char(c, encoding, &mut result) {
+            return Err(EncodingError::Unmappable(c));
+        }
+    }
+    Ok(result)
+}
+
+/// Encodes `text` in `encoding`, replacing what the encoding cannot
+/// represent instead of failing. A modern syllable missing from EUC-KR is
+/// written as its compatibility jamo, so 똠 becomes ㄸㅗㅁ, and conjoining
+/// jamo are written as compatibility jamo. Any other unmappable character
+/// becomes `?`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::encoding::{KoreanEncoding, decode, encode_lossy};
+///
+/// let bytes = encode_lossy("똠방각하 😀", KoreanEncoding::EucKr);
+/// assert_eq!(decode(&bytes).unwrap(), "ㄸㅗㅁ방각하 ?");
+///
+/// let bytes = encode_lossy("똠방각하", KoreanEncoding::Cp949);
+/// assert_eq!(decode(&bytes).unwrap(), "똠방각하");
+/// ```
+pub fn encode_lossy(text: &str, encoding: KoreanEncoding) -> Vec<u8> {
+    let mut result = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        if encode_char(c, encoding, &mut result) {
+            continue;
+        }
+        let jamo: Vec<char> = if let Ok(block) = HangulBlock::from_char(c) {
+            let mut jamo = vec![
+                block.initial.char_compatibility(),
+                block.vowel.char_compatibility(),
+            ];
+            jamo.extend(block.final_optional.map(|f| f.char_compatibility()));
+            jamo
+        } else if let Ok(Character::Hangul(jamo)) = Character::from_char(c) {
+            vec![jamo.char_compatibility()]
+        } else {
+            Vec::new()
+        };
+        let start = result.len();
+        if jamo.is_empty() || !jamo.iter().all(|&j| encode_char(j, encoding, &mut result)) {
+            result.truncate(start);
+            result.push(b'?');
+        }
+    }
+    result
+}
+
+/// Decodes EUC-KR or CP949 `bytes`, or returns the offset of the first
+/// malformed character. Since CP949 is a superset of EUC-KR and text
+/// labeled EUC-KR often contains CP949 syllables, both are decoded as
+/// CP949.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::encoding::{EncodingError, decode};
+///
+/// assert_eq!(decode(&[0xC7, 0xD1, 0xB1, 0xDB, b'!']).unwrap(), "한글!");
+/// assert_eq!(decode(&[b'a', 0xC7, 0xFF]), Err(EncodingError::Malformed(1)));
+/// ```
+pub fn decode(bytes: &[u8]) -> Result<String, EncodingError> {
+    let mut decoder = EUC_KR.new_decoder_without_bom_handling();
+    let mut result = String::with_capacity(bytes.len() * 3 / 2 + 4);
+    let mut read = 0;
+    loop {
+        let (status, consumed) =
+            decoder.decode_to_string_without_replacement(&bytes[read..], &mut result, true);
+        read += consumed;
+        match status {
+            DecoderResult::InputEmpty => return Ok(result),
+            DecoderResult::OutputFull => result.reserve(bytes.len() - read + 4),
+            DecoderResult::Malformed(len, after) => {
+                return Err(EncodingError::Malformed(
+                    read - after as usize - len as usize,
+                ));
+            }
+        }
+    }
+}
+
+/// Decodes EUC-KR or CP949 `bytes` like `decode`, replacing malformed
+/// characters with U+FFFD.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::encoding::decode_lossy;
+///
+/// assert_eq!(decode_lossy(&[0xC7, 0xD1, 0xC7]), "한\u{FFFD}");
+/// ```
+pub fn decode_lossy(bytes: &[u8]) -> String {
+    EUC_KR.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+/// A way Korean text can be garbled by decoding it with the wrong encoding,
+/// as undone by `repair`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mojibake {
+    /// EUC-KR or CP949 bytes decoded as Latin-1 or Windows-1252, so that
+    /// 한글 reads ÇÑ±Û.
+    EucKrAsLatin1,
+
+    /// UTF-8 bytes decoded as EUC-KR or CP949, so that 감사 reads 媛먯궗.
+    Utf8AsCp949,
+
+    /// UTF-8 bytes decoded as Latin-1 or Windows-1252, so that 한 reads
+    /// í•œ.
+    Utf8AsLatin1,
+}
+
+/// Text recovered from mojibake by `repair`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repaired {
+    /// The recovered text.
+    pub text: String,
+
+    /// How the text had been garbled.
+    pub mojibake: Mojibake,
+
+    /// How likely the recovered text is to be the original, from `0.0` to
+    /// `1.0`: the share of its non-ASCII characters that are among the
+    /// 2,350 common syllables of EUC-KR.
+    pub confidence: f64,
+}
+
+/// Detects whether `text` is Korean garbled by decoding it with the wrong
+/// encoding, as described by `Mojibake`, and returns the recovered text.
+///
+/// Each kind of mojibake is undone by encoding `text` back to bytes the
+/// way it was wrongly decoded and decoding those bytes correctly. A
+/// candidate is kept only if the bytes decode without errors and more of
+/// the result is Hangul than of `text` itself, and the most
+/// confident candidate is returned. Returns `None` if no candidate
+/// improves on `text`, including when it was garbled beyond recovery and
+/// contains U+FFFD in place of the lost bytes.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::encoding::{Mojibake, repair};
+///
+/// let repaired = repair("ÇÑ±Û ÆùÆ®").unwrap();
+/// assert_eq!(repaired.text, "한글 폰트");
+/// assert_eq!(repaired.mojibake, Mojibake::EucKrAsLatin1);
+/// assert_eq!(repaired.confidence, 1.0);
+///
+/// assert_eq!(repair("媛먯궗!").unwrap().text, "감사!");
+/// assert_eq!(repair("한글"), None);
+/// assert_eq!(repair("café"), None);
+/// ```
+pub fn repair(text: &str) -> Option<Repaired> {
+    let original = hangul_share(text);
+    let latin1 = latin1_bytes(text);
+    let candidates = [
+        (
+            latin1.as_deref().and_then(|b| decode(b).ok()),
+            Mojibake::EucKrAsLatin1,
+        ),
+        (
+            encode(text, KoreanEncoding::Cp949)
+                .ok()
+                .and_then(|b| String::from_utf8(b).ok()),
+            Mojibake::Utf8AsCp949,
+        ),
+        (
+            latin1.and_then(|b| String::from_utf8(b).ok()),
+            Mojibake::Utf8AsLatin1,
+        ),
+    ];
+    candidates
+        .into_iter()
+        .filter_map(|(text, mojibake)| {
+            let text = text?;
+            let confidence = hangul_share(&text)?;
+            Some(Repaired {
+                text,
+                mojibake,
+                confidence,
+            })
+        })
+        .filter(|repaired| original.is_none_or(|share| repaired.confidence > share))
+        .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+}
+
+// Returns the bytes `text` would have been decoded from as Latin-1 or
+// Windows-1252, or `None` if it contains characters in neither.
+fn latin1_bytes(text: &str) -> Option<Vec<u8>> {
+    text.chars()
+        .map(|c| {
+            if let Ok(byte) = u8::try_from(c) {
+                return Some(byte);
+            }
+            let mut buffer = [0; 4];
+            let (encoded, _, unmappable) = WINDOWS_1252.encode(c.encode_utf8(&mut buffer));
+            (!unmappable).then(|| encoded[0])
+        })
+        .collect()
+}
+
+// Returns the share of the non-ASCII characters in `text` that are Hangul
+// syllables, or `None` if it has no non-ASCII characters. The common
+// syllables in EUC-KR count fully, and those only in CP949 count half,
+// since text garbled through CP949 is often made of them.
+fn hangul_share(text: &str) -> Option<f64> {
+    let mut total = 0;
+    let mut score = 0.0;
+    for c in text.chars().filter(|c| !c.is_ascii()) {
+        total += 1;
+        if HangulBlock::from_char(c).is_ok() {
+            score += if encode_char(c, KoreanEncoding::EucKr, &mut Vec::new()) {
+                1.0
+            } else {
+                0.5
+            };
+        }
+    }
+    (total > 0).then(|| score / total as f64)
+}
+
+// Appends `c` encoded in `encoding` to `bytes`, returning whether the
+// encoding can represent it.
+fn encode_char(c: char, encoding: KoreanEncoding, bytes: &mut Vec<u8>) -> bool {
+    let mut buffer = [0; 4];
+    let (encoded, _, unmappable) = EUC_KR.encode(c.encode_utf8(&mut buffer));
+    if unmappable {
+        return false;
+    }
+    // EUC-KR proper only uses bytes from 0xA1 to 0xFE for both halves of a
+    // character; CP949 adds lead and trail bytes outside that range.
+    let in_euc_kr = encoded.len() == 1 || encoded.iter().all(|b| (0xA1..=0xFE).contains(b));
+    if encoding == KoreanEncoding::EucKr && !in_euc_kr {
+        return false;
+    }
+    bytes.extend_from_slice(&encoded);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let text = "안녕하세요, world! ㄱㅏ ㅘ 漢字";
+        for encoding in [KoreanEncoding::EucKr, KoreanEncoding::Cp949] {
+            assert_eq!(decode(&encode(text, encoding).unwrap()).unwrap(), text);
+        }
+        // Every modern syllable is in CP949
+        let syllables: String = crate::block::all_syllables().collect();
+        let bytes = encode(&syllables, KoreanEncoding::Cp949).unwrap();
+        assert_eq!(bytes.len(), 11172 * 2);
+        assert_eq!(decode(&bytes).unwrap(), syllables);
+    }
+
+    #[test]
+    fn euc_kr_syllable_count() {
+        let count = crate::block::all_syllables()
+            .filter(|&c| encode(&c.to_string(), KoreanEncoding::EucKr).is_ok())
+            .count();
+        assert_eq!(count, 2350);
+    }
+
+    #[test]
+    fn lossy_encoding() {
+        // Conjoining jamo are written as compatibility jamo without composing
+        let bytes = encode_lossy("\u{1100}\u{1161}\u{11A8} 뷁", KoreanEncoding::EucKr);
+        assert_eq!(decode(&bytes).unwrap(), "ㄱㅏㄱ ㅂㅞㄺ");
+        assert_eq!(encode_lossy("", KoreanEncoding::EucKr), b"");
+        assert_eq!(decode_lossy(b""), "");
+    }
+
+    #[test]
+    fn repairs_mojibake() {
+        let text = "마음 문제, 경제 기업";
+        let as_cp949 = decode(text.as_bytes()).unwrap();
+        let repaired = repair(&as_cp949).unwrap();
+        assert_eq!(repaired.text, text);
+        assert_eq!(repaired.mojibake, Mojibake::Utf8AsCp949);
+
+        let as_latin1: String = text.bytes().map(char::from).collect();
+        assert_eq!(repair(&as_latin1).unwrap().mojibake, Mojibake::Utf8AsLatin1);
+        assert_eq!(repair(&as_latin1).unwrap().text, text);
+
+        // Windows-1252 maps some bytes to characters beyond Latin-1
+        let as_1252 = WINDOWS_1252.decode(text.as_bytes()).0;
+        assert_eq!(repair(&as_1252).unwrap().text, text);
+
+        assert_eq!(repair(""), None);
+        assert_eq!(repair("\u{FFFD}\u{FFFD}"), None);
+    }
+
+    #[test]
+    fn repairs_syllables_only_in_cp949() {
+        // 햏, 뷁, and 쀍 are not among the syllables of EUC-KR
+        for text in ["햏", "뷁 쀍", "햏자 문제"] {
+            let bytes = encode(text, KoreanEncoding::Cp949).unwrap();
+            let as_1252 = WINDOWS_1252.decode(&bytes).0;
+            let repaired = repair(&as_1252).unwrap();
+            assert_eq!(repaired.text, text);
+            assert_eq!(repaired.mojibake, Mojibake::EucKrAsLatin1);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::ffi::{CString, c_char};
+
+use crate::string::StringComposer;
+
+/// Returned by `hangul_composer_push` on success.
+pub const HANGUL_OK: i32 = 0;
+
+/// Returned when a required pointer argument is null.
+pub const HANGUL_ERR_NULL: i32 = -1;
+
+/// Returned when a codepoint is not a valid Unicode scalar value.
+pub const HANGUL_ERR_INVALID_CHAR: i32 = -2;
+
+/// Returned when the composer rejects a character.
+pub const HANGUL_ERR_COMPOSE: i32 = -3;
+
+/// An opaque handle to a `StringComposer`, created with
+/// `hangul_composer_new` and destroyed with `hangul_composer_free`.
+///
+/// **C API:**
+/// ```c
+/// HangulComposer *composer = hangul_composer_new();
+/// // ㅎㅏㄴㄱㅡㄹ
+/// uint32_t codepoints[] = {0x314E, 0x314F, 0x3134, 0x3131, 0x3161, 0x3139};
+/// for (int i = 0; i < 6; i++) hangul_composer_push(composer, codepoints[i]);
+/// char *text = hangul_composer_text(composer); // "한글"
+/// hangul_string_free(text);
+/// hangul_composer_free(composer);
+/// ```
+#[derive(Debug, Default)]
+pub struct HangulComposer {
+    inner: StringComposer,
+}
+
+/// Creates a new, empty composer. The composer must be freed with
+/// `hangul_composer_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn hangul_composer_new() -> *mut HangulComposer {
+    Box::into_raw(Box::default())
+}
+
+/// Pushes the character with Unicode codepoint `codepoint` to `composer`,
+/// returning `HANGUL_OK` or one of the `HANGUL_ERR_*` codes.
+///
+/// # Safety
+///
+/// `composer` must be null or a pointer returned by `hangul_composer_new`
+/// that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hangul_composer_push(
+    composer: *mut HangulComposer,
+    codepoint: u32,
+) -> i32 {
+    // SAFETY: the caller guarantees `composer` is null or valid.
+    let Some(composer) = (unsafe { composer.as_mut() }) else {
+        return HANGUL_ERR_NULL;
+    };
+    let Some(c) = char::from_u32(codepoint) else {
+        return HANGUL_ERR_INVALID_CHAR;
+    };
+    match composer.inner.push_char(c) {
+        Ok(()) => HANGUL_OK,
+        Err(_) => HANGUL_ERR_COMPOSE,
+    }
+}
+
+/// Removes the last character or jamo from `composer`, like backspace, and
+/// returns its codepoint, or `0` if there was nothing to remove or
+/// `composer` is null.
+///
+/// # Safety
+///
+/// `composer` must be null or a pointer returned by `hangul_composer_new`
+/// that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hangul_composer_pop(composer: *mut HangulComposer) -> u32 {
+    // SAFETY: the caller guarantees `composer` is null or valid.
+    let Some(composer) = (unsafe { composer.as_mut() }) else {
+        return 0;
+    };
+    composer.inner.pop().ok().flatten().map_or(0, u32::from)
+}
+
+/// Returns the composed text of `composer` as a newly allocated,
+/// NUL-terminated UTF-8 string, which must be freed with
+/// `hangul_string_free`. Returns null if `composer` is null, composition
+/// fails, or the text contains a NUL character.
+///
+/// # Safety
+///
+/// `composer` must be null or a pointer returned by `hangul_composer_new`
+/// that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hangul_composer_text(composer: *const HangulComposer) -> *mut c_char {
+    // SAFETY: the caller guarantees `composer` is null or valid.
+    let Some(composer) = (unsafe { composer.as_ref() }) else {
+        return std::ptr::null_mut();
+    };
+    composer
+        .inner
+        .as_string()
+        .ok()
+        .and_then(|text| CString::new(text).ok())
+        .map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Frees a composer created with `hangul_composer_new`. Does nothing if
+/// `composer` is null.
+///
+/// # Safety
+///
+/// `composer` must be null or a pointer returned by `hangul_composer_new`
+/// that has not already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hangul_composer_free(composer: *mut HangulComposer) {
+    if !composer.is_null() {
+        // SAFETY: the caller guarantees `composer` came from
+        // `hangul_composer_new` and is freed only once.
+        drop(unsafe { Box::from_raw(composer) });
+    }
+}
+
+/// Frees a string returned by `hangul_composer_text`. Does nothing if `s`
+/// is null.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer returned by `hangul_composer_text` that
+/// has not already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hangul_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        // SAFETY: the caller guarantees `s` came from `CString::into_raw`
+        // and is freed only once.
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    #[test]
+    fn compose_through_c_api() {
+        unsafe {
+            let composer = hangul_composer_new();
+            for c in "ㅎㅏㄴㄱㅡㄹ".chars() {
+                assert_eq!(hangul_composer_push(composer, c as u32), HANGUL_OK);
+            }
+            assert_eq!(
+                hangul_composer_push(composer, 0xD800),
+                HANGUL_ERR_INVALID_CHAR
+            );
+            assert_eq!(hangul_composer_pop(composer), '\u{1105}' as u32);
+
+            let text = hangul_composer_text(composer);
+            assert_eq!(CStr::from_ptr(text).to_str(), Ok("한그"));
+            hangul_string_free(text);
+
+            hangul_composer_push(composer, 0);
+            assert!(hangul_composer_text(composer).is_null());
+            hangul_composer_free(composer);
+        }
+    }
+
+    #[test]
+    fn null_pointers_are_rejected() {
+        unsafe {
+            assert_eq!(
+                hangul_composer_push(std::ptr::null_mut(), 'a' as u32),
+                HANGUL_ERR_NULL
+            );
+            assert_eq!(hangul_composer_pop(std::ptr::null_mut()), 0);
+            assert!(hangul_composer_text(std::ptr::null()).is_null());
+            hangul_composer_free(std::ptr::null_mut());
+            hangul_string_free(std::ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use std::ops::RangeInclusive;
+
+use crate::jamo::{S_BASE, S_COUNT};
+use crate::typing::keyed_jamo;
+
+/// A seeded random generator of valid Hangul syllables, words, and jamo
+/// keystroke sequences, for property-testing code that handles Korean
+/// text. The same seed always produces the same output.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::block::HangulBlock;
+/// use hangul_cd::generate::HangulGenerator;
+///
+/// let mut generator = HangulGenerator::new(42);
+///
+/// // Every generated syllable is a valid precomposed block
+/// let syllable = generator.syllable();
+/// assert!(HangulBlock::from_char(syllable).is_ok());
+///
+/// let word = generator.word(3);
+/// assert_eq!(word.chars().count(), 3);
+///
+/// // Words separated by spaces, each 1 to 4 syllables long
+/// let text = generator.text(5, 1..=4);
+/// assert_eq!(text.split(' ').count(), 5);
+///
+/// // The jamo typed to enter a random word on a Dubeolsik keyboard
+/// let keys = generator.keystrokes(2);
+/// assert!(keys.len() >= 4);
+///
+/// // Generators with the same seed agree
+/// assert_eq!(HangulGenerator::new(7).word(10), HangulGenerator::new(7).word(10));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HangulGenerator {
+    state: u64,
+}
+
+impl HangulGenerator {
+    /// Creates a new `HangulGenerator` from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns a random precomposed Hangul syllable, chosen uniformly from
+    /// all 11,172 modern syllables.
+    pub fn syllable(&mut self) -> char {
+        let index = self.below(S_COUNT as u64) as u32;
+        // All codepoints in the syllable range are valid chars
+        char::from_u32(S_BASE + index).unwrap()
+    }
+
+    /// Returns a random word of `len` syllables.
+    pub fn word(&mut self, len: usize) -> String {
+        (0..len).map(|_| self.syllable()).collect()
+    }
+
+    /// Returns `word_count` random words separated by single spaces, each
+    /// with a random number of syllables in `word_len`.
+    pub fn text(&mut self, word_count: usize, word_len: RangeInclusive<usize>) -> String {
+        let words: Vec<String> = (0..word_count)
+            .map(|_| {
+                let len = self.in_range(&word_len);
+                self.word(len)
+            })
+            .collect();
+        words.join(" ")
+    }
+
+    /// Returns the compatibility jamo typed to enter a random word of `len`
+    /// syllables on a Dubeolsik keyboard, in order. Composite vowels and
+    /// final clusters are split into the keys used to type them.
+    pub fn keystrokes(&mut self, len: usize) -> Vec<char> {
+        keyed_jamo(&self.word(len))
+    }
+
+    fn in_range(&mut self, range: &RangeInclusive<usize>) -> usize {
+        let (start, end) = (*range.start(), *range.end());
+        if end <= start {
+            return start;
+        }
+        start + self.below((end - start) as u64 + 1) as usize
+    }
+
+    // Returns a value in 0..bound. The modulo bias is negligible for the
+    // small bounds used here.
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    // SplitMix64
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// A random precomposed Hangul syllable, for use with `quickcheck`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::HangulBlock;
+/// use hangul_cd::generate::Syllable;
+/// use quickcheck::{Arbitrary, Gen};
+///
+/// let Syllable(c) = Syllable::arbitrary(&mut Gen::new(10));
+/// assert!(HangulBlock::from_char(c).is_ok());
+/// ```
+#[cfg(feature = "quickcheck")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Syllable(pub char);
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Syllable {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Syllable(HangulGenerator::new(u64::arbitrary(g)).syllable())
+    }
+}
+
+/// A random word of Hangul syllables, for use with `quickcheck`. The word
+/// length is bounded by the `Gen` size, and shrinking removes syllables.
+#[cfg(feature = "quickcheck")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Word(pub String);
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Word {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let len = usize::arbitrary(g) % (g.size() + 1);
+        Word(HangulGenerator::new(u64::arbitrary(g)).word(len))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let chars: Vec<char> = self.0.chars().collect();
+        Box::new((0..chars.len()).map(move |i| {
+            let mut shorter = chars.clone();
+            shorter.remove(i);
+            Word(shorter.into_iter().collect())
+        }))
+    }
+}
+
+/// Returns a `proptest` strategy producing any precomposed Hangul syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::HangulBlock;
+/// use hangul_cd::generate::any_syllable;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(c in any_syllable())| {
+///     let block = HangulBlock::from_char(c).unwrap();
+///     prop_assert_eq!(block.to_char(), c);
+/// });
+/// ```
+#[cfg(feature = "proptest")]
+pub fn any_syllable() -> impl proptest::strategy::Strategy<Value = char> {
+    use proptest::strategy::Strategy;
+
+    (0..S_COUNT).prop_map(|index| char::from_u32(S_BASE + index).unwrap())
+}
+
+/// Returns a `proptest` strategy producing strings of up to 32 compatibility
+/// jamo in any order, as might be pushed into a composer one keystroke at a
+/// time. Sequences need not form valid syllables.
+#[cfg(feature = "proptest")]
+pub fn any_jamo_sequence() -> impl proptest::strategy::Strategy<Value = String> {
+    use proptest::strategy::Strategy;
+
+    proptest::collection::vec(COMPATIBILITY_JAMO, 0..32)
+        .prop_map(|codepoints| codepoints.into_iter().filter_map(char::from_u32).collect())
+}
+
+/// Returns a `proptest` strategy producing strings of up to 32 characters
+/// mixing precomposed syllables, compatibility jamo, ASCII, and whitespace.
+#[cfg(feature = "proptest")]
+pub fn any_mixed_string() -> impl proptest::strategy::Strategy<Value = String> {
+    use proptest::prelude::*;
+
+    let any_char = prop_oneof![
+        any_syllable(),
+        COMPATIBILITY_JAMO.prop_map(|c| char::from_u32(c).unwrap()),
+        proptest::char::range(' ', '~'),
+        Just('\n'),
+    ];
+    proptest::collection::vec(any_char, 0..32).prop_map(|chars| chars.into_iter().collect())
+}
+
+// The modern compatibility jamo, ㄱ through ㅣ.
+#[cfg(feature = "proptest")]
+const COMPATIBILITY_JAMO: std::ops::RangeInclusive<u32> = 0x3131..=0x3163;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::compose;
+
+    #[test]
+    fn generated_text_is_valid_hangul() {
+        let mut generator = HangulGenerator::new(0);
+        for _ in 0..1000 {
+            let c = generator.syllable();
+            assert!((S_BASE..S_BASE + S_COUNT).contains(&(c as u32)));
+        }
+        assert_eq!(generator.word(0), "");
+        assert_eq!(generator.text(0, 1..=3), "");
+        assert_eq!(generator.text(3, 2..=2).chars().count(), 8);
+    }
+
+    #[test]
+    fn keystrokes_compose_back_into_syllables() {
+        let mut generator = HangulGenerator::new(1234);
+        for _ in 0..1000 {
+            let syllable = generator.syllable().to_string();
+            let keys: String = keyed_jamo(&syllable).into_iter().collect();
+            assert_eq!(compose(&keys), syllable);
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_words_are_hangul() {
+        fn prop(word: Word) -> bool {
+            word.0
+                .chars()
+                .all(|c| crate::block::HangulBlock::from_char(c).is_ok())
+        }
+        quickcheck::quickcheck(prop as fn(Word) -> bool);
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_jamo_sequences_compose(jamo in any_jamo_sequence()) {
+            let is_hangul = |c: char| {
+                crate::block::HangulBlock::from_char(c).is_ok()
+                    || crate::jamo::JamoUnicodeType::evaluate(c)
+                        == crate::jamo::JamoUnicodeType::Compatibility
+            };
+            proptest::prop_assert!(compose(&jamo).chars().all(is_hangul));
+        }
+
+        #[test]
+        fn proptest_decompose_preserves_ascii(text in any_mixed_string()) {
+            let ascii = |s: &str| s.chars().filter(char::is_ascii).collect::<String>();
+            let decomposed = crate::string::decompose(&text);
+            proptest::prop_assert_eq!(ascii(&decomposed), ascii(&text));
+        }
+    }
+}
@@ -0,0 +1,576 @@
+use crate::block::{HangulBlock, syllable_from_indices, syllable_indices};
+use crate::jamo::{Choseong, Jamo, JamoConsonantSingular, JamoVowelSingular};
+use crate::string::sentences;
+
+/// Applies the initial-sound law (두음법칙) to the first syllable of every
+/// word in `text`, as in South Korean spelling: 력사 → 역사, 로동 → 노동,
+/// 녀자 → 여자. A word is a run of Hangul syllables, so it starts at the
+/// beginning of `text` or after any character that is not a Hangul
+/// syllable. Other syllables are unchanged.
+///
+/// The law only applies to Sino-Korean words, but every word is treated as
+/// one, so loanwords are changed as well (라디오 → 나디오). Use
+/// `apply_dueum_with` to say which words are Sino-Korean.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grammar::apply_dueum;
+///
+/// assert_eq!(apply_dueum("리론과 로동"), "이론과 노동");
+/// assert_eq!(apply_dueum("(래일)"), "(내일)");
+/// ```
+pub fn apply_dueum(text: &str) -> String {
+    apply_dueum_with(text, |_| true)
+}
+
+/// Applies the initial-sound law (두음법칙) like `apply_dueum`, but only to
+/// the words for which `is_sino_korean` returns `true`. It is called with
+/// each word, a run of Hangul syllables, and any other text is unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grammar::apply_dueum_with;
+///
+/// let loanwords = ["라디오", "뉴스"];
+/// assert_eq!(
+///     apply_dueum_with("라디오, 뉴스, 력사", |word| !loanwords.contains(&word)),
+///     "라디오, 뉴스, 역사"
+/// );
+/// ```
+pub fn apply_dueum_with<F: Fn(&str) -> bool>(text: &str, is_sino_korean: F) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| HangulBlock::from_char(c).is_ok()) {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let len = rest
+            .find(|c: char| HangulBlock::from_char(c).is_err())
+            .unwrap_or(rest.len());
+        let word = &rest[..len];
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) if is_sino_korean(word) => {
+                result.push(apply_initial_sound_law(first));
+                result.push_str(chars.as_str());
+            }
+            _ => result.push_str(word),
+        }
+        rest = &rest[len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Applies the initial-sound law (두음법칙) to a single Hangul syllable, as
+/// it would be read at the start of a word:
+/// - ㄹ becomes ㅇ before ㅑ, ㅕ, ㅖ, ㅛ, ㅠ, and ㅣ (리 → 이)
+/// - ㄹ becomes ㄴ before any other vowel (로 → 노)
+/// - ㄴ becomes ㅇ before ㅕ, ㅛ, ㅠ, and ㅣ (녀 → 여)
+///
+/// Any other character is returned unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grammar::apply_initial_sound_law;
+///
+/// assert_eq!(apply_initial_sound_law('력'), '역');
+/// assert_eq!(apply_initial_sound_law('래'), '내');
+/// assert_eq!(apply_initial_sound_law('뇨'), '요');
+/// assert_eq!(apply_initial_sound_law('한'), '한');
+/// ```
+pub fn apply_initial_sound_law(syllable: char) -> char {
+    let Ok(mut block) = HangulBlock::from_char(syllable) else {
+        return syllable;
+    };
+    let is_iotized = matches!(
+        block.vowel.jamo(),
+        Jamo::Vowel(
+            JamoVowelSingular::Ya
+                | JamoVowelSingular::Yeo
+                | JamoVowelSingular::Ye
+                | JamoVowelSingular::Yo
+                | JamoVowelSingular::Yu
+                | JamoVowelSingular::I
+        )
+    );
+    let new_initial = match (block.initial.jamo(), is_iotized) {
+        (Jamo::Consonant(JamoConsonantSingular::Rieul), true) => JamoConsonantSingular::Ieung,
+        (Jamo::Consonant(JamoConsonantSingular::Rieul), false) => JamoConsonantSingular::Nieun,
+        (Jamo::Consonant(JamoConsonantSingular::Nieun), true)
+            if *block.vowel.jamo() != Jamo::Vowel(JamoVowelSingular::Ya)
+                && *block.vowel.jamo() != Jamo::Vowel(JamoVowelSingular::Ye) =>
+        {
+            JamoConsonantSingular::Ieung
+        }
+        _ => return syllable,
+    };
+    // Infallible: ㄴ and ㅇ can both begin a syllable
+    block.initial = Choseong::try_from(Jamo::Consonant(new_initial)).unwrap();
+    block.to_char()
+}
+
+/// Returns the syllables that the initial-sound law turns into `syllable`,
+/// for recovering spellings that do not apply the law, such as North Korean
+/// spelling (여자 → 녀자, 노동 → 로동). The result is empty if no syllable
+/// maps to `syllable`.
+///
+/// The law cannot be reversed in general: 이 may come from 리 (李), 니
+/// (泥), or be an original 이 (耳), so choosing between the candidates and
+/// `syllable` itself requires knowing the word's origin.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grammar::dueum_origins;
+///
+/// assert_eq!(dueum_origins('이'), vec!['리', '니']);
+/// assert_eq!(dueum_origins('노'), vec!['로']);
+/// assert_eq!(dueum_origins('역'), vec!['력', '녁']);
+/// assert!(dueum_origins('가').is_empty());
+/// ```
+pub fn dueum_origins(syllable: char) -> Vec<char> {
+    let Ok(block) = HangulBlock::from_char(syllable) else {
+        return Vec::new();
+    };
+    [JamoConsonantSingular::Rieul, JamoConsonantSingular::Nieun]
+        .into_iter()
+        .filter_map(|initial| {
+            let origin = HangulBlock {
+                initial: Choseong::try_from(Jamo::Consonant(initial)).ok()?,
+                vowel: block.vowel,
+                final_optional: block.final_optional,
+            }
+            .to_char();
+            (origin != syllable && apply_initial_sound_law(origin) == syllable).then_some(origin)
+        })
+        .collect()
+}
+
+/// The speech level (화계) of a Korean sentence, from most to least formal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpeechLevel {
+    /// 합쇼체, the formal polite level: 갑니다, 갑니까?, 가십시오.
+    Hapsyo,
+    /// 하오체, the semi-formal level: 가오, 있소, 가시오.
+    Hao,
+    /// 하게체, the familiar level used to juniors: 가는가?, 가게.
+    Hage,
+    /// 해요체, the informal polite level: 가요, 가죠.
+    Haeyo,
+    /// 해체, the informal plain level (반말): 가, 먹어, 할게.
+    Hae,
+    /// 해라체, the plain level used in writing and to juniors: 간다, 가니?, 가라.
+    Haera,
+    /// No recognizable sentence ending.
+    Unknown,
+}
+
+/// Detects the speech level of `text` from its sentence endings.
+///
+/// Each sentence (as split by `string::sentences`) is classified by its
+/// final syllables, ignoring trailing punctuation and other non-syllable
+/// characters such as ㅋㅋ or emoji. The level of the last sentence with a
+/// recognizable ending is returned, or `SpeechLevel::Unknown` if there is
+/// none. Endings are matched against a table of common forms, so unusual or
+/// ambiguous endings may be misclassified.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grammar::{SpeechLevel, detect_speech_level};
+///
+/// assert_eq!(detect_speech_level("감사합니다."), SpeechLevel::Hapsyo);
+/// assert_eq!(detect_speech_level("어디 가요?"), SpeechLevel::Haeyo);
+/// assert_eq!(detect_speech_level("밥 먹었어? ㅋㅋ"), SpeechLevel::Hae);
+/// assert_eq!(detect_speech_level("나는 학생이다."), SpeechLevel::Haera);
+/// assert_eq!(detect_speech_level("OK!"), SpeechLevel::Unknown);
+/// ```
+pub fn detect_speech_level(text: &str) -> SpeechLevel {
+    sentences(text)
+        .filter_map(sentence_level)
+        .last()
+        .unwrap_or(SpeechLevel::Unknown)
+}
+
+// Sentence endings for each level, checked in order against the end of a
+// sentence. 합쇼체 also matches ㅂ니다 and ㅂ니까, which are checked first,
+// and 해체 is checked last, and also matches any open syllable ending in an
+// 아/어 vowel.
+const SPEECH_LEVEL_ENDINGS: [(SpeechLevel, &[&str]); 5] = [
+    (SpeechLevel::Hapsyo, &["십시오", "시지요"]),
+    (SpeechLevel::Haeyo, &["요", "죠"]),
+    (SpeechLevel::Hao, &["시오", "소", "오"]),
+    (SpeechLevel::Hage, &["는가", "던가", "하게", "하세", "시게"]),
+    (
+        SpeechLevel::Haera,
+        &["다", "냐", "니", "라", "자", "구나", "군"],
+    ),
+];
+
+const HAE_ENDINGS: [char; 8] = ['지', '야', '게', '래', '걸', '데', '든', '까'];
+
+fn sentence_level(sentence: &str) -> Option<SpeechLevel> {
+    let ending = sentence.trim_end_matches(|c| HangulBlock::from_char(c).is_err());
+    let last = ending.chars().next_back()?;
+    if ends_with_formal_ending(ending) {
+        return Some(SpeechLevel::Hapsyo);
+    }
+    SPEECH_LEVEL_ENDINGS
+        .iter()
+        .find(|(_, endings)| endings.iter().any(|e| ending.ends_with(e)))
+        .map(|&(level, _)| level)
+        .or_else(|| (HAE_ENDINGS.contains(&last) || is_a_eo_form(last)).then_some(SpeechLevel::Hae))
+}
+
+// Whether `ending` ends in ㅂ니다 or ㅂ니까, with the ㅂ as the final of the
+// syllable before 니 (갑니다, 먹습니까), rather than 니다 or 니까 alone as in
+// 아니다 and 그러니까.
+fn ends_with_formal_ending(ending: &str) -> bool {
+    let mut chars = ending.chars().rev();
+    matches!(
+        (
+            chars.next(),
+            chars.next(),
+            chars.next().and_then(syllable_indices)
+        ),
+        (Some('다' | '까'), Some('니'), Some((_, _, FINAL_PIEUP)))
+    )
+}
+
+// Whether `syllable` has no final consonant and one of the vowels that the
+// 아/어 ending contracts to, as in 가, 봐, 줘, 해, 돼, and 켜.
+fn is_a_eo_form(syllable: char) -> bool {
+    syllable_indices(syllable)
+        .is_some_and(|(_, v, t)| t == 0 && matches!(v, A | AE | EO | YEO | WA | WAE | WO))
+}
+
+/// Rewrites the sentence endings of `text` to the speech level `level`,
+/// converting between 합쇼체, 해요체, and 해체 (반말): 갑니다 ↔ 가요 ↔ 가.
+///
+/// Each sentence (as split by `string::sentences`) is rewritten by
+/// recovering the stem from its final word with a table of common endings
+/// and conjugating it for `level`; questions use 니까 in 합쇼체. The copula
+/// (학생입니다 ↔ 학생이에요 ↔ 학생이야) and 아니다 are handled separately.
+/// Sentences with an ending not in the table are left unchanged, as is all
+/// of `text` if `level` is not `Hapsyo`, `Haeyo`, or `Hae`. Irregular stems
+/// that cannot be recovered from their conjugated forms, such as 알다 from
+/// 압니다, may be conjugated incorrectly.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::grammar::{SpeechLevel, convert_speech_level};
+///
+/// assert_eq!(convert_speech_level("갑니다.", SpeechLevel::Haeyo), "가요.");
+/// assert_eq!(convert_speech_level("가요.", SpeechLevel::Hae), "가.");
+/// assert_eq!(convert_speech_level("밥 먹었어?", SpeechLevel::Hapsyo), "밥 먹었습니까?");
+/// assert_eq!(
+///     convert_speech_level("저는 학생이에요. 커피를 좋아해요!", SpeechLevel::Hapsyo),
+///     "저는 학생입니다. 커피를 좋아합니다!"
+/// );
+/// ```
+pub fn convert_speech_level(text: &str, level: SpeechLevel) -> String {
+    if !matches!(
+        level,
+        SpeechLevel::Hapsyo | SpeechLevel::Haeyo | SpeechLevel::Hae
+    ) {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len() + text.len() / 4);
+    let mut copied = 0;
+    for sentence in sentences(text) {
+        let start = sentence.as_ptr() as usize - text.as_ptr() as usize;
+        result.push_str(&text[copied..start]);
+        let body = sentence.trim_end_matches(|c| HangulBlock::from_char(c).is_err());
+        let tail = &sentence[body.len()..];
+        match Predicate::parse(body) {
+            Some(predicate) => {
+                let question = tail.contains(['?', '？']);
+                result.push_str(&predicate.conjugate(level, question));
+            }
+            None => result.push_str(body),
+        }
+        result.push_str(tail);
+        copied = start + sentence.len();
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+// Indices of the jamo used by the conjugation rules, as used by
+// `block::syllable_indices`.
+const IEUNG: usize = 11;
+const HIEUH: usize = 18;
+const A: usize = 0;
+const AE: usize = 1;
+const EO: usize = 4;
+const E: usize = 5;
+const YEO: usize = 6;
+const O: usize = 8;
+const WA: usize = 9;
+const WAE: usize = 10;
+const OE: usize = 11;
+const U: usize = 13;
+const WO: usize = 14;
+const EU: usize = 18;
+const I: usize = 20;
+pub(crate) const FINAL_RIEUL: usize = 8;
+pub(crate) const FINAL_PIEUP: usize = 17;
+pub(crate) const FINAL_SSANGSIOS: usize = 20;
+
+// The sentence-final predicate of a sentence, with its ending removed.
+enum Predicate<'a> {
+    // A verb or adjective stem, including any tense or honorific suffix,
+    // such as 가, 먹었, or 가시.
+    Verb(String),
+    // A noun followed by the copula 이다.
+    Copula(&'a str),
+    // The words before 아니다.
+    Negative(&'a str),
+}
+
+impl<'a> Predicate<'a> {
+    fn parse(body: &'a str) -> Option<Self> {
+        for ending in ["니다", "니까"] {
+            if let Some(prefix) = body.strip_suffix(ending) {
+                if let Some(head) = prefix.strip_suffix("아닙") {
+                    return Some(Predicate::Negative(head));
+                }
+                if let Some(noun) = prefix.strip_suffix('입') {
+                    return Some(Predicate::Copula(noun));
+                }
+                if let Some(stem) = prefix.strip_suffix('습') {
+                    return Some(Predicate::Verb(stem.to_string()));
+                }
+                let (stem, last) = split_last(prefix)?;
+                let (l, v, t) = syllable_indices(last)?;
+                return (t == FINAL_PIEUP)
+                    .then(|| Predicate::Verb(format!("{stem}{}", syllable(l, v, 0))));
+            }
+        }
+        if let Some(head) = body
+            .strip_suffix("아니에요")
+            .or_else(|| body.strip_suffix("아니야"))
+        {
+            return Some(Predicate::Negative(head));
+        }
+        if let Some(noun) = body
+            .strip_suffix("이에요")
+            .or_else(|| body.strip_suffix("예요"))
+            .or_else(|| body.strip_suffix("이야"))
+        {
+            return Some(Predicate::Copula(noun));
+        }
+        if let Some(noun) = body.strip_suffix('야')
+            && split_last(noun).is_some_and(|(_, last)| is_open_syllable(last))
+        {
+            return Some(Predicate::Copula(noun));
+        }
+        if let Some(stem) = body.strip_suffix("세요") {
+            return (!stem.is_empty()).then(|| Predicate::Verb(format!("{stem}시")));
+        }
+        let form = body.strip_suffix('요').unwrap_or(body);
+        a_eo_stem(form).map(Predicate::Verb)
+    }
+
+    fn conjugate(&self, level: SpeechLevel, question: bool) -> String {
+        match (self, level) {
+            (Predicate::Verb(stem), SpeechLevel::Hapsyo) => {
+                let ending = if question { "니까" } else { "니다" };
+                match split_last(stem)
+                    .and_then(|(head, last)| Some((head, syllable_indices(last)?)))
+                {
+                    Some((head, (l, v, 0 | FINAL_RIEUL))) => {
+                        format!("{head}{}{ending}", syllable(l, v, FINAL_PIEUP))
+                    }
+                    _ => format!("{stem}습{ending}"),
+                }
+            }
+            (Predicate::Verb(stem), SpeechLevel::Haeyo) => format!("{}요", a_eo_form(stem)),
+            (Predicate::Verb(stem), _) => a_eo_form(stem),
+            (Predicate::Copula(noun), _) => {
+                let open = split_last(noun).is_none_or(|(_, last)| !has_final(last));
+                let ending = match (level, open) {
+                    (SpeechLevel::Hapsyo, _) if question => "입니까",
+                    (SpeechLevel::Hapsyo, _) => "입니다",
+                    (SpeechLevel::Haeyo, true) => "예요",
+                    (SpeechLevel::Haeyo, false) => "이에요",
+                    (_, true) => "야",
+                    (_, false) => "이야",
+                };
+                format!("{noun}{ending}")
+            }
+            (Predicate::Negative(head), _) => {
+                let ending = match level {
+                    SpeechLevel::Hapsyo if question => "아닙니까",
+                    SpeechLevel::Hapsyo => "아닙니다",
+                    SpeechLevel::Haeyo => "아니에요",
+                    _ => "아니야",
+                };
+                format!("{head}{ending}")
+            }
+        }
+    }
+}
+
+// Recovers the stem of a verb from its 아/어 form: 먹어 → 먹, 가 → 가,
+// 봐 → 보, 해 → 하, 마셔 → 마시.
+pub(crate) fn a_eo_stem(form: &str) -> Option<String> {
+    let (head, last) = split_last(form)?;
+    let (l, v, t) = syllable_indices(last)?;
+    if t != 0 {
+        return None;
+    }
+    if l == IEUNG && matches!(v, A | EO) && split_last(head).is_some_and(|(_, c)| has_final(c)) {
+        return Some(head.to_string());
+    }
+    let stem_vowel = match v {
+        AE if l == HIEUH => A,
+        A | AE | EO => v,
+        WA => O,
+        WO => U,
+        WAE => OE,
+        YEO => I,
+        _ => return None,
+    };
+    Some(format!("{head}{}", syllable(l, stem_vowel, 0)))
+}
+
+// Conjugates a verb stem into its 아/어 form, the inverse of `a_eo_stem`.
+fn a_eo_form(stem: &str) -> String {
+    let Some((head, (l, v, t))) =
+        split_last(stem).and_then(|(head, last)| Some((head, syllable_indices(last)?)))
+    else {
+        return stem.to_string();
+    };
+    if t != 0 {
+        let bright = matches!(v, A | O) && t != FINAL_SSANGSIOS;
+        return format!("{stem}{}", if bright { "아" } else { "어" });
+    }
+    let vowel = match v {
+        A if l == HIEUH => AE,
+        A | AE | EO | E | YEO => v,
+        O => WA,
+        U => WO,
+        OE => WAE,
+        I => YEO,
+        EU => EO,
+        _ => return format!("{stem}어"),
+    };
+    format!("{head}{}", syllable(l, vowel, 0))
+}
+
+fn split_last(s: &str) -> Option<(&str, char)> {
+    let last = s.chars().next_back()?;
+    Some((&s[..s.len() - last.len_utf8()], last))
+}
+
+fn has_final(c: char) -> bool {
+    syllable_indices(c).is_some_and(|(_, _, t)| t != 0)
+}
+
+fn is_open_syllable(c: char) -> bool {
+    syllable_indices(c).is_some_and(|(_, _, t)| t == 0)
+}
+
+fn syllable(initial: usize, vowel: usize, final_index: usize) -> char {
+    syllable_from_indices(initial, vowel, final_index).expect("indices are in range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dueum_applies_only_at_word_start() {
+        assert_eq!(apply_dueum("력사 력량"), "역사 역량");
+        assert_eq!(apply_dueum("경력"), "경력");
+        assert_eq!(apply_dueum("뇨소 ㄹㅣ"), "요소 ㄹㅣ");
+        assert_eq!(apply_dueum(""), "");
+    }
+
+    #[test]
+    fn dueum_skips_words_not_marked_sino_korean() {
+        assert_eq!(apply_dueum("라디오"), "나디오");
+        assert_eq!(apply_dueum_with("라디오", |_| false), "라디오");
+        assert_eq!(
+            apply_dueum_with("뉴스를 로인이", |word| word != "뉴스를"),
+            "뉴스를 노인이"
+        );
+    }
+
+    #[test]
+    fn origins_invert_the_law() {
+        for c in ['이', '여', '요', '유', '양', '내', '노', '누', '예'] {
+            for origin in dueum_origins(c) {
+                assert_eq!(apply_initial_sound_law(origin), c);
+            }
+        }
+        assert_eq!(dueum_origins('예'), vec!['례']);
+        assert_eq!(dueum_origins('a'), Vec::<char>::new());
+    }
+
+    #[test]
+    fn speech_level_endings() {
+        let cases = [
+            ("어서 오십시오.", SpeechLevel::Hapsyo),
+            ("어디 가십니까?", SpeechLevel::Hapsyo),
+            ("책을 읽습니다", SpeechLevel::Hapsyo),
+            ("그러니까!", SpeechLevel::Hae),
+            ("이건 내 것이 아니다.", SpeechLevel::Haera),
+            ("여기 있소.", SpeechLevel::Hao),
+            ("자네 왔는가?", SpeechLevel::Hage),
+            ("맛있죠!", SpeechLevel::Haeyo),
+            ("이거 뭐예요", SpeechLevel::Haeyo),
+            ("내가 할게~", SpeechLevel::Hae),
+            ("이게 뭐야?!", SpeechLevel::Hae),
+            ("빨리 와 ㅋㅋㅋ", SpeechLevel::Hae),
+            ("같이 가자.", SpeechLevel::Haera),
+            ("벌써 왔니?", SpeechLevel::Haera),
+            ("", SpeechLevel::Unknown),
+            ("책상", SpeechLevel::Unknown),
+        ];
+        for (text, level) in cases {
+            assert_eq!(detect_speech_level(text), level, "{text}");
+        }
+    }
+
+    #[test]
+    fn speech_level_uses_last_sentence() {
+        assert_eq!(detect_speech_level("안녕하세요. 뭐 해?"), SpeechLevel::Hae);
+        assert_eq!(detect_speech_level("좋아요. 책상"), SpeechLevel::Haeyo);
+    }
+
+    #[test]
+    fn convert_between_levels() {
+        let forms = [
+            ("갑니다.", "가요.", "가."),
+            ("먹었습니까?", "먹었어요?", "먹었어?"),
+            ("좋습니다!", "좋아요!", "좋아!"),
+            ("봅니다", "봐요", "봐"),
+            ("공부합니다.", "공부해요.", "공부해."),
+            ("기다립니다.", "기다려요.", "기다려."),
+            ("학생입니다.", "학생이에요.", "학생이야."),
+            ("학교입니까?", "학교예요?", "학교야?"),
+            ("제 것이 아닙니다.", "제 것이 아니에요.", "제 것이 아니야."),
+        ];
+        for (hapsyo, haeyo, hae) in forms {
+            for from in [hapsyo, haeyo, hae] {
+                assert_eq!(convert_speech_level(from, SpeechLevel::Hapsyo), hapsyo);
+                assert_eq!(convert_speech_level(from, SpeechLevel::Haeyo), haeyo);
+                assert_eq!(convert_speech_level(from, SpeechLevel::Hae), hae);
+            }
+        }
+    }
+
+    #[test]
+    fn convert_keeps_unknown_endings_and_spacing() {
+        assert_eq!(
+            convert_speech_level("  가세요!  그렇죠? ㅋㅋ\n", SpeechLevel::Hapsyo),
+            "  가십니다!  그렇죠? ㅋㅋ\n"
+        );
+        assert_eq!(
+            convert_speech_level("갑니다.", SpeechLevel::Haera),
+            "갑니다."
+        );
+        assert_eq!(convert_speech_level("", SpeechLevel::Hae), "");
+    }
+}
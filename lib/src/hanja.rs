@@ -0,0 +1,693 @@
+use std::fmt;
+
+use crate::block::HangulBlock;
+
+pub use crate::grammar::apply_initial_sound_law;
+
+/// Returns the Hangul reading of a single Hanja character, or `None` if it
+/// is not in the embedded table. The reading is the character's original
+/// (본음) reading, before the initial-sound law is applied; use
+/// `hanja_to_hangul` to convert text.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::hanja::hanja_reading;
+///
+/// assert_eq!(hanja_reading('韓'), Some('한'));
+/// assert_eq!(hanja_reading('李'), Some('리'));
+/// assert_eq!(hanja_reading('a'), None);
+/// ```
+pub fn hanja_reading(c: char) -> Option<char> {
+    HANJA_READINGS
+        .binary_search_by_key(&c, |(hanja, _, _)| *hanja)
+        .ok()
+        .map(|i| HANJA_READINGS[i].1)
+}
+
+/// A Hanja character that can be written for a Hangul syllable, returned by
+/// `hanja_candidates`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HanjaCandidate {
+    /// The Hanja character.
+    pub hanja: char,
+
+    /// The original (본음) reading of the character.
+    pub reading: char,
+
+    /// The meaning (훈) the character is known by, such as 물 for 水, which
+    /// is called 물 수.
+    pub meaning: &'static str,
+}
+
+impl fmt::Display for HanjaCandidate {
+    /// Formats the candidate as in a Hanja conversion list: 水 (물 수).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} {})", self.hanja, self.meaning, self.reading)
+    }
+}
+
+/// Returns the Hanja in the embedded table that can be written for the
+/// Hangul syllable `reading`, roughly from most to least common, for
+/// offering Hanja conversion (한자 변환) as Korean input methods do.
+///
+/// Hanja whose original reading becomes `reading` under the initial-sound
+/// law (두음법칙) are included after those read `reading` directly, so 이
+/// gives 李 (오얏 리) after 二 (두 이).
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::hanja::hanja_candidates;
+///
+/// let candidates = hanja_candidates('수');
+/// assert_eq!(candidates[0].hanja, '水');
+/// assert_eq!(candidates[0].to_string(), "水 (물 수)");
+///
+/// let hanja: String = hanja_candidates('노').iter().map(|c| c.hanja).collect();
+/// assert_eq!(hanja, "路老勞露");
+/// assert!(hanja_candidates('꿁').is_empty());
+/// ```
+pub fn hanja_candidates(reading: char) -> Vec<HanjaCandidate> {
+    let mut candidates = candidates_read(reading);
+    for &(original, _) in HANJA_BY_READING {
+        if original != reading && apply_initial_sound_law(original) == reading {
+            candidates.extend(candidates_read(original));
+        }
+    }
+    candidates
+}
+
+/// Returns the Hanja candidates for each syllable of the Hangul word
+/// `word`, as `hanja_candidates` does, except that the initial-sound law is
+/// only considered for the first syllable. Characters other than Hangul
+/// syllables have no candidates.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::hanja::word_hanja_candidates;
+///
+/// let candidates = word_hanja_candidates("노인");
+/// assert!(candidates[0].iter().any(|c| c.hanja == '老'));
+/// assert_eq!(candidates[1][0].hanja, '人');
+/// ```
+pub fn word_hanja_candidates(word: &str) -> Vec<Vec<HanjaCandidate>> {
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if i == 0 {
+                hanja_candidates(c)
+            } else {
+                candidates_read(c)
+            }
+        })
+        .collect()
+}
+
+// Returns the candidates whose original reading is `reading`.
+fn candidates_read(reading: char) -> Vec<HanjaCandidate> {
+    let Ok(i) = HANJA_BY_READING.binary_search_by_key(&reading, |(reading, _)| *reading) else {
+        return Vec::new();
+    };
+    HANJA_BY_READING[i]
+        .1
+        .chars()
+        .filter_map(|hanja| {
+            let i = HANJA_READINGS
+                .binary_search_by_key(&hanja, |(hanja, _, _)| *hanja)
+                .ok()?;
+            let (hanja, reading, meaning) = HANJA_READINGS[i];
+            Some(HanjaCandidate {
+                hanja,
+                reading,
+                meaning,
+            })
+        })
+        .collect()
+}
+
+/// Converts the Hanja in `text` to their Hangul readings, leaving all other
+/// characters (and Hanja missing from the embedded table) unchanged.
+///
+/// The initial-sound law (두음법칙) is applied to the first syllable of each
+/// word, where a word starts at the beginning of `text` or after any
+/// character that is neither Hangul nor Hanja. For example, 李 is read 이 at
+/// the start of a word and 리 elsewhere.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::hanja::hanja_to_hangul;
+///
+/// assert_eq!(hanja_to_hangul("韓國語"), "한국어");
+/// assert_eq!(hanja_to_hangul("李"), "이");
+/// assert_eq!(hanja_to_hangul("行李"), "행리");
+/// assert_eq!(hanja_to_hangul("勞動 老人"), "노동 노인");
+/// ```
+pub fn hanja_to_hangul(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut word_start = true;
+    for c in text.chars() {
+        match hanja_reading(c) {
+            Some(reading) => {
+                result.push(if word_start {
+                    apply_initial_sound_law(reading)
+                } else {
+                    reading
+                });
+                word_start = false;
+            }
+            None => {
+                result.push(c);
+                word_start = HangulBlock::from_char(c).is_err() && !is_cjk_ideograph(c);
+            }
+        }
+    }
+    result
+}
+
+fn is_cjk_ideograph(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}' | '\u{F900}'..='\u{FAFF}')
+}
+
+// Common Hanja with their original Hangul readings and meanings (훈),
+// sorted by codepoint for binary search.
+const HANJA_READINGS: &[(char, char, &str)] = &[
+    ('一', '일', "한"),
+    ('七', '칠', "일곱"),
+    ('三', '삼', "석"),
+    ('上', '상', "윗"),
+    ('下', '하', "아래"),
+    ('世', '세', "인간"),
+    ('中', '중', "가운데"),
+    ('主', '주', "주인"),
+    ('九', '구', "아홉"),
+    ('事', '사', "일"),
+    ('二', '이', "두"),
+    ('五', '오', "다섯"),
+    ('人', '인', "사람"),
+    ('仁', '인', "어질"),
+    ('令', '령', "하여금"),
+    ('休', '휴', "쉴"),
+    ('低', '저', "낮을"),
+    ('作', '작', "지을"),
+    ('使', '사', "하여금"),
+    ('來', '래', "올"),
+    ('例', '례', "법식"),
+    ('信', '신', "믿을"),
+    ('修', '수', "닦을"),
+    ('倫', '륜', "인륜"),
+    ('停', '정', "머무를"),
+    ('傳', '전', "전할"),
+    ('兄', '형', "형"),
+    ('先', '선', "먼저"),
+    ('入', '입', "들"),
+    ('內', '내', "안"),
+    ('全', '전', "온전할"),
+    ('兩', '량', "두"),
+    ('八', '팔', "여덟"),
+    ('六', '륙', "여섯"),
+    ('典', '전', "법"),
+    ('冬', '동', "겨울"),
+    ('冷', '랭', "찰"),
+    ('出', '출', "날"),
+    ('刀', '도', "칼"),
+    ('分', '분', "나눌"),
+    ('列', '렬', "벌일"),
+    ('利', '리', "이로울"),
+    ('到', '도', "이를"),
+    ('前', '전', "앞"),
+    ('副', '부', "버금"),
+    ('力', '력', "힘"),
+    ('動', '동', "움직일"),
+    ('勞', '로', "일할"),
+    ('北', '북', "북녘"),
+    ('十', '십', "열"),
+    ('千', '천', "일천"),
+    ('南', '남', "남녘"),
+    ('印', '인', "도장"),
+    ('卵', '란', "알"),
+    ('友', '우', "벗"),
+    ('受', '수', "받을"),
+    ('口', '구', "입"),
+    ('右', '우', "오른"),
+    ('同', '동', "한가지"),
+    ('名', '명', "이름"),
+    ('和', '화', "화할"),
+    ('品', '품', "물건"),
+    ('商', '상', "장사"),
+    ('問', '문', "물을"),
+    ('四', '사', "넉"),
+    ('因', '인', "인할"),
+    ('國', '국', "나라"),
+    ('圖', '도', "그림"),
+    ('土', '토', "흙"),
+    ('地', '지', "땅"),
+    ('基', '기', "터"),
+    ('場', '장', "마당"),
+    ('士', '사', "선비"),
+    ('夏', '하', "여름"),
+    ('外', '외', "바깥"),
+    ('多', '다', "많을"),
+    ('大', '대', "큰"),
+    ('天', '천', "하늘"),
+    ('夫', '부', "지아비"),
+    ('女', '녀', "계집"),
+    ('始', '시', "비로소"),
+    ('婦', '부', "며느리"),
+    ('子', '자', "아들"),
+    ('字', '자', "글자"),
+    ('學', '학', "배울"),
+    ('守', '수', "지킬"),
+    ('安', '안', "편안"),
+    ('定', '정', "정할"),
+    ('家', '가', "집"),
+    ('富', '부', "부자"),
+    ('寺', '사', "절"),
+    ('小', '소', "작을"),
+    ('少', '소', "적을"),
+    ('尿', '뇨', "오줌"),
+    ('展', '전', "펼"),
+    ('山', '산', "메"),
+    ('島', '도', "섬"),
+    ('川', '천', "내"),
+    ('工', '공', "장인"),
+    ('左', '좌', "왼"),
+    ('己', '기', "몸"),
+    ('市', '시', "저자"),
+    ('師', '사', "스승"),
+    ('常', '상', "떳떳할"),
+    ('平', '평', "평평할"),
+    ('年', '년', "해"),
+    ('度', '도', "법도"),
+    ('庭', '정', "뜰"),
+    ('引', '인', "끌"),
+    ('弟', '제', "아우"),
+    ('律', '률', "법칙"),
+    ('後', '후', "뒤"),
+    ('心', '심', "마음"),
+    ('念', '념', "생각"),
+    ('思', '사', "생각"),
+    ('情', '정', "뜻"),
+    ('想', '상', "생각"),
+    ('意', '의', "뜻"),
+    ('愛', '애', "사랑"),
+    ('戰', '전', "싸움"),
+    ('所', '소', "바"),
+    ('手', '수', "손"),
+    ('技', '기', "재주"),
+    ('授', '수', "줄"),
+    ('收', '수', "거둘"),
+    ('政', '정', "정사"),
+    ('數', '수', "셈"),
+    ('文', '문', "글월"),
+    ('料', '료', "헤아릴"),
+    ('新', '신', "새"),
+    ('方', '방', "모"),
+    ('旅', '려', "나그네"),
+    ('旗', '기', "기"),
+    ('日', '일', "날"),
+    ('明', '명', "밝을"),
+    ('春', '춘', "봄"),
+    ('時', '시', "때"),
+    ('暗', '암', "어두울"),
+    ('書', '서', "글"),
+    ('會', '회', "모일"),
+    ('月', '월', "달"),
+    ('有', '유', "있을"),
+    ('期', '기', "기약할"),
+    ('木', '목', "나무"),
+    ('本', '본', "근본"),
+    ('李', '리', "오얏"),
+    ('東', '동', "동녘"),
+    ('林', '림', "수풀"),
+    ('柳', '류', "버들"),
+    ('校', '교', "학교"),
+    ('業', '업', "업"),
+    ('樂', '락', "즐길"),
+    ('樓', '루', "다락"),
+    ('樹', '수', "나무"),
+    ('歌', '가', "노래"),
+    ('正', '정', "바를"),
+    ('歷', '력', "지날"),
+    ('死', '사', "죽을"),
+    ('母', '모', "어미"),
+    ('民', '민', "백성"),
+    ('氣', '기', "기운"),
+    ('水', '수', "물"),
+    ('江', '강', "강"),
+    ('法', '법', "법"),
+    ('泥', '니', "진흙"),
+    ('活', '활', "살"),
+    ('流', '류', "흐를"),
+    ('浪', '랑', "물결"),
+    ('海', '해', "바다"),
+    ('漢', '한', "한수"),
+    ('火', '화', "불"),
+    ('烈', '렬', "매울"),
+    ('無', '무', "없을"),
+    ('然', '연', "그럴"),
+    ('父', '부', "아비"),
+    ('物', '물', "물건"),
+    ('王', '왕', "임금"),
+    ('理', '리', "다스릴"),
+    ('生', '생', "날"),
+    ('田', '전', "밭"),
+    ('男', '남', "사내"),
+    ('界', '계', "지경"),
+    ('留', '류', "머무를"),
+    ('略', '략', "간략할"),
+    ('病', '병', "병"),
+    ('白', '백', "흰"),
+    ('百', '백', "일백"),
+    ('目', '목', "눈"),
+    ('直', '직', "곧을"),
+    ('相', '상', "서로"),
+    ('短', '단', "짧을"),
+    ('石', '석', "돌"),
+    ('示', '시', "보일"),
+    ('社', '사', "모일"),
+    ('禮', '례', "예도"),
+    ('秀', '수', "빼어날"),
+    ('秋', '추', "가을"),
+    ('空', '공', "빌"),
+    ('立', '립', "설"),
+    ('第', '제', "차례"),
+    ('答', '답', "대답"),
+    ('算', '산', "셈"),
+    ('精', '정', "정할"),
+    ('紐', '뉴', "맺을"),
+    ('綠', '록', "푸를"),
+    ('練', '련', "익힐"),
+    ('美', '미', "아름다울"),
+    ('義', '의', "옳을"),
+    ('老', '로', "늙을"),
+    ('者', '자', "놈"),
+    ('耳', '이', "귀"),
+    ('聞', '문', "들을"),
+    ('育', '육', "기를"),
+    ('臨', '림', "임할"),
+    ('自', '자', "스스로"),
+    ('良', '량', "어질"),
+    ('花', '화', "꽃"),
+    ('草', '초', "풀"),
+    ('萬', '만', "일만"),
+    ('落', '락', "떨어질"),
+    ('藥', '약', "약"),
+    ('號', '호', "이름"),
+    ('行', '행', "다닐"),
+    ('西', '서', "서녘"),
+    ('要', '요', "요긴할"),
+    ('見', '견', "볼"),
+    ('視', '시', "볼"),
+    ('言', '언', "말씀"),
+    ('計', '계', "셀"),
+    ('記', '기', "기록할"),
+    ('試', '시', "시험"),
+    ('詩', '시', "시"),
+    ('話', '화', "말씀"),
+    ('認', '인', "알"),
+    ('語', '어', "말씀"),
+    ('論', '론', "논할"),
+    ('讀', '독', "읽을"),
+    ('負', '부', "질"),
+    ('資', '자', "재물"),
+    ('賞', '상', "상줄"),
+    ('赤', '적', "붉을"),
+    ('起', '기', "일어날"),
+    ('路', '로', "길"),
+    ('車', '차', "수레"),
+    ('輪', '륜', "바퀴"),
+    ('農', '농', "농사"),
+    ('連', '련', "이을"),
+    ('道', '도', "길"),
+    ('郞', '랑', "사내"),
+    ('部', '부', "떼"),
+    ('都', '도', "도읍"),
+    ('醫', '의', "의원"),
+    ('里', '리', "마을"),
+    ('重', '중', "무거울"),
+    ('量', '량', "헤아릴"),
+    ('金', '금', "쇠"),
+    ('錄', '록', "기록할"),
+    ('錢', '전', "돈"),
+    ('長', '장', "긴"),
+    ('門', '문', "문"),
+    ('間', '간', "사이"),
+    ('院', '원', "집"),
+    ('陸', '륙', "뭍"),
+    ('離', '리', "떠날"),
+    ('雨', '우', "비"),
+    ('雪', '설', "눈"),
+    ('雲', '운', "구름"),
+    ('雷', '뢰', "우레"),
+    ('電', '전', "번개"),
+    ('露', '로', "이슬"),
+    ('靈', '령', "신령"),
+    ('靑', '청', "푸를"),
+    ('韓', '한', "한국"),
+    ('領', '령', "거느릴"),
+    ('題', '제', "제목"),
+    ('類', '류', "무리"),
+    ('風', '풍', "바람"),
+    ('食', '식', "밥"),
+    ('飮', '음', "마실"),
+    ('首', '수', "머리"),
+    ('體', '체', "몸"),
+    ('高', '고', "높을"),
+    ('麗', '려', "고울"),
+    ('黑', '흑', "검을"),
+    ('龍', '룡', "용"),
+];
+// The Hanja in `HANJA_READINGS` for each reading, roughly from most to least
+// common, sorted by reading for binary search.
+const HANJA_BY_READING: &[(char, &str)] = &[
+    ('가', "家歌"),
+    ('간', "間"),
+    ('강', "江"),
+    ('견', "見"),
+    ('계', "計界"),
+    ('고', "高"),
+    ('공', "工空"),
+    ('교', "校"),
+    ('구', "九口"),
+    ('국', "國"),
+    ('금', "金"),
+    ('기', "記氣期基起技己旗"),
+    ('남', "男南"),
+    ('내', "內"),
+    ('녀', "女"),
+    ('년', "年"),
+    ('념', "念"),
+    ('농', "農"),
+    ('뇨', "尿"),
+    ('뉴', "紐"),
+    ('니', "泥"),
+    ('다', "多"),
+    ('단', "短"),
+    ('답', "答"),
+    ('대', "大"),
+    ('도', "道度圖島都到刀"),
+    ('독', "讀"),
+    ('동', "東動同冬"),
+    ('락', "樂落"),
+    ('란', "卵"),
+    ('랑', "浪郞"),
+    ('래', "來"),
+    ('랭', "冷"),
+    ('략', "略"),
+    ('량', "量兩良"),
+    ('려', "旅麗"),
+    ('력', "力歷"),
+    ('련', "連練"),
+    ('렬', "列烈"),
+    ('령', "領令靈"),
+    ('례', "禮例"),
+    ('로', "路老勞露"),
+    ('록', "錄綠"),
+    ('론', "論"),
+    ('뢰', "雷"),
+    ('료', "料"),
+    ('룡', "龍"),
+    ('루', "樓"),
+    ('류', "流類留柳"),
+    ('륙', "六陸"),
+    ('륜', "倫輪"),
+    ('률', "律"),
+    ('리', "理利里李離"),
+    ('림', "林臨"),
+    ('립', "立"),
+    ('만', "萬"),
+    ('명', "明名"),
+    ('모', "母"),
+    ('목', "目木"),
+    ('무', "無"),
+    ('문', "文門問聞"),
+    ('물', "物"),
+    ('미', "美"),
+    ('민', "民"),
+    ('방', "方"),
+    ('백', "白百"),
+    ('법', "法"),
+    ('병', "病"),
+    ('본', "本"),
+    ('부', "部父夫婦富副負"),
+    ('북', "北"),
+    ('분', "分"),
+    ('사', "事社使四死思師士寺"),
+    ('산', "山算"),
+    ('삼', "三"),
+    ('상', "上相想常商賞"),
+    ('생', "生"),
+    ('서', "西書"),
+    ('석', "石"),
+    ('선', "先"),
+    ('설', "雪"),
+    ('세', "世"),
+    ('소', "所小少"),
+    ('수', "水手數首受收守修授秀樹"),
+    ('시', "時市始示試詩視"),
+    ('식', "食"),
+    ('신', "新信"),
+    ('심', "心"),
+    ('십', "十"),
+    ('안', "安"),
+    ('암', "暗"),
+    ('애', "愛"),
+    ('약', "藥"),
+    ('어', "語"),
+    ('언', "言"),
+    ('업', "業"),
+    ('연', "然"),
+    ('오', "五"),
+    ('왕', "王"),
+    ('외', "外"),
+    ('요', "要"),
+    ('우', "友雨右"),
+    ('운', "雲"),
+    ('원', "院"),
+    ('월', "月"),
+    ('유', "有"),
+    ('육', "育"),
+    ('음', "飮"),
+    ('의', "意義醫"),
+    ('이', "二耳"),
+    ('인', "人因認引印仁"),
+    ('일', "日一"),
+    ('입', "入"),
+    ('자', "自子字者資"),
+    ('작', "作"),
+    ('장', "長場"),
+    ('저', "低"),
+    ('적', "赤"),
+    ('전', "前全電戰傳展田典錢"),
+    ('정', "正定政情精庭停"),
+    ('제', "題第弟"),
+    ('좌', "左"),
+    ('주', "主"),
+    ('중', "中重"),
+    ('지', "地"),
+    ('직', "直"),
+    ('차', "車"),
+    ('천', "天千川"),
+    ('청', "靑"),
+    ('체', "體"),
+    ('초', "草"),
+    ('추', "秋"),
+    ('춘', "春"),
+    ('출', "出"),
+    ('칠', "七"),
+    ('토', "土"),
+    ('팔', "八"),
+    ('평', "平"),
+    ('품', "品"),
+    ('풍', "風"),
+    ('하', "下夏"),
+    ('학', "學"),
+    ('한', "韓漢"),
+    ('해', "海"),
+    ('행', "行"),
+    ('형', "兄"),
+    ('호', "號"),
+    ('화', "話和火花"),
+    ('활', "活"),
+    ('회', "會"),
+    ('후', "後"),
+    ('휴', "休"),
+    ('흑', "黑"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(HANJA_READINGS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(HANJA_BY_READING.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn every_hanja_is_a_candidate_for_its_reading() {
+        for &(hanja, reading, _) in HANJA_READINGS {
+            let count = candidates_read(reading)
+                .iter()
+                .filter(|c| c.hanja == hanja)
+                .count();
+            assert_eq!(count, 1, "{hanja}");
+        }
+        let total: usize = HANJA_BY_READING
+            .iter()
+            .map(|(_, h)| h.chars().count())
+            .sum();
+        assert_eq!(total, HANJA_READINGS.len());
+    }
+
+    #[test]
+    fn candidates_with_initial_sound_law() {
+        let hanja =
+            |reading| -> String { hanja_candidates(reading).iter().map(|c| c.hanja).collect() };
+        assert_eq!(hanja('이'), "二耳泥理利里李離");
+        assert_eq!(hanja('리'), "理利里李離");
+        assert_eq!(hanja('여'), "女旅麗");
+        let word: Vec<String> = word_hanja_candidates("여자")
+            .iter()
+            .map(|candidates| candidates.iter().map(|c| c.hanja).collect())
+            .collect();
+        assert_eq!(word, ["女旅麗", "自子字者資"]);
+    }
+
+    #[test]
+    fn initial_sound_law_at_word_start() {
+        assert_eq!(hanja_to_hangul("女子"), "여자");
+        assert_eq!(hanja_to_hangul("男女"), "남녀");
+        assert_eq!(hanja_to_hangul("流行"), "유행");
+        assert_eq!(hanja_to_hangul("落花"), "낙화");
+        assert_eq!(hanja_to_hangul("理 論"), "이 논");
+    }
+
+    #[test]
+    fn unknown_characters_are_kept() {
+        // Any Hanja missing from the table, since a fixed one could be added.
+        // Infallible: the table covers far fewer than all CJK ideographs
+        let missing = ('\u{4E00}'..='\u{9FFF}')
+            .find(|&c| hanja_reading(c).is_none())
+            .unwrap();
+        assert_eq!(
+            hanja_to_hangul(&format!("歷{missing}")),
+            format!("역{missing}")
+        );
+        assert_eq!(hanja_to_hangul("한글!"), "한글!");
+    }
+
+    #[test]
+    fn no_initial_sound_law_after_hangul() {
+        assert_eq!(hanja_to_hangul("김李"), "김리");
+        assert_eq!(hanja_to_hangul("a李"), "a이");
+    }
+
+    #[test]
+    fn initial_sound_law_leaves_other_syllables() {
+        assert_eq!(apply_initial_sound_law('냐'), '냐');
+        assert_eq!(apply_initial_sound_law('나'), '나');
+        assert_eq!(apply_initial_sound_law('x'), 'x');
+        assert_eq!(apply_initial_sound_law('륙'), '육');
+    }
+}
@@ -1,3 +1,7 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
 use thiserror::Error;
 
 /// An error enum for Jamo-related errors.
@@ -6,12 +10,23 @@ pub enum JamoError {
     /// Character could not be converted to Jamo
     #[error("Could not convert character '{0}' to Jamo")]
     FromCharError(char),
+
+    /// Jamo cannot be used in the given position of a syllable block; for
+    /// example, ㄸ as a final consonant.
+    #[error("Jamo '{0}' is not valid in position '{1:?}'")]
+    InvalidPosition(char, JamoPosition),
+
+    /// String could not be parsed as Jamo because it is not exactly one
+    /// character long.
+    #[error("Could not parse \"{0}\" as a single Jamo")]
+    FromStrError(String),
 }
 
 /// An enum for the Unicode type of a Jamo character. Types include
 /// modern, compatibility, non-standard modern, non-standard compatibility,
 /// and non-Hangul.
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JamoUnicodeType {
     /// Modern Jamo; these are used to construct standard modern pre-composed
     /// Hangul syllable blocks.
@@ -43,10 +58,11 @@ impl JamoUnicodeType {
     /// Evaluates a character and determines its Jamo Unicode type
     /// as being modern, compatibility, non-standard modern,
     /// non-standard compatibility, or non-Hangul.
-    pub fn evaluate(c: char) -> JamoUnicodeType {
+    #[inline]
+    pub const fn evaluate(c: char) -> JamoUnicodeType {
         match c as u32 {
             0x1100..=0x1112 | 0x1161..=0x1175 | 0x11A8..=0x11C2 => JamoUnicodeType::Modern,
-            0x3130..=0x3163 => JamoUnicodeType::Compatibility,
+            0x3131..=0x3163 => JamoUnicodeType::Compatibility,
             0x1113..=0x1160 | 0x1176..=0x11A7 | 0x11C3..=0x11FF => {
                 JamoUnicodeType::NonStandardModern
             }
@@ -56,6 +72,89 @@ impl JamoUnicodeType {
     }
 }
 
+/// A range of Unicode codepoints containing Hangul characters, returned by
+/// `ranges`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HangulRange {
+    /// The name of the range, as given by the Unicode block it is part of.
+    pub name: &'static str,
+
+    /// The first codepoint in the range.
+    pub start: char,
+
+    /// The last codepoint in the range.
+    pub end: char,
+}
+
+impl HangulRange {
+    /// Returns whether `c` is in this range.
+    pub fn contains(&self, c: char) -> bool {
+        (self.start..=self.end).contains(&c)
+    }
+
+    /// Returns an iterator over every codepoint in this range, including
+    /// those not yet assigned a character.
+    pub fn chars(&self) -> impl Iterator<Item = char> {
+        self.start..=self.end
+    }
+}
+
+/// Returns the Unicode ranges containing Hangul jamo and syllables, in
+/// codepoint order. Each range is a whole Unicode block, except for the
+/// halfwidth Hangul jamo, which are only part of the Halfwidth and
+/// Fullwidth Forms block.
+///
+/// Only some of these characters are used by the rest of the crate: modern
+/// syllables are composed from the modern jamo in Hangul Jamo, and the
+/// other jamo are archaic or kept for compatibility with older encodings.
+/// See `JamoUnicodeType` for classifying individual jamo, and
+/// `block::all_syllables` for iterating over the assigned syllables only.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::ranges;
+///
+/// let compatibility = ranges().iter().find(|r| r.contains('ㄱ')).unwrap();
+/// assert_eq!(compatibility.name, "Hangul Compatibility Jamo");
+/// assert_eq!(compatibility.chars().count(), 96);
+/// assert!(ranges().iter().all(|r| !r.contains('A')));
+/// ```
+pub fn ranges() -> &'static [HangulRange] {
+    const RANGES: [HangulRange; 6] = [
+        HangulRange {
+            name: "Hangul Jamo",
+            start: '\u{1100}',
+            end: '\u{11FF}',
+        },
+        HangulRange {
+            name: "Hangul Compatibility Jamo",
+            start: '\u{3130}',
+            end: '\u{318F}',
+        },
+        HangulRange {
+            name: "Hangul Jamo Extended-A",
+            start: '\u{A960}',
+            end: '\u{A97F}',
+        },
+        HangulRange {
+            name: "Hangul Syllables",
+            start: '\u{AC00}',
+            end: '\u{D7AF}',
+        },
+        HangulRange {
+            name: "Hangul Jamo Extended-B",
+            start: '\u{D7B0}',
+            end: '\u{D7FF}',
+        },
+        HangulRange {
+            name: "Halfwidth and Fullwidth Forms",
+            start: '\u{FFA0}',
+            end: '\u{FFDC}',
+        },
+    ];
+    &RANGES
+}
+
 // Jamo arithmetic
 pub(crate) const S_BASE: u32 = 0xAC00;
 pub(crate) const L_BASE: u32 = 0x1100;
@@ -90,7 +189,7 @@ pub(crate) const S_COUNT: u32 = 11172;
 /// This function maps compatibility jamo characters to their modern equivalents.
 /// If the input character is not a compatibility jamo, it is returned unchanged
 /// (including if it is not a Hangul jamo at all).
-pub fn modernized_jamo_initial(c: char) -> char {
+pub const fn modernized_jamo_initial(c: char) -> char {
     match c {
         '\u{3131}' => '\u{1100}', // ㄱ
         '\u{3132}' => '\u{1101}', // ㄲ
@@ -139,7 +238,7 @@ pub fn modernized_jamo_initial(c: char) -> char {
 /// This function maps compatibility jamo characters to their modern equivalents.
 /// If the input character is not a compatibility jamo, it is returned unchanged
 /// (including if it is not a Hangul jamo at all).
-pub fn modernized_jamo_vowel(c: char) -> char {
+pub const fn modernized_jamo_vowel(c: char) -> char {
     match c {
         '\u{314F}' => '\u{1161}', // ㅏ
         '\u{3150}' => '\u{1162}', // ㅐ
@@ -190,7 +289,7 @@ pub fn modernized_jamo_vowel(c: char) -> char {
 /// This function maps compatibility jamo characters to their modern equivalents.
 /// If the input character is not a compatibility jamo, it is returned unchanged
 /// (including if it is not a Hangul jamo at all).
-pub fn modernized_jamo_final(c: char) -> char {
+pub const fn modernized_jamo_final(c: char) -> char {
     match c {
         '\u{3131}' => '\u{11A8}', // ㄱ
         '\u{3132}' => '\u{11A9}', // ㄲ
@@ -230,7 +329,8 @@ pub fn modernized_jamo_final(c: char) -> char {
 /// For more info on modern and compatibility jamo, see the documentation
 /// for `modernized_jamo_initial`, `modernized_jamo_vowel`,
 /// or `modernized_jamo_final`.
-pub fn modern_to_compatibility_jamo(c: char) -> char {
+#[inline]
+pub const fn modern_to_compatibility_jamo(c: char) -> char {
     match c {
         // Initial consonants
         '\u{1100}' => '\u{3131}', // ㄱ
@@ -312,7 +412,10 @@ pub fn modern_to_compatibility_jamo(c: char) -> char {
 /// An enum representing either a Hangul Jamo character or a non-Hangul
 /// character. Archaic or non-standard jamo like ᅀ will be classified as NonHangul
 /// because they are not used in standard modern Hangul syllable composition.
+/// With the `archaic` feature enabled, the `archaic` module can be used to
+/// classify these characters.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Character {
     NonHangul(char),
     Hangul(Jamo),
@@ -393,7 +496,8 @@ impl Character {
 
 /// An enum representing the different types of Hangul Jamo characters:
 /// consonants, composite consonants, vowels, and composite vowels.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Jamo {
     Consonant(JamoConsonantSingular),
     CompositeConsonant(JamoConsonantComposite),
@@ -402,7 +506,8 @@ pub enum Jamo {
 }
 
 /// An enum representing singular Hangul consonant jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JamoConsonantSingular {
     /// ㄱ
     Giyeok,
@@ -455,7 +560,7 @@ impl JamoConsonantSingular {
     /// assert_eq!(giyeok.char_modern(JamoPosition::Final), Some('\u{11A8}'));   // Final ㄱ
     /// assert_eq!(giyeok.char_modern(JamoPosition::Vowel), None);              // Medial is not applicable
     /// ```
-    pub fn char_modern(&self, position: JamoPosition) -> Option<char> {
+    pub const fn char_modern(&self, position: JamoPosition) -> Option<char> {
         match position {
             JamoPosition::Initial => Some(self.char_modern_initial()),
             JamoPosition::Final => Some(self.char_modern_final()),
@@ -463,7 +568,7 @@ impl JamoConsonantSingular {
         }
     }
 
-    fn char_modern_initial(&self) -> char {
+    const fn char_modern_initial(&self) -> char {
         match self {
             JamoConsonantSingular::Giyeok => '\u{1100}',
             JamoConsonantSingular::Nieun => '\u{1102}',
@@ -482,7 +587,7 @@ impl JamoConsonantSingular {
         }
     }
 
-    fn char_modern_final(&self) -> char {
+    const fn char_modern_final(&self) -> char {
         match self {
             JamoConsonantSingular::Giyeok => '\u{11A8}',
             JamoConsonantSingular::Nieun => '\u{11AB}',
@@ -510,7 +615,7 @@ impl JamoConsonantSingular {
     /// let siot = JamoConsonantSingular::Siot;
     /// assert_eq!(siot.char_compatibility(), 'ㅅ');
     /// ```
-    pub fn char_compatibility(&self) -> char {
+    pub const fn char_compatibility(&self) -> char {
         match self {
             JamoConsonantSingular::Giyeok => 'ㄱ',
             JamoConsonantSingular::Nieun => 'ㄴ',
@@ -655,7 +760,8 @@ impl JamoConsonantSingular {
 }
 
 /// An enum representing composite Hangul consonant jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JamoConsonantComposite {
     /// ㄳ
     GiyeokSiot,
@@ -712,7 +818,7 @@ impl JamoConsonantComposite {
     /// assert_eq!(ssang_giyeok.char_modern(JamoPosition::Final), Some('\u{11A9}'));   // Final ㄲ
     /// assert_eq!(ssang_giyeok.char_modern(JamoPosition::Vowel), None);              // Medial is not applicable
     /// ```
-    pub fn char_modern(&self, position: JamoPosition) -> Option<char> {
+    pub const fn char_modern(&self, position: JamoPosition) -> Option<char> {
         match position {
             JamoPosition::Initial => self.char_modern_initial(),
             JamoPosition::Final => self.char_modern_final(),
@@ -720,7 +826,7 @@ impl JamoConsonantComposite {
         }
     }
 
-    fn char_modern_initial(&self) -> Option<char> {
+    const fn char_modern_initial(&self) -> Option<char> {
         match self {
             JamoConsonantComposite::SsangGiyeok => Some('\u{1101}'),
             JamoConsonantComposite::SsangDigeut => Some('\u{1104}'),
@@ -731,7 +837,7 @@ impl JamoConsonantComposite {
         }
     }
 
-    fn char_modern_final(&self) -> Option<char> {
+    const fn char_modern_final(&self) -> Option<char> {
         match self {
             JamoConsonantComposite::GiyeokSiot => Some('\u{11AA}'),
             JamoConsonantComposite::NieunJieut => Some('\u{11AC}'),
@@ -759,7 +865,7 @@ impl JamoConsonantComposite {
     /// let gieok_siot = JamoConsonantComposite::GiyeokSiot;
     /// assert_eq!(gieok_siot.char_compatibility(), 'ㄳ');
     /// ```
-    pub fn char_compatibility(&self) -> char {
+    pub const fn char_compatibility(&self) -> char {
         match self {
             JamoConsonantComposite::GiyeokSiot => 'ㄳ',
             JamoConsonantComposite::NieunJieut => 'ㄵ',
@@ -877,7 +983,7 @@ impl JamoConsonantComposite {
     /// let gieok_siot = JamoConsonantComposite::GiyeokSiot;
     /// assert!(!gieok_siot.is_valid_initial());
     /// ```
-    pub fn is_valid_initial(&self) -> bool {
+    pub const fn is_valid_initial(&self) -> bool {
         matches!(
             self,
             JamoConsonantComposite::SsangGiyeok
@@ -901,7 +1007,7 @@ impl JamoConsonantComposite {
     /// let ssang_giyeok = JamoConsonantComposite::SsangGiyeok;
     /// assert!(ssang_giyeok.is_valid_final());
     /// ```
-    pub fn is_valid_final(&self) -> bool {
+    pub const fn is_valid_final(&self) -> bool {
         matches!(
             self,
             JamoConsonantComposite::GiyeokSiot
@@ -922,7 +1028,8 @@ impl JamoConsonantComposite {
 }
 
 /// An enum representing singular Hangul vowel jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JamoVowelSingular {
     /// ㅏ
     A,
@@ -966,7 +1073,7 @@ impl JamoVowelSingular {
     /// let eo = JamoVowelSingular::Eo;
     /// assert_eq!(eo.char_modern(), '\u{1165}'); // Modern ㅓ
     /// ```
-    pub fn char_modern(&self) -> char {
+    pub const fn char_modern(&self) -> char {
         match self {
             JamoVowelSingular::A => '\u{1161}',
             JamoVowelSingular::Ae => '\u{1162}',
@@ -994,7 +1101,7 @@ impl JamoVowelSingular {
     /// let yo = JamoVowelSingular::Yo;
     /// assert_eq!(yo.char_compatibility(), 'ㅛ');
     /// ```
-    pub fn char_compatibility(&self) -> char {
+    pub const fn char_compatibility(&self) -> char {
         match self {
             JamoVowelSingular::A => 'ㅏ',
             JamoVowelSingular::Ae => 'ㅐ',
@@ -1051,7 +1158,8 @@ impl JamoVowelSingular {
 }
 
 /// An enum representing composite Hangul vowel jamo.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JamoVowelComposite {
     /// ㅘ
     Wa,
@@ -1081,7 +1189,7 @@ impl JamoVowelComposite {
     /// let wae = JamoVowelComposite::Wae;
     /// assert_eq!(wae.char_modern(), '\u{116B}'); // Modern ㅙ
     /// ```
-    pub fn char_modern(&self) -> char {
+    pub const fn char_modern(&self) -> char {
         match self {
             JamoVowelComposite::Wa => '\u{116A}',
             JamoVowelComposite::Wae => '\u{116B}',
@@ -1102,7 +1210,7 @@ impl JamoVowelComposite {
     /// let wae = JamoVowelComposite::Wae;
     /// assert_eq!(wae.char_compatibility(), 'ㅙ');
     /// ```
-    pub fn char_compatibility(&self) -> char {
+    pub const fn char_compatibility(&self) -> char {
         match self {
             JamoVowelComposite::Wa => 'ㅘ',
             JamoVowelComposite::Wae => 'ㅙ',
@@ -1166,6 +1274,7 @@ impl JamoVowelComposite {
 /// An enum representing Hangul jamo, including both consonants and vowels,
 /// as well as singular and composite forms.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JamoPosition {
     Initial,
     Vowel,
@@ -1182,7 +1291,7 @@ impl Jamo {
     /// let jamo = Jamo::Consonant(JamoConsonantSingular::Giyeok);
     /// assert_eq!(jamo.char_compatibility(), 'ㄱ');
     /// ```
-    pub fn char_compatibility(&self) -> char {
+    pub const fn char_compatibility(&self) -> char {
         match self {
             Jamo::Consonant(c) => c.char_compatibility(),
             Jamo::CompositeConsonant(c) => c.char_compatibility(),
@@ -1204,7 +1313,7 @@ impl Jamo {
     /// let jamo = Jamo::Consonant(JamoConsonantSingular::Giyeok);
     /// assert_eq!(jamo.char_modern(JamoPosition::Initial), Some('ᄀ'));
     /// ```
-    pub fn char_modern(&self, position: JamoPosition) -> Option<char> {
+    pub const fn char_modern(&self, position: JamoPosition) -> Option<char> {
         match self {
             Jamo::Consonant(c) => c.char_modern(position),
             Jamo::CompositeConsonant(c) => match position {
@@ -1232,7 +1341,7 @@ impl Jamo {
     /// let jamo = Jamo::from_modern_jamo('ᄀ').unwrap();
     /// assert_eq!(jamo, Jamo::Consonant(JamoConsonantSingular::Giyeok));
     /// ```
-    pub fn from_modern_jamo(c: char) -> Result<Self, JamoError> {
+    pub const fn from_modern_jamo(c: char) -> Result<Self, JamoError> {
         let cc = modern_to_compatibility_jamo(c);
         Self::from_compatibility_jamo(cc)
     }
@@ -1245,76 +1354,923 @@ impl Jamo {
     /// let jamo = Jamo::from_compatibility_jamo('ㄱ').unwrap();
     /// assert_eq!(jamo, Jamo::Consonant(JamoConsonantSingular::Giyeok));
     /// ```
-    pub fn from_compatibility_jamo(c: char) -> Result<Self, JamoError> {
-        match c {
-            // Singular consonants
-            'ㄱ' => Ok(Jamo::Consonant(JamoConsonantSingular::Giyeok)),
-            'ㄴ' => Ok(Jamo::Consonant(JamoConsonantSingular::Nieun)),
-            'ㄷ' => Ok(Jamo::Consonant(JamoConsonantSingular::Digeut)),
-            'ㄹ' => Ok(Jamo::Consonant(JamoConsonantSingular::Rieul)),
-            'ㅁ' => Ok(Jamo::Consonant(JamoConsonantSingular::Mieum)),
-            'ㅂ' => Ok(Jamo::Consonant(JamoConsonantSingular::Bieup)),
-            'ㅅ' => Ok(Jamo::Consonant(JamoConsonantSingular::Siot)),
-            'ㅇ' => Ok(Jamo::Consonant(JamoConsonantSingular::Ieung)),
-            'ㅈ' => Ok(Jamo::Consonant(JamoConsonantSingular::Jieut)),
-            'ㅊ' => Ok(Jamo::Consonant(JamoConsonantSingular::Chieut)),
-            'ㅋ' => Ok(Jamo::Consonant(JamoConsonantSingular::Kieuk)),
-            'ㅌ' => Ok(Jamo::Consonant(JamoConsonantSingular::Tieut)),
-            'ㅍ' => Ok(Jamo::Consonant(JamoConsonantSingular::Pieup)),
-            'ㅎ' => Ok(Jamo::Consonant(JamoConsonantSingular::Hieut)),
-
-            // Composite consonants
-            'ㄳ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::GiyeokSiot)),
-            'ㄵ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::NieunJieut)),
-            'ㄶ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::NieunHieut)),
-            'ㄺ' => Ok(Jamo::CompositeConsonant(
-                JamoConsonantComposite::RieulGiyeok,
-            )),
-            'ㄻ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::RieulMieum)),
-            'ㄼ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::RieulBieup)),
-            'ㄽ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::RieulSiot)),
-            'ㄾ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::RieulTieut)),
-            'ㄿ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::RieulPieup)),
-            'ㅀ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::RieulHieut)),
-            'ㄲ' => Ok(Jamo::CompositeConsonant(
-                JamoConsonantComposite::SsangGiyeok,
-            )),
-            'ㄸ' => Ok(Jamo::CompositeConsonant(
-                JamoConsonantComposite::SsangDigeut,
-            )),
-            'ㅃ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::SsangBieup)),
-            'ㅆ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::SsangSiot)),
-            'ㅉ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::SsangJieut)),
-            'ㅄ' => Ok(Jamo::CompositeConsonant(JamoConsonantComposite::BieupSiot)),
-
-            // Singular vowels
-            'ㅏ' => Ok(Jamo::Vowel(JamoVowelSingular::A)),
-            'ㅐ' => Ok(Jamo::Vowel(JamoVowelSingular::Ae)),
-            'ㅑ' => Ok(Jamo::Vowel(JamoVowelSingular::Ya)),
-            'ㅒ' => Ok(Jamo::Vowel(JamoVowelSingular::Yae)),
-            'ㅓ' => Ok(Jamo::Vowel(JamoVowelSingular::Eo)),
-            'ㅔ' => Ok(Jamo::Vowel(JamoVowelSingular::E)),
-            'ㅕ' => Ok(Jamo::Vowel(JamoVowelSingular::Yeo)),
-            'ㅖ' => Ok(Jamo::Vowel(JamoVowelSingular::Ye)),
-            'ㅗ' => Ok(Jamo::Vowel(JamoVowelSingular::O)),
-            'ㅛ' => Ok(Jamo::Vowel(JamoVowelSingular::Yo)),
-            'ㅜ' => Ok(Jamo::Vowel(JamoVowelSingular::U)),
-            'ㅠ' => Ok(Jamo::Vowel(JamoVowelSingular::Yu)),
-            'ㅡ' => Ok(Jamo::Vowel(JamoVowelSingular::Eu)),
-            'ㅣ' => Ok(Jamo::Vowel(JamoVowelSingular::I)),
-
-            // Composite vowels
-            'ㅘ' => Ok(Jamo::CompositeVowel(JamoVowelComposite::Wa)),
-            'ㅙ' => Ok(Jamo::CompositeVowel(JamoVowelComposite::Wae)),
-            'ㅚ' => Ok(Jamo::CompositeVowel(JamoVowelComposite::Oe)),
-            'ㅝ' => Ok(Jamo::CompositeVowel(JamoVowelComposite::Wo)),
-            'ㅞ' => Ok(Jamo::CompositeVowel(JamoVowelComposite::We)),
-            'ㅟ' => Ok(Jamo::CompositeVowel(JamoVowelComposite::Wi)),
-            'ㅢ' => Ok(Jamo::CompositeVowel(JamoVowelComposite::Ui)),
-
-            _ => Err(JamoError::FromCharError(c)),
+    pub const fn from_compatibility_jamo(c: char) -> Result<Self, JamoError> {
+        match compatibility_jamo(c) {
+            Some(jamo) => Ok(jamo),
+            None => Err(JamoError::FromCharError(c)),
+        }
+    }
+}
+
+// Returns the `Jamo` for a modern compatibility jamo character. Unlike
+// `Jamo::from_compatibility_jamo`, this can be matched on in const functions,
+// since `JamoError` cannot be dropped at compile time.
+const fn compatibility_jamo(c: char) -> Option<Jamo> {
+    match c {
+        // Singular consonants
+        'ㄱ' => Some(Jamo::Consonant(JamoConsonantSingular::Giyeok)),
+        'ㄴ' => Some(Jamo::Consonant(JamoConsonantSingular::Nieun)),
+        'ㄷ' => Some(Jamo::Consonant(JamoConsonantSingular::Digeut)),
+        'ㄹ' => Some(Jamo::Consonant(JamoConsonantSingular::Rieul)),
+        'ㅁ' => Some(Jamo::Consonant(JamoConsonantSingular::Mieum)),
+        'ㅂ' => Some(Jamo::Consonant(JamoConsonantSingular::Bieup)),
+        'ㅅ' => Some(Jamo::Consonant(JamoConsonantSingular::Siot)),
+        'ㅇ' => Some(Jamo::Consonant(JamoConsonantSingular::Ieung)),
+        'ㅈ' => Some(Jamo::Consonant(JamoConsonantSingular::Jieut)),
+        'ㅊ' => Some(Jamo::Consonant(JamoConsonantSingular::Chieut)),
+        'ㅋ' => Some(Jamo::Consonant(JamoConsonantSingular::Kieuk)),
+        'ㅌ' => Some(Jamo::Consonant(JamoConsonantSingular::Tieut)),
+        'ㅍ' => Some(Jamo::Consonant(JamoConsonantSingular::Pieup)),
+        'ㅎ' => Some(Jamo::Consonant(JamoConsonantSingular::Hieut)),
+
+        // Composite consonants
+        'ㄳ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::GiyeokSiot)),
+        'ㄵ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::NieunJieut)),
+        'ㄶ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::NieunHieut)),
+        'ㄺ' => Some(Jamo::CompositeConsonant(
+            JamoConsonantComposite::RieulGiyeok,
+        )),
+        'ㄻ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::RieulMieum)),
+        'ㄼ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::RieulBieup)),
+        'ㄽ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::RieulSiot)),
+        'ㄾ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::RieulTieut)),
+        'ㄿ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::RieulPieup)),
+        'ㅀ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::RieulHieut)),
+        'ㄲ' => Some(Jamo::CompositeConsonant(
+            JamoConsonantComposite::SsangGiyeok,
+        )),
+        'ㄸ' => Some(Jamo::CompositeConsonant(
+            JamoConsonantComposite::SsangDigeut,
+        )),
+        'ㅃ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::SsangBieup)),
+        'ㅆ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::SsangSiot)),
+        'ㅉ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::SsangJieut)),
+        'ㅄ' => Some(Jamo::CompositeConsonant(JamoConsonantComposite::BieupSiot)),
+
+        // Singular vowels
+        'ㅏ' => Some(Jamo::Vowel(JamoVowelSingular::A)),
+        'ㅐ' => Some(Jamo::Vowel(JamoVowelSingular::Ae)),
+        'ㅑ' => Some(Jamo::Vowel(JamoVowelSingular::Ya)),
+        'ㅒ' => Some(Jamo::Vowel(JamoVowelSingular::Yae)),
+        'ㅓ' => Some(Jamo::Vowel(JamoVowelSingular::Eo)),
+        'ㅔ' => Some(Jamo::Vowel(JamoVowelSingular::E)),
+        'ㅕ' => Some(Jamo::Vowel(JamoVowelSingular::Yeo)),
+        'ㅖ' => Some(Jamo::Vowel(JamoVowelSingular::Ye)),
+        'ㅗ' => Some(Jamo::Vowel(JamoVowelSingular::O)),
+        'ㅛ' => Some(Jamo::Vowel(JamoVowelSingular::Yo)),
+        'ㅜ' => Some(Jamo::Vowel(JamoVowelSingular::U)),
+        'ㅠ' => Some(Jamo::Vowel(JamoVowelSingular::Yu)),
+        'ㅡ' => Some(Jamo::Vowel(JamoVowelSingular::Eu)),
+        'ㅣ' => Some(Jamo::Vowel(JamoVowelSingular::I)),
+
+        // Composite vowels
+        'ㅘ' => Some(Jamo::CompositeVowel(JamoVowelComposite::Wa)),
+        'ㅙ' => Some(Jamo::CompositeVowel(JamoVowelComposite::Wae)),
+        'ㅚ' => Some(Jamo::CompositeVowel(JamoVowelComposite::Oe)),
+        'ㅝ' => Some(Jamo::CompositeVowel(JamoVowelComposite::Wo)),
+        'ㅞ' => Some(Jamo::CompositeVowel(JamoVowelComposite::We)),
+        'ㅟ' => Some(Jamo::CompositeVowel(JamoVowelComposite::Wi)),
+        'ㅢ' => Some(Jamo::CompositeVowel(JamoVowelComposite::Ui)),
+
+        _ => None,
+    }
+}
+
+impl fmt::Display for Jamo {
+    /// Writes the compatibility jamo character.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.char_compatibility())
+    }
+}
+
+impl TryFrom<char> for Jamo {
+    type Error = JamoError;
+
+    /// Accepts both compatibility and modern jamo characters.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Jamo::from_modern_jamo(c)
+    }
+}
+
+impl FromStr for Jamo {
+    type Err = JamoError;
+
+    /// Parses a string containing exactly one compatibility or modern jamo.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::jamo::Jamo;
+    ///
+    /// let jamo: Jamo = "ㅘ".parse().unwrap();
+    /// assert_eq!(jamo.to_string(), "ㅘ");
+    /// assert_eq!(format!("{}", "\u{1100}".parse::<Jamo>().unwrap()), "ㄱ");
+    /// assert!("ㄱㅏ".parse::<Jamo>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Jamo::try_from(c),
+            _ => Err(JamoError::FromStrError(s.to_string())),
+        }
+    }
+}
+
+/// An initial consonant (초성, choseong) of a modern Hangul syllable. Only
+/// the 19 jamo that can begin a syllable can be represented, so a
+/// `HangulBlock` built from `Choseong`, `Jungseong`, and `Jongseong` with
+/// `HangulBlock::from_parts` is always valid.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::jamo::Choseong;
+///
+/// let initial = Choseong::try_from('ㄲ').unwrap();
+/// assert_eq!(initial.index(), 1);
+/// assert_eq!(initial.char_modern(), '\u{1101}');
+/// assert_eq!(Choseong::try_from('\u{1101}'), Ok(initial));
+///
+/// // Clusters and vowels cannot begin a syllable
+/// assert!(Choseong::try_from('ㄳ').is_err());
+/// assert!(Choseong::try_from('ㅏ').is_err());
+///
+/// assert_eq!(Choseong::all().count(), Choseong::COUNT);
+/// assert_eq!(Choseong::from_index(18).unwrap().char_compatibility(), 'ㅎ');
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Jamo", into = "Jamo")
+)]
+pub struct Choseong(Jamo);
+
+impl Choseong {
+    /// The number of initial consonants.
+    pub const COUNT: usize = 19;
+
+    /// Returns the initial consonant at `index` in Unicode order (ㄱ is 0,
+    /// ㅎ is 18), or `None` if `index` is out of range.
+    pub const fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::COUNT {
+            return None;
+        }
+        // Every index in range is a jamo valid in this position
+        match char::from_u32(L_BASE + index as u32) {
+            Some(c) => match compatibility_jamo(modern_to_compatibility_jamo(c)) {
+                Some(jamo) => Some(Choseong(jamo)),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Returns the index of this initial consonant in Unicode order, as
+    /// used to compute syllable codepoints.
+    pub const fn index(&self) -> usize {
+        (self.char_modern() as u32 - L_BASE) as usize
+    }
+
+    /// Returns an iterator over all initial consonants in Unicode order.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..Self::COUNT).filter_map(Self::from_index)
+    }
+
+    /// Returns the underlying `Jamo`.
+    pub const fn jamo(&self) -> &Jamo {
+        &self.0
+    }
+
+    /// Returns the compatibility jamo character for this initial consonant.
+    pub const fn char_compatibility(&self) -> char {
+        self.0.char_compatibility()
+    }
+
+    /// Returns the modern (conjoining) choseong character.
+    pub const fn char_modern(&self) -> char {
+        // Validated on construction
+        self.0.char_modern(JamoPosition::Initial).unwrap()
+    }
+}
+
+impl TryFrom<Jamo> for Choseong {
+    type Error = JamoError;
+
+    fn try_from(jamo: Jamo) -> Result<Self, Self::Error> {
+        check_position(&jamo, JamoPosition::Initial)?;
+        Ok(Choseong(jamo))
+    }
+}
+
+impl TryFrom<char> for Choseong {
+    type Error = JamoError;
+
+    /// Accepts both compatibility jamo and modern choseong characters.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::try_from(Jamo::from_modern_jamo(c)?)
+    }
+}
+
+impl From<Choseong> for Jamo {
+    fn from(initial: Choseong) -> Self {
+        initial.0
+    }
+}
+
+impl fmt::Display for Choseong {
+    /// Writes the compatibility jamo character.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A vowel (중성, jungseong) of a modern Hangul syllable. All 21 modern
+/// vowels, singular and composite, can be represented.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::jamo::Jungseong;
+///
+/// let vowel = Jungseong::try_from('ㅘ').unwrap();
+/// assert_eq!(vowel.index(), 9);
+/// assert_eq!(vowel.char_modern(), '\u{116A}');
+/// assert!(Jungseong::try_from('ㄱ').is_err());
+/// assert_eq!(Jungseong::all().count(), Jungseong::COUNT);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Jamo", into = "Jamo")
+)]
+pub struct Jungseong(Jamo);
+
+impl Jungseong {
+    /// The number of vowels.
+    pub const COUNT: usize = 21;
+
+    /// Returns the vowel at `index` in Unicode order (ㅏ is 0, ㅣ is 20), or
+    /// `None` if `index` is out of range.
+    pub const fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::COUNT {
+            return None;
+        }
+        // Every index in range is a jamo valid in this position
+        match char::from_u32(V_BASE + index as u32) {
+            Some(c) => match compatibility_jamo(modern_to_compatibility_jamo(c)) {
+                Some(jamo) => Some(Jungseong(jamo)),
+                None => None,
+            },
+            None => None,
         }
     }
+
+    /// Returns the index of this vowel in Unicode order, as used to compute
+    /// syllable codepoints.
+    pub const fn index(&self) -> usize {
+        (self.char_modern() as u32 - V_BASE) as usize
+    }
+
+    /// Returns an iterator over all vowels in Unicode order.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..Self::COUNT).filter_map(Self::from_index)
+    }
+
+    /// Returns the underlying `Jamo`.
+    pub const fn jamo(&self) -> &Jamo {
+        &self.0
+    }
+
+    /// Returns the compatibility jamo character for this vowel.
+    pub const fn char_compatibility(&self) -> char {
+        self.0.char_compatibility()
+    }
+
+    /// Returns the modern (conjoining) jungseong character.
+    pub const fn char_modern(&self) -> char {
+        // Validated on construction
+        self.0.char_modern(JamoPosition::Vowel).unwrap()
+    }
+}
+
+impl TryFrom<Jamo> for Jungseong {
+    type Error = JamoError;
+
+    fn try_from(jamo: Jamo) -> Result<Self, Self::Error> {
+        check_position(&jamo, JamoPosition::Vowel)?;
+        Ok(Jungseong(jamo))
+    }
+}
+
+impl TryFrom<char> for Jungseong {
+    type Error = JamoError;
+
+    /// Accepts both compatibility jamo and modern jungseong characters.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::try_from(Jamo::from_modern_jamo(c)?)
+    }
+}
+
+impl From<Jungseong> for Jamo {
+    fn from(vowel: Jungseong) -> Self {
+        vowel.0
+    }
+}
+
+impl fmt::Display for Jungseong {
+    /// Writes the compatibility jamo character.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A final consonant (종성, jongseong) of a modern Hangul syllable. Only the
+/// 27 jamo that can end a syllable can be represented; a syllable without a
+/// final consonant uses `None`.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::jamo::Jongseong;
+///
+/// let last = Jongseong::try_from('ㄺ').unwrap();
+/// assert_eq!(last.index(), 9);
+/// assert_eq!(last.char_modern(), '\u{11B0}');
+///
+/// // Only ㄲ and ㅆ of the double consonants can end a syllable
+/// assert!(Jongseong::try_from('ㄸ').is_err());
+///
+/// // Indices start at 1, since 0 is used for syllables without a final
+/// assert_eq!(Jongseong::from_index(0), None);
+/// assert_eq!(Jongseong::all().count(), Jongseong::COUNT);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Jamo", into = "Jamo")
+)]
+pub struct Jongseong(Jamo);
+
+impl Jongseong {
+    /// The number of final consonants.
+    pub const COUNT: usize = 27;
+
+    /// Returns the final consonant at `index` in Unicode order (ㄱ is 1, ㅎ
+    /// is 27), or `None` if `index` is out of range.
+    pub const fn from_index(index: usize) -> Option<Self> {
+        if index == 0 || index > Self::COUNT {
+            return None;
+        }
+        // Every index in range is a jamo valid in this position
+        match char::from_u32(T_BASE + index as u32) {
+            Some(c) => match compatibility_jamo(modern_to_compatibility_jamo(c)) {
+                Some(jamo) => Some(Jongseong(jamo)),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Returns the index of this final consonant in Unicode order, as used
+    /// to compute syllable codepoints.
+    pub const fn index(&self) -> usize {
+        (self.char_modern() as u32 - T_BASE) as usize
+    }
+
+    /// Returns an iterator over all final consonants in Unicode order.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (1..=Self::COUNT).filter_map(Self::from_index)
+    }
+
+    /// Returns the underlying `Jamo`.
+    pub const fn jamo(&self) -> &Jamo {
+        &self.0
+    }
+
+    /// Returns the compatibility jamo character for this final consonant.
+    pub const fn char_compatibility(&self) -> char {
+        self.0.char_compatibility()
+    }
+
+    /// Returns the modern (conjoining) jongseong character.
+    pub const fn char_modern(&self) -> char {
+        // Validated on construction
+        self.0.char_modern(JamoPosition::Final).unwrap()
+    }
+}
+
+impl TryFrom<Jamo> for Jongseong {
+    type Error = JamoError;
+
+    fn try_from(jamo: Jamo) -> Result<Self, Self::Error> {
+        check_position(&jamo, JamoPosition::Final)?;
+        Ok(Jongseong(jamo))
+    }
+}
+
+impl TryFrom<char> for Jongseong {
+    type Error = JamoError;
+
+    /// Accepts both compatibility jamo and modern jongseong characters.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::try_from(Jamo::from_modern_jamo(c)?)
+    }
+}
+
+impl From<Jongseong> for Jamo {
+    fn from(last: Jongseong) -> Self {
+        last.0
+    }
+}
+
+impl fmt::Display for Jongseong {
+    /// Writes the compatibility jamo character.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Returns the initial consonant written the same as the final consonant
+/// `final_consonant`, as when a final moves to the start of the next
+/// syllable (연음, 먹어 → 머거). Returns `None` for the clusters that cannot
+/// begin a syllable, such as ㄳ and ㅄ; use `split_jongseong` to move
+/// only their second consonant. ㄲ and ㅆ begin syllables as they are.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::{Choseong, Jongseong, final_to_initial};
+///
+/// let giyeok = Jongseong::try_from('ㄱ').unwrap();
+/// assert_eq!(final_to_initial(giyeok), Some(Choseong::try_from('ㄱ').unwrap()));
+/// assert!(final_to_initial(Jongseong::try_from('ㅆ').unwrap()).is_some());
+/// assert_eq!(final_to_initial(Jongseong::try_from('ㅄ').unwrap()), None);
+/// ```
+pub fn final_to_initial(final_consonant: Jongseong) -> Option<Choseong> {
+    Choseong::try_from(final_consonant.0).ok()
+}
+
+/// Splits a final consonant cluster that cannot begin a syllable into the
+/// consonant that stays in the final position and the one that moves to
+/// the next syllable's initial, as in 닭이 → 달기 and 없어 → 업서. Any other
+/// final, including ㄲ and ㅆ, is returned unchanged with `None`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::{Choseong, Jongseong, split_jongseong};
+///
+/// let (kept, moved) = split_jongseong(Jongseong::try_from('ㄺ').unwrap());
+/// assert_eq!(kept, Jongseong::try_from('ㄹ').unwrap());
+/// assert_eq!(moved, Some(Choseong::try_from('ㄱ').unwrap()));
+///
+/// let ssang_siot = Jongseong::try_from('ㅆ').unwrap();
+/// assert_eq!(split_jongseong(ssang_siot), (ssang_siot, None));
+/// ```
+pub fn split_jongseong(final_consonant: Jongseong) -> (Jongseong, Option<Choseong>) {
+    match &final_consonant.0 {
+        Jamo::CompositeConsonant(cluster) if !cluster.is_valid_initial() => {
+            let (first, second) = cluster.decompose();
+            // Infallible: both halves of a final cluster are singular
+            // consonants, which are valid in either position
+            (
+                Jongseong::try_from(first).unwrap(),
+                Some(Choseong::try_from(second).unwrap()),
+            )
+        }
+        _ => (final_consonant, None),
+    }
+}
+
+/// Splits a double consonant (ㄲ, ㄸ, ㅃ, ㅆ, or ㅉ) into its two halves,
+/// given as compatibility or modern jamo. Returns `None` for any other
+/// character. The halves are compatibility jamo.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::split_double_consonant;
+///
+/// assert_eq!(split_double_consonant('ㄲ'), Some(('ㄱ', 'ㄱ')));
+/// assert_eq!(split_double_consonant('\u{1104}'), Some(('ㄷ', 'ㄷ')));
+/// assert_eq!(split_double_consonant('ㄳ'), None);
+/// ```
+pub fn split_double_consonant(c: char) -> Option<(char, char)> {
+    match Jamo::from_modern_jamo(c).ok()? {
+        Jamo::CompositeConsonant(double) if double.is_valid_initial() => {
+            Some(compatibility_pair(double.decompose()))
+        }
+        _ => None,
+    }
+}
+
+/// Splits a composite vowel, such as ㅝ, into the two vowels it is written
+/// with, given as compatibility or modern jamo. Returns `None` for any other
+/// character. The vowels are compatibility jamo.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::split_composite_vowel;
+///
+/// assert_eq!(split_composite_vowel('ㅝ'), Some(('ㅜ', 'ㅓ')));
+/// assert_eq!(split_composite_vowel('ㅢ'), Some(('ㅡ', 'ㅣ')));
+/// assert_eq!(split_composite_vowel('ㅓ'), None);
+/// ```
+pub fn split_composite_vowel(c: char) -> Option<(char, char)> {
+    match Jamo::from_modern_jamo(c).ok()? {
+        Jamo::CompositeVowel(vowel) => Some(compatibility_pair(vowel.decompose())),
+        _ => None,
+    }
+}
+
+/// Splits a final consonant cluster, such as ㅀ, into its two consonants,
+/// given as compatibility or modern jamo. Returns `None` for any other
+/// character, including ㄲ and ㅆ, which are split by
+/// `split_double_consonant`. The consonants are compatibility jamo. See
+/// `split_jongseong` to split a `Jongseong` for resyllabification.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::split_composite_final;
+///
+/// assert_eq!(split_composite_final('ㅀ'), Some(('ㄹ', 'ㅎ')));
+/// assert_eq!(split_composite_final('\u{11B9}'), Some(('ㅂ', 'ㅅ')));
+/// assert_eq!(split_composite_final('ㅆ'), None);
+/// ```
+pub fn split_composite_final(c: char) -> Option<(char, char)> {
+    match Jamo::from_modern_jamo(c).ok()? {
+        Jamo::CompositeConsonant(cluster) if !cluster.is_valid_initial() => {
+            Some(compatibility_pair(cluster.decompose()))
+        }
+        _ => None,
+    }
+}
+
+// Returns the compatibility jamo characters of a pair of jamo.
+fn compatibility_pair((first, second): (Jamo, Jamo)) -> (char, char) {
+    (first.char_compatibility(), second.char_compatibility())
+}
+
+fn check_position(jamo: &Jamo, position: JamoPosition) -> Result<(), JamoError> {
+    match jamo.char_modern(position.clone()) {
+        Some(_) => Ok(()),
+        None => Err(JamoError::InvalidPosition(
+            jamo.char_compatibility(),
+            position,
+        )),
+    }
+}
+
+/// The kinds of pen strokes used to write Hangul jamo, as returned by
+/// `stroke_sequence`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StrokeKind {
+    /// A left-to-right stroke, as in ㅡ.
+    Horizontal,
+    /// A top-to-bottom stroke, as in ㅣ.
+    Vertical,
+    /// A stroke falling down and to the left, as in the left half of ㅅ.
+    LeftFalling,
+    /// A stroke falling down and to the right, as in the right half of ㅅ.
+    RightFalling,
+    /// A horizontal stroke that turns downward, as in ㄱ.
+    HorizontalVertical,
+    /// A horizontal stroke that turns and falls to the left, as in the top
+    /// of ㅈ.
+    HorizontalLeftFalling,
+    /// A vertical stroke that turns to the right, as in ㄴ.
+    VerticalHorizontal,
+    /// A closed circle, as in ㅇ.
+    Circle,
+    /// A short tick, as at the top of ㅊ and ㅎ.
+    Dot,
+}
+
+impl Jamo {
+    /// Returns the strokes used to write this jamo, in stroke order.
+    /// Composite jamo are written as their components in order.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::jamo::{Jamo, JamoConsonantSingular, StrokeKind};
+    ///
+    /// let digeut = Jamo::Consonant(JamoConsonantSingular::Digeut);
+    /// assert_eq!(
+    ///     digeut.strokes(),
+    ///     vec![StrokeKind::Horizontal, StrokeKind::VerticalHorizontal]
+    /// );
+    /// ```
+    pub fn strokes(&self) -> Vec<StrokeKind> {
+        use StrokeKind::*;
+        match self {
+            Jamo::Consonant(c) => match c {
+                JamoConsonantSingular::Giyeok => vec![HorizontalVertical],
+                JamoConsonantSingular::Nieun => vec![VerticalHorizontal],
+                JamoConsonantSingular::Digeut => vec![Horizontal, VerticalHorizontal],
+                JamoConsonantSingular::Rieul => {
+                    vec![HorizontalVertical, Horizontal, VerticalHorizontal]
+                }
+                JamoConsonantSingular::Mieum => vec![Vertical, HorizontalVertical, Horizontal],
+                JamoConsonantSingular::Bieup => vec![Vertical, Vertical, Horizontal, Horizontal],
+                JamoConsonantSingular::Siot => vec![LeftFalling, RightFalling],
+                JamoConsonantSingular::Ieung => vec![Circle],
+                JamoConsonantSingular::Jieut => vec![HorizontalLeftFalling, RightFalling],
+                JamoConsonantSingular::Chieut => vec![Dot, HorizontalLeftFalling, RightFalling],
+                JamoConsonantSingular::Kieuk => vec![HorizontalVertical, Horizontal],
+                JamoConsonantSingular::Tieut => vec![Horizontal, Horizontal, VerticalHorizontal],
+                JamoConsonantSingular::Pieup => vec![Horizontal, Vertical, Vertical, Horizontal],
+                JamoConsonantSingular::Hieut => vec![Dot, Horizontal, Circle],
+            },
+            Jamo::Vowel(v) => match v {
+                JamoVowelSingular::A => vec![Vertical, Horizontal],
+                JamoVowelSingular::Ae => vec![Vertical, Horizontal, Vertical],
+                JamoVowelSingular::Ya => vec![Vertical, Horizontal, Horizontal],
+                JamoVowelSingular::Yae => vec![Vertical, Horizontal, Horizontal, Vertical],
+                JamoVowelSingular::Eo => vec![Horizontal, Vertical],
+                JamoVowelSingular::E => vec![Horizontal, Vertical, Vertical],
+                JamoVowelSingular::Yeo => vec![Horizontal, Horizontal, Vertical],
+                JamoVowelSingular::Ye => vec![Horizontal, Horizontal, Vertical, Vertical],
+                JamoVowelSingular::O => vec![Vertical, Horizontal],
+                JamoVowelSingular::Yo => vec![Vertical, Vertical, Horizontal],
+                JamoVowelSingular::U => vec![Horizontal, Vertical],
+                JamoVowelSingular::Yu => vec![Horizontal, Vertical, Vertical],
+                JamoVowelSingular::Eu => vec![Horizontal],
+                JamoVowelSingular::I => vec![Vertical],
+            },
+            Jamo::CompositeConsonant(c) => {
+                let (first, second) = c.decompose();
+                [first.strokes(), second.strokes()].concat()
+            }
+            Jamo::CompositeVowel(v) => {
+                let (first, second) = v.decompose();
+                [first.strokes(), second.strokes()].concat()
+            }
+        }
+    }
+}
+
+/// Returns the number of strokes used to write the jamo `c`, which may be
+/// a compatibility or modern jamo. Returns `None` if `c` is not a Hangul
+/// jamo.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::stroke_count;
+///
+/// assert_eq!(stroke_count('ㄹ'), Some(3));
+/// assert_eq!(stroke_count('ㅘ'), Some(4));
+/// assert_eq!(stroke_count('\u{1100}'), Some(1)); // Modern ㄱ
+/// assert_eq!(stroke_count('a'), None);
+/// ```
+pub fn stroke_count(c: char) -> Option<u8> {
+    stroke_sequence(c).map(|strokes| strokes.len() as u8)
+}
+
+/// Returns the strokes used to write the jamo `c`, in stroke order, or
+/// `None` if `c` is not a Hangul jamo. See `Jamo::strokes`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::{stroke_sequence, StrokeKind};
+///
+/// assert_eq!(
+///     stroke_sequence('ㅅ'),
+///     Some(vec![StrokeKind::LeftFalling, StrokeKind::RightFalling])
+/// );
+/// ```
+pub fn stroke_sequence(c: char) -> Option<Vec<StrokeKind>> {
+    match Character::from_char(c).ok()? {
+        Character::Hangul(jamo) => Some(jamo.strokes()),
+        Character::NonHangul(_) => None,
+    }
+}
+
+/// A pen stroke of a jamo, with its path, as returned by `strokes`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stroke {
+    /// The kind of stroke.
+    pub kind: StrokeKind,
+
+    /// The points the pen passes through, in order, as a polyline. Points
+    /// are `(x, y)` in the unit em square, with `(0.0, 0.0)` at the top left
+    /// and `y` increasing downward.
+    pub points: Vec<(f32, f32)>,
+}
+
+/// Returns the strokes used to write the jamo `c`, which may be a
+/// compatibility or modern jamo, in stroke order with the path of each, or
+/// an empty slice if `c` is not a Hangul jamo. The strokes are those of
+/// `stroke_sequence`, and each jamo fills the em square on its own; the
+/// components of composite jamo such as ㄲ and ㅘ are drawn side by side,
+/// each in half the width.
+///
+/// The paths are simple and regular rather than calligraphic, so that they
+/// can be used to animate stroke order or to compare with strokes drawn by
+/// a user.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::{StrokeKind, strokes};
+///
+/// let giyeok = strokes('ㄱ');
+/// assert_eq!(giyeok.len(), 1);
+/// assert_eq!(giyeok[0].kind, StrokeKind::HorizontalVertical);
+/// assert_eq!(giyeok[0].points, [(0.15, 0.2), (0.8, 0.2), (0.8, 0.85)]);
+///
+/// // ㄲ is two ㄱ side by side
+/// assert_eq!(strokes('ㄲ')[1].points[0], (0.575, 0.2));
+/// assert_eq!(strokes('\u{1100}'), giyeok);
+/// assert!(strokes('a').is_empty());
+/// ```
+pub fn strokes(c: char) -> &'static [Stroke] {
+    static STROKES: OnceLock<Vec<Vec<Stroke>>> = OnceLock::new();
+    let Ok(Character::Hangul(jamo)) = Character::from_char(c) else {
+        return &[];
+    };
+    let table = STROKES.get_or_init(|| {
+        ('ㄱ'..='ㅣ')
+            .map(|c| match Jamo::from_compatibility_jamo(c) {
+                Ok(jamo) => stroke_paths(&jamo),
+                Err(_) => Vec::new(),
+            })
+            .collect()
+    });
+    let index = jamo.char_compatibility() as usize - 'ㄱ' as usize;
+    table.get(index).map_or(&[], Vec::as_slice)
+}
+
+// Returns the strokes of `jamo` with their paths, drawing the components of
+// composite jamo side by side.
+fn stroke_paths(jamo: &Jamo) -> Vec<Stroke> {
+    let (first, second) = match jamo {
+        Jamo::CompositeConsonant(c) => c.decompose(),
+        Jamo::CompositeVowel(v) => v.decompose(),
+        _ => return singular_stroke_paths(jamo.char_compatibility()),
+    };
+    let squeeze = |strokes: Vec<Stroke>, left: f32| {
+        strokes.into_iter().map(move |stroke| Stroke {
+            kind: stroke.kind,
+            points: stroke
+                .points
+                .into_iter()
+                .map(|(x, y)| (left + x / 2.0, y))
+                .collect(),
+        })
+    };
+    squeeze(stroke_paths(&first), 0.0)
+        .chain(squeeze(stroke_paths(&second), 0.5))
+        .collect()
+}
+
+// Returns the strokes of the singular jamo `c` with their paths, in the
+// order of `Jamo::strokes`.
+fn singular_stroke_paths(c: char) -> Vec<Stroke> {
+    use StrokeKind::*;
+    let stroke = |kind, points: &[(f32, f32)]| Stroke {
+        kind,
+        points: points.to_vec(),
+    };
+    let horizontal = |y, left, right| stroke(Horizontal, &[(left, y), (right, y)]);
+    let vertical = |x, top, bottom| stroke(Vertical, &[(x, top), (x, bottom)]);
+    match c {
+        'ㄱ' => vec![stroke(
+            HorizontalVertical,
+            &[(0.15, 0.2), (0.8, 0.2), (0.8, 0.85)],
+        )],
+        'ㄴ' => vec![stroke(
+            VerticalHorizontal,
+            &[(0.2, 0.15), (0.2, 0.8), (0.85, 0.8)],
+        )],
+        'ㄷ' => vec![
+            horizontal(0.2, 0.2, 0.8),
+            stroke(VerticalHorizontal, &[(0.2, 0.2), (0.2, 0.8), (0.85, 0.8)]),
+        ],
+        'ㄹ' => vec![
+            stroke(HorizontalVertical, &[(0.2, 0.15), (0.8, 0.15), (0.8, 0.5)]),
+            horizontal(0.5, 0.2, 0.8),
+            stroke(VerticalHorizontal, &[(0.2, 0.5), (0.2, 0.85), (0.85, 0.85)]),
+        ],
+        'ㅁ' => vec![
+            vertical(0.2, 0.2, 0.8),
+            stroke(HorizontalVertical, &[(0.2, 0.2), (0.8, 0.2), (0.8, 0.8)]),
+            horizontal(0.8, 0.2, 0.8),
+        ],
+        'ㅂ' => vec![
+            vertical(0.2, 0.15, 0.85),
+            vertical(0.8, 0.15, 0.85),
+            horizontal(0.5, 0.2, 0.8),
+            horizontal(0.85, 0.2, 0.8),
+        ],
+        'ㅅ' => vec![
+            stroke(LeftFalling, &[(0.5, 0.15), (0.15, 0.85)]),
+            stroke(RightFalling, &[(0.5, 0.45), (0.85, 0.85)]),
+        ],
+        'ㅇ' => vec![circle((0.5, 0.5), 0.35)],
+        'ㅈ' => vec![
+            stroke(
+                HorizontalLeftFalling,
+                &[(0.2, 0.2), (0.8, 0.2), (0.15, 0.85)],
+            ),
+            stroke(RightFalling, &[(0.5, 0.5), (0.85, 0.85)]),
+        ],
+        'ㅊ' => vec![
+            stroke(Dot, &[(0.45, 0.05), (0.55, 0.15)]),
+            stroke(
+                HorizontalLeftFalling,
+                &[(0.2, 0.3), (0.8, 0.3), (0.15, 0.9)],
+            ),
+            stroke(RightFalling, &[(0.5, 0.6), (0.85, 0.9)]),
+        ],
+        'ㅋ' => vec![
+            stroke(HorizontalVertical, &[(0.15, 0.2), (0.8, 0.2), (0.8, 0.85)]),
+            horizontal(0.5, 0.15, 0.8),
+        ],
+        'ㅌ' => vec![
+            horizontal(0.15, 0.2, 0.8),
+            horizontal(0.5, 0.2, 0.8),
+            stroke(
+                VerticalHorizontal,
+                &[(0.2, 0.15), (0.2, 0.85), (0.85, 0.85)],
+            ),
+        ],
+        'ㅍ' => vec![
+            horizontal(0.2, 0.15, 0.85),
+            vertical(0.35, 0.2, 0.8),
+            vertical(0.65, 0.2, 0.8),
+            horizontal(0.8, 0.15, 0.85),
+        ],
+        'ㅎ' => vec![
+            stroke(Dot, &[(0.45, 0.05), (0.55, 0.12)]),
+            horizontal(0.25, 0.2, 0.8),
+            circle((0.5, 0.62), 0.25),
+        ],
+        'ㅏ' => vec![vertical(0.4, 0.1, 0.9), horizontal(0.5, 0.4, 0.7)],
+        'ㅐ' => vec![
+            vertical(0.3, 0.1, 0.9),
+            horizontal(0.5, 0.3, 0.55),
+            vertical(0.7, 0.1, 0.9),
+        ],
+        'ㅑ' => vec![
+            vertical(0.4, 0.1, 0.9),
+            horizontal(0.4, 0.4, 0.7),
+            horizontal(0.6, 0.4, 0.7),
+        ],
+        'ㅒ' => vec![
+            vertical(0.3, 0.1, 0.9),
+            horizontal(0.4, 0.3, 0.55),
+            horizontal(0.6, 0.3, 0.55),
+            vertical(0.7, 0.1, 0.9),
+        ],
+        'ㅓ' => vec![horizontal(0.5, 0.3, 0.6), vertical(0.6, 0.1, 0.9)],
+        'ㅔ' => vec![
+            horizontal(0.5, 0.2, 0.45),
+            vertical(0.45, 0.1, 0.9),
+            vertical(0.75, 0.1, 0.9),
+        ],
+        'ㅕ' => vec![
+            horizontal(0.4, 0.3, 0.6),
+            horizontal(0.6, 0.3, 0.6),
+            vertical(0.6, 0.1, 0.9),
+        ],
+        'ㅖ' => vec![
+            horizontal(0.4, 0.2, 0.45),
+            horizontal(0.6, 0.2, 0.45),
+            vertical(0.45, 0.1, 0.9),
+            vertical(0.75, 0.1, 0.9),
+        ],
+        'ㅗ' => vec![vertical(0.5, 0.3, 0.6), horizontal(0.6, 0.1, 0.9)],
+        'ㅛ' => vec![
+            vertical(0.4, 0.3, 0.6),
+            vertical(0.6, 0.3, 0.6),
+            horizontal(0.6, 0.1, 0.9),
+        ],
+        'ㅜ' => vec![horizontal(0.4, 0.1, 0.9), vertical(0.5, 0.4, 0.7)],
+        'ㅠ' => vec![
+            horizontal(0.4, 0.1, 0.9),
+            vertical(0.4, 0.4, 0.7),
+            vertical(0.6, 0.4, 0.7),
+        ],
+        'ㅡ' => vec![horizontal(0.5, 0.1, 0.9)],
+        'ㅣ' => vec![vertical(0.5, 0.1, 0.9)],
+        _ => Vec::new(),
+    }
+}
+
+// Returns a circle stroke around `center`, drawn counterclockwise from the
+// top as ㅇ is written, as a closed polyline of 16 segments.
+fn circle(center: (f32, f32), radius: f32) -> Stroke {
+    const SEGMENTS: usize = 16;
+    let points = (0..=SEGMENTS)
+        .map(|i| {
+            let angle = std::f32::consts::TAU * i as f32 / SEGMENTS as f32;
+            (
+                center.0 - radius * angle.sin(),
+                center.1 - radius * angle.cos(),
+            )
+        })
+        .collect();
+    Stroke {
+        kind: StrokeKind::Circle,
+        points,
+    }
 }
 
 #[cfg(test)]
@@ -1684,4 +2640,139 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn stroke_counts() {
+        let tests = vec![
+            ('ㄱ', 1),
+            ('ㄷ', 2),
+            ('ㅂ', 4),
+            ('ㅈ', 2),
+            ('ㅊ', 3),
+            ('ㅍ', 4),
+            ('ㅎ', 3),
+            ('ㄲ', 2),
+            ('ㅃ', 8),
+            ('ㄻ', 6),
+            ('ㅐ', 3),
+            ('ㅖ', 4),
+            ('ㅙ', 5),
+            ('ㅢ', 2),
+        ];
+        for (c, expected) in tests {
+            assert_eq!(stroke_count(c), Some(expected), "stroke count of {}", c);
+        }
+        assert_eq!(stroke_count('\u{11AF}'), Some(3)); // Final ㄹ
+        assert_eq!(stroke_count('가'), None);
+    }
+
+    #[test]
+    fn positional_newtypes() {
+        assert_eq!(
+            Jongseong::try_from('ㄸ'),
+            Err(JamoError::InvalidPosition('ㄸ', JamoPosition::Final))
+        );
+        assert_eq!(
+            Choseong::try_from('ㅢ'),
+            Err(JamoError::InvalidPosition('ㅢ', JamoPosition::Initial))
+        );
+        assert_eq!(Choseong::try_from('a'), Err(JamoError::FromCharError('a')));
+
+        for (i, initial) in Choseong::all().enumerate() {
+            assert_eq!(initial.index(), i);
+        }
+        assert_eq!(Jungseong::from_index(Jungseong::COUNT), None);
+        assert_eq!(
+            Jongseong::from_index(Jongseong::COUNT)
+                .unwrap()
+                .char_compatibility(),
+            'ㅎ'
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn positional_newtypes_are_validated_on_deserialize() {
+        let json = serde_json::to_string(&Jamo::from_compatibility_jamo('ㄸ').unwrap()).unwrap();
+        assert!(serde_json::from_str::<Choseong>(&json).is_ok());
+        assert!(serde_json::from_str::<Jongseong>(&json).is_err());
+    }
+
+    #[test]
+    fn ranges_cover_hangul() {
+        assert!(ranges().windows(2).all(|w| w[0].end < w[1].start));
+        for c in ranges().iter().flat_map(HangulRange::chars) {
+            assert_eq!(ranges().iter().filter(|r| r.contains(c)).count(), 1);
+        }
+        for c in ('\u{0}'..='\u{FFFF}').filter(|&c| {
+            JamoUnicodeType::evaluate(c) != JamoUnicodeType::NonHangul
+                || crate::block::syllable_index(c).is_some()
+        }) {
+            assert!(ranges().iter().any(|r| r.contains(c)), "{c:?}");
+        }
+    }
+
+    #[test]
+    fn finals_move_to_initials() {
+        let mut clusters = 0;
+        for final_consonant in Jongseong::all() {
+            let (kept, moved) = split_jongseong(final_consonant);
+            match final_to_initial(final_consonant) {
+                Some(initial) => {
+                    assert_eq!(
+                        initial.char_compatibility(),
+                        final_consonant.char_compatibility()
+                    );
+                    assert_eq!((kept, moved), (final_consonant, None));
+                }
+                None => {
+                    assert!(moved.is_some());
+                    clusters += 1;
+                }
+            }
+        }
+        assert_eq!(clusters, 11);
+        let (kept, moved) = split_jongseong(Jongseong::try_from('ㅄ').unwrap());
+        assert_eq!(kept.char_compatibility(), 'ㅂ');
+        assert_eq!(moved.unwrap().char_compatibility(), 'ㅅ');
+    }
+
+    #[test]
+    fn composite_jamo_split_into_halves() {
+        let compatibility = ('ㄱ'..='ㅣ').collect::<Vec<_>>();
+        let count = |split: fn(char) -> Option<(char, char)>| {
+            compatibility
+                .iter()
+                .filter(|&&c| split(c).is_some())
+                .count()
+        };
+        assert_eq!(count(split_double_consonant), 5);
+        assert_eq!(count(split_composite_vowel), 7);
+        assert_eq!(count(split_composite_final), 11);
+        assert_eq!(split_composite_vowel('\u{116A}'), Some(('ㅗ', 'ㅏ')));
+        assert_eq!(split_composite_final('a'), None);
+    }
+
+    #[test]
+    fn stroke_paths_match_stroke_sequences() {
+        for c in 'ㄱ'..='ㅣ' {
+            let paths = strokes(c);
+            let kinds: Vec<StrokeKind> = paths.iter().map(|s| s.kind).collect();
+            assert_eq!(Some(kinds), stroke_sequence(c), "{c}");
+            for stroke in paths {
+                assert!(stroke.points.len() >= 2, "{c}");
+                assert!(
+                    stroke
+                        .points
+                        .iter()
+                        .all(|&(x, y)| (0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y)),
+                    "{c}"
+                );
+            }
+        }
+        // Circles are closed
+        let ieung = &strokes('ㅇ')[0].points;
+        assert_eq!(ieung.first(), Some(&(0.5, 0.15)));
+        assert!((ieung[0].0 - ieung[16].0).abs() < 1e-6);
+    }
 }
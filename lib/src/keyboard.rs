@@ -0,0 +1,1866 @@
+use std::time::Duration;
+
+use crate::analysis::detect_fillers;
+use crate::block::HangulBlock;
+use crate::jamo::{Character, Jamo};
+use crate::string::{CostModel, StringError, compose};
+use crate::typing::keyed_jamo;
+use crate::word::{HangulWordComposer, WordError, WordPushResult};
+
+// The standard Dubeolsik (두벌식) layout, as rows of (QWERTY key, jamo)
+// pairs from top to bottom.
+const DUBEOLSIK_ROWS: [&[(char, char)]; 3] = [
+    &[
+        ('q', 'ㅂ'),
+        ('w', 'ㅈ'),
+        ('e', 'ㄷ'),
+        ('r', 'ㄱ'),
+        ('t', 'ㅅ'),
+        ('y', 'ㅛ'),
+        ('u', 'ㅕ'),
+        ('i', 'ㅑ'),
+        ('o', 'ㅐ'),
+        ('p', 'ㅔ'),
+    ],
+    &[
+        ('a', 'ㅁ'),
+        ('s', 'ㄴ'),
+        ('d', 'ㅇ'),
+        ('f', 'ㄹ'),
+        ('g', 'ㅎ'),
+        ('h', 'ㅗ'),
+        ('j', 'ㅓ'),
+        ('k', 'ㅏ'),
+        ('l', 'ㅣ'),
+    ],
+    &[
+        ('z', 'ㅋ'),
+        ('x', 'ㅌ'),
+        ('c', 'ㅊ'),
+        ('v', 'ㅍ'),
+        ('b', 'ㅠ'),
+        ('n', 'ㅜ'),
+        ('m', 'ㅡ'),
+    ],
+];
+
+// Jamo typed with Shift on a Dubeolsik keyboard, paired with the jamo on
+// the same key without Shift.
+const DUBEOLSIK_SHIFTED: [(char, char); 7] = [
+    ('ㅃ', 'ㅂ'),
+    ('ㅉ', 'ㅈ'),
+    ('ㄸ', 'ㄷ'),
+    ('ㄲ', 'ㄱ'),
+    ('ㅆ', 'ㅅ'),
+    ('ㅒ', 'ㅐ'),
+    ('ㅖ', 'ㅔ'),
+];
+
+// Horizontal offset of each row, in key widths, on a staggered keyboard.
+const ROW_OFFSETS: [f32; 3] = [0.0, 0.25, 0.75];
+
+/// A `CostModel` for typo correction on a Dubeolsik (두벌식) keyboard.
+/// Substituting a jamo for one on a neighbouring key (e.g. ㅐ and ㅔ) or on
+/// the same key with Shift toggled (e.g. ㅐ and ㅒ) is cheaper than an
+/// arbitrary substitution. Insertions and deletions cost `1.0`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::DubeolsikCost;
+/// use hangul_cd::string::CostModel;
+///
+/// let costs = DubeolsikCost::default();
+/// assert_eq!(costs.substitution_cost('ㅐ', 'ㅔ'), costs.adjacent_key_cost);
+/// assert_eq!(costs.substitution_cost('ㅐ', 'ㅒ'), costs.same_key_cost);
+/// assert_eq!(costs.substitution_cost('ㅂ', 'ㅡ'), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DubeolsikCost {
+    /// The cost of substituting jamo on neighbouring keys.
+    pub adjacent_key_cost: f32,
+
+    /// The cost of substituting jamo that share a key and differ only by
+    /// Shift.
+    pub same_key_cost: f32,
+}
+
+impl Default for DubeolsikCost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DubeolsikCost {
+    /// Creates a `DubeolsikCost` with an adjacent-key cost of `0.5` and a
+    /// same-key cost of `0.25`.
+    pub fn new() -> Self {
+        Self {
+            adjacent_key_cost: 0.5,
+            same_key_cost: 0.25,
+        }
+    }
+}
+
+impl CostModel for DubeolsikCost {
+    fn substitution_cost(&self, from: char, to: char) -> f32 {
+        let (Some(a), Some(b)) = (dubeolsik_key_position(from), dubeolsik_key_position(to)) else {
+            return 1.0;
+        };
+        let (dx, dy) = (a.0 - b.0, a.1 - b.1);
+        if dx == 0.0 && dy == 0.0 {
+            self.same_key_cost
+        } else if dx.abs() <= 1.0 && dy.abs() <= 1.0 {
+            self.adjacent_key_cost
+        } else {
+            1.0
+        }
+    }
+}
+
+/// A Korean keyboard layout, used to estimate typing effort with
+/// `keystrokes`.
+pub trait KeyboardLayout {
+    /// Returns the number of keystrokes needed to type the compatibility
+    /// jamo `jamo` on this layout, including modifier keys such as Shift, or
+    /// `None` if the jamo cannot be typed.
+    fn jamo_keystrokes(&self, jamo: char) -> Option<usize>;
+
+    /// Returns whether typing a consonant twice in a row doubles it on this
+    /// layout, as on mobile keypads with no Shift key, for use with
+    /// `ComposerOptions::with_double_tap`. Defaults to `false`.
+    fn double_tap(&self) -> bool {
+        false
+    }
+}
+
+/// The standard Dubeolsik (두벌식) layout. Each jamo on the keyboard takes
+/// one keystroke, double consonants and ㅒ/ㅖ take two (Shift and the key),
+/// and composite vowels and final clusters are typed as their components.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Dubeolsik;
+
+impl KeyboardLayout for Dubeolsik {
+    fn jamo_keystrokes(&self, jamo: char) -> Option<usize> {
+        if DUBEOLSIK_SHIFTED
+            .iter()
+            .any(|(shifted, _)| *shifted == jamo)
+        {
+            return Some(2);
+        }
+        if DUBEOLSIK_ROWS
+            .iter()
+            .any(|keys| keys.iter().any(|(_, j)| *j == jamo))
+        {
+            return Some(1);
+        }
+        let (first, second) = match Jamo::from_compatibility_jamo(jamo).ok()? {
+            Jamo::CompositeConsonant(c) => c.decompose(),
+            Jamo::CompositeVowel(v) => v.decompose(),
+            _ => return None,
+        };
+        Some(
+            self.jamo_keystrokes(first.char_compatibility())?
+                + self.jamo_keystrokes(second.char_compatibility())?,
+        )
+    }
+}
+
+/// Counts the keystrokes needed to type `text` on `layout`. Hangul
+/// syllables are typed jamo by jamo, and every other character, including
+/// any jamo the layout cannot type, is counted as a single keystroke.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::{keystrokes, Dubeolsik};
+///
+/// assert_eq!(keystrokes("한글", &Dubeolsik), 6);
+/// assert_eq!(keystrokes("까", &Dubeolsik), 3); // Shift + ㄱ, ㅏ
+/// assert_eq!(keystrokes("과 1", &Dubeolsik), 5);
+/// ```
+pub fn keystrokes<L: KeyboardLayout + ?Sized>(text: &str, layout: &L) -> usize {
+    let count = |jamo: &Jamo| {
+        layout
+            .jamo_keystrokes(jamo.char_compatibility())
+            .unwrap_or(1)
+    };
+    text.chars()
+        .map(|c| {
+            if let Ok(block) = HangulBlock::from_char(c) {
+                count(block.initial.jamo())
+                    + count(block.vowel.jamo())
+                    + block.final_jamo().map_or(0, count)
+            } else if let Ok(Character::Hangul(jamo)) = Character::from_char(c) {
+                count(&jamo)
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+/// Converts text typed on a QWERTY layout while a Dubeolsik Hangul layout
+/// was intended into Hangul (영타 → 한글). Letters are mapped to the jamo on
+/// the same key, with uppercase letters typing the shifted jamo where the
+/// key has one, and the jamo are composed as an IME would. All other
+/// characters are kept.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::qwerty_to_hangul;
+///
+/// assert_eq!(qwerty_to_hangul("dkssudgktpdy").unwrap(), "안녕하세요");
+/// assert_eq!(qwerty_to_hangul("Rhcdl, dlTek!").unwrap(), "꽃이, 있다!");
+/// ```
+pub fn qwerty_to_hangul(text: &str) -> Result<String, StringError> {
+    let jamo: String = text
+        .chars()
+        .map(|c| qwerty_key_jamo(c).unwrap_or(c))
+        .collect();
+    Ok(compose(&jamo))
+}
+
+/// Converts Hangul typed while a QWERTY layout was intended back into the
+/// keys pressed on a Dubeolsik keyboard (한타 → 영타). Shifted jamo become
+/// uppercase letters, and all non-Hangul characters are kept.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::hangul_to_qwerty;
+///
+/// assert_eq!(hangul_to_qwerty("안녕"), "dkssud");
+/// assert_eq!(hangul_to_qwerty("ㅗ디ㅣㅐ 꽃"), "hello Rhc");
+/// ```
+pub fn hangul_to_qwerty(text: &str) -> String {
+    keyed_jamo(text)
+        .into_iter()
+        .map(|c| jamo_qwerty_key(c).unwrap_or(c))
+        .collect()
+}
+
+/// Fixes text typed with the wrong keyboard layout, a common feature of
+/// Korean text editors (한/영 자동 교정). The words made of Latin letters
+/// are judged together with `detect_layout_error`, as are the words made of
+/// Hangul, and the words of each kind are converted with
+/// `LayoutError::correct` only if they are reported as mistyped as a whole.
+/// Correctly typed English and Korean, words mixing Latin letters and
+/// Hangul, and other words are kept, as is whitespace.
+///
+/// Judging the words of each kind together keeps English words that happen
+/// to compose on a Dubeolsik layout, such as world (재깅), when the text
+/// around them is plainly English. Text of fewer than four keys, such as rk
+/// for 가, is too ambiguous and is kept. Use `hangul_to_qwerty` or
+/// `qwerty_to_hangul` to convert text regardless.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::fix_mistyped;
+///
+/// assert_eq!(fix_mistyped("dkssudgktpdy").unwrap(), "안녕하세요");
+/// assert_eq!(fix_mistyped("안녕").unwrap(), "안녕");
+/// assert_eq!(fix_mistyped("hello world").unwrap(), "hello world");
+/// assert_eq!(fix_mistyped("rkskek 123 ㅗ디ㅣㅐ").unwrap(), "가나다 123 hello");
+/// ```
+pub fn fix_mistyped(text: &str) -> Result<String, StringError> {
+    let is_latin_word =
+        |word: &str| word.chars().any(|c| c.is_ascii_alphabetic()) && !word.chars().any(is_hangul);
+    let is_hangul_word =
+        |word: &str| word.chars().any(is_hangul) && !word.chars().any(|c| c.is_ascii_alphabetic());
+    let words_where = |keep: &dyn Fn(&str) -> bool| {
+        text.split_whitespace()
+            .filter(|word| keep(word))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let latin_error = detect_layout_error(&words_where(&is_latin_word));
+    let hangul_error = detect_layout_error(&words_where(&is_hangul_word));
+
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut word_start = 0;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        if !c.is_whitespace() {
+            continue;
+        }
+        let word = &text[word_start..i];
+        let error = if is_latin_word(word) {
+            latin_error
+        } else if is_hangul_word(word) {
+            hangul_error
+        } else {
+            None
+        };
+        match error {
+            Some(error) => result.push_str(&error.correct(word)?),
+            None => result.push_str(word),
+        }
+        if i < text.len() {
+            result.push(c);
+        }
+        word_start = i + c.len_utf8();
+    }
+    Ok(result)
+}
+
+/// A keyboard layout mistake found by `detect_layout_error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutError {
+    /// Korean typed while a QWERTY layout was active, such as dkssud for
+    /// 안녕.
+    HangulTypedAsQwerty,
+
+    /// English typed while a Dubeolsik Hangul layout was active, such as
+    /// ㅗ디ㅣㅐ for hello.
+    QwertyTypedAsHangul,
+}
+
+impl LayoutError {
+    /// Converts `text` into what was meant to be typed, using
+    /// `qwerty_to_hangul` or `hangul_to_qwerty`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::keyboard::LayoutError;
+    ///
+    /// let error = LayoutError::HangulTypedAsQwerty;
+    /// assert_eq!(error.correct("dkssud").unwrap(), "안녕");
+    /// ```
+    pub fn correct(&self, text: &str) -> Result<String, StringError> {
+        match self {
+            LayoutError::HangulTypedAsQwerty => qwerty_to_hangul(text),
+            LayoutError::QwertyTypedAsHangul => Ok(hangul_to_qwerty(text)),
+        }
+    }
+}
+
+/// Guesses whether `text` was typed with the wrong keyboard layout active,
+/// for deciding when to offer the conversion made by `fix_mistyped`.
+///
+/// Text typed on a Dubeolsik layout is composed by the input method, so
+/// jamo are left loose only where the keys pressed do not form syllables.
+/// Latin text is therefore reported as `HangulTypedAsQwerty` if nearly all
+/// of its keys compose into syllables on a Dubeolsik layout and it has no
+/// capital letters other than the shifted keys Q, W, E, R, T, O, and P.
+/// Hangul text is reported as `QwertyTypedAsHangul` if at least a quarter
+/// of its keys are loose jamo, not counting fillers such as ㅋㅋ (see
+/// `analysis::detect_fillers`), and every word it types on a QWERTY layout
+/// has a vowel. Text mixing Hangul and Latin letters, and text of fewer
+/// than four keys, is too ambiguous and never reported.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::{LayoutError, detect_layout_error};
+///
+/// assert_eq!(
+///     detect_layout_error("dkssudgktpdy"),
+///     Some(LayoutError::HangulTypedAsQwerty)
+/// );
+/// assert_eq!(
+///     detect_layout_error("ㅗ디ㅣㅐ 재깅"),
+///     Some(LayoutError::QwertyTypedAsHangul)
+/// );
+/// assert_eq!(detect_layout_error("hello world"), None);
+/// assert_eq!(detect_layout_error("안녕하세요 ㅋㅋㅋ"), None);
+/// ```
+pub fn detect_layout_error(text: &str) -> Option<LayoutError> {
+    let has_latin = text.chars().any(|c| c.is_ascii_alphabetic());
+    let has_hangul = text.chars().any(is_hangul);
+    if has_latin == has_hangul {
+        return None;
+    }
+
+    if has_latin {
+        if text
+            .chars()
+            .any(|c| c.is_ascii_uppercase() && !"QWERTOP".contains(c))
+        {
+            return None;
+        }
+        // Infallible: the composer is lenient, so composing never fails
+        let (composed, total) = jamo_composition(&qwerty_to_hangul(text).unwrap());
+        (total >= 4 && composed * 10 >= total * 9).then_some(LayoutError::HangulTypedAsQwerty)
+    } else {
+        let mut without_fillers = String::with_capacity(text.len());
+        let mut last = 0;
+        for filler in detect_fillers(text) {
+            without_fillers.push_str(&text[last..filler.offset]);
+            without_fillers.push(' ');
+            last = filler.offset + filler.len;
+        }
+        without_fillers.push_str(&text[last..]);
+
+        let (composed, total) = jamo_composition(&without_fillers);
+        let keys = hangul_to_qwerty(&without_fillers);
+        let every_word_has_vowel = keys
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|word| !word.is_empty())
+            .all(|word| word.chars().any(|c| "aeiouyAEIOUY".contains(c)));
+        (total >= 4 && (total - composed) * 4 >= total && every_word_has_vowel)
+            .then_some(LayoutError::QwertyTypedAsHangul)
+    }
+}
+
+/// What a tap on a `Keypad` did to the text being composed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapResult {
+    /// The tap typed a new jamo.
+    Typed(char),
+
+    /// The tap replaced the jamo typed last with another, as when tapping
+    /// a key again cycles to its next jamo or a stroke changes a vowel.
+    Replaced(char),
+
+    /// The tap added a stroke that does not form a jamo yet, such as ㆍ on
+    /// a 천지인 keypad.
+    Stroke(char),
+
+    /// The tap changed nothing, as when a modifier key does not apply to
+    /// the jamo typed last.
+    Ignored,
+}
+
+/// A keypad whose keys are tapped to compose Hangul, such as a phone's
+/// 10-key keypad, with the timing of multi-tap left to the caller.
+///
+/// When a tap leaves a cycle pending, tapping the same key again replaces
+/// the jamo it typed with the next one on the key. A GUI host typically
+/// starts a timer whenever `is_pending` returns `true` after a tap, and
+/// calls `commit_pending` when it fires, so that the next tap types a new
+/// jamo. The composers' `press` methods do the same with times given by
+/// the caller.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::{Keypad, SkyComposer, SkyKey, TapResult};
+///
+/// let mut keypad = SkyComposer::new();
+/// assert_eq!(keypad.tap(SkyKey::GiyeokKieuk), Ok(TapResult::Typed('ㄱ')));
+/// assert!(keypad.is_pending());
+/// assert_eq!(keypad.tap(SkyKey::GiyeokKieuk), Ok(TapResult::Replaced('ㅋ')));
+/// keypad.tap(SkyKey::AYa).unwrap();
+/// keypad.tap(SkyKey::GiyeokKieuk).unwrap();
+///
+/// // The host's timer fired, so the next tap types ㄱ again
+/// keypad.commit_pending();
+/// assert!(!keypad.is_pending());
+/// keypad.tap(SkyKey::GiyeokKieuk).unwrap();
+/// keypad.tap(SkyKey::IEu).unwrap();
+/// assert_eq!(keypad.as_string().unwrap(), "칵기");
+/// ```
+pub trait Keypad {
+    /// The keys of the keypad.
+    type Key;
+
+    /// Taps `key`, continuing the pending cycle if `key` is the key that
+    /// started it, and returns what the tap did.
+    fn tap(&mut self, key: Self::Key) -> Result<TapResult, StringError>;
+
+    /// Returns whether a cycle is pending, so that tapping the same key
+    /// again would replace the jamo it typed.
+    fn is_pending(&self) -> bool;
+
+    /// Ends the pending cycle, if any, so that the next tap types a new
+    /// jamo. The jamo typed is kept.
+    fn commit_pending(&mut self);
+}
+
+/// A key on the 천지인 (Cheonjiin) keypad, the 10-key Korean layout used on
+/// Samsung feature phones and as a mobile keyboard. Vowels are built from
+/// the strokes ㅣ (인, person), ㆍ (천, heaven), and ㅡ (지, earth), and each
+/// consonant key types several consonants when tapped repeatedly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheonjiinKey {
+    /// The vertical stroke ㅣ.
+    I,
+
+    /// The dot ㆍ, which adds a short stroke to the vowel being typed.
+    Dot,
+
+    /// The horizontal stroke ㅡ.
+    Eu,
+
+    /// ㄱ, then ㅋ and ㄲ when tapped again.
+    GiyeokKieuk,
+
+    /// ㄴ, then ㄹ when tapped again.
+    NieunRieul,
+
+    /// ㄷ, then ㅌ and ㄸ when tapped again.
+    DigeutTieut,
+
+    /// ㅂ, then ㅍ and ㅃ when tapped again.
+    BieupPieup,
+
+    /// ㅅ, then ㅎ and ㅆ when tapped again.
+    SiotHieut,
+
+    /// ㅈ, then ㅊ and ㅉ when tapped again.
+    JieutChieut,
+
+    /// ㅇ, then ㅁ when tapped again.
+    IeungMieum,
+}
+
+impl CheonjiinKey {
+    /// Returns the key on a phone keypad's digit `digit`, as laid out on
+    /// Samsung phones: 1 ㅣ, 2 ㆍ, 3 ㅡ, 4 ㄱㅋ, 5 ㄴㄹ, 6 ㄷㅌ, 7 ㅂㅍ,
+    /// 8 ㅅㅎ, 9 ㅈㅊ, and 0 ㅇㅁ.
+    pub fn from_digit(digit: char) -> Option<Self> {
+        Some(match digit {
+            '1' => CheonjiinKey::I,
+            '2' => CheonjiinKey::Dot,
+            '3' => CheonjiinKey::Eu,
+            '4' => CheonjiinKey::GiyeokKieuk,
+            '5' => CheonjiinKey::NieunRieul,
+            '6' => CheonjiinKey::DigeutTieut,
+            '7' => CheonjiinKey::BieupPieup,
+            '8' => CheonjiinKey::SiotHieut,
+            '9' => CheonjiinKey::JieutChieut,
+            '0' => CheonjiinKey::IeungMieum,
+            _ => return None,
+        })
+    }
+
+    /// Returns the compatibility jamo the key types, in the order repeated
+    /// taps cycle through them, or the stroke of a vowel key.
+    pub fn jamo(self) -> &'static [char] {
+        match self {
+            CheonjiinKey::I => &['ㅣ'],
+            CheonjiinKey::Dot => &['ㆍ'],
+            CheonjiinKey::Eu => &['ㅡ'],
+            CheonjiinKey::GiyeokKieuk => &['ㄱ', 'ㅋ', 'ㄲ'],
+            CheonjiinKey::NieunRieul => &['ㄴ', 'ㄹ'],
+            CheonjiinKey::DigeutTieut => &['ㄷ', 'ㅌ', 'ㄸ'],
+            CheonjiinKey::BieupPieup => &['ㅂ', 'ㅍ', 'ㅃ'],
+            CheonjiinKey::SiotHieut => &['ㅅ', 'ㅎ', 'ㅆ'],
+            CheonjiinKey::JieutChieut => &['ㅈ', 'ㅊ', 'ㅉ'],
+            CheonjiinKey::IeungMieum => &['ㅇ', 'ㅁ'],
+        }
+    }
+
+    /// Returns `true` for the vowel stroke keys ㅣ, ㆍ, and ㅡ.
+    pub fn is_stroke(self) -> bool {
+        matches!(self, CheonjiinKey::I | CheonjiinKey::Dot | CheonjiinKey::Eu)
+    }
+}
+
+// The consonant keys of the 천지인 keypad.
+const CHEONJIIN_CONSONANT_KEYS: [CheonjiinKey; 7] = [
+    CheonjiinKey::GiyeokKieuk,
+    CheonjiinKey::NieunRieul,
+    CheonjiinKey::DigeutTieut,
+    CheonjiinKey::BieupPieup,
+    CheonjiinKey::SiotHieut,
+    CheonjiinKey::JieutChieut,
+    CheonjiinKey::IeungMieum,
+];
+
+// The strokes that type each vowel on the 천지인 keypad. Every sequence of
+// strokes that begins one of these is a vowel, except ㆍ and ㆍㆍ.
+const CHEONJIIN_VOWELS: [(&str, char); 21] = [
+    ("ㅣ", 'ㅣ'),
+    ("ㅡ", 'ㅡ'),
+    ("ㅣㆍ", 'ㅏ'),
+    ("ㅣㆍㆍ", 'ㅑ'),
+    ("ㆍㅣ", 'ㅓ'),
+    ("ㆍㆍㅣ", 'ㅕ'),
+    ("ㆍㅡ", 'ㅗ'),
+    ("ㆍㆍㅡ", 'ㅛ'),
+    ("ㅡㆍ", 'ㅜ'),
+    ("ㅡㆍㆍ", 'ㅠ'),
+    ("ㅣㆍㅣ", 'ㅐ'),
+    ("ㅣㆍㆍㅣ", 'ㅒ'),
+    ("ㆍㅣㅣ", 'ㅔ'),
+    ("ㆍㆍㅣㅣ", 'ㅖ'),
+    ("ㆍㅡㅣ", 'ㅚ'),
+    ("ㆍㅡㅣㆍ", 'ㅘ'),
+    ("ㆍㅡㅣㆍㅣ", 'ㅙ'),
+    ("ㅡㆍㅣ", 'ㅟ'),
+    ("ㅡㆍㆍㅣ", 'ㅝ'),
+    ("ㅡㆍㆍㅣㅣ", 'ㅞ'),
+    ("ㅡㅣ", 'ㅢ'),
+];
+
+/// The 천지인 (Cheonjiin) 10-key layout; see `CheonjiinKey`. A consonant
+/// takes as many taps as its place on its key, so ㅋ takes two and ㄲ
+/// three, and a vowel takes one tap per stroke, so ㅏ (ㅣㆍ) takes two.
+/// Final clusters are typed as their components.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Cheonjiin;
+
+impl KeyboardLayout for Cheonjiin {
+    fn jamo_keystrokes(&self, jamo: char) -> Option<usize> {
+        composite_keystrokes(jamo, &|jamo| {
+            CHEONJIIN_VOWELS
+                .iter()
+                .find(|(_, v)| *v == jamo)
+                .map(|(strokes, _)| strokes.chars().count())
+                .or_else(|| cycle_keystrokes(&CHEONJIIN_CONSONANT_KEYS, jamo))
+        })
+    }
+}
+
+/// A composer driven by the keys of a 천지인 keypad, as in a phone's input
+/// method.
+///
+/// Tapping a consonant key again within the composer's timeout, one second
+/// by default, replaces the consonant it typed with the next one on the
+/// key, so ㄱ becomes ㅋ and then ㄲ. To type the same consonant twice, as
+/// in 각기, wait for the timeout between taps. Vowel strokes are not timed:
+/// each stroke changes the vowel being typed for as long as the strokes
+/// form a vowel, so ㅣ becomes ㅏ with ㆍ and ㅐ with another ㅣ, and a
+/// stroke that cannot be added starts a new vowel. A double consonant
+/// followed by a vowel always begins the next syllable, as in 가끔.
+///
+/// Times are given as the time since any fixed point, such as the start of
+/// input, so the composer needs no clock of its own.
+///
+/// **Example:**
+/// ```rust
+/// use std::time::Duration;
+///
+/// use hangul_cd::keyboard::{CheonjiinComposer, CheonjiinKey};
+///
+/// // Presses each digit's key 200 ms after the one before
+/// fn type_digits(composer: &mut CheonjiinComposer, digits: &str, at: &mut Duration) {
+///     for digit in digits.chars() {
+///         *at += Duration::from_millis(200);
+///         composer.press(CheonjiinKey::from_digit(digit).unwrap(), *at).unwrap();
+///     }
+/// }
+///
+/// let mut composer = CheonjiinComposer::new();
+/// let mut at = Duration::ZERO;
+/// // ㅎ (ㅅ tapped twice), ㅏ (ㅣㆍ), ㄴ, then ㄱ, ㅡ, and ㄹ (ㄴ tapped twice)
+/// type_digits(&mut composer, "881254355", &mut at);
+/// assert_eq!(composer.as_string().unwrap(), "한글");
+///
+/// // Waiting between taps of the same key types the consonant again
+/// composer.push_char(' ').unwrap();
+/// type_digits(&mut composer, "4124", &mut at);
+/// at += Duration::from_secs(2);
+/// type_digits(&mut composer, "41", &mut at);
+/// assert_eq!(composer.as_string().unwrap(), "한글 각기");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CheonjiinComposer {
+    taps: MultiTap<CheonjiinKey>,
+    // The strokes of the vowel being typed, and whether they form a vowel
+    // that has been typed.
+    strokes: String,
+    vowel_typed: bool,
+}
+
+impl Default for CheonjiinComposer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CheonjiinComposer {
+    /// Creates a new, empty `CheonjiinComposer` with a timeout of one
+    /// second.
+    pub fn new() -> Self {
+        Self::with_timeout(DEFAULT_TAP_TIMEOUT)
+    }
+
+    /// Creates a new, empty `CheonjiinComposer` that cycles through the
+    /// consonants on a key when it is tapped again within `timeout`.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        CheonjiinComposer {
+            taps: MultiTap::new(timeout),
+            strokes: String::new(),
+            vowel_typed: false,
+        }
+    }
+
+    /// Returns the time within which tapping a consonant key again cycles
+    /// to its next consonant.
+    pub fn timeout(&self) -> Duration {
+        self.taps.timeout
+    }
+
+    /// Presses `key` at time `at`, measured from any fixed point, committing
+    /// the pending cycle first if the timeout has passed since the last
+    /// press, and returns what the press did.
+    pub fn press(&mut self, key: CheonjiinKey, at: Duration) -> Result<TapResult, StringError> {
+        self.taps.press_at(at);
+        self.tap(key)
+    }
+
+    /// Pushes a character, such as a space or punctuation, ending the jamo
+    /// being typed. Jamo are composed as if typed on a Dubeolsik keyboard.
+    pub fn push_char(&mut self, c: char) -> Result<(), StringError> {
+        self.end_jamo();
+        self.taps.text.push(c)
+    }
+
+    /// Removes the last stroke of a vowel that has not been formed yet, or
+    /// else the last jamo or character, like the backspace key, and returns
+    /// it as a compatibility jamo or character.
+    pub fn pop(&mut self) -> Result<Option<char>, StringError> {
+        if !self.vowel_typed
+            && let Some(stroke) = self.strokes.pop()
+        {
+            return Ok(Some(stroke));
+        }
+        self.end_jamo();
+        self.taps.text.pop()
+    }
+
+    /// Returns the composed string, followed by the strokes of a vowel that
+    /// has not been formed yet, such as ㆍ.
+    pub fn as_string(&self) -> Result<String, StringError> {
+        let mut result = self.taps.text.as_string()?;
+        if !self.vowel_typed {
+            result.push_str(&self.strokes);
+        }
+        Ok(result)
+    }
+
+    fn push_stroke(&mut self, stroke: char) -> Result<TapResult, StringError> {
+        let mut strokes = format!("{}{stroke}", self.strokes);
+        if !CHEONJIIN_VOWELS
+            .iter()
+            .any(|(s, _)| s.starts_with(&strokes))
+        {
+            strokes = stroke.to_string();
+            self.vowel_typed = false;
+        }
+        let vowel = CHEONJIIN_VOWELS.iter().find(|(s, _)| *s == strokes);
+        self.strokes = strokes;
+        match vowel {
+            Some(&(_, vowel)) if self.vowel_typed => {
+                self.taps.text.replace(vowel)?;
+                Ok(TapResult::Replaced(vowel))
+            }
+            Some(&(_, vowel)) => {
+                self.vowel_typed = true;
+                self.taps.text.type_new(vowel)
+            }
+            None => Ok(TapResult::Stroke(stroke)),
+        }
+    }
+
+    // Ends the consonant cycle or vowel being typed, dropping strokes that
+    // do not form a vowel.
+    fn end_jamo(&mut self) {
+        self.strokes.clear();
+        self.vowel_typed = false;
+        self.taps.end();
+    }
+}
+
+impl Keypad for CheonjiinComposer {
+    type Key = CheonjiinKey;
+
+    fn tap(&mut self, key: CheonjiinKey) -> Result<TapResult, StringError> {
+        if key.is_stroke() {
+            self.taps.commit_pending();
+            self.push_stroke(key.jamo()[0])
+        } else {
+            self.strokes.clear();
+            self.vowel_typed = false;
+            self.taps.tap(key, key.jamo())
+        }
+    }
+
+    fn is_pending(&self) -> bool {
+        self.taps.pending.is_some()
+    }
+
+    fn commit_pending(&mut self) {
+        self.taps.commit_pending();
+    }
+}
+
+/// A key on the 나랏글 (Naratgeul) keypad of LG phones. Each key types one
+/// basic jamo, and two modifier keys change the jamo typed last: 획추가
+/// (add stroke) turns ㄱ into ㅋ, ㄴ into ㄷ and then ㅌ, or ㅏ into ㅑ, and
+/// 쌍자음 (add tense) turns ㄱ into ㄲ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NaratgeulKey {
+    /// ㄱ.
+    Giyeok,
+
+    /// ㄴ.
+    Nieun,
+
+    /// ㅏ, then ㅓ when tapped again.
+    AEo,
+
+    /// ㄹ.
+    Rieul,
+
+    /// ㅁ.
+    Mieum,
+
+    /// ㅗ, then ㅜ when tapped again.
+    OU,
+
+    /// ㅅ.
+    Siot,
+
+    /// ㅇ.
+    Ieung,
+
+    /// ㅣ.
+    I,
+
+    /// 획추가, which adds a stroke to the jamo typed last.
+    AddStroke,
+
+    /// ㅡ.
+    Eu,
+
+    /// 쌍자음, which makes the consonant typed last tense.
+    AddTense,
+}
+
+impl NaratgeulKey {
+    /// Returns the key on a phone keypad's key `digit`, as laid out on LG
+    /// phones: 1 ㄱ, 2 ㄴ, 3 ㅏㅓ, 4 ㄹ, 5 ㅁ, 6 ㅗㅜ, 7 ㅅ, 8 ㅇ, 9 ㅣ,
+    /// `*` 획추가, 0 ㅡ, and `#` 쌍자음.
+    pub fn from_digit(digit: char) -> Option<Self> {
+        Some(match digit {
+            '1' => NaratgeulKey::Giyeok,
+            '2' => NaratgeulKey::Nieun,
+            '3' => NaratgeulKey::AEo,
+            '4' => NaratgeulKey::Rieul,
+            '5' => NaratgeulKey::Mieum,
+            '6' => NaratgeulKey::OU,
+            '7' => NaratgeulKey::Siot,
+            '8' => NaratgeulKey::Ieung,
+            '9' => NaratgeulKey::I,
+            '*' => NaratgeulKey::AddStroke,
+            '0' => NaratgeulKey::Eu,
+            '#' => NaratgeulKey::AddTense,
+            _ => return None,
+        })
+    }
+
+    /// Returns the compatibility jamo the key types, in the order repeated
+    /// taps cycle through them, or nothing for the modifier keys.
+    pub fn jamo(self) -> &'static [char] {
+        match self {
+            NaratgeulKey::Giyeok => &['ㄱ'],
+            NaratgeulKey::Nieun => &['ㄴ'],
+            NaratgeulKey::AEo => &['ㅏ', 'ㅓ'],
+            NaratgeulKey::Rieul => &['ㄹ'],
+            NaratgeulKey::Mieum => &['ㅁ'],
+            NaratgeulKey::OU => &['ㅗ', 'ㅜ'],
+            NaratgeulKey::Siot => &['ㅅ'],
+            NaratgeulKey::Ieung => &['ㅇ'],
+            NaratgeulKey::I => &['ㅣ'],
+            NaratgeulKey::Eu => &['ㅡ'],
+            NaratgeulKey::AddStroke | NaratgeulKey::AddTense => &[],
+        }
+    }
+
+    /// Returns `true` for the modifier keys 획추가 and 쌍자음.
+    pub fn is_modifier(self) -> bool {
+        matches!(self, NaratgeulKey::AddStroke | NaratgeulKey::AddTense)
+    }
+}
+
+// The jamo 획추가 turns each jamo into on the 나랏글 keypad.
+const NARATGEUL_ADD_STROKE: [(char, char); 17] = [
+    ('ㄱ', 'ㅋ'),
+    ('ㅋ', 'ㄱ'),
+    ('ㄴ', 'ㄷ'),
+    ('ㄷ', 'ㅌ'),
+    ('ㅌ', 'ㄴ'),
+    ('ㅁ', 'ㅂ'),
+    ('ㅂ', 'ㅍ'),
+    ('ㅍ', 'ㅁ'),
+    ('ㅅ', 'ㅈ'),
+    ('ㅈ', 'ㅊ'),
+    ('ㅊ', 'ㅅ'),
+    ('ㅇ', 'ㅎ'),
+    ('ㅎ', 'ㅇ'),
+    ('ㅏ', 'ㅑ'),
+    ('ㅓ', 'ㅕ'),
+    ('ㅗ', 'ㅛ'),
+    ('ㅜ', 'ㅠ'),
+];
+
+// The consonants 쌍자음 makes tense on the 나랏글 keypad.
+const NARATGEUL_ADD_TENSE: [(char, char); 5] = [
+    ('ㄱ', 'ㄲ'),
+    ('ㄷ', 'ㄸ'),
+    ('ㅂ', 'ㅃ'),
+    ('ㅅ', 'ㅆ'),
+    ('ㅈ', 'ㅉ'),
+];
+
+/// The 나랏글 (Naratgeul) 10-key layout; see `NaratgeulKey`. A jamo takes
+/// one keystroke for its key, plus one for each tap of a modifier key or
+/// repeated tap needed to reach it, so ㅌ (ㄴ and 획추가 twice) takes three.
+/// ㅐ and ㅔ are typed as ㅏ or ㅓ followed by ㅣ, and composite vowels and
+/// final clusters as their components.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Naratgeul;
+
+impl KeyboardLayout for Naratgeul {
+    fn jamo_keystrokes(&self, jamo: char) -> Option<usize> {
+        composite_keystrokes(jamo, &naratgeul_keystrokes)
+    }
+}
+
+/// A composer driven by the keys of a 나랏글 keypad; see `NaratgeulKey`.
+///
+/// Keys are not timed. Tapping ㅏㅓ or ㅗㅜ again switches the vowel it
+/// typed, and the modifier keys change the jamo typed last, doing nothing
+/// if it cannot be changed or the result would not compose. As on other
+/// keypads, a double consonant followed by a vowel always begins the next
+/// syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::{NaratgeulComposer, NaratgeulKey};
+///
+/// let mut composer = NaratgeulComposer::new();
+/// // ㅎ (ㅇ and 획추가), ㅏ, ㄴ, then ㄱ, ㅡ, and ㄹ
+/// for digit in "8*32104".chars() {
+///     composer.press(NaratgeulKey::from_digit(digit).unwrap()).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "한글");
+///
+/// // ㄱ, ㅏ, ㄲ (ㄱ and 쌍자음), and ㅡ
+/// composer.push_char(' ').unwrap();
+/// for digit in "131#0".chars() {
+///     composer.press(NaratgeulKey::from_digit(digit).unwrap()).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "한글 가끄");
+/// ```
+#[derive(Debug, Clone)]
+pub struct NaratgeulComposer {
+    taps: MultiTap<NaratgeulKey>,
+}
+
+impl Default for NaratgeulComposer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NaratgeulComposer {
+    /// Creates a new, empty `NaratgeulComposer`.
+    pub fn new() -> Self {
+        NaratgeulComposer {
+            taps: MultiTap::new(Duration::MAX),
+        }
+    }
+
+    /// Presses `key` and returns what the press did. Since 나랏글 keys are
+    /// not timed, this is the same as `Keypad::tap`.
+    pub fn press(&mut self, key: NaratgeulKey) -> Result<TapResult, StringError> {
+        self.tap(key)
+    }
+
+    /// Pushes a character, such as a space or punctuation, ending the jamo
+    /// being typed. Jamo are composed as if typed on a Dubeolsik keyboard.
+    pub fn push_char(&mut self, c: char) -> Result<(), StringError> {
+        self.taps.end();
+        self.taps.text.push(c)
+    }
+
+    /// Removes the last jamo or character, like the backspace key, and
+    /// returns it as a compatibility jamo or character.
+    pub fn pop(&mut self) -> Result<Option<char>, StringError> {
+        self.taps.end();
+        self.taps.text.pop()
+    }
+
+    /// Returns the composed string.
+    pub fn as_string(&self) -> Result<String, StringError> {
+        self.taps.text.as_string()
+    }
+}
+
+/// 나랏글 keys are not timed, so a `NaratgeulComposer` never has a pending
+/// cycle to commit: tapping ㅏㅓ or ㅗㅜ again always switches the vowel it
+/// typed.
+impl Keypad for NaratgeulComposer {
+    type Key = NaratgeulKey;
+
+    fn tap(&mut self, key: NaratgeulKey) -> Result<TapResult, StringError> {
+        let table: &[(char, char)] = match key {
+            NaratgeulKey::AddStroke => &NARATGEUL_ADD_STROKE,
+            NaratgeulKey::AddTense => &NARATGEUL_ADD_TENSE,
+            _ => {
+                if key.jamo().len() == 1 {
+                    self.taps.commit_pending();
+                }
+                return self.taps.tap(key, key.jamo());
+            }
+        };
+        self.taps.commit_pending();
+        let modified = self
+            .taps
+            .text
+            .last()
+            .and_then(|last| table.iter().find(|(from, _)| *from == last));
+        match modified {
+            Some(&(_, to)) if self.taps.text.replace_within_word(to)? => {
+                Ok(TapResult::Replaced(to))
+            }
+            _ => Ok(TapResult::Ignored),
+        }
+    }
+
+    fn is_pending(&self) -> bool {
+        false
+    }
+
+    fn commit_pending(&mut self) {}
+}
+
+/// A key on the SKY keypad of Pantech phones. Every key types a consonant
+/// or a vowel, and tapping it again cycles through the jamo on it, ending
+/// with its double consonant if it has one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkyKey {
+    /// ㄱ, then ㅋ and ㄲ when tapped again.
+    GiyeokKieuk,
+
+    /// ㅣ, then ㅡ and ㅢ when tapped again.
+    IEu,
+
+    /// ㅏ, then ㅑ when tapped again.
+    AYa,
+
+    /// ㄷ, then ㅌ and ㄸ when tapped again.
+    DigeutTieut,
+
+    /// ㄴ, then ㄹ when tapped again.
+    NieunRieul,
+
+    /// ㅓ, then ㅕ when tapped again.
+    EoYeo,
+
+    /// ㅂ, then ㅍ and ㅃ when tapped again.
+    BieupPieup,
+
+    /// ㅅ, then ㅎ and ㅆ when tapped again.
+    SiotHieut,
+
+    /// ㅗ, then ㅛ when tapped again.
+    OYo,
+
+    /// ㅈ, then ㅊ and ㅉ when tapped again.
+    JieutChieut,
+
+    /// ㅇ, then ㅁ when tapped again.
+    IeungMieum,
+
+    /// ㅜ, then ㅠ when tapped again.
+    UYu,
+}
+
+// The keys of the SKY keypad.
+const SKY_KEYS: [SkyKey; 12] = [
+    SkyKey::GiyeokKieuk,
+    SkyKey::IEu,
+    SkyKey::AYa,
+    SkyKey::DigeutTieut,
+    SkyKey::NieunRieul,
+    SkyKey::EoYeo,
+    SkyKey::BieupPieup,
+    SkyKey::SiotHieut,
+    SkyKey::OYo,
+    SkyKey::JieutChieut,
+    SkyKey::IeungMieum,
+    SkyKey::UYu,
+];
+
+impl SkyKey {
+    /// Returns the key on a phone keypad's key `digit`, as laid out on
+    /// Pantech phones: 1 ㄱㅋ, 2 ㅣㅡ, 3 ㅏㅑ, 4 ㄷㅌ, 5 ㄴㄹ, 6 ㅓㅕ,
+    /// 7 ㅂㅍ, 8 ㅅㅎ, 9 ㅗㅛ, `*` ㅈㅊ, 0 ㅇㅁ, and `#` ㅜㅠ.
+    pub fn from_digit(digit: char) -> Option<Self> {
+        let index = "123456789*0#".chars().position(|d| d == digit)?;
+        Some(SKY_KEYS[index])
+    }
+
+    /// Returns the compatibility jamo the key types, in the order repeated
+    /// taps cycle through them.
+    pub fn jamo(self) -> &'static [char] {
+        match self {
+            SkyKey::GiyeokKieuk => &['ㄱ', 'ㅋ', 'ㄲ'],
+            SkyKey::IEu => &['ㅣ', 'ㅡ', 'ㅢ'],
+            SkyKey::AYa => &['ㅏ', 'ㅑ'],
+            SkyKey::DigeutTieut => &['ㄷ', 'ㅌ', 'ㄸ'],
+            SkyKey::NieunRieul => &['ㄴ', 'ㄹ'],
+            SkyKey::EoYeo => &['ㅓ', 'ㅕ'],
+            SkyKey::BieupPieup => &['ㅂ', 'ㅍ', 'ㅃ'],
+            SkyKey::SiotHieut => &['ㅅ', 'ㅎ', 'ㅆ'],
+            SkyKey::OYo => &['ㅗ', 'ㅛ'],
+            SkyKey::JieutChieut => &['ㅈ', 'ㅊ', 'ㅉ'],
+            SkyKey::IeungMieum => &['ㅇ', 'ㅁ'],
+            SkyKey::UYu => &['ㅜ', 'ㅠ'],
+        }
+    }
+}
+
+/// The SKY 10-key layout; see `SkyKey`. A jamo takes as many taps as its
+/// place on its key, so ㅋ takes two and ㄲ three. ㅐ and ㅔ are typed as ㅏ
+/// or ㅓ followed by ㅣ, and composite vowels and final clusters as their
+/// components.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sky;
+
+impl KeyboardLayout for Sky {
+    fn jamo_keystrokes(&self, jamo: char) -> Option<usize> {
+        composite_keystrokes(jamo, &|jamo| {
+            cycle_keystrokes(&SKY_KEYS, jamo).or(match jamo {
+                'ㅐ' | 'ㅔ' => Some(2),
+                'ㅒ' | 'ㅖ' => Some(3),
+                _ => None,
+            })
+        })
+    }
+}
+
+/// A composer driven by the keys of a SKY keypad; see `SkyKey`.
+///
+/// Tapping a key again within the composer's timeout, one second by
+/// default, replaces the jamo it typed with the next one on the key, so ㄱ
+/// becomes ㅋ and then ㄲ. To type the same jamo twice, as in 각기, wait for
+/// the timeout between taps. As on other keypads, a double consonant
+/// followed by a vowel always begins the next syllable.
+///
+/// **Example:**
+/// ```rust
+/// use std::time::Duration;
+///
+/// use hangul_cd::keyboard::{SkyComposer, SkyKey};
+///
+/// let mut composer = SkyComposer::new();
+/// let mut at = Duration::ZERO;
+/// // ㅎ (ㅅ tapped twice), ㅏ, ㄴ, then ㄱ, ㅡ (ㅣ tapped twice), and ㄹ
+/// for digit in "883512255".chars() {
+///     at += Duration::from_millis(200);
+///     composer.press(SkyKey::from_digit(digit).unwrap(), at).unwrap();
+/// }
+/// assert_eq!(composer.as_string().unwrap(), "한글");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkyComposer {
+    taps: MultiTap<SkyKey>,
+}
+
+impl Default for SkyComposer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SkyComposer {
+    /// Creates a new, empty `SkyComposer` with a timeout of one second.
+    pub fn new() -> Self {
+        Self::with_timeout(DEFAULT_TAP_TIMEOUT)
+    }
+
+    /// Creates a new, empty `SkyComposer` that cycles through the jamo on a
+    /// key when it is tapped again within `timeout`.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        SkyComposer {
+            taps: MultiTap::new(timeout),
+        }
+    }
+
+    /// Returns the time within which tapping a key again cycles to its next
+    /// jamo.
+    pub fn timeout(&self) -> Duration {
+        self.taps.timeout
+    }
+
+    /// Presses `key` at time `at`, measured from any fixed point, committing
+    /// the pending cycle first if the timeout has passed since the last
+    /// press, and returns what the press did.
+    pub fn press(&mut self, key: SkyKey, at: Duration) -> Result<TapResult, StringError> {
+        self.taps.press_at(at);
+        self.tap(key)
+    }
+
+    /// Pushes a character, such as a space or punctuation, ending the jamo
+    /// being typed. Jamo are composed as if typed on a Dubeolsik keyboard.
+    pub fn push_char(&mut self, c: char) -> Result<(), StringError> {
+        self.taps.end();
+        self.taps.text.push(c)
+    }
+
+    /// Removes the last jamo or character, like the backspace key, and
+    /// returns it as a compatibility jamo or character.
+    pub fn pop(&mut self) -> Result<Option<char>, StringError> {
+        self.taps.end();
+        self.taps.text.pop()
+    }
+
+    /// Returns the composed string.
+    pub fn as_string(&self) -> Result<String, StringError> {
+        self.taps.text.as_string()
+    }
+}
+
+impl Keypad for SkyComposer {
+    type Key = SkyKey;
+
+    fn tap(&mut self, key: SkyKey) -> Result<TapResult, StringError> {
+        self.taps.tap(key, key.jamo())
+    }
+
+    fn is_pending(&self) -> bool {
+        self.taps.pending.is_some()
+    }
+
+    fn commit_pending(&mut self) {
+        self.taps.commit_pending();
+    }
+}
+
+// The time within which keypad composers cycle through the jamo on a key by
+// default.
+const DEFAULT_TAP_TIMEOUT: Duration = Duration::from_secs(1);
+
+// A key of a multi-tap keypad, which types the jamo of `jamo` in turn.
+trait TapKey: Copy + PartialEq {
+    fn jamo(self) -> &'static [char];
+}
+
+impl TapKey for CheonjiinKey {
+    fn jamo(self) -> &'static [char] {
+        CheonjiinKey::jamo(self)
+    }
+}
+
+impl TapKey for SkyKey {
+    fn jamo(self) -> &'static [char] {
+        SkyKey::jamo(self)
+    }
+}
+
+// Returns the number of taps of one of `keys` that type `jamo`.
+fn cycle_keystrokes<K: TapKey>(keys: &[K], jamo: char) -> Option<usize> {
+    keys.iter()
+        .find_map(|key| key.jamo().iter().position(|&j| j == jamo))
+        .map(|position| position + 1)
+}
+
+// Returns the keystrokes for `jamo` given by `base`, or else the sum of the
+// keystrokes for its components if it is a composite vowel or cluster.
+fn composite_keystrokes(jamo: char, base: &dyn Fn(char) -> Option<usize>) -> Option<usize> {
+    if let Some(count) = base(jamo) {
+        return Some(count);
+    }
+    let (first, second) = match Jamo::from_compatibility_jamo(jamo).ok()? {
+        Jamo::CompositeConsonant(c) => c.decompose(),
+        Jamo::CompositeVowel(v) => v.decompose(),
+        _ => return None,
+    };
+    Some(
+        composite_keystrokes(first.char_compatibility(), base)?
+            + composite_keystrokes(second.char_compatibility(), base)?,
+    )
+}
+
+// The jamo that are not composed of others on the 나랏글 keypad, by the
+// number of keystrokes typing them takes, from one to four.
+const NARATGEUL_KEYSTROKES: [&str; 4] = [
+    "ㄱㄴㄹㅁㅅㅇㅏㅗㅣㅡ",
+    "ㅋㄷㅂㅈㅎㄲㅆㅓㅜㅑㅛㅐ",
+    "ㅌㅍㅊㄸㅃㅉㅕㅠㅔㅒ",
+    "ㅖ",
+];
+
+fn naratgeul_keystrokes(jamo: char) -> Option<usize> {
+    NARATGEUL_KEYSTROKES
+        .iter()
+        .position(|jamo_list| jamo_list.contains(jamo))
+        .map(|index| index + 1)
+}
+
+// Types jamo on a keypad where tapping a key again cycles through the jamo
+// on it. A cycle lasts until it is committed, or until the timeout passes
+// between presses recorded with `press_at`.
+#[derive(Debug, Clone)]
+struct MultiTap<K> {
+    text: TapText,
+    timeout: Duration,
+    // The key being tapped and how many times it has been tapped in a row.
+    pending: Option<(K, usize)>,
+    // When the last timed press was made.
+    pressed_at: Option<Duration>,
+}
+
+impl<K: Copy + PartialEq> MultiTap<K> {
+    fn new(timeout: Duration) -> Self {
+        MultiTap {
+            text: TapText::default(),
+            timeout,
+            pending: None,
+            pressed_at: None,
+        }
+    }
+
+    // Taps `key`, which types the jamo of `cycle` in turn, replacing the jamo
+    // typed by the key before it if it is being cycled.
+    fn tap(&mut self, key: K, cycle: &[char]) -> Result<TapResult, StringError> {
+        match self.pending {
+            Some((pending, taps)) if pending == key => {
+                let jamo = cycle[taps % cycle.len()];
+                self.pending = Some((key, taps + 1));
+                self.text.replace(jamo)?;
+                Ok(TapResult::Replaced(jamo))
+            }
+            _ => {
+                self.pending = Some((key, 1));
+                self.text.type_new(cycle[0])
+            }
+        }
+    }
+
+    // Records a press at time `at`, committing the cycle if the timeout has
+    // passed since the last press.
+    fn press_at(&mut self, at: Duration) {
+        let timed_out = self
+            .pressed_at
+            .is_none_or(|pressed| at.checked_sub(pressed).is_none_or(|d| d >= self.timeout));
+        if timed_out {
+            self.commit_pending();
+        }
+        self.pressed_at = Some(at);
+    }
+
+    // Ends the cycle, so that the next tap types a new jamo.
+    fn commit_pending(&mut self) {
+        self.pending = None;
+    }
+
+    // Ends the cycle and stops the last jamo typed from being changed.
+    fn end(&mut self) {
+        self.commit_pending();
+        self.text.end();
+    }
+}
+
+// Returns whether `jamo` is one of the double consonants ㄲ, ㄸ, ㅃ, ㅆ,
+// and ㅉ.
+fn is_double_consonant(jamo: char) -> bool {
+    matches!(jamo, 'ㄲ' | 'ㄸ' | 'ㅃ' | 'ㅆ' | 'ㅉ')
+}
+
+// The vowels keypads type as a vowel followed by ㅣ, which do not combine
+// from those jamo on other keyboards.
+const KEYPAD_VOWELS: [(char, char, char); 4] = [
+    ('ㅏ', 'ㅣ', 'ㅐ'),
+    ('ㅓ', 'ㅣ', 'ㅔ'),
+    ('ㅑ', 'ㅣ', 'ㅒ'),
+    ('ㅕ', 'ㅣ', 'ㅖ'),
+];
+
+// Text typed on a keypad, where a tap can replace the jamo typed by the tap
+// before it, as when cycling through the jamo on a key. A jamo that cannot
+// join the word being composed starts a new one.
+#[derive(Debug, Clone, Default)]
+struct TapText {
+    committed: String,
+    word: HangulWordComposer,
+    // The text as it was before the jamo that can be replaced was typed, as
+    // the length of `committed` and a copy of `word`.
+    before: Option<(usize, HangulWordComposer)>,
+    // The jamo typed by `type_new`, or the jamo that replaced it.
+    last: Option<char>,
+}
+
+impl TapText {
+    // Types `jamo` so that it can be replaced by a later tap. A double
+    // consonant followed by a vowel is first moved whole to a new syllable,
+    // and a vowel followed by ㅣ that forms one of `KEYPAD_VOWELS` is
+    // replaced with it.
+    fn type_new(&mut self, jamo: char) -> Result<TapResult, StringError> {
+        if let Some(last) = self.last()
+            && let Some(&(_, _, vowel)) = KEYPAD_VOWELS
+                .iter()
+                .find(|&&(first, second, _)| first == last && second == jamo)
+        {
+            self.replace(vowel)?;
+            return Ok(TapResult::Replaced(vowel));
+        }
+        if let Some(last) = self.last()
+            && is_double_consonant(last)
+            && ('ㅏ'..='ㅣ').contains(&jamo)
+        {
+            self.move_to_next_block(last)?;
+        }
+        self.before = Some((self.committed.len(), self.word.clone()));
+        self.last = Some(jamo);
+        self.push(jamo)?;
+        Ok(TapResult::Typed(jamo))
+    }
+
+    // Replaces the jamo typed by `type_new` with `jamo`.
+    fn replace(&mut self, jamo: char) -> Result<(), StringError> {
+        self.restore();
+        self.last = Some(jamo);
+        self.push(jamo)
+    }
+
+    // Replaces the jamo typed by `type_new` with `jamo` if it joins the word
+    // that jamo was typed into, and otherwise leaves the text unchanged.
+    // Returns whether the jamo was replaced.
+    fn replace_within_word(&mut self, jamo: char) -> Result<bool, StringError> {
+        let Some((len, word)) = &self.before else {
+            return Ok(false);
+        };
+        let mut word = word.clone();
+        if word.push_char(jamo)? != WordPushResult::Continue {
+            return Ok(false);
+        }
+        self.committed.truncate(*len);
+        self.word = word;
+        self.last = Some(jamo);
+        Ok(true)
+    }
+
+    // Returns the jamo typed by `type_new`, if it can still be replaced.
+    fn last(&self) -> Option<char> {
+        self.before.as_ref().and(self.last)
+    }
+
+    // Replaces the jamo typed by `type_new` with `jamo` at the start of a
+    // new syllable block, so that a double consonant that ended a syllable
+    // moves whole to the next one.
+    fn move_to_next_block(&mut self, jamo: char) -> Result<(), StringError> {
+        self.restore();
+        match self.word.complete_current_block() {
+            Ok(()) | Err(WordError::CannotCompleteCurrentBlock(_)) => {}
+            Err(e) => return Err(e.into()),
+        }
+        self.push(jamo)
+    }
+
+    // Stops the last jamo typed from being replaced.
+    fn end(&mut self) {
+        self.before = None;
+        self.last = None;
+    }
+
+    // Restores the text to how it was before the jamo typed by `type_new`.
+    fn restore(&mut self) {
+        if let Some((len, word)) = &self.before {
+            self.committed.truncate(*len);
+            self.word = word.clone();
+        }
+    }
+
+    fn push(&mut self, c: char) -> Result<(), StringError> {
+        match self.word.push_char(c)? {
+            WordPushResult::Continue => Ok(()),
+            WordPushResult::InvalidHangul => {
+                // The jamo starts a new word
+                self.word.push_to(&mut self.committed)?;
+                self.word = HangulWordComposer::new();
+                match self.word.push_char(c)? {
+                    WordPushResult::Continue => {}
+                    _ => self.committed.push(c),
+                }
+                Ok(())
+            }
+            WordPushResult::NonHangul => {
+                self.word.push_to(&mut self.committed)?;
+                self.committed.push(c);
+                self.word = HangulWordComposer::new();
+                Ok(())
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Result<Option<char>, StringError> {
+        match self.word.pop()? {
+            Some(jamo) => Ok(Some(jamo.char_compatibility())),
+            None => Ok(self.committed.pop()),
+        }
+    }
+
+    fn as_string(&self) -> Result<String, StringError> {
+        let mut result = self.committed.clone();
+        self.word.push_to(&mut result)?;
+        Ok(result)
+    }
+}
+
+// Counts the keys typing the Hangul in `text` that are part of syllables,
+// and all keys typing Hangul.
+fn jamo_composition(text: &str) -> (usize, usize) {
+    let mut composed = 0;
+    let mut total = 0;
+    for c in text.chars().filter(|&c| is_hangul(c)) {
+        let keys = keyed_jamo(&c.to_string()).len();
+        if HangulBlock::from_char(c).is_ok() {
+            composed += keys;
+        }
+        total += keys;
+    }
+    (composed, total)
+}
+
+fn is_hangul(c: char) -> bool {
+    HangulBlock::from_char(c).is_ok() || matches!(Character::from_char(c), Ok(Character::Hangul(_)))
+}
+
+// Returns the compatibility jamo typed by a QWERTY key on a Dubeolsik
+// keyboard. Uppercase letters type the shifted jamo if the key has one.
+fn qwerty_key_jamo(key: char) -> Option<char> {
+    let base = DUBEOLSIK_ROWS
+        .iter()
+        .flat_map(|keys| keys.iter())
+        .find(|(k, _)| *k == key.to_ascii_lowercase())
+        .map(|(_, jamo)| *jamo)?;
+    if key.is_ascii_uppercase()
+        && let Some((shifted, _)) = DUBEOLSIK_SHIFTED.iter().find(|(_, b)| *b == base)
+    {
+        return Some(*shifted);
+    }
+    Some(base)
+}
+
+// Returns the QWERTY key that types the compatibility jamo `jamo` on a
+// Dubeolsik keyboard, uppercase for shifted jamo.
+fn jamo_qwerty_key(jamo: char) -> Option<char> {
+    let (base, shifted) = match DUBEOLSIK_SHIFTED.iter().find(|(s, _)| *s == jamo) {
+        Some((_, base)) => (*base, true),
+        None => (jamo, false),
+    };
+    let key = DUBEOLSIK_ROWS
+        .iter()
+        .flat_map(|keys| keys.iter())
+        .find(|(_, j)| *j == base)
+        .map(|(k, _)| *k)?;
+    Some(if shifted {
+        key.to_ascii_uppercase()
+    } else {
+        key
+    })
+}
+
+// Returns the (x, y) position, in key widths, of the key used to type the
+// compatibility jamo `c` on a Dubeolsik keyboard.
+fn dubeolsik_key_position(c: char) -> Option<(f32, f32)> {
+    let base = DUBEOLSIK_SHIFTED
+        .iter()
+        .find(|(shifted, _)| *shifted == c)
+        .map_or(c, |(_, base)| *base);
+    DUBEOLSIK_ROWS.iter().enumerate().find_map(|(row, keys)| {
+        let col = keys.iter().position(|(_, jamo)| *jamo == base)?;
+        Some((col as f32 + ROW_OFFSETS[row], row as f32))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::jamo_distance_with_cost;
+
+    #[test]
+    fn neighbouring_keys_are_cheaper() {
+        let costs = DubeolsikCost::new();
+        // Same row
+        assert_eq!(costs.substitution_cost('ㄱ', 'ㅅ'), 0.5);
+        // Diagonal between rows
+        assert_eq!(costs.substitution_cost('ㄱ', 'ㄹ'), 0.5);
+        assert_eq!(costs.substitution_cost('ㅏ', 'ㅡ'), 0.5);
+        // Shift variants
+        assert_eq!(costs.substitution_cost('ㄲ', 'ㄱ'), 0.25);
+        assert_eq!(costs.substitution_cost('ㅖ', 'ㅔ'), 0.25);
+        // Distant keys and non-Hangul
+        assert_eq!(costs.substitution_cost('ㅂ', 'ㅔ'), 1.0);
+        assert_eq!(costs.substitution_cost('a', 'ㅔ'), 1.0);
+    }
+
+    #[test]
+    fn weighted_distance_prefers_typos() {
+        let costs = DubeolsikCost::new();
+        assert_eq!(jamo_distance_with_cost("새", "세", &costs), 0.5);
+        assert_eq!(jamo_distance_with_cost("얘", "애", &costs), 0.25);
+        assert_eq!(
+            jamo_distance_with_cost("까", "가", &costs),
+            costs.same_key_cost
+        );
+        assert_eq!(
+            jamo_distance_with_cost("갔", "갓", &costs),
+            costs.same_key_cost
+        );
+        assert_eq!(jamo_distance_with_cost("사랑", "사랑", &costs), 0.0);
+    }
+
+    #[test]
+    fn dubeolsik_keystrokes() {
+        assert_eq!(Dubeolsik.jamo_keystrokes('ㄱ'), Some(1));
+        assert_eq!(Dubeolsik.jamo_keystrokes('ㅆ'), Some(2));
+        assert_eq!(Dubeolsik.jamo_keystrokes('ㅒ'), Some(2));
+        assert_eq!(Dubeolsik.jamo_keystrokes('ㅙ'), Some(2));
+        assert_eq!(Dubeolsik.jamo_keystrokes('ㄳ'), Some(2));
+        assert_eq!(Dubeolsik.jamo_keystrokes('a'), None);
+
+        assert_eq!(keystrokes("", &Dubeolsik), 0);
+        assert_eq!(keystrokes("있었다", &Dubeolsik), 10);
+        assert_eq!(keystrokes("얘기", &Dubeolsik), 5);
+        assert_eq!(keystrokes("ㅋㅋ", &Dubeolsik), 2);
+        assert_eq!(keystrokes("hi!", &Dubeolsik), 3);
+    }
+
+    #[test]
+    fn fix_mistyped_keeps_correctly_typed_text() {
+        // world composes as 재깅 on its own, but is judged with hello
+        assert_eq!(fix_mistyped("hello world").unwrap(), "hello world");
+        for text in [
+            "we were told to go there over the week",
+            "this is an ordinary english sentence, typed correctly",
+        ] {
+            assert_eq!(fix_mistyped(text).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn fix_mistyped_keeps_correctly_typed_korean() {
+        for text in [
+            "안녕하세요 반갑습니다",
+            "오늘 날씨가 정말 좋네요 ㅋㅋㅋ",
+            "회의는 meeting room 3에서 해요",
+            "값싼 닭고기, 12,000원!",
+        ] {
+            assert_eq!(fix_mistyped(text).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn mistyped_layout_roundtrip() {
+        for text in ["안녕하세요", "값싼 닭고기", "얘기 좀 해", "뭐해?"] {
+            let keys = hangul_to_qwerty(text);
+            assert_eq!(qwerty_to_hangul(&keys).unwrap(), text, "via {}", keys);
+        }
+        assert_eq!(hangul_to_qwerty("ㅃㅉㄸㄲㅆㅒㅖ"), "QWERTOP");
+        assert_eq!(qwerty_to_hangul("").unwrap(), "");
+        assert_eq!(fix_mistyped("  ").unwrap(), "  ");
+        assert_eq!(fix_mistyped("dkssud\nㅗ디ㅣㅐ").unwrap(), "안녕\nhello");
+        assert_eq!(
+            fix_mistyped("dkssud rkskek, ㅗ디ㅣㅐ ㅈㅐ깅").unwrap(),
+            "안녕 가나다, hello world"
+        );
+        // Words of fewer than four keys are too short to judge
+        assert_eq!(fix_mistyped("rk ㅗㅑ").unwrap(), "rk ㅗㅑ");
+        assert_eq!(qwerty_to_hangul("rk").unwrap(), "가");
+    }
+
+    #[test]
+    fn layout_error_detection() {
+        let cases = [
+            ("rkawkgkqslek", Some(LayoutError::HangulTypedAsQwerty)),
+            ("dkssud, tptkd!", Some(LayoutError::HangulTypedAsQwerty)),
+            ("Rhcdl dlTek", Some(LayoutError::HangulTypedAsQwerty)),
+            ("The quick brown fox", None),
+            ("dog", None),
+            (
+                "ㅑ ㅣㅐㅍㄷ ㅏㅐㄱㄷㅁ",
+                Some(LayoutError::QwertyTypedAsHangul),
+            ),
+            ("ㅇㅋ ㄱㄱ", None),
+            ("ㅇㅋ 알겠어", None),
+            ("ㅋㅋㅋㅋ ㅠㅠ", None),
+            ("값싼 닭고기", None),
+            ("안녕 hello", None),
+            ("", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(detect_layout_error(text), expected, "{text}");
+        }
+        let error = LayoutError::QwertyTypedAsHangul;
+        assert_eq!(
+            error.correct("ㅑ ㅣㅐㅍㄷ ㅏㅐㄱㄷㅁ").unwrap(),
+            "i love korea"
+        );
+    }
+
+    // Types `digits` on a 천지인 keypad 100 ms apart, pausing for two
+    // seconds at each `.`
+    fn type_cheonjiin(digits: &str) -> CheonjiinComposer {
+        let mut composer = CheonjiinComposer::new();
+        let mut at = Duration::ZERO;
+        for digit in digits.chars() {
+            if digit == '.' {
+                at += Duration::from_secs(2);
+                continue;
+            }
+            at += Duration::from_millis(100);
+            let key = CheonjiinKey::from_digit(digit).unwrap();
+            composer.press(key, at).unwrap();
+        }
+        composer
+    }
+
+    #[test]
+    fn cheonjiin_composition() {
+        let cases = [
+            ("03", "으"),
+            ("021", "어"),
+            ("01", "이"),
+            // ㅇ, ㅏ (ㅣㆍ), then ㄴ; ㄴ, ㅕ (ㆍㆍㅣ), then ㅇ
+            ("0125.5221", "안녀"),
+            ("0125.52210", "안녕"),
+            // ㄲ moves whole to the next syllable
+            ("412444300", "가끔"),
+            // A pause types the same consonant twice
+            ("4124.41", "각기"),
+            ("41244", "갘"),
+            // Composite vowels from strokes
+            ("0231", "외"),
+            ("023121", "왜"),
+            ("032211", "웨"),
+            ("031", "의"),
+        ];
+        for (digits, expected) in cases {
+            let composer = type_cheonjiin(digits);
+            assert_eq!(composer.as_string().unwrap(), expected, "{digits}");
+        }
+
+        // A dot that does not form a vowel yet is shown, and dropped when a
+        // consonant follows
+        let composer = type_cheonjiin("02");
+        assert_eq!(composer.as_string().unwrap(), "\u{110B}ㆍ");
+        let composer = type_cheonjiin("0241");
+        assert_eq!(composer.as_string().unwrap(), "\u{110B}기");
+    }
+
+    #[test]
+    fn cheonjiin_pop_and_keystrokes() {
+        let mut composer = type_cheonjiin("0221");
+        assert_eq!(composer.as_string().unwrap(), "여");
+        assert_eq!(composer.pop().unwrap(), Some('ㅕ'));
+        assert_eq!(composer.pop().unwrap(), Some('ㅇ'));
+        assert_eq!(composer.pop().unwrap(), None);
+        composer.push_char('!').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "!");
+
+        assert_eq!(Cheonjiin.jamo_keystrokes('ㄱ'), Some(1));
+        assert_eq!(Cheonjiin.jamo_keystrokes('ㄲ'), Some(3));
+        assert_eq!(Cheonjiin.jamo_keystrokes('ㅁ'), Some(2));
+        assert_eq!(Cheonjiin.jamo_keystrokes('ㅘ'), Some(4));
+        assert_eq!(Cheonjiin.jamo_keystrokes('ㄺ'), Some(3));
+        assert_eq!(Cheonjiin.jamo_keystrokes('a'), None);
+        assert_eq!(keystrokes("한글", &Cheonjiin), 9);
+    }
+
+    #[test]
+    fn naratgeul_composition() {
+        let cases = [
+            ("8*32104", "한글"),
+            ("833", "어"),
+            ("8339", "에"),
+            ("83*9", "얘"),
+            ("16392", "괜"),
+            ("131#05", "가끔"),
+            ("2*3", "다"),
+            ("2**3", "타"),
+            ("2*#3", "따"),
+            // Modifiers that do not apply or would not compose do nothing
+            ("83#", "아"),
+            ("163*", "과"),
+        ];
+        for (digits, expected) in cases {
+            let mut composer = NaratgeulComposer::new();
+            for digit in digits.chars() {
+                let key = NaratgeulKey::from_digit(digit).unwrap();
+                composer.press(key).unwrap();
+            }
+            assert_eq!(composer.as_string().unwrap(), expected, "{digits}");
+        }
+
+        let mut composer = NaratgeulComposer::new();
+        for digit in "8*3".chars() {
+            let key = NaratgeulKey::from_digit(digit).unwrap();
+            composer.press(key).unwrap();
+        }
+        assert_eq!(composer.pop().unwrap(), Some('ㅏ'));
+        assert_eq!(composer.pop().unwrap(), Some('ㅎ'));
+        assert_eq!(composer.as_string().unwrap(), "");
+
+        assert_eq!(Naratgeul.jamo_keystrokes('ㅌ'), Some(3));
+        assert_eq!(Naratgeul.jamo_keystrokes('ㄲ'), Some(2));
+        assert_eq!(Naratgeul.jamo_keystrokes('ㅖ'), Some(4));
+        assert_eq!(Naratgeul.jamo_keystrokes('ㅙ'), Some(3));
+        assert_eq!(Naratgeul.jamo_keystrokes('ㄺ'), Some(2));
+        assert_eq!(keystrokes("한글", &Naratgeul), 7);
+    }
+
+    #[test]
+    fn sky_composition() {
+        let cases = [
+            ("883512255", "한글"),
+            ("131112200", "가끔"),
+            ("13.1.13", "각가"),
+            ("033", "야"),
+            ("0332", "얘"),
+            ("0932", "왜"),
+            ("0#62", "웨"),
+            ("0222", "의"),
+        ];
+        for (digits, expected) in cases {
+            let mut composer = SkyComposer::new();
+            let mut at = Duration::ZERO;
+            for digit in digits.chars() {
+                if digit == '.' {
+                    at += Duration::from_secs(2);
+                    continue;
+                }
+                at += Duration::from_millis(100);
+                let key = SkyKey::from_digit(digit).unwrap();
+                composer.press(key, at).unwrap();
+            }
+            assert_eq!(composer.as_string().unwrap(), expected, "{digits}");
+        }
+
+        assert_eq!(Sky.jamo_keystrokes('ㄲ'), Some(3));
+        assert_eq!(Sky.jamo_keystrokes('ㅢ'), Some(3));
+        assert_eq!(Sky.jamo_keystrokes('ㅐ'), Some(2));
+        assert_eq!(Sky.jamo_keystrokes('ㅞ'), Some(3));
+        assert_eq!(keystrokes("한글", &Sky), 9);
+    }
+
+    #[test]
+    fn keypad_taps() {
+        let mut keypad = CheonjiinComposer::new();
+        let taps = [
+            (CheonjiinKey::IeungMieum, TapResult::Typed('ㅇ')),
+            (CheonjiinKey::IeungMieum, TapResult::Replaced('ㅁ')),
+            (CheonjiinKey::Dot, TapResult::Stroke('ㆍ')),
+            (CheonjiinKey::I, TapResult::Typed('ㅓ')),
+            (CheonjiinKey::I, TapResult::Replaced('ㅔ')),
+            (CheonjiinKey::IeungMieum, TapResult::Typed('ㅇ')),
+        ];
+        for (key, expected) in taps {
+            assert_eq!(keypad.tap(key), Ok(expected), "{key:?}");
+        }
+        // Without a commit, the key keeps cycling however long the host waits
+        assert!(keypad.is_pending());
+        keypad.tap(CheonjiinKey::IeungMieum).unwrap();
+        assert_eq!(keypad.as_string().unwrap(), "멤");
+        keypad.commit_pending();
+        keypad.tap(CheonjiinKey::IeungMieum).unwrap();
+        assert_eq!(keypad.as_string().unwrap(), "멤\u{110B}");
+
+        let mut keypad = NaratgeulComposer::new();
+        assert_eq!(keypad.tap(NaratgeulKey::AddTense), Ok(TapResult::Ignored));
+        assert_eq!(keypad.tap(NaratgeulKey::Siot), Ok(TapResult::Typed('ㅅ')));
+        assert!(!keypad.is_pending());
+        assert_eq!(
+            keypad.tap(NaratgeulKey::AddTense),
+            Ok(TapResult::Replaced('ㅆ'))
+        );
+        assert_eq!(keypad.tap(NaratgeulKey::AEo), Ok(TapResult::Typed('ㅏ')));
+        assert_eq!(
+            keypad.tap(NaratgeulKey::AddStroke),
+            Ok(TapResult::Replaced('ㅑ'))
+        );
+        assert_eq!(keypad.as_string().unwrap(), "쌰");
+    }
+}
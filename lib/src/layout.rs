@@ -0,0 +1,185 @@
+use crate::string::char_width;
+
+/// The marker cell placed before a run of rotated Latin text by
+/// `to_vertical`.
+pub const ROTATION_START: char = '↻';
+
+/// The marker cell placed after a run of rotated Latin text by
+/// `to_vertical`.
+pub const ROTATION_END: char = '↺';
+
+/// Options for laying out text vertically with `to_vertical`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::layout::VerticalOptions;
+///
+/// let options = VerticalOptions::new().with_height(8).with_right_to_left(false);
+/// assert_eq!(options.height, 8);
+/// assert!(options.rotate_latin);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerticalOptions {
+    /// The number of characters in each column. Defaults to 20.
+    pub height: usize,
+
+    /// Whether columns are ordered from right to left, as in traditional
+    /// vertical writing. Defaults to `true`.
+    pub right_to_left: bool,
+
+    /// Whether runs of Latin letters and digits are rotated, as in
+    /// traditional vertical typesetting, rather than set upright one
+    /// character per cell. Defaults to `true`.
+    pub rotate_latin: bool,
+}
+
+impl Default for VerticalOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VerticalOptions {
+    /// Creates `VerticalOptions` with the default settings.
+    pub fn new() -> Self {
+        Self {
+            height: 20,
+            right_to_left: true,
+            rotate_latin: true,
+        }
+    }
+
+    /// Sets the number of characters in each column.
+    pub fn with_height(mut self, height: usize) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets whether columns are ordered from right to left.
+    pub fn with_right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
+    /// Sets whether runs of Latin letters and digits are rotated.
+    pub fn with_rotate_latin(mut self, rotate_latin: bool) -> Self {
+        self.rotate_latin = rotate_latin;
+        self
+    }
+}
+
+/// Lays out `text` vertically in columns of `options.height` characters,
+/// read top to bottom, and returns it as horizontal lines for display in a
+/// terminal or plain text. Each character occupies a cell two columns wide,
+/// and adjacent columns are separated by a space. Line breaks in `text`
+/// start a new column.
+///
+/// A terminal cannot rotate glyphs, so a rotated run of Latin letters and
+/// digits is still written one character per cell, reading downwards, but
+/// is marked by a `ROTATION_START` cell before it and a `ROTATION_END` cell
+/// after it so that renderers can rotate it.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::layout::{VerticalOptions, to_vertical};
+///
+/// let options = VerticalOptions::new().with_height(3);
+/// assert_eq!(to_vertical("가나다라마", &options), "라 가\n마 나\n   다");
+///
+/// let options = VerticalOptions::new().with_height(4);
+/// assert_eq!(
+///     to_vertical("한글 OK", &options),
+///     "O  한\nK  글\n↺\n   ↻"
+/// );
+/// ```
+pub fn to_vertical(text: &str, options: &VerticalOptions) -> String {
+    let height = options.height.max(1);
+    let mut columns: Vec<Vec<String>> = Vec::new();
+    for line in text.lines() {
+        let cells = vertical_cells(line, options.rotate_latin);
+        if cells.is_empty() {
+            columns.push(Vec::new());
+        }
+        columns.extend(cells.chunks(height).map(<[String]>::to_vec));
+    }
+    if options.right_to_left {
+        columns.reverse();
+    }
+
+    let rows = columns.iter().map(Vec::len).max().unwrap_or(0);
+    let mut result = String::new();
+    for row in 0..rows {
+        let line: Vec<&str> = columns
+            .iter()
+            .map(|column| column.get(row).map_or("  ", String::as_str))
+            .collect();
+        if row > 0 {
+            result.push('\n');
+        }
+        result.push_str(line.join(" ").trim_end());
+    }
+    result
+}
+
+// Splits a line of text into the two-column cells of a vertical column.
+fn vertical_cells(line: &str, rotate_latin: bool) -> Vec<String> {
+    let mut cells: Vec<String> = Vec::new();
+    let mut in_rotation = false;
+    for c in line.chars() {
+        let latin = rotate_latin && c.is_ascii_alphanumeric();
+        if in_rotation && !latin {
+            cells.push(pad_cell(ROTATION_END));
+        } else if !in_rotation && latin {
+            cells.push(pad_cell(ROTATION_START));
+        }
+        in_rotation = latin;
+
+        match (char_width(c), cells.last_mut()) {
+            // Combining characters stay in the cell of the character before
+            (0, Some(last)) if !c.is_whitespace() => last.push(c),
+            (2, _) => cells.push(c.to_string()),
+            _ if c.is_whitespace() => cells.push("  ".to_string()),
+            _ => cells.push(pad_cell(c)),
+        }
+    }
+    if in_rotation {
+        cells.push(pad_cell(ROTATION_END));
+    }
+    // Whitespace at the bottom of a column is not shown
+    while cells.last().is_some_and(|cell| cell.trim().is_empty()) {
+        cells.pop();
+    }
+    cells
+}
+
+// Pads a narrow character to fill a cell.
+fn pad_cell(c: char) -> String {
+    format!("{c} ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_follow_lines() {
+        let options = VerticalOptions::new().with_height(2);
+        assert_eq!(
+            to_vertical("가나다\n\n라", &options),
+            "라    다 가\n         나"
+        );
+        let options = options.with_right_to_left(false);
+        assert_eq!(to_vertical("가나다\n\n라", &options), "가 다    라\n나");
+        assert_eq!(to_vertical("", &options), "");
+    }
+
+    #[test]
+    fn latin_can_be_upright() {
+        let options = VerticalOptions::new()
+            .with_height(10)
+            .with_rotate_latin(false);
+        assert_eq!(to_vertical("A4 용지", &options), "A\n4\n\n용\n지");
+        let nfd = "\u{1112}\u{1161}\u{11AB}";
+        assert_eq!(to_vertical(nfd, &options), nfd);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+
+use crate::{jamo::Jamo, string::decompose};
+
+/// A dictionary of words, shared by the crate's dictionary-backed features
+/// such as `string::expand_chosung_with`. Implement this trait to plug in
+/// your own wordlist.
+pub trait Lexicon {
+    /// Returns whether `word` is in the lexicon.
+    fn contains(&self, word: &str) -> bool;
+
+    /// Returns the words in the lexicon that start with `prefix`, most
+    /// relevant first. Implementations should match at the jamo level, so
+    /// that a partially typed syllable such as 다 or 닭's prefix 달 matches.
+    fn words_with_prefix(&self, prefix: &str) -> Vec<&str>;
+
+    /// Returns every word in the lexicon, most relevant first.
+    fn words(&self) -> Vec<&str> {
+        self.words_with_prefix("")
+    }
+}
+
+/// A lexicon stored as a trie over jamo, so prefix searches match partially
+/// typed syllables. Syllables and composite jamo are split into their
+/// singular compatibility jamo (닭 is stored as ㄷㅏㄹㄱ), and words are
+/// ranked in insertion order.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::lexicon::{JamoTrie, Lexicon};
+///
+/// let trie: JamoTrie = ["달걀", "닭", "다리", "한국"].into_iter().collect();
+/// assert!(trie.contains("닭"));
+/// // 달걀 starts with ㄷㅏㄹㄱ, and 다리 with ㄷㅏㄹ
+/// assert_eq!(trie.words_with_prefix("닭"), ["달걀", "닭"]);
+/// assert_eq!(trie.words_with_prefix("달"), ["달걀", "닭", "다리"]);
+/// assert_eq!(trie.words_with_prefix("ㅎ"), ["한국"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JamoTrie {
+    nodes: Vec<TrieNode>,
+    len: usize,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct TrieNode {
+    children: BTreeMap<char, usize>,
+    // The word ending at this node and its rank.
+    word: Option<(String, usize)>,
+}
+
+impl Default for JamoTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JamoTrie {
+    /// Creates an empty trie.
+    pub fn new() -> Self {
+        JamoTrie {
+            nodes: vec![TrieNode::default()],
+            len: 0,
+        }
+    }
+
+    /// Adds `word` at the lowest rank. Returns `false` if it was already
+    /// present, in which case its rank is unchanged.
+    pub fn insert(&mut self, word: &str) -> bool {
+        let mut node = 0;
+        for jamo in jamo_key(word) {
+            node = match self.nodes[node].children.get(&jamo) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(jamo, child);
+                    child
+                }
+            };
+        }
+        if self.nodes[node].word.is_some() {
+            return false;
+        }
+        self.nodes[node].word = Some((word.to_string(), self.len));
+        self.len += 1;
+        true
+    }
+
+    /// Returns the number of words in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the trie contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns the node reached by following the jamo of `prefix`.
+    fn find(&self, prefix: &str) -> Option<usize> {
+        jamo_key(prefix).into_iter().try_fold(0, |node, jamo| {
+            self.nodes[node].children.get(&jamo).copied()
+        })
+    }
+}
+
+impl Lexicon for JamoTrie {
+    fn contains(&self, word: &str) -> bool {
+        self.find(word)
+            .is_some_and(|node| self.nodes[node].word.is_some())
+    }
+
+    fn words_with_prefix(&self, prefix: &str) -> Vec<&str> {
+        let mut found = Vec::new();
+        let mut stack: Vec<usize> = self.find(prefix).into_iter().collect();
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            found.extend(
+                node.word
+                    .as_ref()
+                    .map(|(word, rank)| (*rank, word.as_str())),
+            );
+            stack.extend(node.children.values());
+        }
+        found.sort_unstable_by_key(|&(rank, _)| rank);
+        found.into_iter().map(|(_, word)| word).collect()
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for JamoTrie {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut trie = JamoTrie::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for JamoTrie {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word.as_ref());
+        }
+    }
+}
+
+// Whether `word` starts with `prefix` at the jamo level.
+pub(crate) fn has_jamo_prefix(word: &str, prefix: &str) -> bool {
+    jamo_key(word).starts_with(&jamo_key(prefix))
+}
+
+// Splits `text` into singular compatibility jamo, passing other characters
+// through.
+fn jamo_key(text: &str) -> Vec<char> {
+    let decomposed = decompose(text);
+    let mut key = Vec::with_capacity(decomposed.len());
+    for c in decomposed.chars() {
+        let (first, second) = match Jamo::from_compatibility_jamo(c) {
+            Ok(Jamo::CompositeConsonant(composite)) => composite.decompose(),
+            Ok(Jamo::CompositeVowel(composite)) => composite.decompose(),
+            _ => {
+                key.push(c);
+                continue;
+            }
+        };
+        key.extend([first.char_compatibility(), second.char_compatibility()]);
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_search() {
+        let mut trie = JamoTrie::new();
+        assert!(trie.is_empty());
+        assert!(trie.insert("과일"));
+        assert!(trie.insert("고기"));
+        assert!(!trie.insert("과일"));
+        assert!(trie.insert("go"));
+        assert_eq!(trie.len(), 3);
+
+        assert!(trie.contains("고기"));
+        assert!(!trie.contains("고"));
+        assert!(!trie.contains("과일들"));
+        assert_eq!(trie.words_with_prefix("고"), ["과일", "고기"]);
+        assert_eq!(trie.words_with_prefix("곽"), Vec::<&str>::new());
+        assert_eq!(trie.words_with_prefix("g"), ["go"]);
+        assert_eq!(trie.words(), ["과일", "고기", "go"]);
+    }
+}
@@ -2,14 +2,110 @@
 //! A library for working with Hangul (Korean script) at the jamo, block,
 //! word, and string levels.
 
+/// A module for computing jamo statistics and finding chat fillers in Hangul text.
+pub mod analysis;
+
+/// A module for working with archaic Hangul jamo and syllables.
+#[cfg(feature = "archaic")]
+pub mod archaic;
+
+/// A module for correcting common typing mistakes while composing Hangul.
+pub mod autocorrect;
+
 /// A module for working with Hangul syllable blocks.
 pub mod block;
 
+/// A module for converting between Hangul and Korean braille (점자).
+pub mod braille;
+
+/// A module for sorting strings in Korean dictionary order (가나다순).
+pub mod collate;
+
+/// A module for formatting dates and times in Korean.
+pub mod datetime;
+
+/// A module for converting Korean text to and from EUC-KR and CP949.
+#[cfg(feature = "encoding")]
+pub mod encoding;
+
+/// A module exposing a C ABI for the string composer, for native IMEs.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// A module for generating random Hangul text for testing.
+pub mod generate;
+
+/// A module for Korean spelling and grammar rules.
+pub mod grammar;
+
+/// A module for converting Hanja to their Hangul readings.
+#[cfg(feature = "hanja")]
+pub mod hanja;
+
 /// A module for working with Hangul jamo characters.
 pub mod jamo;
 
+/// A module for working with Korean keyboard layouts.
+pub mod keyboard;
+
+/// A module for laying out Hangul text vertically.
+pub mod layout;
+
+/// A module defining dictionaries of Korean words for search features.
+pub mod lexicon;
+
+/// A module for encoding and decoding Korean Morse code.
+pub mod morse;
+
+/// A module for normalizing Hangul text to NFC, NFD, NFKC, and NFKD.
+pub mod normalize;
+
+/// A module for spelling out numbers with Korean numerals.
+pub mod number;
+
+/// A module for searching Hangul text with patterns over jamo.
+pub mod pattern;
+
+/// A module exposing PyO3 bindings for using the crate from Python.
+#[cfg(feature = "python")]
+pub mod python;
+
+/// A module for writing Korean in the Latin alphabet and other scripts.
+pub mod romanize;
+
+/// A module for building search keys that match partially typed Hangul.
+pub mod search;
+
+/// A module for suggesting spelling corrections from a lexicon.
+pub mod spell;
+
 /// A module for working with strings mixing Hangul and non-Hangul characters.
 pub mod string;
 
+/// A module for tokenizing Hangul text for language models and search.
+pub mod tokenize;
+
+/// A module for stylizing Hangul text with reversible transformations.
+pub mod transform;
+
+/// A module for transcribing English loanwords in Hangul.
+pub mod transliterate;
+
+/// A module for spelling out mixed text in Hangul for text-to-speech.
+pub mod tts;
+
+/// A module for tracking Korean typing practice against a target text.
+pub mod typing;
+
+/// A module for exhaustively checking composition and decomposition in tests.
+// Also built for the crate's own tests, so that a plain `cargo test` runs
+// the round trip of every syllable
+#[cfg(any(test, feature = "test-utils"))]
+pub mod verify;
+
+/// A module exposing `wasm-bindgen` bindings for web-based Korean IMEs.
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 /// A module for working with Hangul words.
 pub mod word;
@@ -0,0 +1,224 @@
+use thiserror::Error;
+
+use crate::jamo::modern_to_compatibility_jamo;
+use crate::string::{StringComposer, StringError, decompose_to_singular_jamo};
+
+/// An error type for Korean Morse code encoding and decoding.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum MorseError {
+    /// Occurs when a character has no Korean Morse code representation.
+    #[error("Character {0} cannot be encoded in Korean Morse code")]
+    UnencodableChar(char),
+
+    /// Occurs when a code does not correspond to any jamo or digit.
+    #[error("Unknown Korean Morse code: {0}")]
+    UnknownCode(String),
+
+    /// Occurs when decoded jamo cannot be composed into text.
+    #[error("String error: {0}")]
+    StringError(#[from] StringError),
+}
+
+// The Korean telegraph code (국문 전신 부호). Composite vowels other than ㅐ
+// and ㅔ, and double consonants, are sent as their components.
+const MORSE_CODES: [(char, &str); 36] = [
+    ('ㄱ', ".-.."),
+    ('ㄴ', "..-."),
+    ('ㄷ', "-..."),
+    ('ㄹ', "...-"),
+    ('ㅁ', "--"),
+    ('ㅂ', ".--"),
+    ('ㅅ', "--."),
+    ('ㅇ', "-.-"),
+    ('ㅈ', ".--."),
+    ('ㅊ', "-.-."),
+    ('ㅋ', "-..-"),
+    ('ㅌ', "--.."),
+    ('ㅍ', "---"),
+    ('ㅎ', ".---"),
+    ('ㅏ', "."),
+    ('ㅑ', ".."),
+    ('ㅓ', "-"),
+    ('ㅕ', "..."),
+    ('ㅗ', ".-"),
+    ('ㅛ', "-."),
+    ('ㅜ', "...."),
+    ('ㅠ', ".-."),
+    ('ㅡ', "-.."),
+    ('ㅣ', "..-"),
+    ('ㅐ', "--.-"),
+    ('ㅔ', "-.--"),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+];
+
+// Jamo without their own code, and the jamo they are sent as.
+const SPLIT_JAMO: [(char, [char; 2]); 14] = [
+    ('ㄲ', ['ㄱ', 'ㄱ']),
+    ('ㄸ', ['ㄷ', 'ㄷ']),
+    ('ㅃ', ['ㅂ', 'ㅂ']),
+    ('ㅆ', ['ㅅ', 'ㅅ']),
+    ('ㅉ', ['ㅈ', 'ㅈ']),
+    ('ㅒ', ['ㅑ', 'ㅣ']),
+    ('ㅖ', ['ㅕ', 'ㅣ']),
+    ('ㅘ', ['ㅗ', 'ㅏ']),
+    ('ㅙ', ['ㅗ', 'ㅐ']),
+    ('ㅚ', ['ㅗ', 'ㅣ']),
+    ('ㅝ', ['ㅜ', 'ㅓ']),
+    ('ㅞ', ['ㅜ', 'ㅔ']),
+    ('ㅟ', ['ㅜ', 'ㅣ']),
+    ('ㅢ', ['ㅡ', 'ㅣ']),
+];
+
+// Separates words in encoded Morse text; codes within a word are separated
+// by a single space.
+const WORD_SEPARATOR: &str = " / ";
+
+/// Encodes Hangul text as Korean Morse code (국문 전신 부호, also known as
+/// SKATS). Syllables are decomposed into jamo, each of which is sent as its
+/// own code, separated by spaces; words are separated by `" / "`. Digits
+/// use the international Morse codes.
+///
+/// Returns an error if `text` contains characters other than Hangul,
+/// digits, and whitespace.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::morse::encode;
+///
+/// assert_eq!(encode("한").unwrap(), ".--- . ..-.");
+/// assert_eq!(encode("가 나").unwrap(), ".-.. . / ..-. .");
+/// assert!(encode("a").is_err());
+/// ```
+pub fn encode(text: &str) -> Result<String, MorseError> {
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        let mut codes = Vec::new();
+        for jamo in decompose_to_singular_jamo(word) {
+            for part in split_jamo(jamo) {
+                let code = MORSE_CODES
+                    .iter()
+                    .find(|(c, _)| *c == part)
+                    .map(|(_, code)| *code)
+                    .ok_or(MorseError::UnencodableChar(part))?;
+                codes.push(code);
+            }
+        }
+        words.push(codes.join(" "));
+    }
+    Ok(words.join(WORD_SEPARATOR))
+}
+
+/// Decodes Korean Morse code into Hangul text, composing the decoded jamo
+/// into syllables. Codes are separated by whitespace, and words by `/`.
+///
+/// Since double consonants are sent as two consonants, some sequences are
+/// ambiguous; these are resolved the same way as typing the jamo into a
+/// `StringComposer` (e.g. ㄱㄱㅏ decodes as 까).
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::morse::decode;
+///
+/// assert_eq!(decode(".--- . ..-. / .-.. -.. ...-").unwrap(), "한 글");
+/// assert!(decode("......").is_err());
+/// ```
+pub fn decode(morse: &str) -> Result<String, MorseError> {
+    let mut words = Vec::new();
+    for word in morse.split('/') {
+        let mut jamo = Vec::new();
+        for code in word.split_whitespace() {
+            let c = MORSE_CODES
+                .iter()
+                .find(|(_, morse)| *morse == code)
+                .map(|(c, _)| *c)
+                .ok_or_else(|| MorseError::UnknownCode(code.to_string()))?;
+            jamo.push(c);
+        }
+
+        let mut composer = StringComposer::new();
+        for c in join_vowels(&jamo) {
+            composer.push_char(c)?;
+        }
+        // Lone jamo are given as compatibility jamo, like `compose`
+        words.push(
+            composer
+                .as_string()?
+                .chars()
+                .map(modern_to_compatibility_jamo)
+                .collect::<String>(),
+        );
+    }
+    Ok(words.join(" "))
+}
+
+fn split_jamo(jamo: char) -> Vec<char> {
+    match SPLIT_JAMO.iter().find(|(c, _)| *c == jamo) {
+        Some((_, parts)) => parts.to_vec(),
+        None => vec![jamo],
+    }
+}
+
+// Rejoins the vowels ㅒ and ㅖ, which the composer does not combine on its
+// own. Other composite vowels are combined by the composer.
+fn join_vowels(jamo: &[char]) -> Vec<char> {
+    let mut result: Vec<char> = Vec::with_capacity(jamo.len());
+    for &c in jamo {
+        match (result.last(), c) {
+            (Some('ㅑ'), 'ㅣ') => *result.last_mut().unwrap() = 'ㅒ',
+            (Some('ㅕ'), 'ㅣ') => *result.last_mut().unwrap() = 'ㅖ',
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for text in [
+            "안녕하세요",
+            "대한민국 1945",
+            "얘기 계속",
+            "괜찮아요",
+            "값싼 닭",
+        ] {
+            let morse = encode(text).unwrap();
+            assert_eq!(decode(&morse).unwrap(), text, "roundtrip of {}", text);
+        }
+    }
+
+    #[test]
+    fn lone_jamo_decode_as_compatibility_jamo() {
+        for text in ["ㄱ", "ㅏ", "한글 ㅋㅋ"] {
+            let morse = encode(text).unwrap();
+            assert_eq!(decode(&morse).unwrap(), text, "roundtrip of {}", text);
+        }
+    }
+
+    #[test]
+    fn composite_jamo_are_split() {
+        assert_eq!(encode("꽤").unwrap(), encode("ㄱㄱㅗㅐ").unwrap());
+        assert_eq!(encode("예").unwrap(), "-.- ... ..-");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(encode("한글!"), Err(MorseError::UnencodableChar('!')));
+        assert_eq!(
+            decode(".-.. ......"),
+            Err(MorseError::UnknownCode("......".to_string()))
+        );
+    }
+}
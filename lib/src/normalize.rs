@@ -0,0 +1,433 @@
+use std::borrow::Cow;
+
+use crate::jamo::{L_BASE, N_COUNT, S_BASE, S_COUNT, T_BASE, T_COUNT, V_BASE, V_COUNT};
+
+/// Normalizes the Hangul in `text` to NFC (canonical composition): runs of
+/// conjoining jamo that form a modern syllable (an initial and a vowel,
+/// optionally followed by a final) are replaced by the precomposed
+/// syllable, and a precomposed syllable without a final followed by a
+/// conjoining final is combined with it. `text` is borrowed if it contains
+/// no conjoining jamo.
+///
+/// Only Hangul is normalized; all other characters are passed through
+/// unchanged, so the result matches a full Unicode normalizer such as ICU
+/// whenever the non-Hangul characters of `text` are already normalized.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::normalize::to_nfc;
+///
+/// assert_eq!(to_nfc("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"), "한글");
+/// assert_eq!(to_nfc("가\u{11A8}"), "각");
+/// // Compatibility jamo are not canonically equivalent to conjoining jamo
+/// assert_eq!(to_nfc("ㄱㅏ"), "ㄱㅏ");
+/// ```
+pub fn to_nfc(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(is_conjoining_jamo) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(compose(text.chars()))
+}
+
+/// Normalizes the Hangul in `text` to NFD (canonical decomposition): each
+/// precomposed syllable is replaced by its conjoining initial, vowel, and
+/// final (if any). `text` is borrowed if it contains no precomposed
+/// syllables. Like `to_nfc`, only Hangul is normalized.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::normalize::to_nfd;
+///
+/// assert_eq!(to_nfd("한a"), "\u{1112}\u{1161}\u{11AB}a");
+/// assert_eq!(to_nfd("ㄱ"), "ㄱ");
+/// ```
+pub fn to_nfd(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(is_syllable) {
+        return Cow::Borrowed(text);
+    }
+    let mut result = String::with_capacity(text.len() * 3);
+    for c in text.chars() {
+        push_decomposed(&mut result, c);
+    }
+    Cow::Owned(result)
+}
+
+/// Normalizes the Hangul in `text` to NFKC (compatibility composition):
+/// compatibility jamo (ㄱ), halfwidth jamo (ﾡ), and enclosed Hangul (㉠, ㈎,
+/// ㈜) are first replaced by their conjoining jamo equivalents, and the
+/// result is then composed as in `to_nfc`. `text` is borrowed if it is
+/// already normalized. Like `to_nfc`, only Hangul is normalized.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::normalize::to_nfkc;
+///
+/// assert_eq!(to_nfkc("ㄱㅏ"), "가");
+/// assert_eq!(to_nfkc("가ㄳ"), "갃");
+/// assert_eq!(to_nfkc("㈜한글"), "(주)한글");
+/// // A lone compatibility jamo becomes a conjoining jamo
+/// assert_eq!(to_nfkc("ㅋ"), "\u{110F}");
+/// ```
+pub fn to_nfkc(text: &str) -> Cow<'_, str> {
+    let unchanged = text
+        .chars()
+        .all(|c| !is_conjoining_jamo(c) && compatibility_decomposition(c).is_none());
+    if unchanged {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(compose(to_nfkd(text).chars()))
+}
+
+/// Normalizes the Hangul in `text` to NFKD (compatibility decomposition):
+/// compatibility, halfwidth, and enclosed Hangul are replaced by their
+/// conjoining jamo equivalents as in `to_nfkc`, and precomposed syllables
+/// are decomposed as in `to_nfd`. `text` is borrowed if it is already
+/// normalized. Like `to_nfc`, only Hangul is normalized.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::normalize::to_nfkd;
+///
+/// assert_eq!(to_nfkd("ㄱ가"), "\u{1100}\u{1100}\u{1161}");
+/// assert_eq!(to_nfkd("㉮"), "\u{1100}\u{1161}");
+/// ```
+pub fn to_nfkd(text: &str) -> Cow<'_, str> {
+    let unchanged = text
+        .chars()
+        .all(|c| !is_syllable(c) && compatibility_decomposition(c).is_none());
+    if unchanged {
+        return Cow::Borrowed(text);
+    }
+    let mut result = String::with_capacity(text.len() * 3);
+    for c in text.chars() {
+        match compatibility_decomposition(c) {
+            Some(decomposition) => result.push_str(decomposition),
+            None => push_decomposed(&mut result, c),
+        }
+    }
+    Cow::Owned(result)
+}
+
+// Applies canonical composition to `chars`, as described in `to_nfc`.
+fn compose(chars: impl Iterator<Item = char>) -> String {
+    let mut result = String::new();
+    let mut last: Option<char> = None;
+    for c in chars {
+        let cp = c as u32;
+        let composed = last.and_then(|prev| {
+            let prev_cp = prev as u32;
+            let is_l = (L_BASE..L_BASE + 19).contains(&prev_cp);
+            let is_lv = is_syllable(prev) && (prev_cp - S_BASE).is_multiple_of(T_COUNT);
+            if is_l && (V_BASE..V_BASE + V_COUNT).contains(&cp) {
+                char::from_u32(S_BASE + ((prev_cp - L_BASE) * V_COUNT + cp - V_BASE) * T_COUNT)
+            } else if is_lv && (T_BASE + 1..T_BASE + T_COUNT).contains(&cp) {
+                char::from_u32(prev_cp + cp - T_BASE)
+            } else {
+                None
+            }
+        });
+        match composed {
+            Some(syllable) => {
+                result.pop();
+                result.push(syllable);
+                last = Some(syllable);
+            }
+            None => {
+                result.push(c);
+                last = Some(c);
+            }
+        }
+    }
+    result
+}
+
+// Pushes the canonical decomposition of `c` onto `result`.
+fn push_decomposed(result: &mut String, c: char) {
+    if !is_syllable(c) {
+        result.push(c);
+        return;
+    }
+    let s_index = c as u32 - S_BASE;
+    let t_index = s_index % T_COUNT;
+    // Infallible: the sums are all within the Hangul Jamo block
+    result.push(char::from_u32(L_BASE + s_index / N_COUNT).unwrap());
+    result.push(char::from_u32(V_BASE + (s_index % N_COUNT) / T_COUNT).unwrap());
+    if t_index != 0 {
+        result.push(char::from_u32(T_BASE + t_index).unwrap());
+    }
+}
+
+fn is_syllable(c: char) -> bool {
+    (S_BASE..S_BASE + S_COUNT).contains(&(c as u32))
+}
+
+fn is_conjoining_jamo(c: char) -> bool {
+    ('\u{1100}'..='\u{11FF}').contains(&c)
+}
+
+// Returns the compatibility decomposition of a compatibility, halfwidth, or
+// enclosed Hangul character.
+fn compatibility_decomposition(c: char) -> Option<&'static str> {
+    COMPATIBILITY_DECOMPOSITIONS
+        .binary_search_by_key(&c, |(hangul, _)| *hangul)
+        .ok()
+        .map(|i| COMPATIBILITY_DECOMPOSITIONS[i].1)
+}
+
+// Hangul characters with compatibility decompositions, and those
+// decompositions in conjoining jamo, sorted by codepoint for binary search.
+// Generated from the Unicode Character Database.
+const COMPATIBILITY_DECOMPOSITIONS: [(char, &str); 208] = [
+    ('ㄱ', "\u{1100}"),
+    ('ㄲ', "\u{1101}"),
+    ('ㄳ', "\u{11AA}"),
+    ('ㄴ', "\u{1102}"),
+    ('ㄵ', "\u{11AC}"),
+    ('ㄶ', "\u{11AD}"),
+    ('ㄷ', "\u{1103}"),
+    ('ㄸ', "\u{1104}"),
+    ('ㄹ', "\u{1105}"),
+    ('ㄺ', "\u{11B0}"),
+    ('ㄻ', "\u{11B1}"),
+    ('ㄼ', "\u{11B2}"),
+    ('ㄽ', "\u{11B3}"),
+    ('ㄾ', "\u{11B4}"),
+    ('ㄿ', "\u{11B5}"),
+    ('ㅀ', "\u{111A}"),
+    ('ㅁ', "\u{1106}"),
+    ('ㅂ', "\u{1107}"),
+    ('ㅃ', "\u{1108}"),
+    ('ㅄ', "\u{1121}"),
+    ('ㅅ', "\u{1109}"),
+    ('ㅆ', "\u{110A}"),
+    ('ㅇ', "\u{110B}"),
+    ('ㅈ', "\u{110C}"),
+    ('ㅉ', "\u{110D}"),
+    ('ㅊ', "\u{110E}"),
+    ('ㅋ', "\u{110F}"),
+    ('ㅌ', "\u{1110}"),
+    ('ㅍ', "\u{1111}"),
+    ('ㅎ', "\u{1112}"),
+    ('ㅏ', "\u{1161}"),
+    ('ㅐ', "\u{1162}"),
+    ('ㅑ', "\u{1163}"),
+    ('ㅒ', "\u{1164}"),
+    ('ㅓ', "\u{1165}"),
+    ('ㅔ', "\u{1166}"),
+    ('ㅕ', "\u{1167}"),
+    ('ㅖ', "\u{1168}"),
+    ('ㅗ', "\u{1169}"),
+    ('ㅘ', "\u{116A}"),
+    ('ㅙ', "\u{116B}"),
+    ('ㅚ', "\u{116C}"),
+    ('ㅛ', "\u{116D}"),
+    ('ㅜ', "\u{116E}"),
+    ('ㅝ', "\u{116F}"),
+    ('ㅞ', "\u{1170}"),
+    ('ㅟ', "\u{1171}"),
+    ('ㅠ', "\u{1172}"),
+    ('ㅡ', "\u{1173}"),
+    ('ㅢ', "\u{1174}"),
+    ('ㅣ', "\u{1175}"),
+    ('ㅤ', "\u{1160}"),
+    ('ㅥ', "\u{1114}"),
+    ('ㅦ', "\u{1115}"),
+    ('ㅧ', "\u{11C7}"),
+    ('ㅨ', "\u{11C8}"),
+    ('ㅩ', "\u{11CC}"),
+    ('ㅪ', "\u{11CE}"),
+    ('ㅫ', "\u{11D3}"),
+    ('ㅬ', "\u{11D7}"),
+    ('ㅭ', "\u{11D9}"),
+    ('ㅮ', "\u{111C}"),
+    ('ㅯ', "\u{11DD}"),
+    ('ㅰ', "\u{11DF}"),
+    ('ㅱ', "\u{111D}"),
+    ('ㅲ', "\u{111E}"),
+    ('ㅳ', "\u{1120}"),
+    ('ㅴ', "\u{1122}"),
+    ('ㅵ', "\u{1123}"),
+    ('ㅶ', "\u{1127}"),
+    ('ㅷ', "\u{1129}"),
+    ('ㅸ', "\u{112B}"),
+    ('ㅹ', "\u{112C}"),
+    ('ㅺ', "\u{112D}"),
+    ('ㅻ', "\u{112E}"),
+    ('ㅼ', "\u{112F}"),
+    ('ㅽ', "\u{1132}"),
+    ('ㅾ', "\u{1136}"),
+    ('ㅿ', "\u{1140}"),
+    ('ㆀ', "\u{1147}"),
+    ('ㆁ', "\u{114C}"),
+    ('ㆂ', "\u{11F1}"),
+    ('ㆃ', "\u{11F2}"),
+    ('ㆄ', "\u{1157}"),
+    ('ㆅ', "\u{1158}"),
+    ('ㆆ', "\u{1159}"),
+    ('ㆇ', "\u{1184}"),
+    ('ㆈ', "\u{1185}"),
+    ('ㆉ', "\u{1188}"),
+    ('ㆊ', "\u{1191}"),
+    ('ㆋ', "\u{1192}"),
+    ('ㆌ', "\u{1194}"),
+    ('ㆍ', "\u{119E}"),
+    ('ㆎ', "\u{11A1}"),
+    ('㈀', "(\u{1100})"),
+    ('㈁', "(\u{1102})"),
+    ('㈂', "(\u{1103})"),
+    ('㈃', "(\u{1105})"),
+    ('㈄', "(\u{1106})"),
+    ('㈅', "(\u{1107})"),
+    ('㈆', "(\u{1109})"),
+    ('㈇', "(\u{110B})"),
+    ('㈈', "(\u{110C})"),
+    ('㈉', "(\u{110E})"),
+    ('㈊', "(\u{110F})"),
+    ('㈋', "(\u{1110})"),
+    ('㈌', "(\u{1111})"),
+    ('㈍', "(\u{1112})"),
+    ('㈎', "(\u{1100}\u{1161})"),
+    ('㈏', "(\u{1102}\u{1161})"),
+    ('㈐', "(\u{1103}\u{1161})"),
+    ('㈑', "(\u{1105}\u{1161})"),
+    ('㈒', "(\u{1106}\u{1161})"),
+    ('㈓', "(\u{1107}\u{1161})"),
+    ('㈔', "(\u{1109}\u{1161})"),
+    ('㈕', "(\u{110B}\u{1161})"),
+    ('㈖', "(\u{110C}\u{1161})"),
+    ('㈗', "(\u{110E}\u{1161})"),
+    ('㈘', "(\u{110F}\u{1161})"),
+    ('㈙', "(\u{1110}\u{1161})"),
+    ('㈚', "(\u{1111}\u{1161})"),
+    ('㈛', "(\u{1112}\u{1161})"),
+    ('㈜', "(\u{110C}\u{116E})"),
+    ('㈝', "(\u{110B}\u{1169}\u{110C}\u{1165}\u{11AB})"),
+    ('㈞', "(\u{110B}\u{1169}\u{1112}\u{116E})"),
+    ('㉠', "\u{1100}"),
+    ('㉡', "\u{1102}"),
+    ('㉢', "\u{1103}"),
+    ('㉣', "\u{1105}"),
+    ('㉤', "\u{1106}"),
+    ('㉥', "\u{1107}"),
+    ('㉦', "\u{1109}"),
+    ('㉧', "\u{110B}"),
+    ('㉨', "\u{110C}"),
+    ('㉩', "\u{110E}"),
+    ('㉪', "\u{110F}"),
+    ('㉫', "\u{1110}"),
+    ('㉬', "\u{1111}"),
+    ('㉭', "\u{1112}"),
+    ('㉮', "\u{1100}\u{1161}"),
+    ('㉯', "\u{1102}\u{1161}"),
+    ('㉰', "\u{1103}\u{1161}"),
+    ('㉱', "\u{1105}\u{1161}"),
+    ('㉲', "\u{1106}\u{1161}"),
+    ('㉳', "\u{1107}\u{1161}"),
+    ('㉴', "\u{1109}\u{1161}"),
+    ('㉵', "\u{110B}\u{1161}"),
+    ('㉶', "\u{110C}\u{1161}"),
+    ('㉷', "\u{110E}\u{1161}"),
+    ('㉸', "\u{110F}\u{1161}"),
+    ('㉹', "\u{1110}\u{1161}"),
+    ('㉺', "\u{1111}\u{1161}"),
+    ('㉻', "\u{1112}\u{1161}"),
+    ('㉼', "\u{110E}\u{1161}\u{11B7}\u{1100}\u{1169}"),
+    ('㉽', "\u{110C}\u{116E}\u{110B}\u{1174}"),
+    ('㉾', "\u{110B}\u{116E}"),
+    ('ﾠ', "\u{1160}"),
+    ('ﾡ', "\u{1100}"),
+    ('ﾢ', "\u{1101}"),
+    ('ﾣ', "\u{11AA}"),
+    ('ﾤ', "\u{1102}"),
+    ('ﾥ', "\u{11AC}"),
+    ('ﾦ', "\u{11AD}"),
+    ('ﾧ', "\u{1103}"),
+    ('ﾨ', "\u{1104}"),
+    ('ﾩ', "\u{1105}"),
+    ('ﾪ', "\u{11B0}"),
+    ('ﾫ', "\u{11B1}"),
+    ('ﾬ', "\u{11B2}"),
+    ('ﾭ', "\u{11B3}"),
+    ('ﾮ', "\u{11B4}"),
+    ('ﾯ', "\u{11B5}"),
+    ('ﾰ', "\u{111A}"),
+    ('ﾱ', "\u{1106}"),
+    ('ﾲ', "\u{1107}"),
+    ('ﾳ', "\u{1108}"),
+    ('ﾴ', "\u{1121}"),
+    ('ﾵ', "\u{1109}"),
+    ('ﾶ', "\u{110A}"),
+    ('ﾷ', "\u{110B}"),
+    ('ﾸ', "\u{110C}"),
+    ('ﾹ', "\u{110D}"),
+    ('ﾺ', "\u{110E}"),
+    ('ﾻ', "\u{110F}"),
+    ('ﾼ', "\u{1110}"),
+    ('ﾽ', "\u{1111}"),
+    ('ﾾ', "\u{1112}"),
+    ('ￂ', "\u{1161}"),
+    ('ￃ', "\u{1162}"),
+    ('ￄ', "\u{1163}"),
+    ('ￅ', "\u{1164}"),
+    ('ￆ', "\u{1165}"),
+    ('ￇ', "\u{1166}"),
+    ('ￊ', "\u{1167}"),
+    ('ￋ', "\u{1168}"),
+    ('ￌ', "\u{1169}"),
+    ('ￍ', "\u{116A}"),
+    ('ￎ', "\u{116B}"),
+    ('ￏ', "\u{116C}"),
+    ('ￒ', "\u{116D}"),
+    ('ￓ', "\u{116E}"),
+    ('ￔ', "\u{116F}"),
+    ('ￕ', "\u{1170}"),
+    ('ￖ', "\u{1171}"),
+    ('ￗ', "\u{1172}"),
+    ('ￚ', "\u{1173}"),
+    ('ￛ', "\u{1174}"),
+    ('ￜ', "\u{1175}"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(
+            COMPATIBILITY_DECOMPOSITIONS
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0)
+        );
+    }
+
+    #[test]
+    fn round_trips_every_syllable() {
+        let syllables: String = ('가'..='힣').collect();
+        let nfd = to_nfd(&syllables);
+        assert_eq!(nfd.chars().count(), 11172 * 2 + 11172 / 28 * 27);
+        assert_eq!(to_nfc(&nfd), syllables);
+        assert_eq!(to_nfkc(&to_nfkd(&syllables)), syllables);
+    }
+
+    #[test]
+    fn normalized_text_is_borrowed() {
+        assert!(matches!(to_nfc("한글 ㄱ"), Cow::Borrowed(_)));
+        assert!(matches!(to_nfd("ㄱ abc \u{1100}"), Cow::Borrowed(_)));
+        assert!(matches!(to_nfkc("한글 abc"), Cow::Borrowed(_)));
+        assert!(matches!(to_nfkd("\u{1100}\u{1161} abc"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn only_modern_jamo_compose() {
+        // Old initials and vowels have no precomposed syllables
+        assert_eq!(to_nfc("\u{1113}\u{1161}"), "\u{1113}\u{1161}");
+        assert_eq!(to_nfc("\u{1100}\u{1176}"), "\u{1100}\u{1176}");
+        assert_eq!(to_nfc("\u{1100}\u{1161}\u{11C3}"), "가\u{11C3}");
+        // A final does not attach to a syllable that already has one
+        assert_eq!(to_nfc("각\u{11A8}"), "각\u{11A8}");
+        // The Hangul fillers only compose through the compatibility forms
+        assert_eq!(to_nfkc("ﾡ\u{FFC2}"), "가");
+        assert_eq!(to_nfkc("ㅤ"), "\u{1160}");
+    }
+}
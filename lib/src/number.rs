@@ -0,0 +1,469 @@
+/// Spells out `n` with Sino-Korean numerals (일, 이, 삼, ...), as used for
+/// dates, minutes, money, and most measurements. Following the Korean
+/// spelling rules, groups of four digits are separated by spaces at each
+/// of 만, 억, 조, and 경, and a leading 일 is omitted before 십, 백, 천, and 만.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::to_sino_korean;
+///
+/// assert_eq!(to_sino_korean(0), "영");
+/// assert_eq!(to_sino_korean(15), "십오");
+/// assert_eq!(to_sino_korean(2024), "이천이십사");
+/// assert_eq!(to_sino_korean(1_234_567), "백이십삼만 사천오백육십칠");
+/// assert_eq!(to_sino_korean(100_010_000), "일억 만");
+/// ```
+pub fn to_sino_korean(n: u64) -> String {
+    sino_korean(n as u128)
+}
+
+/// Spells out an amount of money in won with Sino-Korean numerals, as
+/// written on checks and receipts. Negative amounts are read with 마이너스.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::format_currency;
+///
+/// assert_eq!(format_currency(1_234_567), "백이십삼만 사천오백육십칠 원");
+/// assert_eq!(format_currency(10_000), "만 원");
+/// assert_eq!(format_currency(-500), "마이너스 오백 원");
+/// ```
+pub fn format_currency(amount: i128) -> String {
+    let sign = if amount < 0 { "마이너스 " } else { "" };
+    format!("{sign}{} 원", sino_korean(amount.unsigned_abs()))
+}
+
+/// Writes `n` with digits grouped the Korean way, by powers of 10⁴ with
+/// the unit names 만, 억, 조, and so on, and with commas within each group:
+/// 1,234,567 is written 123만 4,567. Empty groups are left out.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::format_mixed;
+///
+/// assert_eq!(format_mixed(1_234_567), "123만 4,567");
+/// assert_eq!(format_mixed(300_000_000), "3억");
+/// assert_eq!(format_mixed(-10_005), "-1만 5");
+/// assert_eq!(format_mixed(0), "0");
+/// ```
+pub fn format_mixed(n: i128) -> String {
+    let sign = if n < 0 { "-" } else { "" };
+    let groups = large_groups(n.unsigned_abs())
+        .into_iter()
+        .map(|(group, unit)| {
+            let digits = group.to_string();
+            match digits.len() {
+                4 => format!("{},{}{unit}", &digits[..1], &digits[1..]),
+                _ => format!("{digits}{unit}"),
+            }
+        })
+        .collect::<Vec<_>>();
+    if groups.is_empty() {
+        return "0".to_string();
+    }
+    format!("{sign}{}", groups.join(" "))
+}
+
+/// Spells out `n` with native Korean numerals (하나, 둘, 셋, ...), as used
+/// for counting and ages. Native numerals only go up to 99, so this returns
+/// `None` for 0 and for numbers above 99.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::to_native_korean;
+///
+/// assert_eq!(to_native_korean(3).as_deref(), Some("셋"));
+/// assert_eq!(to_native_korean(20).as_deref(), Some("스물"));
+/// assert_eq!(to_native_korean(99).as_deref(), Some("아흔아홉"));
+/// assert_eq!(to_native_korean(100), None);
+/// ```
+pub fn to_native_korean(n: u32) -> Option<String> {
+    native(n, &NATIVE_ONES, NATIVE_TENS[2])
+}
+
+/// Spells out `n` with native Korean numerals in the shortened form used
+/// before a counter (한 시, 두 개, 스무 살), or returns `None` for 0 and for
+/// numbers above 99.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::to_native_korean_determiner;
+///
+/// assert_eq!(to_native_korean_determiner(3).as_deref(), Some("세"));
+/// assert_eq!(to_native_korean_determiner(20).as_deref(), Some("스무"));
+/// assert_eq!(to_native_korean_determiner(21).as_deref(), Some("스물한"));
+/// assert_eq!(to_native_korean_determiner(5).as_deref(), Some("다섯"));
+/// ```
+pub fn to_native_korean_determiner(n: u32) -> Option<String> {
+    native(n, &NATIVE_DETERMINER_ONES, "스무")
+}
+
+/// The form of an ordinal number made by `to_ordinal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrdinalForm {
+    /// The -째 form, as in 첫째, 둘째, and 셋째, used for ranks and order
+    /// in a family.
+    Jjae,
+
+    /// The 번째 form, as in 첫 번째, 두 번째, and 세 번째, used for
+    /// position in a sequence and number of times.
+    Beonjjae,
+}
+
+/// Makes the ordinal for `n` in the given form, or returns `None` for 0
+/// and for numbers above 99, which have no native Korean ordinal. The
+/// irregular forms for 1 (첫째, 첫 번째), 2 (둘째), and 20 (스무째) are used
+/// where standard.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::{OrdinalForm, to_ordinal};
+///
+/// assert_eq!(to_ordinal(1, OrdinalForm::Jjae).as_deref(), Some("첫째"));
+/// assert_eq!(to_ordinal(3, OrdinalForm::Jjae).as_deref(), Some("셋째"));
+/// assert_eq!(to_ordinal(12, OrdinalForm::Jjae).as_deref(), Some("열두째"));
+/// assert_eq!(to_ordinal(1, OrdinalForm::Beonjjae).as_deref(), Some("첫 번째"));
+/// assert_eq!(to_ordinal(21, OrdinalForm::Beonjjae).as_deref(), Some("스물한 번째"));
+/// assert_eq!(to_ordinal(0, OrdinalForm::Jjae), None);
+/// ```
+pub fn to_ordinal(n: u32, form: OrdinalForm) -> Option<String> {
+    match (form, n) {
+        (_, 1) => Some(FIRST.to_string() + form_suffix(form)),
+        (OrdinalForm::Jjae, 2) => Some("둘째".to_string()),
+        (OrdinalForm::Jjae, _) => native(n, &JJAE_ONES, "스무").map(|n| n + "째"),
+        (OrdinalForm::Beonjjae, _) => to_native_korean_determiner(n).map(|n| n + " 번째"),
+    }
+}
+
+/// Reads an ordinal in either form made by `to_ordinal` back into a
+/// number, or returns `None` if `ordinal` is not one. The space before 번째
+/// is optional.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::parse_ordinal;
+///
+/// assert_eq!(parse_ordinal("둘째"), Some(2));
+/// assert_eq!(parse_ordinal("스무 번째"), Some(20));
+/// assert_eq!(parse_ordinal("세번째"), Some(3));
+/// assert_eq!(parse_ordinal("셋"), None);
+/// ```
+pub fn parse_ordinal(ordinal: &str) -> Option<u32> {
+    let ordinal: String = ordinal.split_whitespace().collect();
+    (1..=99).find(|&n| {
+        [OrdinalForm::Jjae, OrdinalForm::Beonjjae]
+            .into_iter()
+            .filter_map(|form| to_ordinal(n, form))
+            .any(|candidate| candidate.replace(' ', "") == ordinal)
+    })
+}
+
+fn form_suffix(form: OrdinalForm) -> &'static str {
+    match form {
+        OrdinalForm::Jjae => "째",
+        OrdinalForm::Beonjjae => " 번째",
+    }
+}
+
+/// How `verbalize_digits_with_options` reads the digit 0.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZeroReading {
+    /// 공, as usual for phone numbers and other digit strings.
+    #[default]
+    Gong,
+
+    /// 영, the Sino-Korean reading of 0.
+    Yeong,
+}
+
+/// Options for reading digit strings with `verbalize_digits_with_options`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::{DigitOptions, ZeroReading};
+///
+/// let options = DigitOptions::new()
+///     .with_zero(ZeroReading::Yeong)
+///     .with_pause(", ")
+///     .with_group_size(Some(3));
+/// assert_eq!(options.pause, ", ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitOptions {
+    /// How the digit 0 is read. Defaults to `ZeroReading::Gong`.
+    pub zero: ZeroReading,
+
+    /// The text marking a pause, written in place of each run of
+    /// separators (whitespace, `-`, `.`, `/`, `(`, and `)`) and between
+    /// groups. Defaults to a single space.
+    pub pause: String,
+
+    /// If set, a pause is also added after every this many digits in a
+    /// run of digits. Defaults to `None`.
+    pub group_size: Option<usize>,
+}
+
+impl Default for DigitOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DigitOptions {
+    /// Creates `DigitOptions` with the default settings.
+    pub fn new() -> Self {
+        Self {
+            zero: ZeroReading::default(),
+            pause: " ".to_string(),
+            group_size: None,
+        }
+    }
+
+    /// Sets how the digit 0 is read.
+    pub fn with_zero(mut self, zero: ZeroReading) -> Self {
+        self.zero = zero;
+        self
+    }
+
+    /// Sets the text marking a pause.
+    pub fn with_pause(mut self, pause: &str) -> Self {
+        self.pause = pause.to_string();
+        self
+    }
+
+    /// Sets how many digits are read before each added pause.
+    pub fn with_group_size(mut self, group_size: Option<usize>) -> Self {
+        self.group_size = group_size;
+        self
+    }
+}
+
+/// Reads the digits of `text` one by one in Korean, as for phone numbers
+/// and account numbers, with 0 read as 공 and separators such as `-` read
+/// as a pause (a space). Other characters are left unchanged. Use
+/// `verbalize_digits_with_options` to change these readings.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::verbalize_digits;
+///
+/// assert_eq!(verbalize_digits("010-1234-5678"), "공일공 일이삼사 오육칠팔");
+/// assert_eq!(verbalize_digits("112"), "일일이");
+/// ```
+pub fn verbalize_digits(text: &str) -> String {
+    verbalize_digits_with_options(text, &DigitOptions::new())
+}
+
+/// Reads the digits of `text` one by one in Korean according to `options`,
+/// as `verbalize_digits` does.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::number::{DigitOptions, ZeroReading, verbalize_digits_with_options};
+///
+/// let options = DigitOptions::new()
+///     .with_zero(ZeroReading::Yeong)
+///     .with_pause(", ")
+///     .with_group_size(Some(4));
+/// assert_eq!(
+///     verbalize_digits_with_options("02 12345678", &options),
+///     "영이, 일이삼사, 오육칠팔"
+/// );
+/// ```
+pub fn verbalize_digits_with_options(text: &str, options: &DigitOptions) -> String {
+    let mut result = String::with_capacity(text.len() * 3);
+    let mut run = 0;
+    let mut pending_pause = false;
+    for c in text.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            if pending_pause || options.group_size.is_some_and(|n| n > 0 && run == n) {
+                result.push_str(&options.pause);
+                run = 0;
+            }
+            pending_pause = false;
+            result.push_str(match (digit, options.zero) {
+                (0, ZeroReading::Gong) => "공",
+                _ => SINO_DIGITS[digit as usize],
+            });
+            run += 1;
+        } else if matches!(c, '-' | '.' | '/' | '(' | ')') || c.is_whitespace() {
+            // Separators at the start or end of the text are dropped
+            pending_pause = !result.is_empty();
+        } else {
+            if pending_pause {
+                result.push_str(&options.pause);
+                pending_pause = false;
+            }
+            result.push(c);
+            run = 0;
+        }
+    }
+    result
+}
+
+// Spells out a number from 1 to 99 with the given ones, using `twenty` for
+// exactly 20.
+fn native(n: u32, ones: &[&str; 10], twenty: &str) -> Option<String> {
+    match n {
+        20 => Some(twenty.to_string()),
+        1..=99 => Some(format!(
+            "{}{}",
+            NATIVE_TENS[(n / 10) as usize],
+            ones[(n % 10) as usize]
+        )),
+        _ => None,
+    }
+}
+
+// Spells out `n` as described in `to_sino_korean`.
+fn sino_korean(n: u128) -> String {
+    if n == 0 {
+        return SINO_DIGITS[0].to_string();
+    }
+    large_groups(n)
+        .into_iter()
+        .map(|(group, unit)| match (group, unit) {
+            (1, "만") => unit.to_string(),
+            _ => sino_group(group) + unit,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Splits `n` into its non-zero groups of four digits and their units, from
+// the largest to the smallest.
+fn large_groups(n: u128) -> Vec<(u128, &'static str)> {
+    let mut groups = Vec::new();
+    let mut rest = n;
+    for unit in LARGE_UNITS {
+        let group = rest % 10_000;
+        rest /= 10_000;
+        if group != 0 {
+            groups.push((group, unit));
+        }
+        if rest == 0 {
+            break;
+        }
+    }
+    groups.reverse();
+    groups
+}
+
+// Spells out a group of up to four digits, omitting a leading 일 before
+// 십, 백, and 천.
+fn sino_group(group: u128) -> String {
+    let mut result = String::new();
+    for (place, unit) in SMALL_UNITS.iter().enumerate().rev() {
+        let digit = (group / 10u128.pow(place as u32) % 10) as usize;
+        if digit == 0 {
+            continue;
+        }
+        if digit != 1 || place == 0 {
+            result.push_str(SINO_DIGITS[digit]);
+        }
+        result.push_str(unit);
+    }
+    result
+}
+
+const SINO_DIGITS: [&str; 10] = ["영", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+
+const SMALL_UNITS: [&str; 4] = ["", "십", "백", "천"];
+
+// The units of each power of 10⁴, enough for any `u128`.
+const LARGE_UNITS: [&str; 10] = ["", "만", "억", "조", "경", "해", "자", "양", "구", "간"];
+
+const NATIVE_ONES: [&str; 10] = [
+    "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+const NATIVE_DETERMINER_ONES: [&str; 10] = [
+    "", "한", "두", "세", "네", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+// The ones of the -째 ordinals after 열, 스물, and so on.
+const JJAE_ONES: [&str; 10] = [
+    "", "한", "두", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+// The irregular ordinal form of 1.
+const FIRST: &str = "첫";
+
+const NATIVE_TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sino_korean_units() {
+        assert_eq!(to_sino_korean(10), "십");
+        assert_eq!(to_sino_korean(111), "백십일");
+        assert_eq!(to_sino_korean(10_000), "만");
+        assert_eq!(to_sino_korean(110_000), "십일만");
+        assert_eq!(to_sino_korean(100_000_000), "일억");
+        assert_eq!(to_sino_korean(3_0000_0000_0005), "삼조 오");
+        assert_eq!(
+            to_sino_korean(u64::MAX),
+            "천팔백사십사경 육천칠백사십사조 칠백삼십칠억 구백오십오만 천육백십오"
+        );
+    }
+
+    #[test]
+    fn currency_and_mixed_grouping() {
+        assert_eq!(format_currency(0), "영 원");
+        assert_eq!(
+            format_currency(i128::MIN),
+            "마이너스 백칠십간 천사백십일구 팔천삼백사십육양 사백육십구자 \
+             이천삼백십칠해 삼천백육십팔경 칠천삼백삼조 칠천백오십팔억 \
+             팔천사백십만 오천칠백이십팔 원"
+        );
+        assert_eq!(format_mixed(1_0000_1000), "1억 1,000");
+        assert_eq!(format_mixed(999), "999");
+    }
+
+    #[test]
+    fn ordinals_round_trip() {
+        assert_eq!(
+            to_ordinal(2, OrdinalForm::Beonjjae).as_deref(),
+            Some("두 번째")
+        );
+        assert_eq!(to_ordinal(20, OrdinalForm::Jjae).as_deref(), Some("스무째"));
+        assert_eq!(to_ordinal(40, OrdinalForm::Jjae).as_deref(), Some("마흔째"));
+        assert_eq!(to_ordinal(11, OrdinalForm::Jjae).as_deref(), Some("열한째"));
+        assert_eq!(to_ordinal(100, OrdinalForm::Beonjjae), None);
+        for n in 1..=99 {
+            for form in [OrdinalForm::Jjae, OrdinalForm::Beonjjae] {
+                assert_eq!(parse_ordinal(&to_ordinal(n, form).unwrap()), Some(n));
+            }
+        }
+        assert_eq!(parse_ordinal(""), None);
+    }
+
+    #[test]
+    fn digit_strings() {
+        assert_eq!(verbalize_digits("(02) 555-0100"), "공이 오오오 공일공공");
+        assert_eq!(verbalize_digits(" 1.5 "), "일 오");
+        assert_eq!(verbalize_digits("A-7"), "A 칠");
+        let options = DigitOptions::new().with_group_size(Some(2));
+        assert_eq!(
+            verbalize_digits_with_options("12345-6", &options),
+            "일이 삼사 오 육"
+        );
+    }
+
+    #[test]
+    fn native_korean_range() {
+        assert_eq!(to_native_korean(0), None);
+        assert_eq!(to_native_korean(1).as_deref(), Some("하나"));
+        assert_eq!(to_native_korean(10).as_deref(), Some("열"));
+        assert_eq!(to_native_korean(24).as_deref(), Some("스물넷"));
+        assert_eq!(to_native_korean_determiner(12).as_deref(), Some("열두"));
+        assert_eq!(to_native_korean_determiner(40).as_deref(), Some("마흔"));
+        assert_eq!(to_native_korean_determiner(100), None);
+    }
+}
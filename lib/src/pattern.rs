@@ -0,0 +1,377 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::block::HangulBlock;
+
+/// An error that can occur when compiling a `JamoPattern`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PatternError {
+    /// Occurs when the pattern has no syllable terms.
+    #[error("Pattern is empty")]
+    Empty,
+
+    /// Occurs when a syllable term does not have two or three slots, or a
+    /// quantifier is not at the end of a term.
+    #[error("Invalid syllable term \"{0}\"")]
+    InvalidTerm(String),
+
+    /// Occurs when a `[` is not closed by a `]`.
+    #[error("Unclosed character class in \"{0}\"")]
+    UnclosedClass(String),
+
+    /// Occurs when a character other than a compatibility jamo appears where
+    /// a jamo is expected.
+    #[error("Expected a jamo, found '{0}'")]
+    UnexpectedCharacter(char),
+}
+
+/// A compiled pattern over the jamo of Hangul syllables, for linguistic
+/// searches such as finding every syllable ending in ㄹ followed by one
+/// starting with ㄴ.
+///
+/// A pattern is a sequence of syllable terms separated by whitespace. Each
+/// term describes one syllable with two or three slots, for its initial,
+/// vowel, and optionally its final, written in that order as in 가 = ㄱㅏ.
+/// Each slot is one of:
+///
+/// - a compatibility jamo, such as `ㄱ` or `ㅘ`;
+/// - a class of jamo in brackets, such as `[ㄱㄴ]`, a range in
+///   compatibility jamo order, such as `[ㄱ-ㅎ]` or `[ㅏ-ㅣ]`, or a negated
+///   class, such as `[^ㄹ]`;
+/// - `.`, matching any jamo in that position.
+///
+/// A term with no final slot matches syllables with or without a final,
+/// and `_` in the final slot matches only syllables without one. A term may
+/// end with a quantifier: `?` to match zero or one syllables, `*` to match
+/// any number, or `+` to match at least one. Terms only match precomposed
+/// Hangul syllables, so a match never spans other characters.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::pattern::JamoPattern;
+///
+/// // A syllable ending in ㄹ followed by one starting with ㄴ
+/// let pattern = JamoPattern::new("..ㄹ ㄴ.").unwrap();
+/// assert!(pattern.is_match("설날"));
+/// assert!(!pattern.is_match("설 날"));
+///
+/// // Syllables with ㅏ and no final
+/// let open_a: JamoPattern = "[ㄱ-ㅎ]ㅏ_+".parse().unwrap();
+/// assert_eq!(open_a.find_iter("바나나 우유와 사과"), [0..9, 20..23]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JamoPattern {
+    terms: Vec<Term>,
+}
+
+impl JamoPattern {
+    /// Compiles `pattern`, written as described for `JamoPattern`.
+    pub fn new(pattern: &str) -> Result<Self, PatternError> {
+        let terms = pattern
+            .split_whitespace()
+            .map(Term::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if terms.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        Ok(JamoPattern { terms })
+    }
+
+    /// Returns whether the pattern matches anywhere in `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        !self.find_iter(text).is_empty()
+    }
+
+    /// Returns the byte ranges of the non-overlapping matches of the pattern
+    /// in `text`, from left to right. At each position the longest match is
+    /// taken, and empty matches are not reported.
+    pub fn find_iter(&self, text: &str) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, Option<Syllable>)> = text
+            .char_indices()
+            .map(|(offset, c)| (offset, Syllable::from_char(c)))
+            .collect();
+        let syllables: Vec<Option<Syllable>> = chars.iter().map(|(_, s)| *s).collect();
+        let mut matcher = Matcher::new(&self.terms, &syllables);
+        let mut ranges = Vec::new();
+        let mut start = 0;
+        while start < chars.len() {
+            match matcher.match_terms(0, start) {
+                Some(end) if end > start => {
+                    let end_offset = chars.get(end).map_or(text.len(), |(offset, _)| *offset);
+                    ranges.push(chars[start].0..end_offset);
+                    start = end;
+                }
+                _ => start += 1,
+            }
+        }
+        ranges
+    }
+}
+
+impl FromStr for JamoPattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JamoPattern::new(s)
+    }
+}
+
+// The initial, vowel, and final compatibility jamo of a syllable.
+#[derive(Debug, Clone, Copy)]
+struct Syllable {
+    initial: char,
+    vowel: char,
+    final_optional: Option<char>,
+}
+
+impl Syllable {
+    fn from_char(c: char) -> Option<Self> {
+        let block = HangulBlock::from_char(c).ok()?;
+        Some(Syllable {
+            initial: block.initial.char_compatibility(),
+            vowel: block.vowel.char_compatibility(),
+            final_optional: block.final_optional.map(|f| f.char_compatibility()),
+        })
+    }
+}
+
+// A pattern for one syllable, repeated according to its quantifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Term {
+    initial: Slot,
+    vowel: Slot,
+    final_slot: FinalSlot,
+    min: usize,
+    max: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FinalSlot {
+    Any,
+    None,
+    Some(Slot),
+}
+
+// A set of jamo allowed in one position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Slot {
+    Any,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Slot {
+    fn matches(&self, jamo: char) -> bool {
+        match self {
+            Slot::Any => true,
+            Slot::Class { negated, ranges } => {
+                ranges
+                    .iter()
+                    .any(|(start, end)| (start..=end).contains(&&jamo))
+                    != *negated
+            }
+        }
+    }
+}
+
+impl Term {
+    fn parse(term: &str) -> Result<Self, PatternError> {
+        let (term, min, max) = match term.chars().next_back() {
+            Some('?') => (&term[..term.len() - 1], 0, 1),
+            Some('*') => (&term[..term.len() - 1], 0, usize::MAX),
+            Some('+') => (&term[..term.len() - 1], 1, usize::MAX),
+            _ => (term, 1, 1),
+        };
+        let mut chars = term.chars().peekable();
+        let mut slots = Vec::new();
+        let mut no_final = false;
+        while let Some(c) = chars.next() {
+            if no_final {
+                return Err(PatternError::InvalidTerm(term.to_string()));
+            }
+            let slot = match c {
+                '.' => Slot::Any,
+                '_' if slots.len() == 2 => {
+                    no_final = true;
+                    continue;
+                }
+                '[' => {
+                    let negated = chars.next_if_eq(&'^').is_some();
+                    let mut ranges = Vec::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(start) => {
+                                let end = if chars.next_if_eq(&'-').is_some() {
+                                    chars.next().filter(|&c| c != ']').ok_or_else(|| {
+                                        PatternError::UnclosedClass(term.to_string())
+                                    })?
+                                } else {
+                                    start
+                                };
+                                ranges.push((jamo(start)?, jamo(end)?));
+                            }
+                            None => return Err(PatternError::UnclosedClass(term.to_string())),
+                        }
+                    }
+                    Slot::Class { negated, ranges }
+                }
+                c => {
+                    let c = jamo(c)?;
+                    Slot::Class {
+                        negated: false,
+                        ranges: vec![(c, c)],
+                    }
+                }
+            };
+            slots.push(slot);
+        }
+        let mut slots = slots.into_iter();
+        let (Some(initial), Some(vowel)) = (slots.next(), slots.next()) else {
+            return Err(PatternError::InvalidTerm(term.to_string()));
+        };
+        let final_slot = match (slots.next(), no_final) {
+            (Some(slot), false) => FinalSlot::Some(slot),
+            (None, true) => FinalSlot::None,
+            (None, false) => FinalSlot::Any,
+            (Some(_), true) => unreachable!("`_` ends the term"),
+        };
+        if slots.next().is_some() {
+            return Err(PatternError::InvalidTerm(term.to_string()));
+        }
+        Ok(Term {
+            initial,
+            vowel,
+            final_slot,
+            min,
+            max,
+        })
+    }
+
+    fn matches(&self, syllable: &Syllable) -> bool {
+        self.initial.matches(syllable.initial)
+            && self.vowel.matches(syllable.vowel)
+            && match (&self.final_slot, syllable.final_optional) {
+                (FinalSlot::Any, _) => true,
+                (FinalSlot::None, final_optional) => final_optional.is_none(),
+                (FinalSlot::Some(slot), Some(f)) => slot.matches(f),
+                (FinalSlot::Some(_), None) => false,
+            }
+    }
+}
+
+// Returns `c` if it is a modern compatibility jamo.
+fn jamo(c: char) -> Result<char, PatternError> {
+    if ('ㄱ'..='ㅣ').contains(&c) {
+        Ok(c)
+    } else {
+        Err(PatternError::UnexpectedCharacter(c))
+    }
+}
+
+// Matches the terms of a pattern against a text, remembering the result for
+// each term and position so that quantified terms are not retried from the
+// same place, which would take exponential time.
+struct Matcher<'a> {
+    terms: &'a [Term],
+    syllables: &'a [Option<Syllable>],
+    // The result of `match_terms` for each term index and position, indexed
+    // by `index * (syllables.len() + 1) + start`.
+    memo: Vec<Option<Option<usize>>>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(terms: &'a [Term], syllables: &'a [Option<Syllable>]) -> Self {
+        Matcher {
+            terms,
+            syllables,
+            memo: vec![None; terms.len() * (syllables.len() + 1)],
+        }
+    }
+
+    // Matches the terms from `index` on starting at `start`, returning the
+    // end of the longest match, backtracking over quantified terms.
+    fn match_terms(&mut self, index: usize, start: usize) -> Option<usize> {
+        let Some(term) = self.terms.get(index) else {
+            return Some(start);
+        };
+        let key = index * (self.syllables.len() + 1) + start;
+        if let Some(result) = self.memo[key] {
+            return result;
+        }
+        let mut count = 0;
+        while count < term.max
+            && self
+                .syllables
+                .get(start + count)
+                .and_then(Option::as_ref)
+                .is_some_and(|s| term.matches(s))
+        {
+            count += 1;
+        }
+        let result = (term.min..=count)
+            .rev()
+            .find_map(|count| self.match_terms(index + 1, start + count));
+        self.memo[key] = Some(result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(JamoPattern::new("  "), Err(PatternError::Empty));
+        assert_eq!(
+            JamoPattern::new("ㄱ"),
+            Err(PatternError::InvalidTerm("ㄱ".to_string()))
+        );
+        assert_eq!(
+            JamoPattern::new("ㄱㅏㄴㄴ"),
+            Err(PatternError::InvalidTerm("ㄱㅏㄴㄴ".to_string()))
+        );
+        assert_eq!(
+            JamoPattern::new("ㄱㅏ_ㄴ"),
+            Err(PatternError::InvalidTerm("ㄱㅏ_ㄴ".to_string()))
+        );
+        assert_eq!(
+            JamoPattern::new("[ㄱ-ㅎㅏ"),
+            Err(PatternError::UnclosedClass("[ㄱ-ㅎㅏ".to_string()))
+        );
+        assert_eq!(
+            JamoPattern::new("가."),
+            Err(PatternError::UnexpectedCharacter('가'))
+        );
+    }
+
+    #[test]
+    fn slots_and_quantifiers() {
+        let pattern = JamoPattern::new("[^ㅇ][ㅗㅜ]_").unwrap();
+        assert_eq!(pattern.find_iter("오고 우주 곡"), [3..6, 10..13]);
+
+        // Optional and repeated terms
+        let pattern = JamoPattern::new("ㅎ. ..? ㄷㅏ").unwrap();
+        assert_eq!(pattern.find_iter("한다 하였다"), [0..6, 7..16]);
+        let pattern = JamoPattern::new("ㅋ.* ㅎㅏ").unwrap();
+        assert_eq!(pattern.find_iter("크크크하 하"), [0..12, 13..16]);
+
+        // Empty matches are not reported
+        assert!(JamoPattern::new("...*").unwrap().find_iter("가").is_empty());
+        assert!(!JamoPattern::new("..").unwrap().is_match("ㄱㅏ abc"));
+    }
+
+    #[test]
+    fn starred_terms_do_not_backtrack_exponentially() {
+        let pattern = JamoPattern::new("..* ..* ..* ..* ..* ㄱㄱ").unwrap();
+        assert!(pattern.find_iter(&"가".repeat(500)).is_empty());
+        let pattern = JamoPattern::new("..* ..* ..* ..* ..* ㄱㅗㄱ").unwrap();
+        let text = format!("{}곡 곡", "가".repeat(500));
+        assert_eq!(pattern.find_iter(&text), [0..1503, 1504..1507]);
+    }
+}
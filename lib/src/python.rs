@@ -0,0 +1,211 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::block::HangulBlock;
+use crate::romanize::RomanizationSystem;
+use crate::string::{self, StringComposer};
+
+/// A PyO3 wrapper around `StringComposer`, exposed to Python as
+/// `hangul_cd.Composer`.
+///
+/// **Python API:**
+/// ```python
+/// from hangul_cd import Composer
+///
+/// composer = Composer()
+/// composer.push_str("ㅎㅏㄴㄱㅡㄹ")
+/// composer.as_string()  # "한글"
+/// composer.pop()        # "ᄅ"
+/// str(composer)         # "한그"
+/// ```
+#[pyclass(name = "Composer")]
+#[derive(Debug, Default)]
+pub struct PyComposer {
+    inner: StringComposer,
+}
+
+#[pymethods]
+impl PyComposer {
+    /// Creates a new, empty composer.
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes a character to the composer. Raises `ValueError` if
+    /// composition fails.
+    pub fn push_char(&mut self, c: char) -> PyResult<()> {
+        self.inner.push_char(c).map_err(to_py_error)
+    }
+
+    /// Pushes each character of `s` in order. Raises `ValueError` if
+    /// composition fails.
+    pub fn push_str(&mut self, s: &str) -> PyResult<()> {
+        self.inner.feed_str(s).map_err(to_py_error)
+    }
+
+    /// Pops the last character or jamo from the composer, returning `None`
+    /// if there is nothing to pop.
+    pub fn pop(&mut self) -> PyResult<Option<char>> {
+        self.inner.pop().map_err(to_py_error)
+    }
+
+    /// Returns the composed text, including the block currently being
+    /// composed.
+    pub fn as_string(&self) -> PyResult<String> {
+        self.inner.as_string().map_err(to_py_error)
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        self.as_string()
+    }
+}
+
+/// A PyO3 wrapper around `HangulBlock`, exposed to Python as
+/// `hangul_cd.Block`. Jamo are exposed as compatibility jamo.
+///
+/// **Python API:**
+/// ```python
+/// from hangul_cd import Block
+///
+/// block = Block("닭")
+/// (block.initial, block.vowel, block.final)  # ("ㄷ", "ㅏ", "ㄺ")
+/// block.decompose()                          # "ㄷㅏㄹㄱ"
+/// ```
+#[pyclass(name = "Block", frozen, skip_from_py_object)]
+#[derive(Debug, Clone)]
+pub struct PyBlock {
+    inner: HangulBlock,
+}
+
+#[pymethods]
+impl PyBlock {
+    /// Creates a block from a precomposed Hangul syllable. Raises
+    /// `ValueError` if `syllable` is not one.
+    #[new]
+    pub fn new(syllable: char) -> PyResult<Self> {
+        let inner = HangulBlock::from_char(syllable).map_err(to_py_error)?;
+        Ok(Self { inner })
+    }
+
+    /// The initial consonant.
+    #[getter]
+    pub fn initial(&self) -> char {
+        self.inner.initial.char_compatibility()
+    }
+
+    /// The vowel.
+    #[getter]
+    pub fn vowel(&self) -> char {
+        self.inner.vowel.char_compatibility()
+    }
+
+    /// The final consonant, or `None` if there is none.
+    #[getter(r#final)]
+    pub fn final_optional(&self) -> Option<char> {
+        self.inner
+            .final_optional
+            .as_ref()
+            .map(|jamo| jamo.char_compatibility())
+    }
+
+    /// Returns the block as a precomposed syllable.
+    pub fn to_char(&self) -> char {
+        self.inner.to_char()
+    }
+
+    /// Returns the block's jamo, with composite jamo split into their
+    /// components.
+    pub fn decompose(&self) -> PyResult<String> {
+        let text = self.to_char().to_string();
+        let options = crate::block::HangulBlockDecompositionOptions {
+            decompose_composites: true,
+            jamo_era: crate::jamo::JamoUnicodeType::Compatibility,
+        };
+        string::decompose_with_options(&text, &options).map_err(to_py_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Block({:?})", self.inner.to_string())
+    }
+}
+
+/// Composes the jamo in `text` into syllables, passing other characters
+/// through.
+#[pyfunction]
+pub fn compose(text: &str) -> String {
+    string::compose(text)
+}
+
+/// Decomposes the syllables in `text` into compatibility jamo.
+#[pyfunction]
+pub fn decompose(text: &str) -> String {
+    string::decompose(text)
+}
+
+/// Replaces each syllable in `text` with its initial consonant.
+#[pyfunction]
+pub fn to_chosung(text: &str) -> String {
+    string::to_chosung(text)
+}
+
+/// Writes the Hangul in `text` in Revised Romanization.
+#[pyfunction]
+pub fn romanize(text: &str) -> String {
+    crate::romanize::romanize(text, RomanizationSystem::RevisedRomanization)
+}
+
+/// Attaches the particle `josa` to `word` in the form that follows its
+/// last syllable, such as 을 or 를.
+#[pyfunction]
+pub fn attach_josa(word: &str, josa: &str) -> String {
+    crate::word::attach_josa(word, josa)
+}
+
+/// The `hangul_cd` Python module.
+#[pymodule]
+fn hangul_cd(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyComposer>()?;
+    m.add_class::<PyBlock>()?;
+    m.add_function(wrap_pyfunction!(compose, m)?)?;
+    m.add_function(wrap_pyfunction!(decompose, m)?)?;
+    m.add_function(wrap_pyfunction!(to_chosung, m)?)?;
+    m.add_function(wrap_pyfunction!(romanize, m)?)?;
+    m.add_function(wrap_pyfunction!(attach_josa, m)?)?;
+    Ok(())
+}
+
+fn to_py_error(err: impl std::error::Error) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_decompose_splits_composites() {
+        let block = PyBlock::new('닭').unwrap();
+        assert_eq!(block.decompose().unwrap(), "ㄷㅏㄹㄱ");
+        assert_eq!(block.final_optional(), Some('ㄺ'));
+        assert!(PyBlock::new('a').is_err());
+    }
+
+    #[test]
+    fn block_final_optional_without_final() {
+        let block = PyBlock::new('가').unwrap();
+        assert_eq!((block.initial(), block.vowel()), ('ㄱ', 'ㅏ'));
+        assert_eq!(block.final_optional(), None);
+    }
+
+    #[test]
+    fn romanize_revised() {
+        assert_eq!(romanize("한국어"), "hangugeo");
+    }
+
+    #[test]
+    fn attach_josa_by_batchim() {
+        assert_eq!(attach_josa("책", "를"), "책을");
+        assert_eq!(attach_josa("사과", "을"), "사과를");
+    }
+}
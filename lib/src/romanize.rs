@@ -0,0 +1,818 @@
+use std::collections::HashMap;
+
+use crate::block::HangulBlock;
+
+/// A system for writing Korean in another script.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RomanizationSystem {
+    /// The Revised Romanization of Korean (국어의 로마자 표기법), the
+    /// official romanization of South Korea: 한국어 → hangugeo.
+    #[default]
+    RevisedRomanization,
+
+    /// The Kontsevich system (Концевич), the standard transcription of Korean
+    /// in Cyrillic for Russian: 한국어 → хангуго.
+    Kontsevich,
+
+    /// An approximation of Korean in Japanese katakana, as used for Korean
+    /// names and places in Japanese: 한국어 → ハングゴ.
+    Katakana,
+}
+
+/// Writes Hangul text in the script of `system`. Words are transcribed as
+/// they are pronounced, following the main rules of standard pronunciation
+/// (표준 발음법):
+/// - Final consonants move to a following syllable starting with ㅇ
+///   (한국어 → 한구거), and final ㄷ and ㅌ before 이 become 지 and 치
+///   (같이 → 가치). Before 히 they both become 치 (묻히다 → 무치다).
+/// - Other final consonants are reduced to ㄱ, ㄴ, ㄷ, ㄹ, ㅁ, ㅂ, or ㅇ.
+/// - Final ㄱ, ㄷ, and ㅂ before ㄴ or ㅁ become ㅇ, ㄴ, and ㅁ (백마 → 뱅마),
+///   ㄴ next to ㄹ becomes ㄹ (신라 → 실라), and ㄹ after other consonants
+///   becomes ㄴ (종로 → 종노).
+/// - Final ㅎ aspirates a following ㄱ, ㄷ, or ㅈ (좋다 → 조타), and is
+///   silent before a vowel.
+///
+/// Tensing is not written, as in Revised Romanization, and ㅎ after ㄱ, ㄷ,
+/// and ㅂ is kept, as in nouns. Characters other than precomposed Hangul
+/// syllables are passed through unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::romanize::{RomanizationSystem, romanize};
+///
+/// assert_eq!(
+///     romanize("안녕하세요", RomanizationSystem::RevisedRomanization),
+///     "annyeonghaseyo"
+/// );
+/// assert_eq!(romanize("서울", RomanizationSystem::Kontsevich), "соуль");
+/// assert_eq!(romanize("김치", RomanizationSystem::Katakana), "キムチ");
+/// ```
+pub fn romanize(text: &str, system: RomanizationSystem) -> String {
+    let mut result = String::with_capacity(text.len());
+    for_each_word(text, &mut result, |word, result| {
+        push_word(word, system, result)
+    });
+    result
+}
+
+/// Options for `romanize_with_options`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RomanizeOptions {
+    /// The system to write in. Defaults to Revised Romanization.
+    pub system: RomanizationSystem,
+
+    /// Whether the text is made of personal names. In name mode, each word
+    /// is read as a surname followed by a given name, which are romanized
+    /// separately and capitalized: 이지훈 → Lee Ji-hun. The syllables of
+    /// given names are romanized without sound changes and joined with
+    /// hyphens in Revised Romanization. A word that is only a surname may be
+    /// followed by the given name as its own word, separated from it only
+    /// by whitespace. Defaults to `false`.
+    pub name_mode: bool,
+
+    /// The spellings of surnames used in name mode with Revised
+    /// Romanization, keyed by the surname in Hangul. Surnames not in the
+    /// table are romanized by the usual rules. Two-syllable surnames in the
+    /// table, such as 남궁, are also recognized in other systems. Defaults
+    /// to the most common passport spellings: 김 → Kim, 이 → Lee,
+    /// 박 → Park, and so on.
+    pub surnames: HashMap<String, String>,
+}
+
+impl Default for RomanizeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RomanizeOptions {
+    /// Creates `RomanizeOptions` with the default settings.
+    pub fn new() -> Self {
+        Self {
+            system: RomanizationSystem::default(),
+            name_mode: false,
+            surnames: SURNAMES
+                .iter()
+                .map(|&(surname, spelling)| (surname.to_string(), spelling.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Sets the system to write in.
+    pub fn with_system(mut self, system: RomanizationSystem) -> Self {
+        self.system = system;
+        self
+    }
+
+    /// Sets whether the text is made of personal names.
+    pub fn with_name_mode(mut self, name_mode: bool) -> Self {
+        self.name_mode = name_mode;
+        self
+    }
+
+    /// Sets the spelling of a surname in name mode, replacing any spelling
+    /// it already has.
+    pub fn with_surname(mut self, surname: &str, spelling: &str) -> Self {
+        self.surnames
+            .insert(surname.to_string(), spelling.to_string());
+        self
+    }
+}
+
+/// Writes Hangul text in another script as `romanize` does, with the
+/// given options.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::romanize::{RomanizeOptions, romanize_with_options};
+///
+/// let options = RomanizeOptions::new().with_name_mode(true);
+/// assert_eq!(romanize_with_options("이지훈", &options), "Lee Ji-hun");
+/// assert_eq!(
+///     romanize_with_options("박서준, 남궁민", &options),
+///     "Park Seo-jun, Namgung Min"
+/// );
+///
+/// let options = options.with_surname("이", "Yi");
+/// assert_eq!(romanize_with_options("이 순신", &options), "Yi Sun-sin");
+/// ```
+pub fn romanize_with_options(text: &str, options: &RomanizeOptions) -> String {
+    if !options.name_mode {
+        return romanize(text, options.system);
+    }
+
+    let mut result = String::with_capacity(text.len());
+    // The length of `result` after a word that is only a surname, whose
+    // given name is the next word if only whitespace comes between them
+    let mut surname_end = None;
+    for_each_word(text, &mut result, |word, result| {
+        if let Some(end) = surname_end.take()
+            && result[end..].chars().all(char::is_whitespace)
+        {
+            push_given_name(word, options.system, result);
+            return;
+        }
+
+        let prefix = word.char_indices().nth(2).map_or(word, |(i, _)| &word[..i]);
+        let surname_len = if prefix.chars().count() == 2 && options.surnames.contains_key(prefix) {
+            prefix.len()
+        } else {
+            // Infallible: words are never empty
+            word.chars().next().unwrap().len_utf8()
+        };
+        let (surname, given_name) = word.split_at(surname_len);
+
+        match options.surnames.get(surname) {
+            Some(spelling) if options.system == RomanizationSystem::RevisedRomanization => {
+                result.push_str(spelling);
+            }
+            _ => {
+                let mut spelling = String::new();
+                push_word(surname, options.system, &mut spelling);
+                result.push_str(&capitalize(&spelling));
+            }
+        }
+        if given_name.is_empty() {
+            surname_end = Some(result.len());
+        } else {
+            result.push(match options.system {
+                RomanizationSystem::Katakana => '・',
+                _ => ' ',
+            });
+            push_given_name(given_name, options.system, result);
+        }
+    });
+    result
+}
+
+// Calls `f` with each run of Hangul syllables in `text`, pushing the
+// characters between them to `result` unchanged.
+fn for_each_word(text: &str, result: &mut String, mut f: impl FnMut(&str, &mut String)) {
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (Syllable::from_char(c), start) {
+            (Some(_), None) => start = Some(i),
+            (Some(_), Some(_)) => {}
+            (None, word_start) => {
+                if let Some(word_start) = word_start {
+                    f(&text[word_start..i], result);
+                }
+                start = None;
+                result.push(c);
+            }
+        }
+    }
+    if let Some(start) = start {
+        f(&text[start..], result);
+    }
+}
+
+// Pushes a given name, capitalized. In Revised Romanization each syllable
+// is romanized on its own and the syllables are joined with hyphens.
+fn push_given_name(given_name: &str, system: RomanizationSystem, result: &mut String) {
+    let mut spelling = String::new();
+    if system == RomanizationSystem::RevisedRomanization {
+        for (i, c) in given_name.char_indices() {
+            if i > 0 {
+                spelling.push('-');
+            }
+            push_word(&given_name[i..i + c.len_utf8()], system, &mut spelling);
+        }
+    } else {
+        push_word(given_name, system, &mut spelling);
+    }
+    result.push_str(&capitalize(&spelling));
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// Pushes a word of Hangul syllables in the script of `system`.
+fn push_word(word: &str, system: RomanizationSystem, result: &mut String) {
+    let word: Vec<Syllable> = word.chars().filter_map(Syllable::from_char).collect();
+    let syllables = pronounce(&word);
+    for (i, syllable) in syllables.iter().enumerate() {
+        let previous_final = i.checked_sub(1).and_then(|j| syllables[j].final_optional);
+        let next_initial = syllables.get(i + 1).map(|s| s.initial);
+        // Plain stops are voiced after a vowel or a sonorant
+        let voiced = i > 0 && previous_final.is_none_or(|f| matches!(f, 'ㄴ' | 'ㄹ' | 'ㅁ' | 'ㅇ'));
+        match system {
+            RomanizationSystem::RevisedRomanization => {
+                result.push_str(match syllable.initial {
+                    'ㄹ' if previous_final == Some('ㄹ') => "l",
+                    initial => lookup(&RR_INITIALS, initial),
+                });
+                result.push_str(lookup(&RR_VOWELS, syllable.vowel));
+                if let Some(f) = syllable.final_optional {
+                    result.push_str(lookup(&RR_FINALS, f));
+                }
+            }
+            RomanizationSystem::Kontsevich => {
+                push_kontsevich(syllable, previous_final, next_initial, voiced, result);
+            }
+            RomanizationSystem::Katakana => {
+                push_katakana(syllable, voiced, result);
+            }
+        }
+    }
+}
+
+fn push_kontsevich(
+    syllable: &Syllable,
+    previous_final: Option<char>,
+    next_initial: Option<char>,
+    voiced: bool,
+    result: &mut String,
+) {
+    result.push_str(match syllable.initial {
+        'ㄱ' if voiced => "г",
+        'ㄷ' if voiced => "д",
+        'ㅂ' if voiced => "б",
+        'ㅈ' if voiced => "чж",
+        'ㄹ' if previous_final == Some('ㄹ') => "л",
+        initial => lookup(&KONTSEVICH_INITIALS, initial),
+    });
+
+    let after_consonant = syllable.initial != 'ㅇ';
+    let after_ch = matches!(syllable.initial, 'ㅈ' | 'ㅉ' | 'ㅊ');
+    result.push_str(match syllable.vowel {
+        // ㅈ, ㅉ, and ㅊ already contain the y glide
+        'ㅑ' if after_ch => "а",
+        'ㅕ' | 'ㅛ' if after_ch => "о",
+        'ㅠ' if after_ch => "у",
+        'ㅒ' | 'ㅖ' if after_consonant => "е",
+        'ㅒ' | 'ㅖ' => "йе",
+        'ㅔ' if after_consonant => "е",
+        'ㅔ' => "э",
+        'ㅢ' if after_consonant => "и",
+        vowel => lookup(&KONTSEVICH_VOWELS, vowel),
+    });
+
+    if let Some(f) = syllable.final_optional {
+        result.push_str(match (f, next_initial) {
+            ('ㄹ', Some('ㄹ')) => "л",
+            ('ㄹ', _) => "ль",
+            // ㅇ before a vowel is separated with ъ
+            ('ㅇ', Some('ㅇ')) => "нъ",
+            (f, _) => lookup(&KONTSEVICH_FINALS, f),
+        });
+    }
+}
+
+fn push_katakana(syllable: &Syllable, voiced: bool, result: &mut String) {
+    let row = match syllable.initial {
+        'ㄱ' if voiced => KanaRow::G,
+        'ㄷ' if voiced => KanaRow::D,
+        'ㅂ' if voiced => KanaRow::B,
+        'ㅈ' if voiced => KanaRow::J,
+        'ㄱ' | 'ㄲ' | 'ㅋ' => KanaRow::K,
+        'ㄷ' | 'ㄸ' | 'ㅌ' => KanaRow::T,
+        'ㅂ' | 'ㅃ' | 'ㅍ' => KanaRow::P,
+        'ㅈ' | 'ㅉ' | 'ㅊ' => KanaRow::Ch,
+        'ㅅ' | 'ㅆ' => KanaRow::S,
+        'ㄴ' => KanaRow::N,
+        'ㄹ' => KanaRow::R,
+        'ㅁ' => KanaRow::M,
+        'ㅎ' => KanaRow::H,
+        _ => KanaRow::None,
+    };
+    let kana = KANA[row as usize];
+    let (a, i, u, e, o) = (kana[0], kana[1], kana[2], kana[3], kana[4]);
+    // The kana used before a small kana for a y or w glide
+    let y = match row {
+        KanaRow::T => "テ",
+        KanaRow::D => "デ",
+        KanaRow::Ch => "チ",
+        KanaRow::J => "ジ",
+        _ => i,
+    };
+    let w = match row {
+        KanaRow::T => "ト",
+        KanaRow::D => "ド",
+        _ => u,
+    };
+
+    let with_y = |small: &str| {
+        if row == KanaRow::None {
+            small.to_string()
+        } else {
+            format!("{y}{}", small_kana(small))
+        }
+    };
+    let with_w = |small: &str| format!("{w}{small}");
+    result.push_str(&match syllable.vowel {
+        'ㅏ' => a.to_string(),
+        'ㅐ' | 'ㅔ' => e.to_string(),
+        'ㅓ' | 'ㅗ' => o.to_string(),
+        'ㅜ' | 'ㅡ' => u.to_string(),
+        'ㅣ' => i.to_string(),
+        'ㅑ' => with_y("ヤ"),
+        'ㅕ' | 'ㅛ' => with_y("ヨ"),
+        'ㅠ' => with_y("ユ"),
+        'ㅒ' | 'ㅖ' if row == KanaRow::None => "イェ".to_string(),
+        'ㅒ' | 'ㅖ' => with_y("ェ"),
+        'ㅘ' if row == KanaRow::None => "ワ".to_string(),
+        'ㅘ' => with_w("ァ"),
+        'ㅙ' | 'ㅚ' | 'ㅞ' => with_w("ェ"),
+        'ㅝ' => with_w("ォ"),
+        'ㅟ' => with_w("ィ"),
+        'ㅢ' if row == KanaRow::None => "ウイ".to_string(),
+        _ => i.to_string(),
+    });
+
+    if let Some(f) = syllable.final_optional {
+        result.push_str(lookup(&KATAKANA_FINALS, f));
+    }
+}
+
+// Returns the small form of a kana used after another kana.
+fn small_kana(kana: &str) -> &'static str {
+    match kana {
+        "ヤ" => "ャ",
+        "ユ" => "ュ",
+        "ヨ" => "ョ",
+        _ => "ェ",
+    }
+}
+
+// Applies the sound changes at syllable boundaries within a word, and
+// reduces final consonants to those that are pronounced.
+fn pronounce(word: &[Syllable]) -> Vec<Syllable> {
+    let mut syllables = word.to_vec();
+    for i in 0..syllables.len() {
+        let Some(f) = syllables[i].final_optional else {
+            continue;
+        };
+        let Some(next) = syllables.get(i + 1).copied() else {
+            syllables[i].final_optional = Some(neutralize(f));
+            continue;
+        };
+
+        if next.initial == 'ㅇ' && f != 'ㅇ' {
+            let (kept, moved) = split_final(f);
+            syllables[i].final_optional = kept;
+            syllables[i + 1].initial = match (moved, next.vowel) {
+                (None, _) => 'ㅇ',
+                (Some('ㄷ'), 'ㅣ') => 'ㅈ',
+                (Some('ㅌ'), 'ㅣ') => 'ㅊ',
+                (Some(moved), _) => moved,
+            };
+            continue;
+        }
+
+        if matches!(f, 'ㄷ' | 'ㅌ') && next.initial == 'ㅎ' && next.vowel == 'ㅣ' {
+            syllables[i].final_optional = None;
+            syllables[i + 1].initial = 'ㅊ';
+            continue;
+        }
+
+        if matches!(f, 'ㅎ' | 'ㄶ' | 'ㅀ') && matches!(next.initial, 'ㄱ' | 'ㄷ' | 'ㅈ') {
+            syllables[i].final_optional = match f {
+                'ㄶ' => Some('ㄴ'),
+                'ㅀ' => Some('ㄹ'),
+                _ => None,
+            };
+            syllables[i + 1].initial = lookup(&ASPIRATED, next.initial);
+            continue;
+        }
+
+        let mut f = neutralize(f);
+        let mut initial = next.initial;
+        match (f, initial) {
+            ('ㄴ' | 'ㄹ', 'ㄹ') | ('ㄹ', 'ㄴ') => {
+                f = 'ㄹ';
+                initial = 'ㄹ';
+            }
+            (_, 'ㄹ') => initial = 'ㄴ',
+            _ => {}
+        }
+        if matches!(initial, 'ㄴ' | 'ㅁ') {
+            f = match f {
+                'ㄱ' => 'ㅇ',
+                'ㄷ' => 'ㄴ',
+                'ㅂ' => 'ㅁ',
+                f => f,
+            };
+        }
+        syllables[i].final_optional = Some(f);
+        syllables[i + 1].initial = initial;
+    }
+    syllables
+}
+
+// Returns the consonant a final is pronounced as before a consonant or at
+// the end of a word.
+fn neutralize(f: char) -> char {
+    match f {
+        'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' => 'ㄱ',
+        'ㄴ' | 'ㄵ' | 'ㄶ' => 'ㄴ',
+        'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => 'ㄷ',
+        'ㄹ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => 'ㄹ',
+        'ㅁ' | 'ㄻ' => 'ㅁ',
+        'ㅂ' | 'ㅍ' | 'ㄿ' | 'ㅄ' => 'ㅂ',
+        f => f,
+    }
+}
+
+// Splits a final before a vowel into the part that stays and the part that
+// moves to the next syllable. ㅎ is silent before a vowel.
+fn split_final(f: char) -> (Option<char>, Option<char>) {
+    match f {
+        'ㅎ' => (None, None),
+        'ㄶ' => (None, Some('ㄴ')),
+        'ㅀ' => (None, Some('ㄹ')),
+        'ㄳ' => (Some('ㄱ'), Some('ㅅ')),
+        'ㄵ' => (Some('ㄴ'), Some('ㅈ')),
+        'ㄺ' => (Some('ㄹ'), Some('ㄱ')),
+        'ㄻ' => (Some('ㄹ'), Some('ㅁ')),
+        'ㄼ' => (Some('ㄹ'), Some('ㅂ')),
+        'ㄽ' => (Some('ㄹ'), Some('ㅅ')),
+        'ㄾ' => (Some('ㄹ'), Some('ㅌ')),
+        'ㄿ' => (Some('ㄹ'), Some('ㅍ')),
+        'ㅄ' => (Some('ㅂ'), Some('ㅅ')),
+        f => (None, Some(f)),
+    }
+}
+
+fn lookup<T: Copy>(table: &[(char, T)], c: char) -> T {
+    // Infallible: the tables cover every modern jamo that can reach them
+    table.iter().find(|(jamo, _)| *jamo == c).unwrap().1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Syllable {
+    initial: char,
+    vowel: char,
+    final_optional: Option<char>,
+}
+
+impl Syllable {
+    fn from_char(c: char) -> Option<Self> {
+        let block = HangulBlock::from_char(c).ok()?;
+        Some(Self {
+            initial: block.initial.char_compatibility(),
+            vowel: block.vowel.char_compatibility(),
+            final_optional: block.final_optional.map(|f| f.char_compatibility()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KanaRow {
+    None,
+    K,
+    G,
+    S,
+    T,
+    D,
+    N,
+    H,
+    B,
+    P,
+    M,
+    R,
+    Ch,
+    J,
+}
+
+// The kana for each row, with the vowels a, i, u, e, and o.
+const KANA: [[&str; 5]; 14] = [
+    ["ア", "イ", "ウ", "エ", "オ"],
+    ["カ", "キ", "ク", "ケ", "コ"],
+    ["ガ", "ギ", "グ", "ゲ", "ゴ"],
+    ["サ", "シ", "ス", "セ", "ソ"],
+    ["タ", "ティ", "トゥ", "テ", "ト"],
+    ["ダ", "ディ", "ドゥ", "デ", "ド"],
+    ["ナ", "ニ", "ヌ", "ネ", "ノ"],
+    ["ハ", "ヒ", "フ", "ヘ", "ホ"],
+    ["バ", "ビ", "ブ", "ベ", "ボ"],
+    ["パ", "ピ", "プ", "ペ", "ポ"],
+    ["マ", "ミ", "ム", "メ", "モ"],
+    ["ラ", "リ", "ル", "レ", "ロ"],
+    ["チャ", "チ", "チュ", "チェ", "チョ"],
+    ["ジャ", "ジ", "ジュ", "ジェ", "ジョ"],
+];
+
+const KATAKANA_FINALS: [(char, &str); 7] = [
+    ('ㄱ', "ク"),
+    ('ㄴ', "ン"),
+    ('ㄷ', "ッ"),
+    ('ㄹ', "ル"),
+    ('ㅁ', "ム"),
+    ('ㅂ', "プ"),
+    ('ㅇ', "ン"),
+];
+
+const ASPIRATED: [(char, char); 3] = [('ㄱ', 'ㅋ'), ('ㄷ', 'ㅌ'), ('ㅈ', 'ㅊ')];
+
+const RR_INITIALS: [(char, &str); 19] = [
+    ('ㄱ', "g"),
+    ('ㄲ', "kk"),
+    ('ㄴ', "n"),
+    ('ㄷ', "d"),
+    ('ㄸ', "tt"),
+    ('ㄹ', "r"),
+    ('ㅁ', "m"),
+    ('ㅂ', "b"),
+    ('ㅃ', "pp"),
+    ('ㅅ', "s"),
+    ('ㅆ', "ss"),
+    ('ㅇ', ""),
+    ('ㅈ', "j"),
+    ('ㅉ', "jj"),
+    ('ㅊ', "ch"),
+    ('ㅋ', "k"),
+    ('ㅌ', "t"),
+    ('ㅍ', "p"),
+    ('ㅎ', "h"),
+];
+
+const RR_VOWELS: [(char, &str); 21] = [
+    ('ㅏ', "a"),
+    ('ㅐ', "ae"),
+    ('ㅑ', "ya"),
+    ('ㅒ', "yae"),
+    ('ㅓ', "eo"),
+    ('ㅔ', "e"),
+    ('ㅕ', "yeo"),
+    ('ㅖ', "ye"),
+    ('ㅗ', "o"),
+    ('ㅘ', "wa"),
+    ('ㅙ', "wae"),
+    ('ㅚ', "oe"),
+    ('ㅛ', "yo"),
+    ('ㅜ', "u"),
+    ('ㅝ', "wo"),
+    ('ㅞ', "we"),
+    ('ㅟ', "wi"),
+    ('ㅠ', "yu"),
+    ('ㅡ', "eu"),
+    ('ㅢ', "ui"),
+    ('ㅣ', "i"),
+];
+
+const RR_FINALS: [(char, &str); 7] = [
+    ('ㄱ', "k"),
+    ('ㄴ', "n"),
+    ('ㄷ', "t"),
+    ('ㄹ', "l"),
+    ('ㅁ', "m"),
+    ('ㅂ', "p"),
+    ('ㅇ', "ng"),
+];
+
+const KONTSEVICH_INITIALS: [(char, &str); 19] = [
+    ('ㄱ', "к"),
+    ('ㄲ', "кк"),
+    ('ㄴ', "н"),
+    ('ㄷ', "т"),
+    ('ㄸ', "тт"),
+    ('ㄹ', "р"),
+    ('ㅁ', "м"),
+    ('ㅂ', "п"),
+    ('ㅃ', "пп"),
+    ('ㅅ', "с"),
+    ('ㅆ', "сс"),
+    ('ㅇ', ""),
+    ('ㅈ', "ч"),
+    ('ㅉ', "чч"),
+    ('ㅊ', "чх"),
+    ('ㅋ', "кх"),
+    ('ㅌ', "тх"),
+    ('ㅍ', "пх"),
+    ('ㅎ', "х"),
+];
+
+const KONTSEVICH_VOWELS: [(char, &str); 21] = [
+    ('ㅏ', "а"),
+    ('ㅐ', "э"),
+    ('ㅑ', "я"),
+    ('ㅒ', "йе"),
+    ('ㅓ', "о"),
+    ('ㅔ', "е"),
+    ('ㅕ', "ё"),
+    ('ㅖ', "йе"),
+    ('ㅗ', "о"),
+    ('ㅘ', "ва"),
+    ('ㅙ', "вэ"),
+    ('ㅚ', "вэ"),
+    ('ㅛ', "ё"),
+    ('ㅜ', "у"),
+    ('ㅝ', "во"),
+    ('ㅞ', "ве"),
+    ('ㅟ', "ви"),
+    ('ㅠ', "ю"),
+    ('ㅡ', "ы"),
+    ('ㅢ', "ый"),
+    ('ㅣ', "и"),
+];
+
+const KONTSEVICH_FINALS: [(char, &str); 7] = [
+    ('ㄱ', "к"),
+    ('ㄴ', "н"),
+    ('ㄷ', "т"),
+    ('ㄹ', "ль"),
+    ('ㅁ', "м"),
+    ('ㅂ', "п"),
+    ('ㅇ', "н"),
+];
+
+// Common surnames and their most common spellings in passports, which
+// often differ from Revised Romanization.
+const SURNAMES: [(&str, &str); 60] = [
+    ("김", "Kim"),
+    ("이", "Lee"),
+    ("박", "Park"),
+    ("최", "Choi"),
+    ("정", "Jung"),
+    ("강", "Kang"),
+    ("조", "Cho"),
+    ("윤", "Yoon"),
+    ("장", "Jang"),
+    ("임", "Lim"),
+    ("한", "Han"),
+    ("오", "Oh"),
+    ("서", "Seo"),
+    ("신", "Shin"),
+    ("권", "Kwon"),
+    ("황", "Hwang"),
+    ("안", "Ahn"),
+    ("송", "Song"),
+    ("류", "Ryu"),
+    ("유", "Yoo"),
+    ("홍", "Hong"),
+    ("전", "Jeon"),
+    ("고", "Ko"),
+    ("문", "Moon"),
+    ("양", "Yang"),
+    ("손", "Son"),
+    ("배", "Bae"),
+    ("백", "Baek"),
+    ("허", "Heo"),
+    ("남", "Nam"),
+    ("심", "Shim"),
+    ("노", "Noh"),
+    ("하", "Ha"),
+    ("곽", "Kwak"),
+    ("성", "Sung"),
+    ("차", "Cha"),
+    ("주", "Joo"),
+    ("우", "Woo"),
+    ("구", "Koo"),
+    ("민", "Min"),
+    ("진", "Jin"),
+    ("지", "Ji"),
+    ("엄", "Um"),
+    ("채", "Chae"),
+    ("원", "Won"),
+    ("천", "Chun"),
+    ("방", "Bang"),
+    ("공", "Kong"),
+    ("현", "Hyun"),
+    ("변", "Byun"),
+    ("염", "Yeom"),
+    ("추", "Choo"),
+    ("석", "Seok"),
+    ("명", "Myung"),
+    ("남궁", "Namgung"),
+    ("황보", "Hwangbo"),
+    ("제갈", "Jegal"),
+    ("선우", "Sunwoo"),
+    ("독고", "Dokgo"),
+    ("사공", "Sagong"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revised_romanization() {
+        let cases = [
+            ("한국어", "hangugeo"),
+            ("신라", "silla"),
+            ("종로", "jongno"),
+            ("독립", "dongnip"),
+            ("백마", "baengma"),
+            ("같이", "gachi"),
+            ("묻히다", "muchida"),
+            ("닫히다", "dachida"),
+            ("좋다", "jota"),
+            ("닭", "dak"),
+            ("값이", "gapsi"),
+            ("설날", "seollal"),
+            ("묵호", "mukho"),
+            ("서울 2024", "seoul 2024"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(
+                romanize(text, RomanizationSystem::RevisedRomanization),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn kontsevich() {
+        let cases = [
+            ("부산", "пусан"),
+            ("한국", "хангук"),
+            ("대구", "тэгу"),
+            ("인천", "инчхон"),
+            ("평양", "пхёнъян"),
+            ("제주도", "чечжудо"),
+            ("일본", "ильбон"),
+            ("설날", "соллаль"),
+            ("김정은", "кимчжонъын"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(romanize(text, RomanizationSystem::Kontsevich), expected);
+        }
+    }
+
+    #[test]
+    fn katakana() {
+        let cases = [
+            ("서울", "ソウル"),
+            ("부산", "プサン"),
+            ("대구", "テグ"),
+            ("제주", "チェジュ"),
+            ("안녕하세요", "アンニョンハセヨ"),
+            ("광주", "クァンジュ"),
+            ("비빔밥", "ピビムバプ"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(romanize(text, RomanizationSystem::Katakana), expected);
+        }
+    }
+
+    #[test]
+    fn names() {
+        let options = RomanizeOptions::new().with_name_mode(true);
+        let cases = [
+            ("김정은", "Kim Jeong-eun"),
+            ("한복남", "Han Bok-nam"),
+            ("황보관", "Hwangbo Gwan"),
+            ("황보", "Hwangbo"),
+            ("이", "Lee"),
+            ("봉준호, 손흥민", "Bong Jun-ho, Son Heung-min"),
+            ("이 순신", "Lee Sun-sin"),
+            // Only whitespace separates a surname from its given name
+            ("김, 박서준", "Kim, Park Seo-jun"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(romanize_with_options(text, &options), expected);
+        }
+
+        let options = options.with_system(RomanizationSystem::Kontsevich);
+        assert_eq!(romanize_with_options("김정은", &options), "Ким Чонъын");
+        let options = options.with_system(RomanizationSystem::Katakana);
+        assert_eq!(romanize_with_options("박지성", &options), "パク・チソン");
+    }
+}
@@ -0,0 +1,155 @@
+use std::ops::Range;
+
+use crate::block::HangulBlock;
+use crate::string::{decompose_to_singular_jamo, normalize_hangul};
+use crate::typing::keyed_jamo;
+
+/// Returns a key for indexing `text` so that prefix searches match what a
+/// Korean typist has entered so far. Every syllable and jamo is decomposed
+/// into single compatibility jamo in typing order, with composite vowels and
+/// consonants split (ㅘ → ㅗㅏ, ㄲ → ㄱㄱ), after normalizing conjoining jamo
+/// to precomposed syllables. Other characters are kept unchanged.
+///
+/// While typing, the last consonant of a syllable is shown as a final and
+/// only moves to the next syllable once a vowel is typed: on the way to 달기,
+/// an input field shows 닭. Splitting finals makes the key of each such
+/// partial input a prefix of the key of the finished text.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::search::index_key;
+///
+/// assert_eq!(index_key("값"), "ㄱㅏㅂㅅ");
+/// assert!(index_key("갑시다").starts_with(&index_key("값")));
+/// assert!(index_key("과일").starts_with(&index_key("고")));
+/// assert!(!index_key("과일").starts_with(&index_key("기")));
+/// ```
+pub fn index_key(text: &str) -> String {
+    decompose_to_singular_jamo(&normalize_hangul(text))
+        .into_iter()
+        .collect()
+}
+
+/// Finds the parts of `text` matching `query` as the user typed it, for
+/// highlighting search results, returning their byte ranges from left to
+/// right without overlaps.
+///
+/// Each character of `query` matches the same character in `text`, and a
+/// loose consonant also matches any syllable starting with it, so ㅎㄱ
+/// matches 한글 as an initial-consonant (초성) search. The last character
+/// may still be being typed, so it also matches a syllable that it is a
+/// partial form of: 그 matches 글, and 닭 matches the 달기 it turns into
+/// once the next vowel is typed. Double consonants are typed with their own
+/// key, so ㄱ does not match 까. `text` is expected to be precomposed
+/// (NFC); `query` is normalized with `normalize_hangul`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::search::highlight;
+///
+/// let text = "한국어 한글 사전";
+/// assert_eq!(highlight(text, "한글"), [10..16]);
+/// assert_eq!(highlight(text, "한"), [0..3, 10..13]);
+/// assert_eq!(highlight(text, "ㅎㄱ"), [0..6, 10..16]);
+/// assert_eq!(highlight(text, "한그"), [10..16]);
+/// assert_eq!(&text[highlight(text, "사저")[0].clone()], "사전");
+/// ```
+pub fn highlight(text: &str, query: &str) -> Vec<Range<usize>> {
+    let query: Vec<char> = normalize_hangul(query).chars().collect();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match match_at(&chars, i, &query) {
+            Some(end) => {
+                let end_offset = chars.get(end).map_or(text.len(), |&(offset, _)| offset);
+                ranges.push(chars[i].0..end_offset);
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+    ranges
+}
+
+// Returns the index just past the characters of `chars` matched by `query`
+// starting at `start`, or `None` if `query` is empty or does not match.
+fn match_at(chars: &[(usize, char)], start: usize, query: &[char]) -> Option<usize> {
+    let (&last, init) = query.split_last()?;
+    let mut pos = start;
+    for &q in init {
+        let &(_, c) = chars.get(pos)?;
+        if !matches_char(q, c) {
+            return None;
+        }
+        pos += 1;
+    }
+    let &(_, c) = chars.get(pos)?;
+    if matches_char(last, c) {
+        return Some(pos + 1);
+    }
+    // The last character may be a partial syllable. Double consonants have
+    // their own key, so ㄱ is not a partial form of 까
+    let key = keyed_jamo(&last.to_string());
+    let first = keyed_jamo(&c.to_string());
+    if first.starts_with(&key) {
+        return Some(pos + 1);
+    }
+    let &(_, next) = chars.get(pos + 1)?;
+    let both = keyed_jamo(&format!("{c}{next}"));
+    (key.starts_with(&first) && both.starts_with(&key)).then_some(pos + 2)
+}
+
+// Returns whether the query character `q` matches the text character `c`,
+// either exactly or as the initial consonant of a syllable.
+fn matches_char(q: char, c: char) -> bool {
+    q == c || HangulBlock::from_char(c).is_ok_and(|block| block.initial.char_compatibility() == q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_input_is_prefix() {
+        // The states of an input field while typing each word
+        let cases: [(&str, &[&str]); 3] = [
+            ("달기", &["ㄷ", "다", "달", "닭", "달기"]),
+            ("빨리", &["ㅃ", "빠", "빨", "빨ㄹ", "빨리"]),
+            ("왜요", &["ㅇ", "오", "왜", "왱", "왜요"]),
+        ];
+        for (text, inputs) in cases {
+            for input in inputs {
+                assert!(
+                    index_key(text).starts_with(&index_key(input)),
+                    "{input} is not a prefix of {text}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn normalizes_jamo() {
+        assert_eq!(index_key("\u{1112}\u{1161}\u{11AB}"), index_key("한"));
+        assert_eq!(index_key("ㅘ ㄳ"), "ㅗㅏ ㄱㅅ");
+        assert_eq!(index_key("Hangul 2"), "Hangul 2");
+    }
+
+    #[test]
+    fn highlights() {
+        assert!(highlight("한글", "").is_empty());
+        assert!(highlight("", "한").is_empty());
+        assert_eq!(highlight("달기 닭", "닭"), [0..6, 7..10]);
+        // 과 is typed through 고
+        assert_eq!(highlight("과일 고기", "고"), [0..3, 7..10]);
+        assert_eq!(highlight("Rust 한글, Rust 한", "Rust ㅎ"), [0..8, 13..21]);
+        assert_eq!(highlight("ㅎㅎ 하하", "ㅎ"), [0..3, 3..6, 7..10, 10..13]);
+        // ㄲ is not typed through ㄱ
+        assert!(highlight("까치", "ㄱ").is_empty());
+        assert!(highlight("까치", "가").is_empty());
+        assert_eq!(highlight("까치 ㄲ", "ㄲ"), [0..3, 7..10]);
+        assert_eq!(highlight("닦기 다", "다"), [0..3, 7..10]);
+        // The last character is not completed by a syllable after a space
+        assert!(highlight("달 기", "닭").is_empty());
+    }
+}
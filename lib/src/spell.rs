@@ -0,0 +1,101 @@
+use crate::{
+    keyboard::DubeolsikCost,
+    lexicon::Lexicon,
+    string::{CostModel, jamo_distance_with_cost},
+};
+
+/// A spelling correction returned by `suggest`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    /// The suggested word from the lexicon.
+    pub word: String,
+
+    /// The jamo-level edit distance from the misspelled word.
+    pub distance: f32,
+}
+
+/// Suggests corrections for `word` from `lexicon`, ranked from closest to
+/// furthest. Words are compared with `string::jamo_distance_with_cost`
+/// using `DubeolsikCost`, so typos between neighbouring keys on a Dubeolsik
+/// keyboard rank higher, and only words within `max_distance` are
+/// returned. Words at the same distance keep the lexicon's order. If `word`
+/// is in the lexicon, it is returned first with a distance of `0.0`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::lexicon::JamoTrie;
+/// use hangul_cd::spell::suggest;
+///
+/// let lexicon: JamoTrie = ["감사", "검사", "강사", "한국"].into_iter().collect();
+/// let suggestions = suggest("갑사", &lexicon, 1.0);
+/// let words: Vec<&str> = suggestions.iter().map(|s| s.word.as_str()).collect();
+/// assert_eq!(words, ["감사", "검사", "강사"]);
+/// // ㅁ and ㅂ are neighbouring keys
+/// assert_eq!(suggestions[0].distance, 0.5);
+/// ```
+pub fn suggest<L: Lexicon + ?Sized>(word: &str, lexicon: &L, max_distance: f32) -> Vec<Suggestion> {
+    suggest_with_cost(word, lexicon, max_distance, &DubeolsikCost::default())
+}
+
+/// Suggests corrections for `word` from `lexicon` like `suggest`, pricing
+/// edits with `costs` instead of `DubeolsikCost`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::lexicon::JamoTrie;
+/// use hangul_cd::spell::suggest_with_cost;
+/// use hangul_cd::string::UniformCost;
+///
+/// let lexicon: JamoTrie = ["감사", "검사", "강사"].into_iter().collect();
+/// let suggestions = suggest_with_cost("감소", &lexicon, 1.0, &UniformCost);
+/// assert_eq!(suggestions.len(), 1);
+/// assert_eq!(suggestions[0].word, "감사");
+/// assert_eq!(suggestions[0].distance, 1.0);
+/// ```
+pub fn suggest_with_cost<L: Lexicon + ?Sized, C: CostModel + ?Sized>(
+    word: &str,
+    lexicon: &L,
+    max_distance: f32,
+    costs: &C,
+) -> Vec<Suggestion> {
+    let mut suggestions: Vec<Suggestion> = lexicon
+        .words()
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = jamo_distance_with_cost(word, candidate, costs);
+            (distance <= max_distance).then(|| Suggestion {
+                word: candidate.to_string(),
+                distance,
+            })
+        })
+        .collect();
+    suggestions.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexicon::JamoTrie;
+
+    #[test]
+    fn suggestions_are_ranked_by_distance() {
+        let lexicon: JamoTrie = ["한국어", "한국", "한글", "항구"].into_iter().collect();
+        let suggestions = suggest("한국", &lexicon, 2.0);
+        let words: Vec<&str> = suggestions.iter().map(|s| s.word.as_str()).collect();
+        assert_eq!(words, ["한국", "한글", "항구", "한국어"]);
+        assert_eq!(suggestions[0].distance, 0.0);
+        assert!(
+            suggestions
+                .windows(2)
+                .all(|w| w[0].distance <= w[1].distance)
+        );
+    }
+
+    #[test]
+    fn no_suggestions_beyond_max_distance() {
+        let lexicon: JamoTrie = ["사과", "바나나"].into_iter().collect();
+        assert!(suggest("포도", &lexicon, 1.0).is_empty());
+        assert!(suggest("포도", &JamoTrie::new(), 10.0).is_empty());
+    }
+}
@@ -1,7 +1,20 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::ops::{Bound, RangeBounds};
+use std::sync::OnceLock;
+
 use thiserror::Error;
 
 use crate::{
-    jamo::{Jamo, JamoPosition},
+    block::{self, BlockError, HangulBlock, HangulBlockDecompositionOptions, SyllablePattern},
+    jamo::{
+        Character, Choseong, Jamo, JamoPosition, JamoUnicodeType, Jongseong, Jungseong, L_BASE,
+        N_COUNT, S_BASE, S_COUNT, T_BASE, T_COUNT, V_BASE, modern_to_compatibility_jamo,
+    },
+    lexicon::{Lexicon, has_jamo_prefix},
+    normalize,
+    typing::keyed_jamo,
     word::*,
 };
 
@@ -11,6 +24,142 @@ pub enum StringError {
     /// Occurs when there is an error related to word composition.
     #[error("Word error: {0}")]
     WordError(#[from] WordError),
+
+    /// Occurs when there is an error related to a syllable block.
+    #[error("Block error: {0}")]
+    BlockError(#[from] BlockError),
+
+    /// Occurs in `ComposerMode::Strict` when a Hangul character cannot be
+    /// composed into the current word.
+    #[error("Character '{0}' cannot be composed into a valid Hangul syllable")]
+    InvalidHangul(char),
+
+    /// Occurs when a deserialized `TextComposer` has a cursor past the end
+    /// of its text or inside a character, or when
+    /// `TextComposer::replace_range` is given a range that starts after it
+    /// ends.
+    #[error("Cursor {0} is not at a character boundary of the text")]
+    InvalidCursor(usize),
+}
+
+/// Determines how a `StringComposer` handles Hangul characters that cannot
+/// be composed into a valid syllable, such as a vowel with no preceding
+/// consonant.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComposerMode {
+    /// Returns `StringError::InvalidHangul` and leaves the composer
+    /// unchanged.
+    Strict,
+
+    /// Commits the current word and starts a new word with the character,
+    /// or appends it as a standalone jamo if it cannot start one either.
+    #[default]
+    Lenient,
+
+    /// Like `Lenient`, but appends U+FFFD REPLACEMENT CHARACTER in place of
+    /// a character that cannot start a word.
+    Replace,
+}
+
+/// Options for configuring a `StringComposer`, created with
+/// `ComposerOptions::new` and passed to `StringComposer::with_options`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::{ComposerMode, ComposerOptions, StringComposer, StringError};
+///
+/// let options = ComposerOptions::new().with_mode(ComposerMode::Strict);
+/// let mut composer = StringComposer::with_options(options);
+/// assert_eq!(composer.push_char('ㅏ'), Err(StringError::InvalidHangul('ㅏ')));
+///
+/// let options = ComposerOptions::new().with_mode(ComposerMode::Replace);
+/// let mut composer = StringComposer::with_options(options);
+/// composer.push_char('ㄱ').unwrap();
+/// composer.push_char('ㅏ').unwrap();
+/// composer.push_char('ㅏ').unwrap();
+/// assert_eq!(composer.as_string().unwrap(), "가\u{FFFD}");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComposerOptions {
+    /// How invalid Hangul input is handled. Defaults to
+    /// `ComposerMode::Lenient`.
+    pub mode: ComposerMode,
+
+    /// Whether text passed to `StringComposer::feed_str` or
+    /// `compose_with_options` is normalized with `normalize_hangul` before
+    /// composition, so decomposed (NFD) syllables are treated the same as
+    /// precomposed ones. Defaults to `false`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub normalize: bool,
+
+    /// Whether a `TextComposer` reopens the committed Hangul syllable
+    /// before the cursor when a jamo is typed after it, so that typing ㄴ
+    /// after 가 gives 간 and typing ㅏ after 간 gives 가나. Ignored by
+    /// `StringComposer`. Defaults to `false`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reopen_syllables: bool,
+
+    /// Whether a consonant typed twice in a row is doubled, as on mobile
+    /// keypads; see `HangulWordComposer::with_double_tap`. Defaults to
+    /// `false`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub double_tap: bool,
+}
+
+impl ComposerOptions {
+    /// Creates `ComposerOptions` with the default settings.
+    pub fn new() -> Self {
+        Self {
+            mode: ComposerMode::default(),
+            normalize: false,
+            reopen_syllables: false,
+            double_tap: false,
+        }
+    }
+
+    /// Sets how invalid Hangul input is handled.
+    pub fn with_mode(mut self, mode: ComposerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether string input is normalized to precomposed syllables
+    /// before composition.
+    pub fn with_normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Sets whether a `TextComposer` reopens the syllable before the cursor
+    /// when a jamo is typed after it.
+    pub fn with_reopen_syllables(mut self, reopen_syllables: bool) -> Self {
+        self.reopen_syllables = reopen_syllables;
+        self
+    }
+
+    /// Sets whether a consonant typed twice in a row is doubled, as on
+    /// mobile keypads. Use `KeyboardLayout::double_tap` to match a layout.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::{ComposerOptions, StringComposer};
+    ///
+    /// let options = ComposerOptions::new().with_double_tap(true);
+    /// let mut composer = StringComposer::with_options(options);
+    /// composer.feed_str("ㅇㅏㅂㅂㅏ ㄱㅏㅂㅂㅏ").unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "아빠 가빠");
+    /// ```
+    pub fn with_double_tap(mut self, double_tap: bool) -> Self {
+        self.double_tap = double_tap;
+        self
+    }
+}
+
+// Returns an empty word composer configured by `options`.
+fn word_composer(options: &ComposerOptions) -> HangulWordComposer {
+    HangulWordComposer::new().with_double_tap(options.double_tap)
 }
 
 /// A composer struct that manages the composition of strings of text
@@ -60,9 +209,36 @@ pub enum StringError {
 /// assert_eq!(composer.as_string().unwrap(), "바".to_string());
 /// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringComposer {
     completed: String,
     current: HangulWordComposer,
+    #[cfg_attr(feature = "serde", serde(default))]
+    options: ComposerOptions,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    undo_stack: Vec<ComposerSnapshot>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    redo_stack: Vec<ComposerSnapshot>,
+}
+
+/// A saved copy of a `StringComposer`'s text and composition state,
+/// returned by `StringComposer::state` and used by its undo history.
+/// Restoring a snapshot with `StringComposer::restore` resumes composition
+/// exactly where it left off, including any incomplete syllable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComposerSnapshot {
+    completed: String,
+    current: HangulWordComposer,
+}
+
+impl ComposerSnapshot {
+    /// Returns the composed string as it was when the snapshot was taken.
+    pub fn as_string(&self) -> Result<String, StringError> {
+        let mut result = self.completed.clone();
+        self.current.push_to(&mut result)?;
+        Ok(result)
+    }
 }
 
 impl Default for StringComposer {
@@ -74,28 +250,85 @@ impl Default for StringComposer {
 impl StringComposer {
     /// Creates a new, empty `StringComposer`.
     pub fn new() -> Self {
+        Self::with_options(ComposerOptions::new())
+    }
+
+    /// Creates a new, empty `StringComposer` configured by `options`.
+    pub fn with_options(options: ComposerOptions) -> Self {
         Self {
             completed: String::new(),
-            current: HangulWordComposer::new(),
+            current: word_composer(&options),
+            options,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
         }
     }
 
+    /// Returns the options this composer was created with.
+    pub fn options(&self) -> &ComposerOptions {
+        &self.options
+    }
+
     /// Pushes a character to the `StringComposer`.
     ///
     /// If the character is part of a Hangul word, it will be composed into syllables.
-    /// Otherwise, it will be added directly to the completed string.
+    /// Otherwise, it will be added directly to the completed string. Hangul
+    /// characters that cannot be composed are handled according to the
+    /// composer's `ComposerMode`.
     pub fn push_char(&mut self, c: char) -> Result<(), StringError> {
         match self.current.push_char(c)? {
             WordPushResult::Continue => Ok(()),
-            _ => self.handle_invalid_input(c),
+            WordPushResult::NonHangul => self.handle_invalid_input(c),
+            // The character may still start a word of its own
+            WordPushResult::InvalidHangul
+                if self.options.mode != ComposerMode::Strict && self.is_composing() =>
+            {
+                self.current.push_to(&mut self.completed)?;
+                self.current = word_composer(&self.options);
+                self.push_char(c)
+            }
+            WordPushResult::InvalidHangul => match self.options.mode {
+                ComposerMode::Strict => Err(StringError::InvalidHangul(c)),
+                ComposerMode::Lenient => self.handle_invalid_input(c),
+                ComposerMode::Replace => self.handle_invalid_input(char::REPLACEMENT_CHARACTER),
+            },
+        }
+    }
+
+    /// Pushes each character of `s` in order, stopping at the first error.
+    /// Any input, including arbitrary non-Hangul text and jamo in any order,
+    /// is handled without panicking. If the composer's options enable
+    /// normalization, `s` is first normalized with `normalize_hangul`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::StringComposer;
+    ///
+    /// let mut composer = StringComposer::new();
+    /// composer.feed_str("ㅎㅏㄴㄱㅡㄹ, ok").unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "한글, ok");
+    /// ```
+    pub fn feed_str(&mut self, s: &str) -> Result<(), StringError> {
+        let s = if self.options.normalize {
+            normalize_hangul(s)
+        } else {
+            Cow::Borrowed(s)
+        };
+        for c in s.chars() {
+            self.push_char(c)?;
         }
+        Ok(())
+    }
+
+    /// Returns whether a Hangul word is being composed.
+    pub fn is_composing(&self) -> bool {
+        self.current != word_composer(&self.options)
     }
 
     /// Returns the composed string, combining completed text and the current word.
     pub fn as_string(&self) -> Result<String, StringError> {
         let mut result = self.completed.clone();
-        let current_string = self.current.as_string()?;
-        result.push_str(&current_string);
+        self.current.push_to(&mut result)?;
         Ok(result)
     }
 
@@ -118,74 +351,3267 @@ impl StringComposer {
         }
     }
 
+    /// Returns a snapshot of the composer's current text and composition
+    /// state.
+    pub fn state(&self) -> ComposerSnapshot {
+        ComposerSnapshot {
+            completed: self.completed.clone(),
+            current: self.current.clone(),
+        }
+    }
+
+    /// Replaces the composer's text and composition state with `snapshot`.
+    /// The undo history is left untouched.
+    pub fn restore(&mut self, snapshot: &ComposerSnapshot) {
+        self.completed = snapshot.completed.clone();
+        self.current = snapshot.current.clone();
+    }
+
+    /// Saves the current state to the undo history and clears the redo
+    /// history.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::StringComposer;
+    ///
+    /// let mut composer = StringComposer::new();
+    /// for c in "ㅎㅏㄴ".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// composer.checkpoint();
+    /// for c in "ㄱㅡㄹ".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "한글");
+    ///
+    /// assert!(composer.undo());
+    /// assert_eq!(composer.as_string().unwrap(), "한");
+    ///
+    /// // The restored syllable is still open for composition
+    /// composer.push_char('ㅈ').unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "핝");
+    /// composer.pop().unwrap();
+    ///
+    /// assert!(composer.redo());
+    /// assert_eq!(composer.as_string().unwrap(), "한글");
+    /// assert!(!composer.redo());
+    /// ```
+    pub fn checkpoint(&mut self) {
+        self.undo_stack.push(self.state());
+        self.redo_stack.clear();
+    }
+
+    /// Restores the most recent checkpoint, saving the current state so it
+    /// can be restored with `redo`. Returns `false` if there is nothing to
+    /// undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop() {
+            Some(snapshot) => {
+                self.redo_stack.push(self.state());
+                self.restore(&snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reverts the most recent `undo`. Returns `false` if there is nothing
+    /// to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                self.undo_stack.push(self.state());
+                self.restore(&snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
     fn handle_invalid_input(&mut self, c: char) -> Result<(), StringError> {
-        let current_string = self.current.as_string()?;
-        self.completed.push_str(&current_string);
+        self.current.push_to(&mut self.completed)?;
         self.completed.push(c);
-        self.current = HangulWordComposer::new();
+        self.current = word_composer(&self.options);
         Ok(())
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+/// A composer for editing multi-word text with a cursor, such as the
+/// contents of an input field.
+///
+/// Jamo are composed into the word at the cursor, shown in place as they
+/// are typed (the preedit). Spaces, punctuation, and other non-Hangul
+/// characters end the word being composed: the word is committed to the
+/// text and the character is inserted after it. Hangul characters that
+/// cannot be composed are handled according to the composer's
+/// `ComposerMode`, as in a `StringComposer`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::TextComposer;
+///
+/// let mut composer = TextComposer::new();
+/// composer.feed_str("ㅇㅏㄴㄴㅕㅇ, ㅎㅏㄴㄱㅡㄹ").unwrap();
+/// assert_eq!(composer.as_string().unwrap(), "안녕, 한글");
+/// assert_eq!(composer.preedit().unwrap(), "한글");
+/// assert_eq!(composer.cursor(), 6);
+///
+/// // Backspace removes jamo from the word being composed
+/// composer.pop().unwrap();
+/// assert_eq!(composer.as_string().unwrap(), "안녕, 한그");
+///
+/// // Committed text is removed a character at a time
+/// composer.commit().unwrap();
+/// composer.pop().unwrap();
+/// assert_eq!(composer.as_string().unwrap(), "안녕, 한");
+/// assert!(!composer.is_composing());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TextComposerFields")
+)]
+pub struct TextComposer {
+    text: String,
+    // The byte offset in `text` at which the preedit is shown
+    cursor: usize,
+    current: HangulWordComposer,
+    #[cfg_attr(feature = "serde", serde(default))]
+    options: ComposerOptions,
+}
 
-    #[test]
-    fn test_no_new_words() {
-        let input = "ㅎㅏㄴㄱㅡㄹ";
-        let mut composer = StringComposer::new();
-        for c in input.chars() {
-            composer.push_char(c).unwrap();
+// The fields of a deserialized `TextComposer`, before its cursor is checked
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TextComposerFields {
+    text: String,
+    cursor: usize,
+    current: HangulWordComposer,
+    #[serde(default)]
+    options: ComposerOptions,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TextComposerFields> for TextComposer {
+    type Error = StringError;
+
+    fn try_from(fields: TextComposerFields) -> Result<Self, Self::Error> {
+        if !fields.text.is_char_boundary(fields.cursor) {
+            return Err(StringError::InvalidCursor(fields.cursor));
         }
-        let result = composer.as_string().unwrap();
-        assert_eq!(result, "한글".to_string());
+        Ok(TextComposer {
+            text: fields.text,
+            cursor: fields.cursor,
+            current: fields.current,
+            options: fields.options,
+        })
     }
+}
 
-    #[test]
-    fn test_new_hangul_word() {
-        let input = "ㅎㅏㄴㄱㅡㄹ ㅇㅏㄴㄴㅕㅇㅎㅏㅅㅔㅇㅛ";
-        let mut composer = StringComposer::new();
-        for c in input.chars() {
-            composer.push_char(c).unwrap();
+impl Default for TextComposer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextComposer {
+    /// Creates a new, empty `TextComposer`.
+    pub fn new() -> Self {
+        Self::with_options(ComposerOptions::new())
+    }
+
+    /// Creates a new, empty `TextComposer` configured by `options`.
+    pub fn with_options(options: ComposerOptions) -> Self {
+        Self {
+            text: String::new(),
+            cursor: 0,
+            current: word_composer(&options),
+            options,
         }
-        let result = composer.as_string().unwrap();
-        assert_eq!(result, "한글 안녕하세요".to_string());
     }
 
-    #[test]
-    fn test_new_non_hangul_word() {
-        let input = "ㅎㅏㄴㄱㅡㄹ beans";
-        let mut composer = StringComposer::new();
-        for c in input.chars() {
-            composer.push_char(c).unwrap();
+    /// Returns the options this composer was created with.
+    pub fn options(&self) -> &ComposerOptions {
+        &self.options
+    }
+
+    /// Types a character at the cursor. Jamo are composed into the word
+    /// being composed; any other character commits the word and is
+    /// inserted after it. If the composer's options enable reopening
+    /// syllables, a jamo typed directly after a committed Hangul syllable
+    /// is composed into it.
+    pub fn push_char(&mut self, c: char) -> Result<(), StringError> {
+        if self.options.reopen_syllables && !self.is_composing() && self.reopen(c)? {
+            return Ok(());
+        }
+        match self.current.push_char(c)? {
+            WordPushResult::Continue => Ok(()),
+            WordPushResult::NonHangul => self.commit_and_insert(c),
+            // The character may still start a word of its own
+            WordPushResult::InvalidHangul
+                if self.options.mode != ComposerMode::Strict && self.is_composing() =>
+            {
+                self.commit()?;
+                self.push_char(c)
+            }
+            WordPushResult::InvalidHangul => match self.options.mode {
+                ComposerMode::Strict => Err(StringError::InvalidHangul(c)),
+                ComposerMode::Lenient => self.commit_and_insert(c),
+                ComposerMode::Replace => self.commit_and_insert(char::REPLACEMENT_CHARACTER),
+            },
         }
-        let result = composer.as_string().unwrap();
-        assert_eq!(result, "한글 beans".to_string());
     }
 
-    #[test]
-    fn test_multiple_words() {
-        let input = "ㅎㅏㄴㄱㅡㄹ 123  \n ㅇㅏㄴㄴㅕㅇ!";
-        let mut composer = StringComposer::new();
-        for c in input.chars() {
-            composer.push_char(c).unwrap();
+    /// Types each character of `s` in order, stopping at the first error.
+    /// If the composer's options enable normalization, `s` is first
+    /// normalized with `normalize_hangul`.
+    pub fn feed_str(&mut self, s: &str) -> Result<(), StringError> {
+        let s = if self.options.normalize {
+            normalize_hangul(s)
+        } else {
+            Cow::Borrowed(s)
+        };
+        for c in s.chars() {
+            self.push_char(c)?;
         }
-        let result = composer.as_string().unwrap();
-        assert_eq!(result, "한글 123  \n 안녕!".to_string());
+        Ok(())
     }
 
-    #[test]
-    fn test_backspace() {
-        let input = "ㅇㅏㄴㄴㅕㅇ ㄹㅏㅁㅕㄴ";
-        let mut composer = StringComposer::new();
-        for c in input.chars() {
-            composer.push_char(c).unwrap();
+    /// Deletes backwards from the cursor, like the backspace key, and
+    /// returns what was deleted. While a word is being composed, its last
+    /// jamo is removed; otherwise the whole character before the cursor is.
+    pub fn pop(&mut self) -> Result<Option<char>, StringError> {
+        if self.is_composing() {
+            return Ok(self.current.pop()?.map(|jamo| jamo.char_compatibility()));
         }
-        for _ in 0..7 {
-            composer.pop().unwrap();
+        match self.text[..self.cursor].chars().next_back() {
+            Some(c) => {
+                self.cursor -= c.len_utf8();
+                self.text.remove(self.cursor);
+                Ok(Some(c))
+            }
+            None => Ok(None),
         }
-        let result = composer.as_string().unwrap();
-        assert_eq!(result, "안".to_string());
+    }
+
+    /// Commits the word being composed to the text, leaving the cursor
+    /// after it.
+    pub fn commit(&mut self) -> Result<(), StringError> {
+        let word = self.preedit()?;
+        self.text.insert_str(self.cursor, &word);
+        self.cursor += word.len();
+        self.current = word_composer(&self.options);
+        Ok(())
+    }
+
+    /// Returns whether a word is being composed.
+    pub fn is_composing(&self) -> bool {
+        self.current != word_composer(&self.options)
+    }
+
+    /// Returns the word being composed, or an empty string if there is
+    /// none. Jamo that are not part of a complete syllable are shown as
+    /// compatibility jamo, as they are when committed.
+    pub fn preedit(&self) -> Result<String, StringError> {
+        Ok(self
+            .current
+            .as_string()?
+            .chars()
+            .map(modern_to_compatibility_jamo)
+            .collect())
+    }
+
+    /// Returns the full text, including the word being composed.
+    pub fn as_string(&self) -> Result<String, StringError> {
+        let mut result = String::with_capacity(self.text.len() + 8);
+        result.push_str(&self.text[..self.cursor]);
+        result.push_str(&self.preedit()?);
+        result.push_str(&self.text[self.cursor..]);
+        Ok(result)
+    }
+
+    /// Returns the position of the cursor in `as_string`, in characters.
+    /// While a word is being composed, the cursor is after it.
+    pub fn cursor(&self) -> usize {
+        let preedit_len = self.preedit().map_or(0, |s| s.chars().count());
+        self.text[..self.cursor].chars().count() + preedit_len
+    }
+
+    /// Commits the word being composed and moves the cursor one character
+    /// to the left, if it is not at the start of the text.
+    pub fn move_left(&mut self) -> Result<(), StringError> {
+        self.commit()?;
+        if let Some(c) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+        }
+        Ok(())
+    }
+
+    /// Commits the word being composed and moves the cursor one character
+    /// to the right, if it is not at the end of the text.
+    pub fn move_right(&mut self) -> Result<(), StringError> {
+        self.commit()?;
+        if let Some(c) = self.text[self.cursor..].chars().next() {
+            self.cursor += c.len_utf8();
+        }
+        Ok(())
+    }
+
+    /// Commits the word being composed and moves the cursor to `position`,
+    /// in characters, or to the end of the text if `position` is past it.
+    /// Typing then composes a new word at the cursor.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::TextComposer;
+    ///
+    /// let mut composer = TextComposer::new();
+    /// composer.feed_str("ㅎㅏㄴㄱㅡㄹ").unwrap();
+    /// composer.set_cursor(1).unwrap();
+    /// composer.feed_str("ㄱㅜㄱ").unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "한국글");
+    /// assert_eq!(composer.cursor(), 2);
+    /// ```
+    pub fn set_cursor(&mut self, position: usize) -> Result<(), StringError> {
+        self.commit()?;
+        self.cursor = self.byte_offset(position);
+        Ok(())
+    }
+
+    /// Deletes forwards from the cursor, like the delete key, and returns
+    /// the deleted character. The word being composed is committed first.
+    pub fn delete(&mut self) -> Result<Option<char>, StringError> {
+        self.commit()?;
+        if self.cursor == self.text.len() {
+            return Ok(None);
+        }
+        Ok(Some(self.text.remove(self.cursor)))
+    }
+
+    /// Removes the character at `position`, in characters, and returns it,
+    /// keeping the cursor next to the same text. The word being composed
+    /// is committed first.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::TextComposer;
+    ///
+    /// let mut composer = TextComposer::new();
+    /// composer.feed_str("ㄱㅏ ㄴㅏ").unwrap();
+    /// assert_eq!(composer.remove(1).unwrap(), Some(' '));
+    /// assert_eq!(composer.as_string().unwrap(), "가나");
+    /// assert_eq!(composer.cursor(), 2);
+    /// assert_eq!(composer.remove(5).unwrap(), None);
+    /// ```
+    pub fn remove(&mut self, position: usize) -> Result<Option<char>, StringError> {
+        self.commit()?;
+        let offset = self.byte_offset(position);
+        if offset == self.text.len() {
+            return Ok(None);
+        }
+        let c = self.text.remove(offset);
+        if offset < self.cursor {
+            self.cursor -= c.len_utf8();
+        }
+        Ok(Some(c))
+    }
+
+    /// Replaces the characters in `range`, in characters, with
+    /// `replacement`, and leaves the cursor after it. The word being
+    /// composed is committed first. A range ending past the end of the text
+    /// is clamped to it, so `5..` or `5..100` on a text of 3 characters
+    /// appends `replacement` at the end. Returns
+    /// `StringError::InvalidCursor` with the start, leaving the composer
+    /// unchanged, if the range starts after it ends.
+    ///
+    /// The replacement is typed at the start of the range, as `feed_str`
+    /// does, so its jamo are composed into syllables and, if the composer's
+    /// options enable reopening syllables, merged with the syllable before
+    /// the range. The result is then committed.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::{ComposerOptions, TextComposer};
+    ///
+    /// let mut composer = TextComposer::new();
+    /// composer.feed_str("ㅎㅏㄴㄱㅡㄹ ㅈㅏ").unwrap();
+    /// composer.replace_range(3.., "ㅁㅜㄴㅈㅏ").unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "한글 문자");
+    ///
+    /// let options = ComposerOptions::new().with_reopen_syllables(true);
+    /// let mut composer = TextComposer::with_options(options);
+    /// composer.feed_str("ㄱㅏㄴ ㄷㅏ").unwrap();
+    /// composer.replace_range(1..2, "ㅏ").unwrap();
+    /// assert_eq!(composer.as_string().unwrap(), "가나다");
+    /// assert_eq!(composer.cursor(), 2);
+    /// ```
+    pub fn replace_range<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        replacement: &str,
+    ) -> Result<(), StringError> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => usize::MAX,
+        };
+        if start > end {
+            return Err(StringError::InvalidCursor(start));
+        }
+        self.commit()?;
+        let start = self.byte_offset(start);
+        let end = self.byte_offset(end);
+        self.text.replace_range(start..end, "");
+        self.cursor = start;
+        self.feed_str(replacement)?;
+        self.commit()
+    }
+
+    // Returns the byte offset in the committed text of the character at
+    // `position`, or the length of the text if `position` is past its end.
+    fn byte_offset(&self, position: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(position)
+            .map_or(self.text.len(), |(i, _)| i)
+    }
+
+    // Reopens the Hangul syllable or jamo before the cursor and composes
+    // `c` into it. Returns `false`, leaving the composer unchanged, if there
+    // is no Hangul before the cursor or `c` cannot be composed after it.
+    fn reopen(&mut self, c: char) -> Result<bool, StringError> {
+        let Some(previous) = self.text[..self.cursor].chars().next_back() else {
+            return Ok(false);
+        };
+        let jamo = match (
+            HangulBlock::from_char(previous),
+            Character::from_char(previous),
+        ) {
+            (Ok(block), _) => [
+                Some(block.initial.into()),
+                Some(block.vowel.into()),
+                block.final_optional.map(Jamo::from),
+            ],
+            (_, Ok(Character::Hangul(jamo))) => [Some(jamo), None, None],
+            _ => return Ok(false),
+        };
+        let mut word = word_composer(&self.options);
+        for jamo in jamo.iter().flatten() {
+            if word.push(jamo) != Ok(WordPushResult::Continue) {
+                return Ok(false);
+            }
+        }
+        if word.push_char(c) != Ok(WordPushResult::Continue) {
+            return Ok(false);
+        }
+        self.cursor -= previous.len_utf8();
+        self.text.remove(self.cursor);
+        self.current = word;
+        Ok(true)
+    }
+
+    fn commit_and_insert(&mut self, c: char) -> Result<(), StringError> {
+        self.commit()?;
+        self.text.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+        Ok(())
+    }
+}
+
+/// Composes every run of loose jamo in `text` into Hangul syllables, passing
+/// non-Hangul characters and already-composed syllables through untouched.
+/// Each character is pushed through a `StringComposer`, so runs are
+/// composed exactly as if they had been typed. Jamo that cannot be composed
+/// into a syllable are returned as compatibility jamo, so this never fails.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::compose;
+///
+/// assert_eq!(compose("ㅇㅏㄴㄴㅕㅇ world ㅎㅏ"), "안녕 world 하");
+/// assert_eq!(compose("ㄱㅏ1ㄴㅏ"), "가1나");
+/// ```
+pub fn compose(text: &str) -> String {
+    // Lenient composition passes through anything it cannot compose
+    compose_with_options(text, &ComposerOptions::new()).expect("lenient composition does not fail")
+}
+
+/// Composes every run of loose jamo in `text` like `compose`, using a
+/// `StringComposer` configured by `options`. Returns
+/// `StringError::InvalidHangul` for the first jamo that cannot be composed
+/// if `options` use `ComposerMode::Strict`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::{ComposerOptions, compose, compose_with_options};
+///
+/// // 가 followed by a conjoining final ㄱ, as in NFD text
+/// let text = "가\u{11A8}ㅇㅣ";
+/// assert_eq!(compose(text), "가ㄱ이");
+///
+/// let options = ComposerOptions::new().with_normalize(true);
+/// assert_eq!(compose_with_options(text, &options).unwrap(), "각이");
+/// ```
+pub fn compose_with_options(text: &str, options: &ComposerOptions) -> Result<String, StringError> {
+    let mut composer = StringComposer::with_options(*options);
+    composer.feed_str(text)?;
+    Ok(composer
+        .as_string()?
+        .chars()
+        .map(modern_to_compatibility_jamo)
+        .collect())
+}
+
+/// Normalizes the Hangul in `text` to NFC: runs of conjoining jamo that
+/// form a modern syllable (an initial and a vowel, optionally followed by a
+/// final) are replaced by the precomposed syllable, and a precomposed
+/// syllable without a final followed by a conjoining final is combined
+/// with it. All other characters are left unchanged, and `text` is
+/// borrowed if it contains no conjoining jamo.
+///
+/// Text from macOS file names and some input methods is often decomposed
+/// (NFD), which this converts into the precomposed form expected by the
+/// rest of the crate. This is the same as `normalize::to_nfc`; see the
+/// `normalize` module for the other normalization forms.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::normalize_hangul;
+///
+/// let nfd = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}.txt";
+/// assert_eq!(normalize_hangul(nfd), "한글.txt");
+/// assert_eq!(normalize_hangul("가\u{11A8}"), "각");
+/// ```
+pub fn normalize_hangul(text: &str) -> Cow<'_, str> {
+    normalize::to_nfc(text)
+}
+
+/// Decomposes every Hangul syllable in `text` into compatibility jamo,
+/// passing all other characters through untouched. Composite jamo such as
+/// ㅘ are kept whole; use `decompose_with_options` to split them or to emit
+/// modern (conjoining) jamo instead. Every modern syllable has compatibility
+/// jamo, so this never fails.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::decompose;
+///
+/// assert_eq!(decompose("안녕 world"), "ㅇㅏㄴㄴㅕㅇ world");
+/// assert_eq!(decompose("과"), "ㄱㅘ");
+/// ```
+pub fn decompose(text: &str) -> String {
+    decompose_with_options(
+        text,
+        &HangulBlockDecompositionOptions {
+            decompose_composites: false,
+            jamo_era: JamoUnicodeType::Compatibility,
+        },
+    )
+    .expect("modern syllables decompose into compatibility jamo")
+}
+
+/// Decomposes every Hangul syllable in `text` into jamo according to
+/// `options`, passing all other characters through untouched. The text is
+/// normalized with `normalize_hangul` first, so syllables written as
+/// conjoining jamo (NFD) are decomposed the same way as precomposed ones.
+/// Returns an error only if `options.jamo_era` is neither
+/// `JamoUnicodeType::Modern` nor `JamoUnicodeType::Compatibility`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::HangulBlockDecompositionOptions;
+/// use hangul_cd::jamo::JamoUnicodeType;
+/// use hangul_cd::string::decompose_with_options;
+///
+/// let options = HangulBlockDecompositionOptions {
+///     decompose_composites: true,
+///     jamo_era: JamoUnicodeType::Modern,
+/// };
+/// assert_eq!(
+///     decompose_with_options("값", &options).unwrap(),
+///     "\u{1100}\u{1161}\u{11B8}\u{11BA}"
+/// );
+/// ```
+pub fn decompose_with_options(
+    text: &str,
+    options: &HangulBlockDecompositionOptions,
+) -> Result<String, StringError> {
+    let mut result = String::with_capacity(text.len() * 3);
+    for c in normalize_hangul(text).chars() {
+        match HangulBlock::from_char(c) {
+            Ok(block) => result.extend(block.decomposed_vec(options)?),
+            Err(_) => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+/// Converts `text` to its initial consonants (초성체), replacing each
+/// precomposed syllable with the compatibility jamo of its initial
+/// consonant. All other characters are kept.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::to_chosung;
+///
+/// assert_eq!(to_chosung("감사합니다"), "ㄱㅅㅎㄴㄷ");
+/// assert_eq!(to_chosung("ㅋㅋ 진짜?"), "ㅋㅋ ㅈㅉ?");
+/// ```
+pub fn to_chosung(text: &str) -> String {
+    map_syllable_jamo(text, |initial, _, _| {
+        Choseong::from_index(initial).map(|j| j.char_compatibility())
+    })
+}
+
+/// Converts `text` to its vowels, replacing each precomposed syllable with
+/// the compatibility jamo of its vowel. All other characters are kept.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::to_jungsung;
+///
+/// assert_eq!(to_jungsung("감사합니다"), "ㅏㅏㅏㅣㅏ");
+/// assert_eq!(to_jungsung("뭐 해?"), "ㅝ ㅐ?");
+/// ```
+pub fn to_jungsung(text: &str) -> String {
+    map_syllable_jamo(text, |_, vowel, _| {
+        Jungseong::from_index(vowel).map(|j| j.char_compatibility())
+    })
+}
+
+/// Converts `text` to its final consonants, replacing each precomposed
+/// syllable with the compatibility jamo of its final consonant, or removing
+/// it if it has none. All other characters are kept.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::to_jongsung;
+///
+/// assert_eq!(to_jongsung("감사합니다"), "ㅁㅂ");
+/// assert_eq!(to_jongsung("닭 값!"), "ㄺ ㅄ!");
+/// ```
+pub fn to_jongsung(text: &str) -> String {
+    map_syllable_jamo(text, |_, _, final_index| {
+        Jongseong::from_index(final_index).map(|j| j.char_compatibility())
+    })
+}
+
+/// A ranked list of words used to expand initial-consonant abbreviations
+/// (초성체) such as ㄱㅅ. Words inserted earlier rank higher, so apps can
+/// plug in their own wordlists ordered by frequency.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::ChosungDictionary;
+///
+/// let mut dictionary: ChosungDictionary = ["감사", "고생", "김수"].into_iter().collect();
+/// dictionary.insert("가상");
+/// assert_eq!(dictionary.expand("ㄱㅅ"), ["감사", "고생", "김수", "가상"]);
+/// assert_eq!(dictionary.expand("ㄱ생"), ["고생"]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChosungDictionary {
+    words: Vec<String>,
+}
+
+impl ChosungDictionary {
+    /// Creates an empty dictionary.
+    pub fn new() -> Self {
+        ChosungDictionary { words: Vec::new() }
+    }
+
+    /// Creates a dictionary of common words and chat expressions, as used
+    /// by `expand_chosung`.
+    pub fn common() -> Self {
+        COMMON_CHOSUNG_WORDS.iter().copied().collect()
+    }
+
+    /// Adds `word` at the lowest rank, unless it is already present.
+    pub fn insert(&mut self, word: &str) {
+        if !self.words.iter().any(|w| w == word) {
+            self.words.push(word.to_string());
+        }
+    }
+
+    /// Returns the words matching `abbreviation`, from highest to lowest
+    /// rank. A word matches if it has the same number of characters and
+    /// each character of `abbreviation` is either the compatibility jamo of
+    /// the corresponding syllable's initial consonant or the character
+    /// itself, so abbreviations can mix jamo and syllables.
+    pub fn expand(&self, abbreviation: &str) -> Vec<String> {
+        expand_chosung_with(abbreviation, self)
+    }
+}
+
+impl Lexicon for ChosungDictionary {
+    fn contains(&self, word: &str) -> bool {
+        self.words.iter().any(|w| w == word)
+    }
+
+    fn words_with_prefix(&self, prefix: &str) -> Vec<&str> {
+        self.words
+            .iter()
+            .map(String::as_str)
+            .filter(|word| has_jamo_prefix(word, prefix))
+            .collect()
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for ChosungDictionary {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut dictionary = ChosungDictionary::new();
+        dictionary.extend(iter);
+        dictionary
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for ChosungDictionary {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word.as_ref());
+        }
+    }
+}
+
+/// Suggests likely expansions of the initial-consonant abbreviation
+/// `abbreviation` (초성체) using a built-in list of common words and chat
+/// expressions, most likely first. Use `ChosungDictionary` to expand with
+/// your own wordlist.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::expand_chosung;
+///
+/// assert_eq!(expand_chosung("ㄱㅅ")[0], "감사");
+/// assert_eq!(expand_chosung("ㅊㅎ"), ["축하"]);
+/// assert!(expand_chosung("ㅃㅃㅃ").is_empty());
+/// ```
+pub fn expand_chosung(abbreviation: &str) -> Vec<String> {
+    static COMMON: OnceLock<ChosungDictionary> = OnceLock::new();
+    COMMON
+        .get_or_init(ChosungDictionary::common)
+        .expand(abbreviation)
+}
+
+/// Suggests expansions of the initial-consonant abbreviation
+/// `abbreviation` (초성체) from the words of `lexicon`, in the lexicon's
+/// order. See `ChosungDictionary::expand` for how words are matched.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::lexicon::JamoTrie;
+/// use hangul_cd::string::expand_chosung_with;
+///
+/// let lexicon: JamoTrie = ["한글", "회사", "한국"].into_iter().collect();
+/// assert_eq!(expand_chosung_with("ㅎㄱ", &lexicon), ["한글", "한국"]);
+/// ```
+pub fn expand_chosung_with<L: Lexicon + ?Sized>(abbreviation: &str, lexicon: &L) -> Vec<String> {
+    lexicon
+        .words()
+        .into_iter()
+        .filter(|word| matches_chosung(word, abbreviation))
+        .map(str::to_string)
+        .collect()
+}
+
+fn matches_chosung(word: &str, abbreviation: &str) -> bool {
+    let mut abbreviation = abbreviation.chars();
+    word.chars().all(|c| {
+        abbreviation.next().is_some_and(|a| {
+            a == c
+                || block::syllable_indices(c)
+                    .and_then(|(initial, _, _)| Choseong::from_index(initial))
+                    .is_some_and(|j| j.char_compatibility() == a)
+        })
+    }) && abbreviation.next().is_none()
+}
+
+// Common words and chat expressions for `expand_chosung`, most frequent
+// first.
+const COMMON_CHOSUNG_WORDS: &[&str] = &[
+    "감사",
+    "수고",
+    "죄송",
+    "축하",
+    "괜찮",
+    "오케이",
+    "응응",
+    "노노",
+    "하이",
+    "바이",
+    "고고",
+    "몰라",
+    "지금",
+    "덜덜",
+    "별로",
+    "어디",
+    "사랑",
+    "미안",
+    "진짜",
+    "정말",
+    "대박",
+    "안녕",
+    "아니",
+    "그래",
+    "근데",
+    "좋아",
+    "잘자",
+    "하하",
+    "크크",
+    "생일",
+    "친구",
+    "고생",
+    "감사합니다",
+    "수고하세요",
+    "생일축하",
+    "김수",
+    "가수",
+    "기사",
+    "사람",
+    "시간",
+    "생각",
+    "학교",
+    "회사",
+    "전화",
+    "연락",
+];
+
+// Replaces each precomposed syllable of `text` with the jamo returned by
+// `f` for its initial, vowel, and final indices, or removes it if `f`
+// returns `None`.
+fn map_syllable_jamo(text: &str, f: impl Fn(usize, usize, usize) -> Option<char>) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match block::syllable_indices(c) {
+            Some((initial, vowel, final_index)) => result.extend(f(initial, vowel, final_index)),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+/// Replaces every precomposed syllable in `text` with the result of `f`,
+/// passing all other characters through untouched.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::Jungseong;
+/// use hangul_cd::string::map_blocks;
+///
+/// let text = map_blocks("한글 OK", |mut block| {
+///     block.vowel = Jungseong::try_from('ㅣ').unwrap();
+///     block
+/// });
+/// assert_eq!(text, "힌길 OK");
+/// ```
+pub fn map_blocks<F: Fn(HangulBlock) -> HangulBlock>(text: &str, f: F) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match HangulBlock::from_char(c) {
+            Ok(block) => result.push(f(block).to_char()),
+            Err(_) => result.push(c),
+        }
+    }
+    result
+}
+
+/// Decomposes `text` like `decompose`, splitting it into pieces at
+/// whitespace and decomposing the pieces across threads with `rayon`. The
+/// result is identical to `decompose`, since whitespace never joins
+/// syllables during normalization.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::{decompose, par_decompose};
+///
+/// let text = "한글 자모 ".repeat(100_000);
+/// assert_eq!(par_decompose(&text), decompose(&text));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_decompose(text: &str) -> String {
+    use rayon::prelude::*;
+
+    let pieces = whitespace_chunks(text, PARALLEL_CHUNK_LEN)
+        .into_par_iter()
+        .map(decompose)
+        .collect::<Vec<_>>();
+    pieces.concat()
+}
+
+/// Maps the syllables of `text` like `map_blocks`, splitting it into pieces
+/// at whitespace and processing the pieces across threads with `rayon`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::jamo::Jongseong;
+/// use hangul_cd::string::par_map_blocks;
+///
+/// let text = "가나 ".repeat(100_000);
+/// let result = par_map_blocks(&text, |mut block| {
+///     block.final_optional = Jongseong::try_from(*block.initial.jamo()).ok();
+///     block
+/// });
+/// assert_eq!(result, "각난 ".repeat(100_000));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_map_blocks<F: Fn(HangulBlock) -> HangulBlock + Sync>(text: &str, f: F) -> String {
+    use rayon::prelude::*;
+
+    whitespace_chunks(text, PARALLEL_CHUNK_LEN)
+        .into_par_iter()
+        .map(|piece| map_blocks(piece, &f))
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+// The approximate length in bytes of the pieces processed by each task in
+// the parallel functions.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_LEN: usize = 64 * 1024;
+
+// Splits `text` into pieces of at least `len` bytes, each ending just
+// before a whitespace character, except for the last piece.
+#[cfg(feature = "rayon")]
+fn whitespace_chunks(text: &str, len: usize) -> Vec<&str> {
+    let mut chunks = Vec::with_capacity(text.len() / len + 1);
+    let mut rest = text;
+    while rest.len() > len {
+        let Some(end) = rest
+            .char_indices()
+            .skip_while(|&(i, _)| i < len)
+            .find(|(_, c)| c.is_whitespace())
+            .map(|(i, _)| i)
+        else {
+            break;
+        };
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks.push(rest);
+    chunks
+}
+
+/// A lazy iterator over the compatibility jamo of a string, yielding the
+/// same characters as `decompose` without allocating. Syllables are split
+/// into their initial, vowel, and final jamo, composite jamo are kept
+/// whole, and all other characters are passed through. Unlike `decompose`,
+/// the input is not normalized, so conjoining jamo are passed through as
+/// is. The iterator can also be traversed from the back.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::JamoIter;
+///
+/// let jamo: String = JamoIter::new("닭 a").collect();
+/// assert_eq!(jamo, "ㄷㅏㄺ a");
+///
+/// let reversed: String = JamoIter::new("한글").rev().collect();
+/// assert_eq!(reversed, "ㄹㅡㄱㄴㅏㅎ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct JamoIter<'a> {
+    chars: std::str::Chars<'a>,
+    front: PendingJamo,
+    back: PendingJamo,
+}
+
+impl<'a> JamoIter<'a> {
+    /// Creates a `JamoIter` over the characters of `text`.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars(),
+            front: PendingJamo::default(),
+            back: PendingJamo::default(),
+        }
+    }
+}
+
+impl Iterator for JamoIter<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.front.pop_front() {
+                return Some(c);
+            }
+            match self.chars.next() {
+                Some(c) => self.front = PendingJamo::from_char(c),
+                None => return self.back.pop_front(),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.front.len() + self.back.len();
+        let (lower, upper) = self.chars.size_hint();
+        (
+            pending + lower,
+            upper.and_then(|n| n.checked_mul(3)?.checked_add(pending)),
+        )
+    }
+}
+
+impl DoubleEndedIterator for JamoIter<'_> {
+    fn next_back(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.back.pop_back() {
+                return Some(c);
+            }
+            match self.chars.next_back() {
+                Some(c) => self.back = PendingJamo::from_char(c),
+                None => return self.front.pop_back(),
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for JamoIter<'_> {}
+
+// The not-yet-yielded jamo of a single decomposed character.
+#[derive(Debug, Clone, Copy, Default)]
+struct PendingJamo {
+    jamo: [char; 3],
+    start: usize,
+    end: usize,
+}
+
+impl PendingJamo {
+    fn from_char(c: char) -> Self {
+        let codepoint = c as u32;
+        if !(S_BASE..S_BASE + S_COUNT).contains(&codepoint) {
+            return Self {
+                jamo: [c; 3],
+                start: 0,
+                end: 1,
+            };
+        }
+        let s_index = codepoint - S_BASE;
+        let modern = |base: u32, index: u32| {
+            // Infallible: the sums are all within the Hangul Jamo block
+            modern_to_compatibility_jamo(char::from_u32(base + index).unwrap())
+        };
+        let t_index = s_index % T_COUNT;
+        Self {
+            jamo: [
+                modern(L_BASE, s_index / N_COUNT),
+                modern(V_BASE, (s_index % N_COUNT) / T_COUNT),
+                modern(T_BASE, t_index),
+            ],
+            start: 0,
+            end: if t_index == 0 { 2 } else { 3 },
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    fn pop_front(&mut self) -> Option<char> {
+        (self.start < self.end).then(|| {
+            self.start += 1;
+            self.jamo[self.start - 1]
+        })
+    }
+
+    fn pop_back(&mut self) -> Option<char> {
+        (self.start < self.end).then(|| {
+            self.end -= 1;
+            self.jamo[self.end]
+        })
+    }
+}
+
+/// Returns an iterator over the syllable clusters of `text`, following the
+/// Hangul rules of Unicode extended grapheme cluster segmentation (UAX
+/// #29). A run of conjoining jamo that forms one syllable, such as the NFD
+/// form of 한 (U+1112 U+1161 U+11AB), is returned as a single cluster, as is
+/// a precomposed syllable followed by trailing conjoining jamo. Every other
+/// character is returned as its own cluster.
+///
+/// This is useful for NFD text such as file names created on macOS.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::graphemes_hangul;
+///
+/// let nfd = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}.txt";
+/// let clusters: Vec<&str> = graphemes_hangul(nfd).collect();
+/// assert_eq!(clusters[0], "\u{1112}\u{1161}\u{11AB}");
+/// assert_eq!(clusters[1], "\u{1100}\u{1173}\u{11AF}");
+/// assert_eq!(&clusters[2..], [".", "t", "x", "t"]);
+/// ```
+pub fn graphemes_hangul(text: &str) -> HangulGraphemes<'_> {
+    HangulGraphemes { rest: text }
+}
+
+/// An iterator over the syllable clusters of a string, returned by
+/// `graphemes_hangul`.
+#[derive(Debug, Clone)]
+pub struct HangulGraphemes<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for HangulGraphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut prev = HangulSyllableType::of(first);
+        let mut end = self.rest.len();
+        for (i, c) in chars {
+            let cur = HangulSyllableType::of(c);
+            if !prev.joins(cur) {
+                end = i;
+                break;
+            }
+            prev = cur;
+        }
+        let (cluster, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(cluster)
+    }
+}
+
+impl std::iter::FusedIterator for HangulGraphemes<'_> {}
+
+// The Hangul_Syllable_Type property used by UAX #29.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HangulSyllableType {
+    LeadingJamo,
+    VowelJamo,
+    TrailingJamo,
+    LvSyllable,
+    LvtSyllable,
+    NotApplicable,
+}
+
+impl HangulSyllableType {
+    fn of(c: char) -> Self {
+        match c as u32 {
+            0x1100..=0x115F | 0xA960..=0xA97C => HangulSyllableType::LeadingJamo,
+            0x1160..=0x11A7 | 0xD7B0..=0xD7C6 => HangulSyllableType::VowelJamo,
+            0x11A8..=0x11FF | 0xD7CB..=0xD7FB => HangulSyllableType::TrailingJamo,
+            cp @ 0xAC00..=0xD7A3 if (cp - S_BASE).is_multiple_of(T_COUNT) => {
+                HangulSyllableType::LvSyllable
+            }
+            0xAC00..=0xD7A3 => HangulSyllableType::LvtSyllable,
+            _ => HangulSyllableType::NotApplicable,
+        }
+    }
+
+    // Rules GB6 to GB8: whether a character of type `next` continues a
+    // cluster ending in a character of this type.
+    fn joins(self, next: Self) -> bool {
+        use HangulSyllableType::*;
+        matches!(
+            (self, next),
+            (
+                LeadingJamo,
+                LeadingJamo | VowelJamo | LvSyllable | LvtSyllable
+            ) | (LvSyllable | VowelJamo, VowelJamo | TrailingJamo)
+                | (LvtSyllable | TrailingJamo, TrailingJamo)
+        )
+    }
+}
+
+/// A problem in Hangul text found by `validate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The byte offset of the problem in the text.
+    pub offset: usize,
+
+    /// The length in bytes of the problematic text.
+    pub len: usize,
+
+    /// The kind of problem.
+    pub kind: ValidationIssueKind,
+}
+
+/// The kind of a `ValidationIssue`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationIssueKind {
+    /// A conjoining jamo that is not part of a syllable, such as a vowel
+    /// jamo with no leading consonant jamo before it.
+    OrphanedJamo,
+
+    /// A sequence of conjoining jamo, possibly following a precomposed
+    /// syllable, that forms one cluster but cannot be composed into a
+    /// single modern syllable, such as two final consonants.
+    ImpossibleSequence,
+
+    /// A syllable written with conjoining jamo (NFD) in text that also
+    /// contains precomposed syllables (NFC).
+    MixedNormalization,
+
+    /// A compatibility jamo in text that also contains conjoining jamo.
+    MixedJamoForms,
+}
+
+/// Audits `text` for inconsistent or malformed Hangul, returning the
+/// problems found in the order they appear. Text is split into clusters
+/// with `graphemes_hangul`, and each cluster of conjoining jamo is checked
+/// for whether it composes into a single modern syllable. Mixing
+/// precomposed and decomposed syllables, or compatibility and conjoining
+/// jamo, is also reported.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::{ValidationIssue, ValidationIssueKind, validate};
+///
+/// assert!(validate("한글 ㅋㅋ").is_empty());
+///
+/// // 한 followed by 글 in NFD
+/// let issues = validate("한\u{1100}\u{1173}\u{11AF}");
+/// assert_eq!(
+///     issues,
+///     [ValidationIssue { offset: 3, len: 9, kind: ValidationIssueKind::MixedNormalization }]
+/// );
+///
+/// // A lone vowel jamo
+/// assert_eq!(validate("a\u{1161}")[0].kind, ValidationIssueKind::OrphanedJamo);
+/// ```
+pub fn validate(text: &str) -> Vec<ValidationIssue> {
+    let mut clusters = Vec::new();
+    let mut offset = 0;
+    for cluster in graphemes_hangul(text) {
+        clusters.push((offset, cluster.len(), ClusterForm::of(cluster)));
+        offset += cluster.len();
+    }
+    let has = |form| clusters.iter().any(|&(_, _, f)| f == form);
+    let mixed_normalization = has(ClusterForm::Precomposed) && has(ClusterForm::Decomposed);
+    let has_conjoining =
+        has(ClusterForm::Decomposed) || has(ClusterForm::Orphaned) || has(ClusterForm::Impossible);
+    let mixed_jamo = has_conjoining && has(ClusterForm::Compatibility);
+    clusters
+        .into_iter()
+        .filter_map(|(offset, len, form)| {
+            let kind = match form {
+                ClusterForm::Orphaned => ValidationIssueKind::OrphanedJamo,
+                ClusterForm::Impossible => ValidationIssueKind::ImpossibleSequence,
+                ClusterForm::Decomposed if mixed_normalization => {
+                    ValidationIssueKind::MixedNormalization
+                }
+                ClusterForm::Compatibility if mixed_jamo => ValidationIssueKind::MixedJamoForms,
+                _ => return None,
+            };
+            Some(ValidationIssue { offset, len, kind })
+        })
+        .collect()
+}
+
+// How a cluster from `graphemes_hangul` writes Hangul, used by `validate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClusterForm {
+    Precomposed,
+    Decomposed,
+    Orphaned,
+    Impossible,
+    Compatibility,
+    Other,
+}
+
+impl ClusterForm {
+    fn of(cluster: &str) -> Self {
+        let mut chars = cluster.chars();
+        let (Some(first), None) = (chars.next(), chars.next()) else {
+            let normalized = normalize_hangul(cluster);
+            let mut chars = normalized.chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) if block::syllable_indices(c).is_some() => ClusterForm::Decomposed,
+                _ => ClusterForm::Impossible,
+            };
+        };
+        match CharClass::of(first) {
+            CharClass::Syllable => ClusterForm::Precomposed,
+            CharClass::ModernJamo | CharClass::NonStandardJamo
+                if HangulSyllableType::of(first) != HangulSyllableType::NotApplicable =>
+            {
+                ClusterForm::Orphaned
+            }
+            CharClass::CompatibilityJamo => ClusterForm::Compatibility,
+            _ => ClusterForm::Other,
+        }
+    }
+}
+
+/// Returns an iterator over the sentences of `text`, with surrounding
+/// whitespace trimmed.
+///
+/// A sentence ends at a run of `.`, `?`, `!`, or `…` (and their full-width
+/// forms) that is followed by whitespace or the end of the text, so decimal
+/// numbers and dotted abbreviations are not split. Terminators inside
+/// quotation marks or brackets do not end the sentence, and a quote that
+/// ends in a terminator only ends it if it is not followed by a quotative
+/// such as 라고 or 하고. A quotation mark or bracket that is not closed
+/// before the next blank line or the end of the text is ignored, as in
+/// 5'11". An ellipsis only ends a sentence after a common sentence-final
+/// syllable such as 다, 요, 까, or 죠, and a period does not end one after
+/// a common English abbreviation such as Mr. or Dr.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::sentences;
+///
+/// let text = "밥 먹었어요? 네, 3.5인분 먹었죠! 그가 \"배불러.\" 하고 웃었다. 그래서... 잤다.";
+/// let split: Vec<&str> = sentences(text).collect();
+/// assert_eq!(
+///     split,
+///     [
+///         "밥 먹었어요?",
+///         "네, 3.5인분 먹었죠!",
+///         "그가 \"배불러.\" 하고 웃었다.",
+///         "그래서... 잤다.",
+///     ]
+/// );
+/// ```
+pub fn sentences(text: &str) -> Sentences<'_> {
+    Sentences { rest: text }
+}
+
+/// An iterator over the sentences of a string, returned by `sentences`.
+#[derive(Debug, Clone)]
+pub struct Sentences<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Sentences<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let text = self.rest.trim_start();
+        if text.is_empty() {
+            self.rest = text;
+            return None;
+        }
+        let mut chars = text.char_indices().peekable();
+        let mut quotes = QuoteState::default();
+        // Where the sentence would end if the open quotes were never closed
+        let mut unquoted_end = None;
+        let mut prev = None;
+        while let Some((run_start, c)) = chars.next() {
+            if !is_sentence_terminator(c) {
+                if c == '\n' && quotes.is_open() && is_paragraph_break(&text[run_start + 1..]) {
+                    if let Some(end) = unquoted_end {
+                        return Some(self.split(text, end));
+                    }
+                    quotes = QuoteState::default();
+                }
+                quotes.update(c);
+                if !quotes.is_open() {
+                    unquoted_end = None;
+                }
+                prev = Some(c);
+                continue;
+            }
+            let before_run = prev;
+            prev = Some(c);
+            let mut run_len = 1;
+            let mut is_dots = matches!(c, '.' | '…');
+            while let Some((_, c)) = chars.next_if(|&(_, c)| is_sentence_terminator(c)) {
+                run_len += 1;
+                is_dots &= matches!(c, '.' | '…');
+                prev = Some(c);
+            }
+            let mut quoted = false;
+            while let Some((_, c)) = chars.next_if(|&(_, c)| quotes.closes(c)) {
+                quotes.update(c);
+                quoted = true;
+                prev = Some(c);
+            }
+            if !quotes.is_open() {
+                unquoted_end = None;
+            }
+            let end = chars.peek().map_or(text.len(), |&(b, _)| b);
+            let after = &text[end..];
+            if !after.is_empty() && !after.starts_with(char::is_whitespace) {
+                continue;
+            }
+            let is_ellipsis = is_dots && (run_len > 1 || c == '…');
+            if is_ellipsis && !before_run.is_some_and(is_sentence_final_syllable) {
+                continue;
+            }
+            if quoted && QUOTATIVES.iter().any(|q| after.trim_start().starts_with(q)) {
+                continue;
+            }
+            if run_len == 1 && c == '.' {
+                let word = text[..run_start]
+                    .rsplit(char::is_whitespace)
+                    .next()
+                    .unwrap_or_default();
+                if ABBREVIATIONS.contains(&word) {
+                    continue;
+                }
+            }
+            if quotes.is_open() {
+                unquoted_end = unquoted_end.or(Some(end));
+                continue;
+            }
+            return Some(self.split(text, end));
+        }
+        if let Some(end) = unquoted_end {
+            return Some(self.split(text, end));
+        }
+        self.rest = "";
+        Some(text.trim_end())
+    }
+}
+
+impl<'a> Sentences<'a> {
+    // Returns the sentence `text[..end]`, continuing after it.
+    fn split(&mut self, text: &'a str, end: usize) -> &'a str {
+        self.rest = &text[end..];
+        &text[..end]
+    }
+}
+
+impl std::iter::FusedIterator for Sentences<'_> {}
+
+// Whether `text`, which follows a line break, starts with another line break
+// after any other whitespace, making a blank line.
+fn is_paragraph_break(text: &str) -> bool {
+    text.trim_start_matches(|c: char| c.is_whitespace() && c != '\n')
+        .starts_with('\n')
+}
+
+// Words that attach a quotation to the rest of its sentence.
+const QUOTATIVES: [&str; 8] = [
+    "라고",
+    "이라고",
+    "하고",
+    "하며",
+    "라며",
+    "하면서",
+    "하는",
+    "라는",
+];
+
+// Abbreviations whose period does not end a sentence, without the period.
+const ABBREVIATIONS: [&str; 11] = [
+    "Mr", "Mrs", "Ms", "Dr", "Prof", "St", "Jr", "Sr", "vs", "e.g", "i.e",
+];
+
+fn is_sentence_terminator(c: char) -> bool {
+    matches!(c, '.' | '?' | '!' | '…' | '。' | '？' | '！')
+}
+
+// Syllables that commonly end a sentence, used to decide whether an
+// ellipsis ends one.
+fn is_sentence_final_syllable(c: char) -> bool {
+    matches!(
+        c,
+        '다' | '요' | '까' | '죠' | '네' | '지' | '자' | '라' | '니' | '군' | '구'
+    )
+}
+
+// Tracks whether the scan of a sentence is inside quotation marks or
+// brackets.
+#[derive(Debug, Default)]
+struct QuoteState {
+    depth: usize,
+    straight: bool,
+}
+
+impl QuoteState {
+    fn update(&mut self, c: char) {
+        match c {
+            '“' | '‘' | '「' | '『' | '«' | '(' | '[' => self.depth += 1,
+            '”' | '’' | '」' | '』' | '»' | ')' | ']' => {
+                self.depth = self.depth.saturating_sub(1)
+            }
+            '"' => self.straight = !self.straight,
+            _ => {}
+        }
+    }
+
+    fn closes(&self, c: char) -> bool {
+        match c {
+            '"' => self.straight,
+            '”' | '’' | '」' | '』' | '»' | ')' | ']' => true,
+            _ => false,
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.depth > 0 || self.straight
+    }
+}
+
+/// Returns whether the syllables of `text` match `pattern`, a
+/// whitespace-separated list of syllable patterns such as `"CVC CV"`, one
+/// for each syllable (see `block::SyllablePattern`). Whitespace in `text` is
+/// ignored, and any other character that is not a precomposed Hangul
+/// syllable never matches.
+///
+/// Returns an error if `pattern` contains an invalid syllable pattern.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::match_pattern;
+///
+/// assert_eq!(match_pattern("한국", "CVC CVC"), Ok(true));
+/// assert_eq!(match_pattern("우유", "V GV"), Ok(true));
+/// assert_eq!(match_pattern("학교", "CVC CV"), Ok(false)); // 교 is CGV
+/// assert!(match_pattern("학교", "CXV").is_err());
+/// ```
+pub fn match_pattern(text: &str, pattern: &str) -> Result<bool, StringError> {
+    let patterns = pattern
+        .split_whitespace()
+        .map(str::parse::<SyllablePattern>)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut syllables = text.chars().filter(|c| !c.is_whitespace());
+    let matched = patterns
+        .iter()
+        .all(|p| syllables.next().and_then(block::pattern) == Some(*p));
+    Ok(matched && syllables.next().is_none())
+}
+
+/// Returns the number of terminal columns `text` occupies. Hangul syllables
+/// and jamo and other East Asian wide characters count as two columns,
+/// conjoining vowels and finals (which combine with the preceding initial)
+/// and other combining or control characters as zero, and everything else
+/// as one.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::display_width;
+///
+/// assert_eq!(display_width("한글 abc"), 8);
+/// assert_eq!(display_width("ㄱ"), 2);
+/// // Decomposed 한 is still two columns
+/// assert_eq!(display_width("\u{1112}\u{1161}\u{11AB}"), 2);
+/// ```
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// Returns the longest prefix of `text` that fits in `width` columns, as
+/// measured by `display_width`. Combining characters are kept with the
+/// character before them, so decomposed syllables are never split.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("한국어 text", 5), "한국");
+/// assert_eq!(truncate_to_width("한국어 text", 9), "한국어 te");
+/// assert_eq!(truncate_to_width("한국어", 1), "");
+/// ```
+pub fn truncate_to_width(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (i, c) in text.char_indices() {
+        used += char_width(c);
+        if used > width {
+            return &text[..i];
+        }
+    }
+    text
+}
+
+/// Pads `text` with spaces on the right so that it occupies `width`
+/// columns, as measured by `display_width`. Text that is already at least
+/// `width` columns wide is returned unchanged. Unlike `format!("{:<10}")`,
+/// which counts characters, this aligns columns of Korean text.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::pad_to_width;
+///
+/// assert_eq!(pad_to_width("이름", 6), "이름  ");
+/// assert_eq!(pad_to_width("name", 6), "name  ");
+/// assert_eq!(pad_to_width("한국어", 4), "한국어");
+/// ```
+pub fn pad_to_width(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    let mut padded = String::with_capacity(text.len() + padding);
+    padded.push_str(text);
+    padded.extend(std::iter::repeat_n(' ', padding));
+    padded
+}
+
+/// Where `wrap` may break lines within Korean text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WrapStyle {
+    /// Lines may break between any two syllables, as is usual in Korean
+    /// typesetting (like CSS `word-break: normal`).
+    #[default]
+    Syllable,
+
+    /// Lines break only at whitespace, keeping each word (어절) whole (like
+    /// CSS `word-break: keep-all`). Words wider than a line are broken
+    /// between syllables.
+    Word,
+}
+
+/// Wraps `text` into lines at most `width` columns wide, following Korean
+/// line-breaking conventions: depending on `style`, lines break between
+/// syllables or only at whitespace, but never inside a run of Latin letters
+/// or digits, before closing punctuation such as `.` or `)`, or after
+/// opening punctuation such as `(`. Widths are measured with
+/// `display_width`.
+///
+/// Existing line breaks in `text` are kept, whitespace at the ends of lines
+/// is removed, and words too wide for a single line are split wherever
+/// needed.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::{WrapStyle, wrap};
+///
+/// let text = "한국어 텍스트를 줄 바꿈합니다.";
+/// assert_eq!(
+///     wrap(text, 10, WrapStyle::Syllable),
+///     ["한국어 텍", "스트를 줄", "바꿈합니", "다."]
+/// );
+/// assert_eq!(
+///     wrap("Rust로 한글을 wrap합니다.", 12, WrapStyle::Word),
+///     ["Rust로", "한글을", "wrap합니다."]
+/// );
+/// ```
+pub fn wrap(text: &str, width: usize, style: WrapStyle) -> Vec<String> {
+    text.lines()
+        .flat_map(|line| wrap_line(line, width, style))
+        .collect()
+}
+
+// An unbreakable piece of a line being wrapped: the whitespace before it
+// spans `space_start..start`, and its text spans `start..end`.
+struct WrapPiece {
+    space_start: usize,
+    start: usize,
+    end: usize,
+}
+
+fn wrap_line(line: &str, width: usize, style: WrapStyle) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+    for piece in wrap_pieces(line, style) {
+        let space = &line[piece.space_start..piece.start];
+        let text = &line[piece.start..piece.end];
+        let text_width = display_width(text);
+        if !current.is_empty() && current_width + display_width(space) + text_width > width {
+            lines.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+        if !current.is_empty() || lines.is_empty() {
+            current.push_str(space);
+            current_width += display_width(space);
+        }
+        if current_width + text_width <= width {
+            current.push_str(text);
+            current_width += text_width;
+            continue;
+        }
+
+        // The piece is too wide for a line of its own
+        let mut split = match style {
+            WrapStyle::Word => wrap_line(text, width, WrapStyle::Syllable),
+            WrapStyle::Syllable => split_to_width(text, width),
+        };
+        if !current.trim().is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        // Infallible: `text` is not empty, so it is split into some lines
+        current = split.pop().unwrap();
+        current_width = display_width(&current);
+        lines.extend(split);
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+// Splits `line` into the pieces that `wrap` will not break, attaching
+// closing punctuation to the piece before it and opening punctuation to the
+// piece after it.
+fn wrap_pieces(line: &str, style: WrapStyle) -> Vec<WrapPiece> {
+    let mut pieces: Vec<WrapPiece> = Vec::new();
+    let mut space_start = 0;
+    let mut prev: Option<char> = None;
+    for (i, c) in line.char_indices() {
+        let end = i + c.len_utf8();
+        if c.is_whitespace() {
+            space_start = if prev.is_some_and(|p| !p.is_whitespace()) {
+                i
+            } else {
+                space_start
+            };
+            prev = Some(c);
+            continue;
+        }
+        let joins_previous = prev.is_some_and(|p| {
+            !p.is_whitespace()
+                && (style == WrapStyle::Word
+                    || char_width(c) == 0
+                    || (char_width(p) < 2 && char_width(c) < 2))
+        });
+        match pieces.last_mut() {
+            Some(last) if joins_previous => last.end = end,
+            _ => {
+                let space_start = if prev.is_some_and(char::is_whitespace) {
+                    space_start
+                } else {
+                    i
+                };
+                pieces.push(WrapPiece {
+                    space_start,
+                    start: i,
+                    end,
+                });
+            }
+        }
+        prev = Some(c);
+    }
+
+    let mut merged: Vec<WrapPiece> = Vec::with_capacity(pieces.len());
+    let mut glue_next = false;
+    for piece in pieces {
+        let text = &line[piece.start..piece.end];
+        let attached = piece.space_start == piece.start;
+        match merged.last_mut() {
+            Some(last) if attached && (glue_next || text.chars().all(is_closing_punctuation)) => {
+                last.end = piece.end;
+                glue_next = false;
+            }
+            _ => {
+                merged.push(piece);
+                glue_next = text.chars().all(is_opening_punctuation);
+            }
+        }
+    }
+    merged
+}
+
+// Splits `text` into lines at most `width` columns wide, between any two
+// characters, keeping at least one character on each line.
+fn split_to_width(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![String::new()];
+    let mut line_width = 0;
+    for c in text.chars() {
+        let w = char_width(c);
+        // Infallible: `lines` is never empty
+        let line = lines.last_mut().unwrap();
+        if !line.is_empty() && w > 0 && line_width + w > width {
+            lines.push(c.to_string());
+            line_width = w;
+        } else {
+            line.push(c);
+            line_width += w;
+        }
+    }
+    lines
+}
+
+fn is_closing_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '.' | ','
+            | '!'
+            | '?'
+            | ':'
+            | ';'
+            | ')'
+            | ']'
+            | '}'
+            | '%'
+            | '~'
+            | '\''
+            | '"'
+            | '…'
+            | '’'
+            | '”'
+            | '」'
+            | '』'
+            | '》'
+            | '〉'
+            | '】'
+            | '〕'
+            | '、'
+            | '。'
+            | '）'
+            | '，'
+            | '．'
+            | '！'
+            | '？'
+    )
+}
+
+fn is_opening_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '(' | '[' | '{' | '\'' | '"' | '‘' | '“' | '「' | '『' | '《' | '〈' | '【' | '〔' | '（'
+    )
+}
+
+// Returns the number of terminal columns `c` occupies, as described in
+// `display_width`.
+pub(crate) fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0000..=0x001F
+        | 0x007F..=0x009F
+        | 0x0300..=0x036F
+        | 0x1160..=0x11FF
+        | 0x200B..=0x200F
+        | 0x302A..=0x302F
+        | 0xD7B0..=0xD7FF
+        | 0xFE00..=0xFE0F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x3029
+        | 0x3030..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA960..=0xA97F
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// A run of characters of the same kind, as yielded by `HangulReader`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HangulSegment {
+    /// A run of precomposed Hangul syllables.
+    Syllables(String),
+
+    /// A run of jamo of any kind: modern, compatibility, or non-standard.
+    Jamo(String),
+
+    /// A run of characters that are not Hangul, including line endings.
+    Other(String),
+}
+
+/// Wraps a reader and yields its text as `HangulSegment`s, reading one line
+/// at a time so that large corpora can be processed without loading them
+/// into memory. Segments never span lines, and line endings are yielded as
+/// part of `HangulSegment::Other`.
+///
+/// Yields an error if reading fails or the input is not valid UTF-8.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::{HangulReader, HangulSegment};
+///
+/// let input = "한글ㅋㅋ ok\n끝".as_bytes();
+/// let segments: Vec<HangulSegment> = HangulReader::new(input)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(
+///     segments,
+///     [
+///         HangulSegment::Syllables("한글".to_string()),
+///         HangulSegment::Jamo("ㅋㅋ".to_string()),
+///         HangulSegment::Other(" ok\n".to_string()),
+///         HangulSegment::Syllables("끝".to_string()),
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct HangulReader<R: BufRead> {
+    reader: R,
+    line: String,
+    pending: VecDeque<HangulSegment>,
+}
+
+impl<R: BufRead> HangulReader<R> {
+    /// Creates a `HangulReader` reading from `reader`.
+    pub fn new(reader: R) -> Self {
+        HangulReader {
+            reader,
+            line: String::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Iterator for HangulReader<R> {
+    type Item = io::Result<HangulSegment>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+            let mut start = 0;
+            let mut kind = None;
+            for (i, c) in self.line.char_indices() {
+                let next = SegmentKind::of(c);
+                if kind.is_some_and(|k| k != next) {
+                    self.pending
+                        .push_back(next_segment(kind, &self.line[start..i]));
+                    start = i;
+                }
+                kind = Some(next);
+            }
+            self.pending
+                .push_back(next_segment(kind, &self.line[start..]));
+        }
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+// The kind of a `HangulSegment`, used while splitting a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SegmentKind {
+    Syllables,
+    Jamo,
+    Other,
+}
+
+impl SegmentKind {
+    fn of(c: char) -> Self {
+        match CharClass::of(c) {
+            CharClass::Syllable => SegmentKind::Syllables,
+            CharClass::ModernJamo | CharClass::CompatibilityJamo | CharClass::NonStandardJamo => {
+                SegmentKind::Jamo
+            }
+            CharClass::NonHangul => SegmentKind::Other,
+        }
+    }
+}
+
+fn next_segment(kind: Option<SegmentKind>, text: &str) -> HangulSegment {
+    let text = text.to_string();
+    match kind {
+        Some(SegmentKind::Syllables) => HangulSegment::Syllables(text),
+        Some(SegmentKind::Jamo) => HangulSegment::Jamo(text),
+        Some(SegmentKind::Other) | None => HangulSegment::Other(text),
+    }
+}
+
+/// Wraps a writer and composes the jamo written to it, writing composed text
+/// to the underlying writer. The written bytes are decoded as UTF-8 and fed
+/// to a `StringComposer`, and text is passed on as soon as it can no longer
+/// change; the word being composed is held back until a non-Hangul
+/// character ends it or the writer is flushed. Flushing, including when the
+/// writer is dropped, writes the current word and starts a new one.
+///
+/// Writing returns an error if the bytes are not valid UTF-8 (a character
+/// split across writes is fine) or the composer rejects a character.
+///
+/// **Example:**
+/// ```rust
+/// use std::io::Write;
+/// use hangul_cd::string::ComposingWriter;
+///
+/// let mut writer = ComposingWriter::new(Vec::new());
+/// writer.write_all("ㅎㅏㄴㄱㅡㄹ ㅇㅣ".as_bytes()).unwrap();
+/// assert_eq!(writer.get_ref().as_slice(), "한글 ".as_bytes());
+/// writer.flush().unwrap();
+/// assert_eq!(writer.into_inner().unwrap(), "한글 이".as_bytes());
+/// ```
+#[derive(Debug)]
+pub struct ComposingWriter<W: Write> {
+    inner: Option<W>,
+    composer: StringComposer,
+    // The bytes of a character split across writes.
+    partial: Vec<u8>,
+}
+
+impl<W: Write> ComposingWriter<W> {
+    /// Creates a `ComposingWriter` writing to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self::with_options(inner, ComposerOptions::new())
+    }
+
+    /// Creates a `ComposingWriter` writing to `inner`, composing with
+    /// `options`.
+    pub fn with_options(inner: W, options: ComposerOptions) -> Self {
+        ComposingWriter {
+            inner: Some(inner),
+            composer: StringComposer::with_options(options),
+            partial: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner
+            .as_ref()
+            .expect("writer is present until into_inner")
+    }
+
+    /// Returns a mutable reference to the underlying writer. Writing to it
+    /// directly may interleave with text held back by this writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner
+            .as_mut()
+            .expect("writer is present until into_inner")
+    }
+
+    /// Flushes this writer and returns the underlying writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self
+            .inner
+            .take()
+            .expect("writer is present until into_inner"))
+    }
+
+    // Writes the completed text of the composer to the underlying writer,
+    // with lone jamo as compatibility jamo like `compose`.
+    fn write_completed(&mut self) -> io::Result<()> {
+        let completed: String = std::mem::take(&mut self.composer.completed)
+            .chars()
+            .map(modern_to_compatibility_jamo)
+            .collect();
+        self.get_mut().write_all(completed.as_bytes())
+    }
+}
+
+impl<W: Write> Write for ComposingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.partial.extend_from_slice(buf);
+        let valid_len = match std::str::from_utf8(&self.partial) {
+            Ok(s) => s.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(e) => {
+                self.partial.clear();
+                return Err(io::Error::new(io::ErrorKind::InvalidData, e));
+            }
+        };
+        let bytes: Vec<u8> = self.partial.drain(..valid_len).collect();
+        let text = std::str::from_utf8(&bytes).expect("validated above");
+        self.composer
+            .feed_str(text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.write_completed()?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let current = std::mem::take(&mut self.composer.current);
+        let word = current
+            .as_string()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.composer.completed.push_str(&word);
+        self.write_completed()?;
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for ComposingWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            // Errors can't be reported from drop; use `flush` to see them.
+            let _ = self.flush();
+        }
+    }
+}
+
+/// The kind of a character in mixed Hangul text, as returned by
+/// `CharClass::of` and `classify_str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharClass {
+    /// A precomposed modern Hangul syllable, U+AC00 to U+D7A3.
+    Syllable,
+
+    /// A modern conjoining jamo used to compose syllables.
+    ModernJamo,
+
+    /// A modern compatibility jamo, such as those produced by IMEs.
+    CompatibilityJamo,
+
+    /// A non-standard or archaic jamo from either jamo block.
+    NonStandardJamo,
+
+    /// Any other character.
+    NonHangul,
+}
+
+impl CharClass {
+    /// Classifies a single character using range checks only.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::string::CharClass;
+    ///
+    /// assert_eq!(CharClass::of('한'), CharClass::Syllable);
+    /// assert_eq!(CharClass::of('ㄱ'), CharClass::CompatibilityJamo);
+    /// assert_eq!(CharClass::of('\u{1100}'), CharClass::ModernJamo);
+    /// assert_eq!(CharClass::of('a'), CharClass::NonHangul);
+    /// ```
+    #[inline]
+    pub const fn of(c: char) -> CharClass {
+        match c as u32 {
+            0x0000..=0x10FF => CharClass::NonHangul,
+            0xAC00..=0xD7A3 => CharClass::Syllable,
+            _ => match JamoUnicodeType::evaluate(c) {
+                JamoUnicodeType::Modern => CharClass::ModernJamo,
+                JamoUnicodeType::Compatibility => CharClass::CompatibilityJamo,
+                JamoUnicodeType::NonStandardModern | JamoUnicodeType::NonStandardCompatibility => {
+                    CharClass::NonStandardJamo
+                }
+                JamoUnicodeType::NonHangul => CharClass::NonHangul,
+            },
+        }
+    }
+}
+
+/// Classifies every character of `text`, in order. Equivalent to mapping
+/// `CharClass::of` over `text.chars()`, but runs of ASCII are classified
+/// eight bytes at a time without decoding, which is considerably faster on
+/// long, mostly-ASCII input.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::{CharClass, classify_str};
+///
+/// assert_eq!(
+///     classify_str("한 ㄱ"),
+///     vec![CharClass::Syllable, CharClass::NonHangul, CharClass::CompatibilityJamo]
+/// );
+/// ```
+pub fn classify_str(text: &str) -> Vec<CharClass> {
+    const ASCII_MASK: u64 = 0x8080_8080_8080_8080;
+
+    let bytes = text.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some(chunk) = bytes.get(i..i + 8) {
+            // Infallible: the slice is exactly eight bytes long
+            let word = u64::from_ne_bytes(chunk.try_into().unwrap());
+            if word & ASCII_MASK == 0 {
+                result.extend([CharClass::NonHangul; 8]);
+                i += 8;
+                continue;
+            }
+        }
+        if bytes[i].is_ascii() {
+            result.push(CharClass::NonHangul);
+            i += 1;
+            continue;
+        }
+        // `i` is always on a char boundary, since only whole chars are skipped
+        let c = text[i..].chars().next().unwrap();
+        result.push(CharClass::of(c));
+        i += c.len_utf8();
+    }
+    result
+}
+
+/// Counts of the Hangul in a string, as returned by `stats`, for language
+/// detection heuristics and input validation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HangulStats {
+    /// The number of precomposed Hangul syllables.
+    pub syllables: usize,
+
+    /// The number of jamo outside precomposed syllables, whether modern,
+    /// compatibility, or non-standard jamo.
+    pub jamo: usize,
+
+    /// The number of characters that are not whitespace, Hangul or not.
+    pub non_whitespace: usize,
+
+    /// The number of characters in the longest run of consecutive syllables
+    /// and jamo.
+    pub longest_run: usize,
+}
+
+impl HangulStats {
+    /// Returns the number of syllables and jamo.
+    pub fn hangul(&self) -> usize {
+        self.syllables + self.jamo
+    }
+
+    /// Returns the fraction of characters that are syllables or jamo, from
+    /// `0.0` to `1.0`, ignoring whitespace. Returns `0.0` for text that is
+    /// empty or all whitespace.
+    pub fn hangul_ratio(&self) -> f32 {
+        if self.non_whitespace == 0 {
+            0.0
+        } else {
+            self.hangul() as f32 / self.non_whitespace as f32
+        }
+    }
+}
+
+/// Counts the syllables and jamo in `text`, the fraction of its characters
+/// they make up, and the longest run of them.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::stats;
+///
+/// let counts = stats("홍길동 ㅋㅋ (Hong)");
+/// assert_eq!(counts.syllables, 3);
+/// assert_eq!(counts.jamo, 2);
+/// assert_eq!(counts.longest_run, 3);
+/// assert_eq!(counts.hangul_ratio(), 5.0 / 11.0);
+///
+/// // "Name must be Korean"
+/// let is_korean_name = |name: &str| stats(name).hangul_ratio() == 1.0;
+/// assert!(is_korean_name("김 민준"));
+/// assert!(!is_korean_name("Kim 민준"));
+/// ```
+pub fn stats(text: &str) -> HangulStats {
+    let mut stats = HangulStats::default();
+    let mut run = 0;
+    for c in text.chars() {
+        match CharClass::of(c) {
+            CharClass::Syllable => stats.syllables += 1,
+            CharClass::ModernJamo | CharClass::CompatibilityJamo | CharClass::NonStandardJamo => {
+                stats.jamo += 1
+            }
+            CharClass::NonHangul => {
+                run = 0;
+                if !c.is_whitespace() {
+                    stats.non_whitespace += 1;
+                }
+                continue;
+            }
+        }
+        stats.non_whitespace += 1;
+        run += 1;
+        stats.longest_run = stats.longest_run.max(run);
+    }
+    stats
+}
+
+/// Estimates how likely `text` is to be Korean, from `0.0` to `1.0`. This
+/// is the fraction of Hangul in `text`, as given by
+/// `HangulStats::hangul_ratio`, weighted by how plausible its syllables are
+/// as Korean: syllables made of common jamo, such as 한 and 다, count for
+/// more than ones made of rare jamo, such as 뷁 and 쮀, which make up most of
+/// the syllables in random or corrupted text. Loose jamo, such as ㅋㅋ, are
+/// neither plausible nor implausible.
+///
+/// The plausibility of a syllable compares the approximate frequency of
+/// its initial, vowel, and final in Korean text to their frequency if every
+/// syllable were equally likely. A single rare syllable can lower the
+/// confidence of a short text considerably.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::korean_confidence;
+///
+/// assert!(korean_confidence("오늘 날씨가 정말 좋네요.") > 0.8);
+/// assert!(korean_confidence("Hello, 세계") < 0.5);
+/// assert!(korean_confidence("뷁쮀퀭") < 0.1);
+/// assert_eq!(korean_confidence("Hello"), 0.0);
+/// ```
+pub fn korean_confidence(text: &str) -> f32 {
+    let counts = stats(text);
+    if counts.hangul() == 0 {
+        return 0.0;
+    }
+    let log_ratio: f32 = text
+        .chars()
+        .filter_map(block::syllable_indices)
+        .map(|(l, v, t)| {
+            (INITIAL_SHARES[l] / INITIAL_TOTAL * Choseong::COUNT as f32).ln()
+                + (VOWEL_SHARES[v] / VOWEL_TOTAL * Jungseong::COUNT as f32).ln()
+                + (FINAL_SHARES[t] / FINAL_TOTAL * T_COUNT as f32).ln()
+        })
+        .sum();
+    let plausibility = 1.0 / (1.0 + (-log_ratio / counts.hangul() as f32).exp());
+    counts.hangul_ratio() * plausibility
+}
+
+/// Returns whether `text` is likely to be Korean, that is, whether
+/// `korean_confidence` is at least `threshold`. A threshold of `0.5` suits
+/// most text; use a higher one to reject text that mixes in other scripts.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::is_korean;
+///
+/// assert!(is_korean("안녕하세요!", 0.5));
+/// assert!(!is_korean("Hello, world", 0.5));
+/// assert!(!is_korean("서울 Seoul", 0.5));
+/// ```
+pub fn is_korean(text: &str, threshold: f32) -> bool {
+    korean_confidence(text) >= threshold
+}
+
+// The approximate share, in percent, of each initial, vowel, and final
+// (including none) among the syllables of Korean text, in jamo index order.
+const INITIAL_SHARES: [f32; 19] = [
+    11.5, 1.0, 6.0, 9.0, 0.8, 5.0, 5.0, 4.5, 0.1, 8.0, 0.5, 26.0, 8.0, 0.2, 2.0, 0.6, 1.0, 1.0, 7.0,
+];
+const VOWEL_SHARES: [f32; 21] = [
+    25.0, 4.0, 0.5, 0.02, 10.0, 4.0, 4.0, 0.4, 8.0, 1.5, 0.1, 1.0, 1.5, 7.0, 0.7, 0.05, 0.5, 0.6,
+    11.0, 2.0, 15.0,
+];
+const FINAL_SHARES: [f32; 28] = [
+    60.0, 4.0, 0.1, 0.01, 12.0, 0.05, 0.3, 0.1, 8.0, 0.1, 0.05, 0.05, 0.01, 0.01, 0.01, 0.05, 3.0,
+    1.5, 0.1, 1.0, 1.5, 7.0, 0.2, 0.2, 0.02, 0.2, 0.1, 0.2,
+];
+const INITIAL_TOTAL: f32 = sum(&INITIAL_SHARES);
+const VOWEL_TOTAL: f32 = sum(&VOWEL_SHARES);
+const FINAL_TOTAL: f32 = sum(&FINAL_SHARES);
+
+const fn sum(shares: &[f32]) -> f32 {
+    let mut total = 0.0;
+    let mut i = 0;
+    while i < shares.len() {
+        total += shares[i];
+        i += 1;
+    }
+    total
+}
+
+/// A set of edit costs used by `jamo_distance_with_cost`. Costs are
+/// given per singular compatibility jamo (or non-Hangul character).
+///
+/// Implementors only need to provide `substitution_cost`; insertions and
+/// deletions cost `1.0` by default.
+pub trait CostModel {
+    /// The cost of replacing `from` with `to`. This is only called when
+    /// `from != to`.
+    fn substitution_cost(&self, from: char, to: char) -> f32;
+
+    /// The cost of inserting `c`.
+    fn insertion_cost(&self, _c: char) -> f32 {
+        1.0
+    }
+
+    /// The cost of deleting `c`.
+    fn deletion_cost(&self, _c: char) -> f32 {
+        1.0
+    }
+}
+
+/// A `CostModel` in which every insertion, deletion, and substitution costs
+/// `1.0`; this is the plain Levenshtein distance used by `jamo_distance`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UniformCost;
+
+impl CostModel for UniformCost {
+    fn substitution_cost(&self, _from: char, _to: char) -> f32 {
+        1.0
+    }
+}
+
+/// Computes the Levenshtein edit distance between two strings at the jamo
+/// level. Hangul syllables are decomposed into compatibility jamo as they
+/// are typed on a Dubeolsik keyboard before comparing: composite vowels and
+/// final clusters are split, but double consonants, which have their own
+/// key, are not. So 간 and 갈 are at distance 1 (one final consonant
+/// substituted), 가 and 과 are at distance 1 (ㅘ is ㅗ + ㅏ), and 가 and 까
+/// are at distance 1 (ㄱ substituted by ㄲ). Non-Hangul characters are
+/// compared as-is.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::jamo_distance;
+///
+/// assert_eq!(jamo_distance("간", "갈"), 1);
+/// assert_eq!(jamo_distance("한글", "한국"), 2);
+/// assert_eq!(jamo_distance("abc", "abd"), 1);
+/// ```
+pub fn jamo_distance(a: &str, b: &str) -> usize {
+    jamo_distance_with_cost(a, b, &UniformCost) as usize
+}
+
+/// Computes a weighted edit distance between two strings at the jamo level,
+/// using `costs` to price each insertion, deletion, and substitution. Strings
+/// are decomposed the same way as in `jamo_distance`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::keyboard::DubeolsikCost;
+/// use hangul_cd::string::{jamo_distance_with_cost, UniformCost};
+///
+/// // ㅐ and ㅔ are neighbouring keys on a Dubeolsik keyboard, so confusing
+/// // them is cheaper than an arbitrary substitution.
+/// let costs = DubeolsikCost::default();
+/// assert!(jamo_distance_with_cost("개", "게", &costs) < jamo_distance_with_cost("개", "고", &costs));
+/// assert_eq!(jamo_distance_with_cost("개", "게", &UniformCost), 1.0);
+/// ```
+pub fn jamo_distance_with_cost<C: CostModel + ?Sized>(a: &str, b: &str, costs: &C) -> f32 {
+    let a = keyed_jamo(a);
+    let b = keyed_jamo(b);
+
+    let mut prev = Vec::with_capacity(b.len() + 1);
+    prev.push(0.0);
+    for cb in &b {
+        prev.push(prev[prev.len() - 1] + costs.insertion_cost(*cb));
+    }
+    let mut cur = vec![0.0; b.len() + 1];
+    for ca in &a {
+        cur[0] = prev[0] + costs.deletion_cost(*ca);
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = if ca == cb {
+                prev[j]
+            } else {
+                prev[j] + costs.substitution_cost(*ca, *cb)
+            };
+            let deletion = prev[j + 1] + costs.deletion_cost(*ca);
+            let insertion = cur[j] + costs.insertion_cost(*cb);
+            cur[j + 1] = substitution.min(deletion).min(insertion);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// Reduces `text` to a canonical form for matching against a list of
+/// filtered words, undoing common ways of disguising Korean words. The
+/// canonical form contains only Hangul, so words in the filter list should
+/// be canonicalized the same way before comparing.
+///
+/// - Everything other than Hangul syllables and jamo is removed, so
+///   separators such as spaces, punctuation, and digits between syllables
+///   no longer break a word apart (씨.발, 시1발).
+/// - Characters that look like jamo are read as jamo when they complete a
+///   syllable with a neighbouring loose jamo, such as 1, l, I, and | for ㅣ
+///   after a consonant, or 7 for ㄱ before a vowel (ㅅ1ㅂㅏㄹ, 7ㅐ).
+/// - Runs of loose jamo are composed into syllables, so words spelled out
+///   in loose jamo match their syllables. Syllables that are already
+///   composed are kept whole, so loose jamo after them never become their
+///   final (시발 ㅅㅂ).
+/// - Tense consonants are replaced by their plain counterparts (ㅆ → ㅅ),
+///   and vowels that sound alike are merged (ㅔ → ㅐ, ㅖ → ㅒ, ㅚ and ㅞ
+///   → ㅙ).
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::string::canonicalize_for_filtering;
+///
+/// let canonical = canonicalize_for_filtering("씨발");
+/// assert_eq!(canonical, "시발");
+/// assert_eq!(canonicalize_for_filtering("ㅅ1ㅂㅏㄹ"), canonical);
+/// assert_eq!(canonicalize_for_filtering("시 1 발!!"), canonical);
+/// assert_eq!(
+///     canonicalize_for_filtering("7ㅐ세끼"),
+///     canonicalize_for_filtering("개새끼")
+/// );
+/// ```
+pub fn canonicalize_for_filtering(text: &str) -> String {
+    let chars: Vec<char> = normalize_hangul(text).chars().collect();
+    let loose_jamo = |i: Option<usize>| -> Option<Jamo> {
+        match Character::from_char(*chars.get(i?)?) {
+            Ok(Character::Hangul(jamo)) => Some(jamo),
+            _ => None,
+        }
+    };
+    let mut result = String::with_capacity(text.len());
+    // Loose jamo not yet composed into syllables
+    let mut jamo = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if let Ok(block) = HangulBlock::from_char(c) {
+            // Infallible: composing compatibility jamo in lenient mode never fails
+            result.push_str(&compose(&jamo));
+            jamo.clear();
+            let mut syllable = String::with_capacity(3);
+            syllable.push(canonical_jamo(block.initial.char_compatibility()));
+            syllable.push(canonical_jamo(block.vowel.char_compatibility()));
+            if let Some(f) = &block.final_optional {
+                syllable.push(canonical_jamo(f.char_compatibility()));
+            }
+            // Infallible: as above
+            result.push_str(&compose(&syllable));
+        } else if let Some(jamo_char) = loose_jamo(Some(i)) {
+            jamo.push(canonical_jamo(jamo_char.char_compatibility()));
+        } else if let Some(lookalike) = lookalike_jamo(c) {
+            let completes_syllable = match lookalike {
+                'ㅣ' | 'ㅐ' => matches!(
+                    loose_jamo(i.checked_sub(1)),
+                    Some(Jamo::Consonant(_) | Jamo::CompositeConsonant(_))
+                ),
+                _ => matches!(
+                    loose_jamo(Some(i + 1)),
+                    Some(Jamo::Vowel(_) | Jamo::CompositeVowel(_))
+                ),
+            };
+            if completes_syllable {
+                jamo.push(lookalike);
+            }
+        }
+    }
+    // Infallible: as above
+    result.push_str(&compose(&jamo));
+    result
+}
+
+// Returns the jamo that `c` replaces in `canonicalize_for_filtering`.
+fn canonical_jamo(c: char) -> char {
+    match c {
+        'ㄲ' => 'ㄱ',
+        'ㄸ' => 'ㄷ',
+        'ㅃ' => 'ㅂ',
+        'ㅆ' => 'ㅅ',
+        'ㅉ' => 'ㅈ',
+        'ㅔ' => 'ㅐ',
+        'ㅖ' => 'ㅒ',
+        'ㅚ' | 'ㅞ' => 'ㅙ',
+        _ => c,
+    }
+}
+
+// Returns the jamo that `c` is commonly written in place of, for
+// `canonicalize_for_filtering`.
+fn lookalike_jamo(c: char) -> Option<char> {
+    match c {
+        '1' | 'l' | 'I' | '|' | '!' => Some('ㅣ'),
+        'H' => Some('ㅐ'),
+        '7' => Some('ㄱ'),
+        'L' => Some('ㄴ'),
+        '0' | 'o' | 'O' => Some('ㅇ'),
+        '^' => Some('ㅅ'),
+        _ => None,
+    }
+}
+
+// Splits `s` into singular compatibility jamo, leaving non-Hangul characters
+// untouched.
+pub(crate) fn decompose_to_singular_jamo(s: &str) -> Vec<char> {
+    let options = HangulBlockDecompositionOptions {
+        decompose_composites: true,
+        jamo_era: JamoUnicodeType::Compatibility,
+    };
+    let mut result = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        if let Ok(jamo) = HangulBlock::from_char(c).and_then(|b| b.decomposed_vec(&options)) {
+            result.extend(jamo);
+            continue;
+        }
+        match Character::from_char(c) {
+            Ok(Character::Hangul(Jamo::CompositeConsonant(composite))) => {
+                let (first, second) = composite.decompose();
+                result.push(first.char_compatibility());
+                result.push(second.char_compatibility());
+            }
+            Ok(Character::Hangul(Jamo::CompositeVowel(composite))) => {
+                let (first, second) = composite.decompose();
+                result.push(first.char_compatibility());
+                result.push(second.char_compatibility());
+            }
+            Ok(Character::Hangul(jamo)) => result.push(jamo.char_compatibility()),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display_width_helpers() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("ﾡ가、"), 5);
+        let nfd = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}";
+        assert_eq!(truncate_to_width(nfd, 3), "\u{1112}\u{1161}\u{11AB}");
+        assert_eq!(truncate_to_width("abc", 10), "abc");
+        assert_eq!(pad_to_width("", 2), "  ");
+        assert_eq!(display_width(&pad_to_width("한a", 6)), 6);
+    }
+
+    #[test]
+    fn test_wrap_keeps_latin_words_and_punctuation() {
+        assert_eq!(
+            wrap(
+                "오늘은 Rustacean들의 날(축제)입니다!",
+                8,
+                WrapStyle::Syllable
+            ),
+            ["오늘은", "Rustacea", "n들의 날", "(축제)입", "니다!"]
+        );
+        assert_eq!(
+            wrap("hello world", 7, WrapStyle::Syllable),
+            ["hello", "world"]
+        );
+        assert_eq!(wrap("「가」나", 6, WrapStyle::Syllable), ["「가」", "나"]);
+    }
+
+    #[test]
+    fn test_wrap_lines_and_whitespace() {
+        assert_eq!(
+            wrap("  가나 다\n\n라마  바사  ", 6, WrapStyle::Word),
+            ["  가나", "다", "", "라마", "바사"]
+        );
+        assert_eq!(wrap("", 10, WrapStyle::Word), Vec::<String>::new());
+        // Conjoining jamo are kept with their syllable
+        let nfd = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}";
+        assert_eq!(wrap(nfd, 2, WrapStyle::Syllable).len(), 2);
+        assert_eq!(wrap("한글", 0, WrapStyle::Syllable), ["한", "글"]);
+    }
+
+    #[test]
+    fn test_no_new_words() {
+        let input = "ㅎㅏㄴㄱㅡㄹ";
+        let mut composer = StringComposer::new();
+        for c in input.chars() {
+            composer.push_char(c).unwrap();
+        }
+        let result = composer.as_string().unwrap();
+        assert_eq!(result, "한글".to_string());
+    }
+
+    #[test]
+    fn test_new_hangul_word() {
+        let input = "ㅎㅏㄴㄱㅡㄹ ㅇㅏㄴㄴㅕㅇㅎㅏㅅㅔㅇㅛ";
+        let mut composer = StringComposer::new();
+        for c in input.chars() {
+            composer.push_char(c).unwrap();
+        }
+        let result = composer.as_string().unwrap();
+        assert_eq!(result, "한글 안녕하세요".to_string());
+    }
+
+    #[test]
+    fn test_new_non_hangul_word() {
+        let input = "ㅎㅏㄴㄱㅡㄹ beans";
+        let mut composer = StringComposer::new();
+        for c in input.chars() {
+            composer.push_char(c).unwrap();
+        }
+        let result = composer.as_string().unwrap();
+        assert_eq!(result, "한글 beans".to_string());
+    }
+
+    #[test]
+    fn test_multiple_words() {
+        let input = "ㅎㅏㄴㄱㅡㄹ 123  \n ㅇㅏㄴㄴㅕㅇ!";
+        let mut composer = StringComposer::new();
+        for c in input.chars() {
+            composer.push_char(c).unwrap();
+        }
+        let result = composer.as_string().unwrap();
+        assert_eq!(result, "한글 123  \n 안녕!".to_string());
+    }
+
+    #[test]
+    fn test_backspace() {
+        let input = "ㅇㅏㄴㄴㅕㅇ ㄹㅏㅁㅕㄴ";
+        let mut composer = StringComposer::new();
+        for c in input.chars() {
+            composer.push_char(c).unwrap();
+        }
+        for _ in 0..7 {
+            composer.pop().unwrap();
+        }
+        let result = composer.as_string().unwrap();
+        assert_eq!(result, "안".to_string());
+    }
+
+    #[test]
+    fn test_jamo_distance() {
+        assert_eq!(jamo_distance("", ""), 0);
+        assert_eq!(jamo_distance("간", "갈"), 1);
+        assert_eq!(jamo_distance("가", "과"), 1);
+        assert_eq!(jamo_distance("값", "갑"), 1);
+        assert_eq!(jamo_distance("ㄱ", "가"), 1);
+        assert_eq!(jamo_distance("\u{1100}\u{1161}", "가"), 0);
+        assert_eq!(jamo_distance("안녕", ""), 6);
+        assert_eq!(jamo_distance("사과 a", "사과 b"), 1);
+    }
+
+    #[test]
+    fn test_jamo_distance_with_cost() {
+        struct CheapVowels;
+        impl CostModel for CheapVowels {
+            fn substitution_cost(&self, from: char, to: char) -> f32 {
+                let is_vowel = |c: char| ('ㅏ'..='ㅣ').contains(&c);
+                if is_vowel(from) && is_vowel(to) {
+                    0.25
+                } else {
+                    1.0
+                }
+            }
+        }
+
+        assert_eq!(jamo_distance_with_cost("간", "건", &CheapVowels), 0.25);
+        assert_eq!(jamo_distance_with_cost("간", "난", &CheapVowels), 1.0);
+        assert_eq!(jamo_distance_with_cost("안녕", "", &CheapVowels), 6.0);
+    }
+
+    #[test]
+    fn test_compose() {
+        assert_eq!(compose(""), "");
+        assert_eq!(compose("hello, world"), "hello, world");
+        assert_eq!(compose("ㅎㅏㄴㄱㅡㄹ 한글"), "한글 한글");
+        assert_eq!(compose("한ㄱㅡㄹ"), "한글");
+        assert_eq!(compose("ㅋㅋㅋ"), "ㅋㅋㅋ");
+        assert_eq!(compose("ㅏㅏ"), "ㅏㅏ");
+        assert_eq!(compose("ㄷㅏㄹㄱㅇㅣ"), "닭이");
+        // A jamo that cannot join the word before it starts a new one
+        assert_eq!(compose("ㄱㅇㅣ"), "ㄱ이");
+        assert_eq!(compose("가\u{11A8}ㅇㅣ"), "가ㄱ이");
+        // U+3130 is unassigned, not a jamo
+        assert_eq!(compose("ㄱ\u{3130}ㅏ"), "ㄱ\u{3130}ㅏ");
+
+        let mut composer = TextComposer::new();
+        composer.feed_str("ㄱㄴㅏ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "ㄱ나");
+        assert_eq!(composer.preedit().unwrap(), "나");
+    }
+
+    #[test]
+    fn test_decompose() {
+        assert_eq!(decompose(""), "");
+        assert_eq!(decompose("ㄱ, a!"), "ㄱ, a!");
+        assert_eq!(decompose("닭"), "ㄷㅏㄺ");
+
+        let options = HangulBlockDecompositionOptions {
+            decompose_composites: true,
+            jamo_era: JamoUnicodeType::Compatibility,
+        };
+        assert_eq!(decompose_with_options("닭", &options).unwrap(), "ㄷㅏㄹㄱ");
+        assert_eq!(decompose_with_options("꽤", &options).unwrap(), "ㄱㄱㅗㅐ");
+    }
+
+    #[test]
+    fn test_decompose_compose_roundtrip() {
+        let text = "안녕하세요, 세계! 닭 값 꽃";
+        assert_eq!(compose(&decompose(text)), text);
+    }
+
+    #[test]
+    fn test_composer_modes() {
+        let push_all = |mode: ComposerMode| {
+            let mut composer = StringComposer::with_options(ComposerOptions::new().with_mode(mode));
+            let results: Vec<_> = "ㅗ가ㅏ!".chars().map(|c| composer.push_char(c)).collect();
+            (results, composer.as_string().unwrap())
+        };
+
+        let (results, lenient) = push_all(ComposerMode::Lenient);
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(lenient, "ㅗ가ㅏ!");
+
+        let (results, replaced) = push_all(ComposerMode::Replace);
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(replaced, "\u{FFFD}가\u{FFFD}!");
+
+        let (results, strict) = push_all(ComposerMode::Strict);
+        assert_eq!(results[0], Err(StringError::InvalidHangul('ㅗ')));
+        assert_eq!(results[2], Err(StringError::InvalidHangul('ㅏ')));
+        assert_eq!(strict, "가!");
+    }
+
+    #[test]
+    fn test_text_composer() {
+        let mut composer = TextComposer::new();
+        composer.feed_str("ㄷㅏㄹㄱ ㅇㅣ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "닭 이");
+        assert_eq!(composer.cursor(), 3);
+
+        // Punctuation and spaces commit the word being composed
+        composer.feed_str("ㅆ. ").unwrap();
+        assert!(!composer.is_composing());
+        assert_eq!(composer.as_string().unwrap(), "닭 있. ");
+        assert_eq!(composer.cursor(), 5);
+
+        while composer.pop().unwrap().is_some() {}
+        assert_eq!(composer.as_string().unwrap(), "");
+        assert_eq!(composer.cursor(), 0);
+
+        let options = ComposerOptions::new().with_mode(ComposerMode::Strict);
+        let mut composer = TextComposer::with_options(options);
+        composer.feed_str("ㄱㅏ").unwrap();
+        assert_eq!(
+            composer.push_char('ㅏ'),
+            Err(StringError::InvalidHangul('ㅏ'))
+        );
+        assert_eq!(composer.as_string().unwrap(), "가");
+
+        // A lone consonant looks the same before and after it is committed
+        let mut composer = TextComposer::new();
+        composer.feed_str("ㄱㅏ ㄴ").unwrap();
+        assert_eq!(composer.preedit().unwrap(), "ㄴ");
+        assert_eq!(composer.as_string().unwrap(), "가 ㄴ");
+        composer.commit().unwrap();
+        assert_eq!(composer.as_string().unwrap(), "가 ㄴ");
+    }
+
+    #[test]
+    fn test_text_composer_editing() {
+        let mut composer = TextComposer::new();
+        composer.feed_str("ㄴㅏㄹㅏ ㅁㅏㄹ").unwrap();
+        composer.move_left().unwrap();
+        composer.move_left().unwrap();
+        assert_eq!(composer.cursor(), 2);
+        composer.feed_str("ㅇㅔ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "나라에 말");
+        assert_eq!(composer.cursor(), 3);
+
+        // Backspace in the middle removes the character before the cursor
+        composer.move_right().unwrap();
+        composer.pop().unwrap();
+        assert_eq!(composer.as_string().unwrap(), "나라에말");
+        assert_eq!(composer.delete().unwrap(), Some('말'));
+        assert_eq!(composer.delete().unwrap(), None);
+        assert_eq!(composer.remove(0).unwrap(), Some('나'));
+        assert_eq!(composer.as_string().unwrap(), "라에");
+        assert_eq!(composer.cursor(), 2);
+
+        composer.set_cursor(100).unwrap();
+        assert_eq!(composer.cursor(), 2);
+        composer.set_cursor(0).unwrap();
+        composer.move_left().unwrap();
+        assert_eq!(composer.cursor(), 0);
+    }
+
+    #[test]
+    fn test_text_composer_reopen_syllables() {
+        let options = ComposerOptions::new().with_reopen_syllables(true);
+        let mut composer = TextComposer::with_options(options);
+        composer.feed_str("ㄱㅏ ㄷㅏ").unwrap();
+        composer.set_cursor(1).unwrap();
+        composer.push_char('ㄴ').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "간 다");
+        composer.push_char('ㅏ').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "가나 다");
+        assert_eq!(composer.cursor(), 2);
+
+        // Syllables after non-Hangul characters are not reopened
+        composer.set_cursor(3).unwrap();
+        composer.push_char('ㅏ').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "가나 ㅏ다");
+        composer.set_cursor(0).unwrap();
+        composer.push_char('ㅇ').unwrap();
+        assert_eq!(composer.as_string().unwrap(), "ㅇ가나 ㅏ다");
+    }
+
+    #[test]
+    fn test_text_composer_replace_range() {
+        let mut composer = TextComposer::new();
+        composer.feed_str("ㅇㅏㄴㄴㅕㅇ").unwrap();
+        composer.replace_range(.., "ㅂㅏㄴㄱㅏㅇㅝ!").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "반가워!");
+        composer.replace_range(2..=2, "").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "반가!");
+        assert_eq!(composer.cursor(), 2);
+        composer.replace_range(5.., "ㅇㅛ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "반가!요");
+
+        // Loose jamo before the range are reopened too
+        let options = ComposerOptions::new().with_reopen_syllables(true);
+        let mut composer = TextComposer::with_options(options);
+        composer.feed_str("ㄱ ㄷㅏㄹ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "ㄱ 달");
+        composer.replace_range(1..2, "ㅏ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "가달");
+        composer.replace_range(3.., "ㄱㅣ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "가달기");
+
+        // Inverted ranges are rejected without changing the text
+        let mut composer = TextComposer::new();
+        composer.feed_str("ㄱㅏㄴㅏ").unwrap();
+        let inverted = (Bound::Included(1), Bound::Excluded(0));
+        assert_eq!(
+            composer.replace_range(inverted, "ㄷㅏ"),
+            Err(StringError::InvalidCursor(1))
+        );
+        assert_eq!(composer.as_string().unwrap(), "가나");
+        assert!(composer.is_composing());
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut composer = StringComposer::new();
+        assert!(!composer.undo());
+
+        let push_str = |composer: &mut StringComposer, s: &str| {
+            composer.checkpoint();
+            for c in s.chars() {
+                composer.push_char(c).unwrap();
+            }
+        };
+        push_str(&mut composer, "ㄷㅏ");
+        push_str(&mut composer, "ㄹㄱ ");
+        push_str(&mut composer, "!");
+        assert_eq!(composer.as_string().unwrap(), "닭 !");
+
+        assert!(composer.undo());
+        assert!(composer.undo());
+        assert_eq!(composer.as_string().unwrap(), "다");
+        assert!(composer.redo());
+        assert_eq!(composer.as_string().unwrap(), "닭 ");
+
+        // A new checkpoint discards the redo history
+        push_str(&mut composer, "?");
+        assert!(!composer.redo());
+        assert!(composer.undo());
+        assert!(composer.undo());
+        assert!(composer.undo());
+        assert_eq!(composer.as_string().unwrap(), "");
+        assert!(!composer.undo());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_serde_roundtrip() {
+        let mut composer = StringComposer::new();
+        for c in "ㅎㅏㄴ ㄱㅡ".chars() {
+            composer.push_char(c).unwrap();
+        }
+        let saved = serde_json::to_string(&composer.state()).unwrap();
+        let snapshot: ComposerSnapshot = serde_json::from_str(&saved).unwrap();
+        assert_eq!(snapshot, composer.state());
+
+        let mut restored = StringComposer::new();
+        restored.restore(&snapshot);
+        restored.push_char('ㄹ').unwrap();
+        assert_eq!(restored.as_string().unwrap(), "한 글");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_text_composer_serde_checks_cursor() {
+        let mut composer = TextComposer::new();
+        for c in "ㅎㅏㄴ ㄱㅡ".chars() {
+            composer.push_char(c).unwrap();
+        }
+        let saved = serde_json::to_string(&composer).unwrap();
+        let restored: TextComposer = serde_json::from_str(&saved).unwrap();
+        assert_eq!(restored.as_string().unwrap(), "한 그");
+
+        let mut value: serde_json::Value = serde_json::from_str(&saved).unwrap();
+        for cursor in [100, 1] {
+            value["cursor"] = cursor.into();
+            assert!(serde_json::from_value::<TextComposer>(value.clone()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_classify_str_matches_char_class() {
+        let text = "ASCII run longer than eight bytes, 한글 ㄱㅏ \u{1100}\u{1161}\u{11A8} \u{318D} ᅀ 😀 end";
+        let expected: Vec<CharClass> = text.chars().map(CharClass::of).collect();
+        assert_eq!(classify_str(text), expected);
+        assert_eq!(classify_str(""), vec![]);
+        assert_eq!(CharClass::of('\u{D7A4}'), CharClass::NonHangul);
+        assert_eq!(CharClass::of('\u{318D}'), CharClass::NonStandardJamo);
+    }
+
+    #[test]
+    fn test_stats() {
+        let result = stats("한글\u{1100}\u{1161}abc 가\t\u{318D}!");
+        assert_eq!(
+            result,
+            HangulStats {
+                syllables: 3,
+                jamo: 3,
+                non_whitespace: 10,
+                longest_run: 4,
+            }
+        );
+        assert_eq!(result.hangul_ratio(), 0.6);
+        assert_eq!(stats(""), HangulStats::default());
+        assert_eq!(stats(" \n").hangul_ratio(), 0.0);
+        assert_eq!(stats("안녕").hangul_ratio(), 1.0);
+    }
+
+    #[test]
+    fn test_korean_confidence() {
+        let korean = [
+            "홍길동",
+            "대한민국의 수도는 서울입니다.",
+            "어제 친구랑 같이 밥을 먹었어요",
+        ];
+        for text in korean {
+            assert!(is_korean(text, 0.7), "{text}");
+        }
+        // Syllables spread evenly over all 11,172, as in corrupted text
+        let scattered: String = block::all_syllables().step_by(1117).collect();
+        assert!(korean_confidence(&scattered) < 0.2, "{scattered}");
+        assert_eq!(korean_confidence("ㅋㅋㅋ"), 0.5);
+        assert_eq!(korean_confidence(""), 0.0);
+        assert_eq!(korean_confidence("   "), 0.0);
+        assert!(korean_confidence("한국어") > korean_confidence("한국어 text"));
+    }
+
+    #[test]
+    fn test_jamo_iter_matches_decompose() {
+        let text = "안녕하세요, 닭과 값! ㅋㅋ \u{1100}";
+        let forward: String = JamoIter::new(text).collect();
+        assert_eq!(forward, decompose(text));
+
+        let mut backward: Vec<char> = JamoIter::new(text).rev().collect();
+        backward.reverse();
+        assert_eq!(backward.into_iter().collect::<String>(), forward);
+
+        // Meeting in the middle of a syllable
+        let mut iter = JamoIter::new("닭");
+        assert_eq!(iter.next(), Some('ㄷ'));
+        assert_eq!(iter.next_back(), Some('ㄺ'));
+        assert_eq!(iter.next(), Some('ㅏ'));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_graphemes_hangul() {
+        let clusters = |s| graphemes_hangul(s).collect::<Vec<_>>();
+        assert!(clusters("").is_empty());
+
+        // Precomposed syllables are already whole clusters
+        assert_eq!(clusters("한글"), ["한", "글"]);
+
+        // Precomposed syllables followed by conjoining jamo (GB7, GB8)
+        assert_eq!(
+            clusters("가\u{11A8}개\u{1161}"),
+            ["가\u{11A8}", "개\u{1161}"]
+        );
+        assert_eq!(clusters("각\u{11A8}\u{1161}"), ["각\u{11A8}", "\u{1161}"]);
+
+        // Leading jamo followed by a syllable (GB6), and archaic jamo
+        assert_eq!(clusters("\u{1100}가"), ["\u{1100}가"]);
+        assert_eq!(
+            clusters("\u{1140}\u{119E}\u{11AB}a"),
+            ["\u{1140}\u{119E}\u{11AB}", "a"]
+        );
+
+        // Compatibility jamo never join
+        assert_eq!(clusters("ㄱㅏ"), ["ㄱ", "ㅏ"]);
+    }
+
+    #[test]
+    fn test_normalize_hangul() {
+        assert!(matches!(normalize_hangul("한글 ㄱ"), Cow::Borrowed(_)));
+
+        // Each syllable of 안녕하세요 in NFD
+        let nfd = "\u{110B}\u{1161}\u{11AB}\u{1102}\u{1167}\u{11BC}\u{1112}\u{1161}\u{1109}\u{1166}\u{110B}\u{116D}";
+        assert_eq!(normalize_hangul(nfd), "안녕하세요");
+
+        // Lone and out-of-order conjoining jamo are left alone
+        assert_eq!(normalize_hangul("\u{1161}\u{1100}"), "\u{1161}\u{1100}");
+        assert_eq!(normalize_hangul("각\u{11A8}"), "각\u{11A8}");
+        assert_eq!(normalize_hangul("\u{1100}\u{11A8}"), "\u{1100}\u{11A8}");
+
+        let mut composer =
+            StringComposer::with_options(ComposerOptions::new().with_normalize(true));
+        composer.feed_str("\u{1112}\u{1161}\u{11AB}ㄱㅡㄹ").unwrap();
+        assert_eq!(composer.as_string().unwrap(), "한글");
+    }
+
+    #[test]
+    fn test_sentences() {
+        let split = |s| sentences(s).collect::<Vec<_>>();
+        assert_eq!(
+            split("갑니다. 가요. 가니? 가죠!"),
+            ["갑니다.", "가요.", "가니?", "가죠!"]
+        );
+        assert_eq!(split("  끝나지 않은 문장  "), ["끝나지 않은 문장"]);
+        assert_eq!(split(""), Vec::<&str>::new());
+        assert_eq!(
+            split("정말?! 네…… 알겠어요…"),
+            ["정말?!", "네……", "알겠어요…"]
+        );
+        assert_eq!(split("그건… 아마 맞을걸."), ["그건… 아마 맞을걸."]);
+        assert_eq!(split("www.example.com 에 가."), ["www.example.com 에 가."]);
+        assert_eq!(
+            split("“빨리 와. 늦었어.” 그가 말했다. 「네.」"),
+            ["“빨리 와. 늦었어.”", "그가 말했다.", "「네.」"]
+        );
+        assert_eq!(
+            split("그녀는 \"왜요?\" 라고 물었다. 대답은 없었다."),
+            ["그녀는 \"왜요?\" 라고 물었다.", "대답은 없었다."]
+        );
+        assert_eq!(split("(참고. 2장) 읽었다."), ["(참고. 2장) 읽었다."]);
+        assert_eq!(
+            split("Mr. Kim과 Dr. Lee가 왔다. 반가웠다."),
+            ["Mr. Kim과 Dr. Lee가 왔다.", "반가웠다."]
+        );
+        assert_eq!(split("사과 vs. 배. 끝."), ["사과 vs. 배.", "끝."]);
+        // Unclosed quotes and brackets are ignored
+        assert_eq!(
+            split("키는 5'11\" 입니다. 밥을 먹었다. 잘 잤다."),
+            ["키는 5'11\" 입니다.", "밥을 먹었다.", "잘 잤다."]
+        );
+        assert_eq!(split("(참고 1장. 읽었다"), ["(참고 1장.", "읽었다"]);
+        assert_eq!(
+            split("(참고 1장\n\n좋아. 가자."),
+            ["(참고 1장\n\n좋아.", "가자."]
+        );
+    }
+
+    #[test]
+    fn test_match_pattern() {
+        assert_eq!(match_pattern("", ""), Ok(true));
+        assert_eq!(match_pattern("한 국", "CVC  CVC"), Ok(true));
+        assert_eq!(match_pattern("한국", "CVC"), Ok(false));
+        assert_eq!(match_pattern("한", "CVC CVC"), Ok(false));
+        assert_eq!(match_pattern("한a", "CVC V"), Ok(false));
+        assert_eq!(
+            match_pattern("한", "CVVC"),
+            Err(StringError::BlockError(BlockError::InvalidPattern(
+                "CVVC".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_syllable_jamo_extraction() {
+        let text = "한글, Hangul 1443ㄱ";
+        assert_eq!(to_chosung(text), "ㅎㄱ, Hangul 1443ㄱ");
+        assert_eq!(to_jungsung(text), "ㅏㅡ, Hangul 1443ㄱ");
+        assert_eq!(to_jongsung(text), "ㄴㄹ, Hangul 1443ㄱ");
+        assert_eq!(to_jongsung("가나다"), "");
+        assert_eq!(to_chosung("\u{1100}\u{1161}"), "\u{1100}\u{1161}");
+    }
+
+    #[test]
+    fn test_expand_chosung() {
+        assert_eq!(
+            expand_chosung("ㄱㅅ"),
+            ["감사", "고생", "김수", "가수", "기사"]
+        );
+        assert_eq!(expand_chosung("ㄱㅅㅎㄴㄷ"), ["감사합니다"]);
+        assert_eq!(expand_chosung(""), Vec::<String>::new());
+
+        let mut dictionary = ChosungDictionary::new();
+        dictionary.extend(["한글", "한국", "한글"]);
+        assert_eq!(dictionary.expand("ㅎㄱ"), ["한글", "한국"]);
+        assert_eq!(dictionary.expand("ㅎ국"), ["한국"]);
+        assert_eq!(dictionary.expand("ㅎ"), Vec::<String>::new());
+        assert_eq!(dictionary.expand("ㅎㄱㄱ"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_chosung_dictionary_lexicon() {
+        let dictionary: ChosungDictionary = ["닭", "달걀", "다리", "한글"].into_iter().collect();
+        assert!(dictionary.contains("닭"));
+        assert!(!dictionary.contains("달"));
+        assert_eq!(dictionary.words_with_prefix("닭"), ["닭", "달걀"]);
+        assert_eq!(dictionary.words_with_prefix("달"), ["닭", "달걀", "다리"]);
+        assert_eq!(dictionary.words(), ["닭", "달걀", "다리", "한글"]);
+    }
+
+    #[test]
+    fn test_validate() {
+        use ValidationIssueKind::*;
+        let kinds = |s| validate(s).into_iter().map(|i| i.kind).collect::<Vec<_>>();
+
+        assert!(validate("").is_empty());
+        assert!(validate("\u{1112}\u{1161}\u{11AB}").is_empty());
+        assert_eq!(kinds("\u{1100}a"), [OrphanedJamo]);
+        assert_eq!(kinds("\u{11A8}"), [OrphanedJamo]);
+        // 각 followed by a second final ㄱ
+        assert_eq!(kinds("각\u{11A8}"), [ImpossibleSequence]);
+        assert_eq!(kinds("\u{1100}\u{1161}\u{1161}"), [ImpossibleSequence]);
+        // 가 with a conjoining final, then ㅋ
+        assert_eq!(kinds("가\u{11A8} ㅋ"), [MixedJamoForms]);
+        assert_eq!(
+            validate("ㄱ\u{1161}"),
+            [
+                ValidationIssue {
+                    offset: 0,
+                    len: 3,
+                    kind: MixedJamoForms
+                },
+                ValidationIssue {
+                    offset: 3,
+                    len: 3,
+                    kind: OrphanedJamo
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_blocks() {
+        let opened = map_blocks("각 a", |mut block| {
+            block.final_optional = None;
+            block
+        });
+        assert_eq!(opened, "가 a");
+        assert_eq!(map_blocks("", |block| block), "");
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_whitespace_chunks() {
+        assert_eq!(whitespace_chunks("", 4), [""]);
+        assert_eq!(whitespace_chunks("ab cd ef", 2), ["ab", " cd", " ef"]);
+        assert_eq!(whitespace_chunks("abcdef", 2), ["abcdef"]);
+        assert_eq!(whitespace_chunks("한글 한글", 4), ["한글", " 한글"]);
+
+        let nfd = "\u{1112}\u{1161}\u{11AB} ".repeat(50_000);
+        assert_eq!(par_decompose(&nfd), decompose(&nfd));
+    }
+
+    #[test]
+    fn test_hangul_reader() {
+        let segments = |input: &[u8]| {
+            HangulReader::new(input)
+                .collect::<io::Result<Vec<_>>>()
+                .map_err(|e| e.kind())
+        };
+        assert_eq!(segments(b""), Ok(vec![]));
+        assert_eq!(
+            segments("가\r\n\n\u{1100}\u{1161}".as_bytes()),
+            Ok(vec![
+                HangulSegment::Syllables("가".to_string()),
+                HangulSegment::Other("\r\n".to_string()),
+                HangulSegment::Other("\n".to_string()),
+                HangulSegment::Jamo("\u{1100}\u{1161}".to_string()),
+            ])
+        );
+        assert_eq!(segments(b"\xFF"), Err(io::ErrorKind::InvalidData));
+    }
+
+    #[test]
+    fn test_composing_writer() {
+        let mut out = Vec::new();
+        {
+            let mut writer = ComposingWriter::new(&mut out);
+            let bytes = "ㄷㅏㄹㄱ, ㄷㅏㄹㄱㅏ".as_bytes();
+            // Split inside the UTF-8 encoding of the first ㄹ
+            writer.write_all(&bytes[..7]).unwrap();
+            writer.write_all(&bytes[7..]).unwrap();
+            assert_eq!(writer.get_ref().as_slice(), "닭, ".as_bytes());
+            assert!(writer.write(b"\xFF").is_err());
+        }
+        assert_eq!(String::from_utf8(out).unwrap(), "닭, 달가");
+
+        // Lone jamo are written as compatibility jamo, as `compose` does
+        let mut writer = ComposingWriter::new(Vec::new());
+        writer.write_all("ㄴ, 가ㄱ".as_bytes()).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(writer.get_ref().as_slice(), "ㄴ, 가ㄱ".as_bytes());
+        writer.write_all(" ㅎ".as_bytes()).unwrap();
+        writer.flush().unwrap();
+        let out = writer.into_inner().unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "ㄴ, 가ㄱ ㅎ");
+        assert_eq!(compose("ㄴ, 가ㄱ ㅎ"), "ㄴ, 가ㄱ ㅎ");
+    }
+
+    #[test]
+    fn test_canonicalize_for_filtering() {
+        assert_eq!(canonicalize_for_filtering(""), "");
+        assert_eq!(canonicalize_for_filtering("hello 1 world"), "");
+        assert_eq!(canonicalize_for_filtering("ㅅ ㅂ"), "ㅅㅂ");
+        // Lookalikes that do not complete a syllable are removed
+        assert_eq!(canonicalize_for_filtering("시l발"), "시발");
+        assert_eq!(canonicalize_for_filtering("ㄱH새끼"), "개새기");
+        assert_eq!(canonicalize_for_filtering("^ㅣ발"), "시발");
+        assert_eq!(canonicalize_for_filtering("0ㅏ 닭"), "아닭");
+        // Loose jamo after a syllable do not become its final
+        assert_eq!(canonicalize_for_filtering("시발 ㅅㅂ"), "시발ㅅㅂ");
+        assert_eq!(canonicalize_for_filtering("가ㄴㅏ"), "가나");
+        // NFD input and composite vowels
+        assert_eq!(
+            canonicalize_for_filtering("\u{1112}\u{116C}\u{11AB} 웨"),
+            "홴왜"
+        );
     }
 }
@@ -0,0 +1,284 @@
+use thiserror::Error;
+
+use crate::block::HangulBlock;
+use crate::jamo::{Choseong, L_BASE, N_COUNT, S_BASE, T_BASE, T_COUNT, V_BASE, V_COUNT};
+use crate::word::{PARTICLES, VERBAL_ENDINGS, can_follow};
+
+/// The marker `encode_jamo` writes in place of each space, as in
+/// SentencePiece.
+pub const WORD_BOUNDARY: char = '▁';
+
+/// The marker `encode_jamo` writes after the jamo of each syllable.
+pub const BLOCK_BOUNDARY: char = 'ᴥ';
+
+/// An error that can occur when encoding or decoding jamo tokens.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TokenizeError {
+    /// Occurs when encoding text that contains one of the markers
+    /// `WORD_BOUNDARY` or `BLOCK_BOUNDARY`, which could not be told apart
+    /// from the markers written by `encode_jamo`.
+    #[error("Text contains the reserved marker '{0}'")]
+    ReservedCharacter(char),
+
+    /// Occurs when decoding a `BLOCK_BOUNDARY` marker, at the given byte
+    /// offset, that does not follow the jamo of a modern syllable.
+    #[error("Block boundary at offset {0} does not follow a syllable")]
+    InvalidBlock(usize),
+}
+
+/// Encodes `text` for jamo-level language models and BPE tokenizers. Each
+/// precomposed syllable is written as its conjoining jamo followed by
+/// `BLOCK_BOUNDARY`, and each space as `WORD_BOUNDARY`; everything else,
+/// including loose jamo, is kept. Splitting the result into characters
+/// gives one token per jamo or marker, and `decode_jamo` recovers `text`
+/// exactly.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::tokenize::{decode_jamo, encode_jamo};
+///
+/// let encoded = encode_jamo("학교 가요").unwrap();
+/// assert_eq!(encoded, "\u{1112}\u{1161}\u{11A8}ᴥ\u{1100}\u{116D}ᴥ▁\u{1100}\u{1161}ᴥ\u{110B}\u{116D}ᴥ");
+/// assert_eq!(decode_jamo(&encoded).unwrap(), "학교 가요");
+/// ```
+pub fn encode_jamo(text: &str) -> Result<String, TokenizeError> {
+    let mut result = String::with_capacity(text.len() * 3);
+    for c in text.chars() {
+        match c {
+            WORD_BOUNDARY | BLOCK_BOUNDARY => return Err(TokenizeError::ReservedCharacter(c)),
+            ' ' => result.push(WORD_BOUNDARY),
+            '가'..='힣' => {
+                let s_index = c as u32 - S_BASE;
+                let final_index = s_index % T_COUNT;
+                result.extend(char::from_u32(L_BASE + s_index / N_COUNT));
+                result.extend(char::from_u32(V_BASE + s_index % N_COUNT / T_COUNT));
+                if final_index != 0 {
+                    result.extend(char::from_u32(T_BASE + final_index));
+                }
+                result.push(BLOCK_BOUNDARY);
+            }
+            _ => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+/// Decodes text encoded with `encode_jamo`, composing the jamo before each
+/// `BLOCK_BOUNDARY` into a syllable and replacing each `WORD_BOUNDARY` with
+/// a space. Conjoining jamo not followed by a marker are kept as they are.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::tokenize::{TokenizeError, decode_jamo};
+///
+/// assert_eq!(decode_jamo("\u{1112}\u{1161}\u{11AB}ᴥ!").unwrap(), "한!");
+/// assert_eq!(decode_jamo("aᴥ"), Err(TokenizeError::InvalidBlock(1)));
+/// ```
+pub fn decode_jamo(encoded: &str) -> Result<String, TokenizeError> {
+    let mut result = String::with_capacity(encoded.len());
+    for (offset, c) in encoded.char_indices() {
+        match c {
+            WORD_BOUNDARY => result.push(' '),
+            BLOCK_BOUNDARY => {
+                let syllable =
+                    pop_syllable(&mut result).ok_or(TokenizeError::InvalidBlock(offset))?;
+                result.push(syllable);
+            }
+            _ => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+// Removes the jamo of a modern syllable from the end of `text` and returns
+// the syllable, or returns `None` and leaves `text` unchanged if it does not
+// end in an initial and a vowel, optionally followed by a final.
+fn pop_syllable(text: &mut String) -> Option<char> {
+    let mut chars = text.chars().rev();
+    let last = chars.next()? as u32;
+    let (final_index, vowel) = if (T_BASE + 1..T_BASE + T_COUNT).contains(&last) {
+        (last - T_BASE, chars.next()? as u32)
+    } else {
+        (0, last)
+    };
+    let initial = chars.next()? as u32;
+    if !(V_BASE..V_BASE + V_COUNT).contains(&vowel)
+        || !(L_BASE..L_BASE + Choseong::COUNT as u32).contains(&initial)
+    {
+        return None;
+    }
+    let len = if final_index == 0 { 2 } else { 3 };
+    text.truncate(text.len() - len * 3);
+    char::from_u32(S_BASE + (initial - L_BASE) * N_COUNT + (vowel - V_BASE) * T_COUNT + final_index)
+}
+
+/// A word found by `morph_lite`, with its candidate splits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MorphToken<'a> {
+    /// The word.
+    pub text: &'a str,
+
+    /// The byte offset of the word in the text.
+    pub offset: usize,
+
+    /// The ways the word could be split into a stem and a particle or
+    /// ending, longest suffix first.
+    pub splits: Vec<MorphSplit<'a>>,
+}
+
+/// A candidate split of a word into a stem and a suffix, found by
+/// `morph_lite`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MorphSplit<'a> {
+    /// The start of the word before the suffix.
+    pub stem: &'a str,
+
+    /// The particle or ending at the end of the word.
+    pub suffix: &'a str,
+
+    /// Whether the suffix is a particle or an ending.
+    pub kind: SuffixKind,
+}
+
+/// The kind of suffix in a `MorphSplit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuffixKind {
+    /// A particle (조사) attached to a noun, such as 을 or 에서.
+    Particle,
+
+    /// A verbal ending (어미) or the copula, such as 어요 or 입니다.
+    Ending,
+}
+
+/// Splits `text` into words and lists the candidate stem and suffix splits
+/// of each, for search and highlighting. This is not a morphological
+/// analyzer: each split only checks that the suffix is one of a small list
+/// of common particles (조사) and endings (어미), the same used by
+/// `word::stem`, and that it can follow the last syllable of the stem, so
+/// that 을 only follows a final consonant and 를 only follows a vowel.
+///
+/// Words are runs of Hangul syllables, so spacing and punctuation around
+/// them do not matter. A word with no possible split has no candidates, and
+/// the whole word is never given as a stem without a suffix.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::tokenize::{SuffixKind, morph_lite};
+///
+/// let tokens = morph_lite("학교에서는,  책을 읽어요!");
+/// let words: Vec<&str> = tokens.iter().map(|t| t.text).collect();
+/// assert_eq!(words, ["학교에서는", "책을", "읽어요"]);
+///
+/// let split = tokens[0].splits[0];
+/// assert_eq!((split.stem, split.suffix), ("학교", "에서는"));
+/// assert_eq!(split.kind, SuffixKind::Particle);
+/// assert_eq!(tokens[1].splits[0].stem, "책");
+/// assert_eq!(tokens[2].splits[0].suffix, "어요");
+///
+/// // 를 cannot follow a final consonant
+/// assert!(morph_lite("책를")[0].splits.is_empty());
+/// ```
+pub fn morph_lite(text: &str) -> Vec<MorphToken<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (HangulBlock::from_char(c).is_ok(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(offset)) => {
+                tokens.push(morph_token(&text[offset..i], offset));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+// Lists the candidate splits of the Hangul word `word` at `offset`.
+fn morph_token(word: &str, offset: usize) -> MorphToken<'_> {
+    let suffixes = PARTICLES
+        .iter()
+        .map(|&(suffix, follows)| (suffix, follows, SuffixKind::Particle))
+        .chain(
+            VERBAL_ENDINGS
+                .iter()
+                .map(|&(suffix, follows)| (suffix, follows, SuffixKind::Ending)),
+        );
+    let mut splits: Vec<MorphSplit> = suffixes
+        .filter_map(|(suffix, follows, kind)| {
+            let stem = word.strip_suffix(suffix)?;
+            can_follow(stem, follows).then_some(MorphSplit {
+                stem,
+                suffix: &word[stem.len()..],
+                kind,
+            })
+        })
+        .collect();
+    splits.sort_by_key(|split| std::cmp::Reverse(split.suffix.len()));
+    MorphToken {
+        text: word,
+        offset,
+        splits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_exactly() {
+        let texts = [
+            "",
+            "안녕하세요, world!",
+            "값  닭\t뷁",
+            "ㅋㅋ ㅠㅠ",
+            // Loose conjoining jamo, including a syllable in NFD
+            "\u{1100}\u{1161}\u{11A8}가\u{11A8}",
+            "\u{11A8}\u{1100}",
+        ];
+        for text in texts {
+            let encoded = encode_jamo(text).unwrap();
+            assert_eq!(decode_jamo(&encoded).unwrap(), text);
+        }
+        let all: String = crate::block::all_syllables().collect();
+        assert_eq!(decode_jamo(&encode_jamo(&all).unwrap()).unwrap(), all);
+    }
+
+    #[test]
+    fn reserved_and_invalid_markers() {
+        assert_eq!(
+            encode_jamo("a▁b"),
+            Err(TokenizeError::ReservedCharacter('▁'))
+        );
+        assert_eq!(encode_jamo("ᴥ"), Err(TokenizeError::ReservedCharacter('ᴥ')));
+        assert_eq!(decode_jamo("ᴥ"), Err(TokenizeError::InvalidBlock(0)));
+        // A vowel and final without an initial
+        assert_eq!(
+            decode_jamo("\u{1161}\u{11A8}ᴥ"),
+            Err(TokenizeError::InvalidBlock(6))
+        );
+        assert_eq!(decode_jamo("▁▁"), Ok("  ".to_string()));
+    }
+
+    #[test]
+    fn morph_lite_candidates() {
+        let tokens = morph_lite("  바다가 먹었다");
+        assert_eq!(tokens[0].offset, 2);
+        let splits: Vec<(&str, &str)> = tokens[0]
+            .splits
+            .iter()
+            .map(|s| (s.stem, s.suffix))
+            .collect();
+        assert_eq!(splits, [("바다", "가")]);
+        // Splits are only plausible: 바다 is a noun, not 바 with the ending 다
+        assert_eq!(morph_lite("바다")[0].splits[0].suffix, "다");
+        let splits = &tokens[1].splits;
+        assert_eq!(splits[0].stem, "먹었");
+        assert_eq!(splits[0].kind, SuffixKind::Ending);
+
+        assert!(morph_lite("").is_empty());
+        assert!(morph_lite("hello, world").is_empty());
+        assert!(morph_lite("가")[0].splits.is_empty());
+    }
+}
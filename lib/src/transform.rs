@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+
+use crate::block::{syllable_from_indices, syllable_indices};
+
+/// A stylization of Hangul text, as used online, that rewrites syllable
+/// blocks and can be undone with `revert`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transform::{IeungFinal, Transformer};
+///
+/// assert_eq!(IeungFinal.transform("좋아 고마워"), "좋앙 고마웡");
+/// assert_eq!(IeungFinal.revert("좋앙 고마웡"), "좋아 고마워");
+/// ```
+pub trait Transformer {
+    /// Returns the name of this transformer, as accepted by `by_name`.
+    fn name(&self) -> &'static str;
+
+    /// Applies this stylization to `text`. Characters that are not
+    /// precomposed Hangul syllables are left unchanged.
+    fn transform(&self, text: &str) -> String;
+
+    /// Undoes this stylization. For transformers that map several
+    /// syllables to the same result, reverting also changes syllables that
+    /// were already in the stylized form in the original text.
+    fn revert(&self, text: &str) -> String;
+}
+
+/// Adds ㅇ as a final consonant to the last syllable of each word if it has
+/// none, a cute (애교) style: 좋아 → 좋앙, 해요 → 해용.
+///
+/// Reverting removes a final ㅇ from the last syllable of each word, so it
+/// also changes words that originally end in ㅇ (사랑 → 사라).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IeungFinal;
+
+impl Transformer for IeungFinal {
+    fn name(&self) -> &'static str {
+        "ieung-final"
+    }
+
+    fn transform(&self, text: &str) -> String {
+        map_syllables(text, |c, word_final| match syllable_indices(c) {
+            Some((l, v, 0)) if word_final => syllable_from_indices(l, v, FINAL_IEUNG),
+            _ => None,
+        })
+    }
+
+    fn revert(&self, text: &str) -> String {
+        map_syllables(text, |c, word_final| match syllable_indices(c) {
+            Some((l, v, FINAL_IEUNG)) if word_final => syllable_from_indices(l, v, 0),
+            _ => None,
+        })
+    }
+}
+
+/// Replaces ㅗ with ㅛ and ㅜ with ㅠ after a consonant, a cute (애교) style:
+/// 보고 싶어 → 뵤교 싶어, 누구 → 뉴규.
+///
+/// Reverting replaces ㅛ with ㅗ and ㅠ with ㅜ after a consonant, so it also
+/// changes syllables that originally had ㅛ or ㅠ (교실 → 고실).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Yotization;
+
+impl Transformer for Yotization {
+    fn name(&self) -> &'static str {
+        "yotization"
+    }
+
+    fn transform(&self, text: &str) -> String {
+        map_syllables(text, |c, _| swap_vowel(c, &[(O, YO), (U, YU)]))
+    }
+
+    fn revert(&self, text: &str) -> String {
+        map_syllables(text, |c, _| swap_vowel(c, &[(YO, O), (YU, U)]))
+    }
+}
+
+/// Swaps syllables that look alike when written, as in 야민정음: 명작 ↔
+/// 띵작, 멍멍이 ↔ 댕댕이, 귀엽다 ↔ 커엽다.
+///
+/// Each swap goes both ways, so transforming twice returns the original
+/// text and `revert` is the same as `transform`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Yaminjeongeum;
+
+impl Transformer for Yaminjeongeum {
+    fn name(&self) -> &'static str {
+        "yaminjeongeum"
+    }
+
+    fn transform(&self, text: &str) -> String {
+        map_syllables(text, |c, _| {
+            YAMINJEONGEUM_PAIRS.iter().find_map(|&(a, b)| {
+                if c == a {
+                    Some(b)
+                } else if c == b {
+                    Some(a)
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    fn revert(&self, text: &str) -> String {
+        self.transform(text)
+    }
+}
+
+/// Returns the transformer with the given name, or `None` if there is no
+/// such transformer.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transform::by_name;
+///
+/// let transformer = by_name("yaminjeongeum").unwrap();
+/// assert_eq!(transformer.transform("명작"), "띵작");
+/// assert!(by_name("unknown").is_none());
+/// ```
+pub fn by_name(name: &str) -> Option<Box<dyn Transformer>> {
+    let transformers: [Box<dyn Transformer>; 3] = [
+        Box::new(IeungFinal),
+        Box::new(Yotization),
+        Box::new(Yaminjeongeum),
+    ];
+    transformers.into_iter().find(|t| t.name() == name)
+}
+
+/// Spells Latin letters in Hangul as they are read aloud, with a
+/// customizable table of letter readings and of words that are read as
+/// words rather than letter by letter.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transform::LatinSpeller;
+///
+/// let speller = LatinSpeller::new()
+///     .with_letter('Z', "지")
+///     .with_word("KOSPI", "코스피");
+/// assert_eq!(speller.spell("KOSPI와 LZ"), "코스피와 엘지");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatinSpeller {
+    letters: [String; 26],
+    // Words read as words, keyed by their uppercase spelling.
+    words: HashMap<String, String>,
+}
+
+impl Default for LatinSpeller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LatinSpeller {
+    /// Creates a speller with the common readings of the letters (A 에이,
+    /// B 비, C 씨, ...) and of some words usually read as words, such as OK
+    /// (오케이), NASA (나사), and app (앱).
+    pub fn new() -> Self {
+        Self {
+            letters: LETTER_READINGS.map(str::to_string),
+            words: COMMON_LATIN_WORDS
+                .iter()
+                .map(|&(word, reading)| (word.to_ascii_uppercase(), reading.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Sets the reading of `letter` in either case. Characters other than
+    /// ASCII letters are ignored.
+    pub fn with_letter(mut self, letter: char, reading: &str) -> Self {
+        if letter.is_ascii_alphabetic() {
+            let index = (letter.to_ascii_uppercase() as u8 - b'A') as usize;
+            self.letters[index] = reading.to_string();
+        }
+        self
+    }
+
+    /// Makes `word` read as `reading` instead of letter by letter, in any
+    /// case.
+    pub fn with_word(mut self, word: &str, reading: &str) -> Self {
+        self.words
+            .insert(word.to_ascii_uppercase(), reading.to_string());
+        self
+    }
+
+    /// Replaces each run of ASCII letters in `text` with its reading: the
+    /// word's reading if it is in the table, and otherwise the readings of
+    /// its letters. Other characters are left unchanged.
+    pub fn spell(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len() * 3);
+        let mut rest = text;
+        while let Some(start) = rest.find(|c: char| c.is_ascii_alphabetic()) {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let len = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            match self.words.get(&word.to_ascii_uppercase()) {
+                Some(reading) => result.push_str(reading),
+                None => {
+                    for letter in word.bytes() {
+                        result
+                            .push_str(&self.letters[(letter.to_ascii_uppercase() - b'A') as usize]);
+                    }
+                }
+            }
+            rest = &rest[len..];
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+/// Spells the Latin letters in `text` in Hangul as they are read aloud,
+/// using the default table of `LatinSpeller`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transform::spell_latin;
+///
+/// assert_eq!(spell_latin("ABC"), "에이비씨");
+/// assert_eq!(spell_latin("KBS 뉴스"), "케이비에스 뉴스");
+/// assert_eq!(spell_latin("OK"), "오케이");
+/// ```
+pub fn spell_latin(text: &str) -> String {
+    LatinSpeller::new().spell(text)
+}
+
+// Vowel and final indices used by the transformers, as used by
+// `block::syllable_indices`.
+const IEUNG: usize = 11;
+const O: usize = 8;
+const YO: usize = 12;
+const U: usize = 13;
+const YU: usize = 17;
+const FINAL_IEUNG: usize = 21;
+
+// Pairs of syllables that 야민정음 swaps.
+const YAMINJEONGEUM_PAIRS: [(char, char); 11] = [
+    ('대', '머'),
+    ('댕', '멍'),
+    ('며', '띠'),
+    ('명', '띵'),
+    ('면', '띤'),
+    ('귀', '커'),
+    ('비', '네'),
+    ('빔', '넴'),
+    ('파', '과'),
+    ('팔', '괄'),
+    ('유', '윾'),
+];
+
+// The readings of the letters A to Z.
+const LETTER_READINGS: [&str; 26] = [
+    "에이",
+    "비",
+    "씨",
+    "디",
+    "이",
+    "에프",
+    "지",
+    "에이치",
+    "아이",
+    "제이",
+    "케이",
+    "엘",
+    "엠",
+    "엔",
+    "오",
+    "피",
+    "큐",
+    "알",
+    "에스",
+    "티",
+    "유",
+    "브이",
+    "더블유",
+    "엑스",
+    "와이",
+    "제트",
+];
+
+// Common words that are read as words rather than spelled out.
+const COMMON_LATIN_WORDS: [(&str, &str); 20] = [
+    ("OK", "오케이"),
+    ("NASA", "나사"),
+    ("UNESCO", "유네스코"),
+    ("UNICEF", "유니세프"),
+    ("FIFA", "피파"),
+    ("NATO", "나토"),
+    ("email", "이메일"),
+    ("app", "앱"),
+    ("web", "웹"),
+    ("internet", "인터넷"),
+    ("online", "온라인"),
+    ("blog", "블로그"),
+    ("game", "게임"),
+    ("data", "데이터"),
+    ("server", "서버"),
+    ("computer", "컴퓨터"),
+    ("smart", "스마트"),
+    ("phone", "폰"),
+    ("Google", "구글"),
+    ("YouTube", "유튜브"),
+];
+
+// Maps each precomposed syllable of `text` with `f`, which is given whether
+// the syllable ends a word (is not followed by another syllable) and
+// returns `None` to keep it unchanged.
+fn map_syllables(text: &str, f: impl Fn(char, bool) -> Option<char>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if syllable_indices(c).is_none() {
+            result.push(c);
+            continue;
+        }
+        let word_final = chars.peek().is_none_or(|&n| syllable_indices(n).is_none());
+        result.push(f(c, word_final).unwrap_or(c));
+    }
+    result
+}
+
+// Replaces the vowel of `c` according to `swaps` if its initial is not the
+// silent ㅇ.
+fn swap_vowel(c: char, swaps: &[(usize, usize)]) -> Option<char> {
+    let (l, v, t) = syllable_indices(c)?;
+    let &(_, to) = swaps.iter().find(|&&(from, _)| from == v && l != IEUNG)?;
+    syllable_from_indices(l, to, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ieung_final_only_changes_word_final_open_syllables() {
+        assert_eq!(IeungFinal.transform("미안해요, 사랑 a"), "미안해용, 사랑 a");
+        assert_eq!(IeungFinal.revert("미안해용"), "미안해요");
+        assert_eq!(IeungFinal.transform(""), "");
+    }
+
+    #[test]
+    fn yotization_round_trips() {
+        let text = "보고 싶어 누구야 오늘";
+        let styled = Yotization.transform(text);
+        assert_eq!(styled, "뵤교 싶어 뉴규야 오늘");
+        assert_eq!(Yotization.revert(&styled), text);
+    }
+
+    #[test]
+    fn yaminjeongeum_is_an_involution() {
+        assert_eq!(
+            Yaminjeongeum.transform("댕댕이 팔도비빔면"),
+            "멍멍이 괄도네넴띤"
+        );
+        let text = "세종대왕 명작 귀엽다";
+        assert_eq!(Yaminjeongeum.revert(&Yaminjeongeum.transform(text)), text);
+    }
+
+    #[test]
+    fn latin_spelling() {
+        assert_eq!(spell_latin("I love K-pop!"), "아이 엘오브이이 케이-피오피!");
+        assert_eq!(spell_latin("youtube와 Email"), "유튜브와 이메일");
+        let speller = LatinSpeller::new()
+            .with_letter('r', "아르")
+            .with_letter('1', "일");
+        assert_eq!(speller.spell("R1"), "아르1");
+    }
+
+    #[test]
+    fn names_are_unique() {
+        for name in ["ieung-final", "yotization", "yaminjeongeum"] {
+            assert_eq!(by_name(name).unwrap().name(), name);
+        }
+    }
+}
@@ -0,0 +1,670 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::string::compose;
+
+/// An error that can occur when transliterating.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TransliterateError {
+    /// Occurs when parsing a string that is not an ARPAbet phoneme.
+    #[error("Unknown ARPAbet phoneme: {0}")]
+    UnknownPhoneme(String),
+}
+
+/// An English phoneme, named as in the ARPAbet used by the CMU Pronouncing
+/// Dictionary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phoneme {
+    /// The vowel of "father" (ɑ).
+    Aa,
+    /// The vowel of "cat" (æ).
+    Ae,
+    /// The vowel of "cup" and the schwa (ʌ, ə).
+    Ah,
+    /// The vowel of "thought" (ɔ).
+    Ao,
+    /// The diphthong of "house" (aʊ).
+    Aw,
+    /// The diphthong of "time" (aɪ).
+    Ay,
+    /// The vowel of "bed" (ɛ).
+    Eh,
+    /// The vowel of "bird" (ɝ).
+    Er,
+    /// The diphthong of "cake" (eɪ).
+    Ey,
+    /// The vowel of "sit" (ɪ).
+    Ih,
+    /// The vowel of "see" (i).
+    Iy,
+    /// The diphthong of "boat" (oʊ).
+    Ow,
+    /// The diphthong of "oil" (ɔɪ).
+    Oy,
+    /// The vowel of "book" (ʊ).
+    Uh,
+    /// The vowel of "food" (u).
+    Uw,
+    /// b
+    B,
+    /// The first sound of "chin" (tʃ).
+    Ch,
+    /// d
+    D,
+    /// The first sound of "this" (ð).
+    Dh,
+    /// f
+    F,
+    /// g
+    G,
+    /// h
+    Hh,
+    /// The first sound of "jam" (dʒ).
+    Jh,
+    /// k
+    K,
+    /// l
+    L,
+    /// m
+    M,
+    /// n
+    N,
+    /// The last sound of "sing" (ŋ).
+    Ng,
+    /// p
+    P,
+    /// r
+    R,
+    /// s
+    S,
+    /// The first sound of "ship" (ʃ).
+    Sh,
+    /// t
+    T,
+    /// The first sound of "thin" (θ).
+    Th,
+    /// v
+    V,
+    /// w
+    W,
+    /// The first sound of "yes" (j).
+    Y,
+    /// z
+    Z,
+    /// The middle sound of "vision" (ʒ).
+    Zh,
+}
+
+impl Phoneme {
+    /// Returns whether the phoneme is a vowel or diphthong.
+    pub fn is_vowel(self) -> bool {
+        use Phoneme::*;
+        matches!(
+            self,
+            Aa | Ae | Ah | Ao | Aw | Ay | Eh | Er | Ey | Ih | Iy | Ow | Oy | Uh | Uw
+        )
+    }
+
+    /// Returns the ARPAbet symbol of the phoneme.
+    pub fn arpabet(self) -> &'static str {
+        // Infallible: every phoneme is in the table
+        ARPABET.iter().find(|(_, p)| *p == self).unwrap().0
+    }
+}
+
+impl fmt::Display for Phoneme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.arpabet())
+    }
+}
+
+impl FromStr for Phoneme {
+    type Err = TransliterateError;
+
+    /// Parses an ARPAbet symbol in either case, ignoring the stress digit
+    /// of vowels (`AH0`, `UW1`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let symbol = s.trim_end_matches(|c: char| c.is_ascii_digit());
+        ARPABET
+            .iter()
+            .find(|(arpabet, _)| arpabet.eq_ignore_ascii_case(symbol))
+            .map(|&(_, phoneme)| phoneme)
+            .ok_or_else(|| TransliterateError::UnknownPhoneme(s.to_string()))
+    }
+}
+
+/// Transcribes the pronunciation of an English word in Hangul, following
+/// the loanword transcription rules (외래어 표기법) of the National Institute
+/// of Korean Language for English: for example, final voiceless stops
+/// after a short vowel become final consonants (gap → 갭) and other
+/// consonants not followed by a vowel take 으 (desk → 데스크), ㄹ between
+/// vowels is doubled (slide → 슬라이드), and postvocalic r is not written
+/// (part → 파트).
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transliterate::{Phoneme::*, phonemes_to_hangul};
+///
+/// assert_eq!(phonemes_to_hangul(&[G, Ae, P]), "갭");
+/// assert_eq!(phonemes_to_hangul(&[D, Eh, S, K]), "데스크");
+/// assert_eq!(phonemes_to_hangul(&[S, L, Ay, D]), "슬라이드");
+/// ```
+pub fn phonemes_to_hangul(phonemes: &[Phoneme]) -> String {
+    use Phoneme::*;
+
+    let mut jamo = String::new();
+    let mut i = 0;
+    while i < phonemes.len() {
+        let phoneme = phonemes[i];
+        let prev = i.checked_sub(1).map(|j| phonemes[j]);
+        let next = phonemes.get(i + 1).copied();
+        let after_next = phonemes.get(i + 2).copied();
+        let before_vowel = next.is_some_and(Phoneme::is_vowel);
+        let before_glide_vowel =
+            matches!(next, Some(W | Y)) && after_next.is_some_and(Phoneme::is_vowel);
+
+        if phoneme.is_vowel() {
+            jamo.push('ㅇ');
+            i += push_vowel(&mut jamo, None, &phonemes[i..]);
+            continue;
+        }
+
+        match phoneme {
+            // Glides at the start of a syllable combine with the vowel
+            W | Y if before_vowel => {
+                jamo.push('ㅇ');
+                i += 1 + push_vowel(&mut jamo, Some(phoneme), &phonemes[i + 1..]);
+                continue;
+            }
+            W => jamo.push_str("ㅇㅜ"),
+            Y => jamo.push_str("ㅇㅣ"),
+            // Postvocalic r is not written
+            R if !before_vowel => {}
+            // ŋ between vowels is written as a final
+            Ng => jamo.push('ㅇ'),
+            // l before a vowel or a final nasal is doubled, except at the
+            // start of a word and after a nasal
+            L if before_vowel && i > 0 && !matches!(prev, Some(M | N | Ng)) => {
+                jamo.push_str("ㄹㄹ");
+                i += 1 + push_vowel(&mut jamo, None, &phonemes[i + 1..]);
+                continue;
+            }
+            L if matches!(next, Some(M | N)) && !after_next.is_some_and(Phoneme::is_vowel) => {
+                jamo.push_str("ㄹㄹㅡ");
+            }
+            Sh if before_vowel => {
+                jamo.push('ㅅ');
+                i += 1 + push_vowel(&mut jamo, Some(Y), &phonemes[i + 1..]);
+                continue;
+            }
+            Sh if next.is_none() => jamo.push_str("ㅅㅣ"),
+            Sh => jamo.push_str("ㅅㅠ"),
+            Zh | Ch | Jh if !before_vowel && !before_glide_vowel => {
+                jamo.push(if phoneme == Ch { 'ㅊ' } else { 'ㅈ' });
+                jamo.push('ㅣ');
+            }
+            // g, h, and k combine with a following w into one syllable;
+            // other consonants take 으 before it
+            G | Hh | K if before_glide_vowel && next == Some(W) => {
+                jamo.push(consonant(phoneme));
+                i += 2 + push_vowel(&mut jamo, Some(W), &phonemes[i + 2..]);
+                continue;
+            }
+            _ if before_glide_vowel && next == Some(Y) => {
+                jamo.push(consonant(phoneme));
+                i += 2 + push_vowel(&mut jamo, Some(Y), &phonemes[i + 2..]);
+                continue;
+            }
+            _ if before_vowel => {
+                jamo.push(consonant(phoneme));
+                i += 1 + push_vowel(&mut jamo, None, &phonemes[i + 1..]);
+                continue;
+            }
+            // Voiceless stops after a short vowel are finals at the end of
+            // a word and before consonants other than l, r, m, and n
+            P | T | K
+                if prev.is_some_and(is_short_vowel) && !matches!(next, Some(L | R | M | N)) =>
+            {
+                jamo.push(match phoneme {
+                    P => 'ㅂ',
+                    T => 'ㅅ',
+                    _ => 'ㄱ',
+                });
+            }
+            // m, n, and l are finals after a vowel, and otherwise take 으
+            // like other consonants
+            M | N | L if jamo.ends_with(|c| ('ㅏ'..='ㅣ').contains(&c)) => {
+                jamo.push(consonant(phoneme));
+            }
+            _ => {
+                jamo.push(consonant(phoneme));
+                jamo.push('ㅡ');
+            }
+        }
+        i += 1;
+    }
+    compose(&jamo)
+}
+
+/// Transcribes a pronunciation written in ARPAbet, such as an entry of the
+/// CMU Pronouncing Dictionary, in Hangul as `phonemes_to_hangul` does.
+/// Stress digits are ignored.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transliterate::arpabet_to_hangul;
+///
+/// assert_eq!(arpabet_to_hangul("K AH0 M P Y UW1 T ER0").unwrap(), "컴퓨터");
+/// assert_eq!(arpabet_to_hangul("T AW1 ER0").unwrap(), "타워");
+/// assert!(arpabet_to_hangul("K Q").is_err());
+/// ```
+pub fn arpabet_to_hangul(arpabet: &str) -> Result<String, TransliterateError> {
+    let phonemes = arpabet
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<Vec<Phoneme>, _>>()?;
+    Ok(phonemes_to_hangul(&phonemes))
+}
+
+/// Guesses the pronunciation of an English word from its spelling, using
+/// a small list of common loanwords and otherwise simple spelling rules.
+/// English spelling is irregular, so the result is only an approximation;
+/// use `phonemes_to_hangul` with a pronouncing dictionary where accuracy
+/// matters.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transliterate::{Phoneme::*, english_to_phonemes};
+///
+/// assert_eq!(english_to_phonemes("desk"), [D, Eh, S, K]);
+/// assert_eq!(english_to_phonemes("Shark"), [Sh, Aa, K]);
+/// ```
+pub fn english_to_phonemes(word: &str) -> Vec<Phoneme> {
+    use Phoneme::*;
+
+    let word = word.to_ascii_lowercase();
+    if let Some(&(_, arpabet)) = COMMON_LOANWORDS.iter().find(|(w, _)| *w == word) {
+        // Infallible: the table only contains valid ARPAbet
+        return arpabet
+            .split_whitespace()
+            .map(|p| p.parse().unwrap())
+            .collect();
+    }
+
+    let letters: Vec<u8> = word.bytes().filter(u8::is_ascii_alphabetic).collect();
+    let is_vowel = |b: Option<&u8>| matches!(b, Some(b'a' | b'e' | b'i' | b'o' | b'u' | b'y'));
+    let mut phonemes = Vec::new();
+    let mut i = 0;
+    while i < letters.len() {
+        let rest = &letters[i..];
+        let at = |n: usize| letters.get(i + n);
+        let at_end = |n: usize| i + n == letters.len();
+        // A vowel followed by one consonant and a final e is long
+        let magic_e = at_end(3) && at(2) == Some(&b'e') && !is_vowel(at(1));
+        let (sounds, len): (&[Phoneme], usize) = match rest {
+            [b't', b'i', b'o', b'n', ..] => (&[Sh, Ah, N], 4),
+            [b's', b'i', b'o', b'n', ..] => (&[Zh, Ah, N], 4),
+            [b't', b'u', b'r', b'e', ..] => (&[Ch, Er], 4),
+            [b'i', b'g', b'h', ..] => (&[Ay], 3),
+            [b't', b'c', b'h', ..] => (&[Ch], 3),
+            [b'd', b'g', b'e', ..] => (&[Jh], 3),
+            [b'p', b'h', ..] => (&[F], 2),
+            [b't', b'h', ..] => (&[Th], 2),
+            [b's', b'h', ..] => (&[Sh], 2),
+            [b'c', b'h', ..] => (&[Ch], 2),
+            [b'c', b'k', ..] => (&[K], 2),
+            [b'n', b'g', ..] => (&[Ng], 2),
+            [b'n', b'k', ..] => (&[Ng, K], 2),
+            [b'q', b'u', ..] => (&[K, W], 2),
+            [b'w', b'h', ..] => (&[W], 2),
+            [b'g', b'h', ..] => (&[], 2),
+            [b'k', b'n', ..] if i == 0 => (&[N], 2),
+            [b'w', b'r', ..] if i == 0 => (&[R], 2),
+            [b'r', b'h', ..] if i == 0 => (&[R], 2),
+            [b'e', b'e', ..] | [b'e', b'a', ..] | [b'i', b'e', ..] => (&[Iy], 2),
+            [b'e', b'y', ..] => (&[Iy], 2),
+            [b'o', b'o', ..] | [b'u', b'e', ..] | [b'e', b'w', ..] => (&[Uw], 2),
+            [b'o', b'u', ..] => (&[Aw], 2),
+            [b'o', b'w', ..] => (&[Ow], 2),
+            [b'o', b'i', ..] | [b'o', b'y', ..] => (&[Oy], 2),
+            [b'a', b'i', ..] | [b'a', b'y', ..] => (&[Ey], 2),
+            [b'a', b'u', ..] | [b'a', b'w', ..] => (&[Ao], 2),
+            [b'e' | b'i' | b'u', b'r', ..] if !is_vowel(at(2)) => (&[Er], 2),
+            [b'a', b'r', ..] if !is_vowel(at(2)) => (&[Aa], 2),
+            [b'o', b'r', ..] if !is_vowel(at(2)) => (&[Ao], 2),
+            [b'a', ..] if magic_e => (&[Ey], 1),
+            [b'e', ..] if magic_e => (&[Iy], 1),
+            [b'i', ..] if magic_e => (&[Ay], 1),
+            [b'o', ..] if magic_e => (&[Ow], 1),
+            // u is long before a single consonant and a vowel
+            [b'u', c, v, ..] if !is_vowel(Some(c)) && is_vowel(Some(v)) => (&[Y, Uw], 1),
+            [b'u', ..] if magic_e => (&[Y, Uw], 1),
+            // A final e after a consonant is silent
+            [b'e'] if i > 0 => (&[], 1),
+            [b'a', ..] => (&[Ae], 1),
+            [b'e', ..] => (&[Eh], 1),
+            [b'i', ..] => (&[Ih], 1),
+            [b'o'] => (&[Ow], 1),
+            [b'o', ..] => (&[Ao], 1),
+            [b'u', ..] => (&[Ah], 1),
+            [b'y', ..] if i == 0 => (&[Y], 1),
+            [b'y', ..] if at_end(1) && !letters[..i].iter().any(|&b| is_vowel(Some(&b))) => {
+                (&[Ay], 1)
+            }
+            [b'y', ..] if at_end(1) => (&[Iy], 1),
+            [b'y', ..] => (&[Ih], 1),
+            [b'c', b'e' | b'i' | b'y', ..] => (&[S], 1),
+            [b'g', b'e' | b'i' | b'y', ..] => (&[Jh], 1),
+            [b'x', ..] => (&[K, S], 1),
+            [b'c', ..] | [b'k', ..] | [b'q', ..] => (&[K], 1),
+            [b'j', ..] => (&[Jh], 1),
+            [b'h', ..] => (&[Hh], 1),
+            [b'b', ..] => (&[B], 1),
+            [b'd', ..] => (&[D], 1),
+            [b'f', ..] => (&[F], 1),
+            [b'g', ..] => (&[G], 1),
+            [b'l', ..] => (&[L], 1),
+            [b'm', ..] => (&[M], 1),
+            [b'n', ..] => (&[N], 1),
+            [b'p', ..] => (&[P], 1),
+            [b'r', ..] => (&[R], 1),
+            [b's', ..] => (&[S], 1),
+            [b't', ..] => (&[T], 1),
+            [b'v', ..] => (&[V], 1),
+            [b'w', ..] => (&[W], 1),
+            [b'z', ..] => (&[Z], 1),
+            _ => (&[], 1),
+        };
+        // Doubled consonants are pronounced once
+        if !(sounds.len() == 1 && !sounds[0].is_vowel() && phonemes.last() == Some(&sounds[0])) {
+            phonemes.extend_from_slice(sounds);
+        }
+        i += len;
+    }
+    phonemes
+}
+
+/// Transcribes English text in Hangul, guessing the pronunciation of each
+/// word with `english_to_phonemes` and transcribing it with
+/// `phonemes_to_hangul`. Characters other than ASCII letters are left
+/// unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::transliterate::english_to_hangul;
+///
+/// assert_eq!(english_to_hangul("computer"), "컴퓨터");
+/// assert_eq!(english_to_hangul("desk lamp"), "데스크 램프");
+/// assert_eq!(english_to_hangul("cake"), "케이크");
+/// ```
+pub fn english_to_hangul(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphabetic()) {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        result.push_str(&phonemes_to_hangul(&english_to_phonemes(&rest[..len])));
+        rest = &rest[len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+// Pushes the vowel at the start of `phonemes`, preceded by `glide`, as
+// compatibility jamo after an onset that has already been pushed. Returns
+// the number of phonemes used.
+fn push_vowel(jamo: &mut String, glide: Option<Phoneme>, phonemes: &[Phoneme]) -> usize {
+    use Phoneme::*;
+
+    let Some(&vowel) = phonemes.first() else {
+        // A glide with no vowel after it
+        jamo.push(if glide == Some(W) { 'ㅜ' } else { 'ㅣ' });
+        return 0;
+    };
+    // aʊə is written 아워
+    if vowel == Aw && matches!(phonemes.get(1), Some(Ah | Er)) {
+        jamo.push_str(&glided(glide, 'ㅏ'));
+        jamo.push_str("ㅇㅝ");
+        return 2;
+    }
+    let (first, second) = match vowel {
+        Aa => ('ㅏ', None),
+        Ae => ('ㅐ', None),
+        Ah | Er => ('ㅓ', None),
+        Ao => ('ㅗ', None),
+        Aw => ('ㅏ', Some('ㅜ')),
+        Ay => ('ㅏ', Some('ㅣ')),
+        Eh => ('ㅔ', None),
+        Ey => ('ㅔ', Some('ㅣ')),
+        Ih | Iy => ('ㅣ', None),
+        Ow => ('ㅗ', None),
+        Oy => ('ㅗ', Some('ㅣ')),
+        Uh | Uw => ('ㅜ', None),
+        _ => ('ㅡ', None),
+    };
+    jamo.push_str(&glided(glide, first));
+    if let Some(second) = second {
+        jamo.push('ㅇ');
+        jamo.push(second);
+    }
+    1
+}
+
+// Returns `vowel` as a compatibility jamo, combined with `glide`.
+fn glided(glide: Option<Phoneme>, vowel: char) -> String {
+    let combined = match (glide, vowel) {
+        (Some(Phoneme::Y), 'ㅏ') => 'ㅑ',
+        (Some(Phoneme::Y), 'ㅐ') => 'ㅒ',
+        (Some(Phoneme::Y), 'ㅓ') => 'ㅕ',
+        (Some(Phoneme::Y), 'ㅔ') => 'ㅖ',
+        (Some(Phoneme::Y), 'ㅗ') => 'ㅛ',
+        (Some(Phoneme::Y), 'ㅜ') => 'ㅠ',
+        (Some(Phoneme::W), 'ㅏ') => 'ㅘ',
+        (Some(Phoneme::W), 'ㅐ') => 'ㅙ',
+        (Some(Phoneme::W), 'ㅓ' | 'ㅗ') => 'ㅝ',
+        (Some(Phoneme::W), 'ㅔ') => 'ㅞ',
+        (Some(Phoneme::W), 'ㅣ') => 'ㅟ',
+        _ => vowel,
+    };
+    combined.to_string()
+}
+
+fn is_short_vowel(phoneme: Phoneme) -> bool {
+    use Phoneme::*;
+    matches!(phoneme, Aa | Ae | Ah | Ao | Eh | Ih | Uh)
+}
+
+// Returns the compatibility jamo used to write `phoneme` before a vowel.
+fn consonant(phoneme: Phoneme) -> char {
+    use Phoneme::*;
+    match phoneme {
+        P | F => 'ㅍ',
+        B | V => 'ㅂ',
+        T => 'ㅌ',
+        D | Dh => 'ㄷ',
+        K => 'ㅋ',
+        G => 'ㄱ',
+        S | Th | Sh => 'ㅅ',
+        Z | Zh | Jh => 'ㅈ',
+        Ch => 'ㅊ',
+        M => 'ㅁ',
+        N => 'ㄴ',
+        L | R => 'ㄹ',
+        Hh => 'ㅎ',
+        _ => 'ㅇ',
+    }
+}
+
+const ARPABET: [(&str, Phoneme); 39] = [
+    ("AA", Phoneme::Aa),
+    ("AE", Phoneme::Ae),
+    ("AH", Phoneme::Ah),
+    ("AO", Phoneme::Ao),
+    ("AW", Phoneme::Aw),
+    ("AY", Phoneme::Ay),
+    ("EH", Phoneme::Eh),
+    ("ER", Phoneme::Er),
+    ("EY", Phoneme::Ey),
+    ("IH", Phoneme::Ih),
+    ("IY", Phoneme::Iy),
+    ("OW", Phoneme::Ow),
+    ("OY", Phoneme::Oy),
+    ("UH", Phoneme::Uh),
+    ("UW", Phoneme::Uw),
+    ("B", Phoneme::B),
+    ("CH", Phoneme::Ch),
+    ("D", Phoneme::D),
+    ("DH", Phoneme::Dh),
+    ("F", Phoneme::F),
+    ("G", Phoneme::G),
+    ("HH", Phoneme::Hh),
+    ("JH", Phoneme::Jh),
+    ("K", Phoneme::K),
+    ("L", Phoneme::L),
+    ("M", Phoneme::M),
+    ("N", Phoneme::N),
+    ("NG", Phoneme::Ng),
+    ("P", Phoneme::P),
+    ("R", Phoneme::R),
+    ("S", Phoneme::S),
+    ("SH", Phoneme::Sh),
+    ("T", Phoneme::T),
+    ("TH", Phoneme::Th),
+    ("V", Phoneme::V),
+    ("W", Phoneme::W),
+    ("Y", Phoneme::Y),
+    ("Z", Phoneme::Z),
+    ("ZH", Phoneme::Zh),
+];
+
+// Common loanwords whose spelling the rules in `english_to_phonemes` get
+// wrong, and their pronunciations in ARPAbet, with the vowels on which the
+// established Korean spellings are based.
+const COMMON_LOANWORDS: [(&str, &str); 17] = [
+    ("computer", "K AH M P Y UW T ER"),
+    ("coffee", "K AH F IY"),
+    ("camera", "K AE M AH R AH"),
+    ("banana", "B AA N AA N AA"),
+    ("radio", "R AA D IY OW"),
+    ("piano", "P IY AA N OW"),
+    ("orange", "AO R EH N JH"),
+    ("juice", "JH UW S"),
+    ("bus", "B AH S"),
+    ("music", "M Y UW Z IH K"),
+    ("television", "T EH L AH V IH ZH AH N"),
+    ("chocolate", "CH AO K AO L IH T"),
+    ("hamburger", "HH AE M B ER G ER"),
+    ("mcdonald", "M AE K D OW N AA L D"),
+    ("pizza", "P IY Z AA"),
+    ("rhythm", "R IH DH M"),
+    ("the", "DH AH"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples from the English transcription rules.
+    #[test]
+    fn transcription_rules() {
+        let cases = [
+            ("G AE P", "갭"),
+            ("K AE T", "캣"),
+            ("B UH K", "북"),
+            ("AE P T", "앱트"),
+            ("S EH T B AE K", "셋백"),
+            ("AE K T", "액트"),
+            ("S T AE M P", "스탬프"),
+            ("K EY P", "케이프"),
+            ("N EH S T", "네스트"),
+            ("P AA R T", "파트"),
+            ("AE P L", "애플"),
+            ("B AH L B", "벌브"),
+            ("L AE N D", "랜드"),
+            ("Z IH G Z AE G", "지그재그"),
+            ("M AA S K", "마스크"),
+            ("JH AE Z", "재즈"),
+            ("G R AE F", "그래프"),
+            ("AA L IH V", "알리브"),
+            ("TH R IH L", "스릴"),
+            ("F L AE SH", "플래시"),
+            ("SH R AH B", "슈러브"),
+            ("SH AA R K", "샤크"),
+            ("F AE SH AH N", "패션"),
+            ("M IH R AA ZH", "미라지"),
+            ("S T IY M", "스팀"),
+            ("K AO R N", "콘"),
+            ("R IH NG", "링"),
+            ("L AE M P", "램프"),
+            ("IH NG K", "잉크"),
+            ("HH AE NG IH NG", "행잉"),
+            ("S L AY D", "슬라이드"),
+            ("F IH L M", "필름"),
+            ("HH EH L M", "헬름"),
+            ("HH OW T EH L", "호텔"),
+            ("P AH L P", "펄프"),
+            ("T AY M", "타임"),
+            ("HH AW S", "하우스"),
+            ("S K EY T", "스케이트"),
+            ("OY L", "오일"),
+            ("B OW T", "보트"),
+            ("W ER D", "워드"),
+            ("W AA N T", "완트"),
+            ("W EH S T", "웨스트"),
+            ("W IH CH", "위치"),
+            ("S W IH NG", "스윙"),
+            ("T W IH S T", "트위스트"),
+            ("P EH NG G W IH N", "펭귄"),
+            ("K W AO T ER", "쿼터"),
+            ("Y AA R D", "야드"),
+            ("Y EH L OW", "옐로"),
+            ("Y UW", "유"),
+            // Consonants with no vowel before them take 으
+            ("M P", "므프"),
+            ("N K T", "느크트"),
+        ];
+        for (arpabet, expected) in cases {
+            assert_eq!(arpabet_to_hangul(arpabet).unwrap(), expected, "{arpabet}");
+        }
+    }
+
+    #[test]
+    fn phonemes_parse_and_display() {
+        assert_eq!("uw1".parse(), Ok(Phoneme::Uw));
+        assert_eq!(Phoneme::Zh.to_string(), "ZH");
+        assert_eq!(
+            "X".parse::<Phoneme>(),
+            Err(TransliterateError::UnknownPhoneme("X".to_string()))
+        );
+        assert!(ARPABET.iter().all(|(s, p)| s.parse() == Ok(*p)));
+    }
+
+    #[test]
+    fn common_words() {
+        let cases = [
+            ("coffee", "커피"),
+            ("camera", "캐머러"),
+            ("television", "텔러비전"),
+            ("hamburger", "햄버거"),
+            ("music", "뮤직"),
+            ("shop", "숍"),
+            ("stamp", "스탬프"),
+            ("quiz", "퀴즈"),
+            ("Hello, world!", "헬로, 월드!"),
+            ("McDonald", "맥도날드"),
+            ("pizza", "피자"),
+            ("the", "더"),
+            ("rhythm", "리듬"),
+            ("rhetoric", "레토릭"),
+            ("mp3", "므프3"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(english_to_hangul(word), expected, "{word}");
+        }
+    }
+}
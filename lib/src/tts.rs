@@ -0,0 +1,562 @@
+use crate::{
+    datetime::month_name,
+    number::{to_native_korean_determiner, to_sino_korean, verbalize_digits},
+    transform::spell_latin,
+};
+
+/// A rewriting pass of a `TtsNormalizer`, which spells out one kind of
+/// non-Hangul text in Hangul. Implement this trait to add your own rules.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::tts::{TtsNormalizer, TtsRule};
+///
+/// struct Ampersand;
+///
+/// impl TtsRule for Ampersand {
+///     fn name(&self) -> &'static str {
+///         "ampersand"
+///     }
+///
+///     fn apply(&self, text: &str) -> String {
+///         text.replace('&', " 앤드 ")
+///     }
+/// }
+///
+/// let mut normalizer = TtsNormalizer::new();
+/// normalizer.add_rule(Ampersand);
+/// assert_eq!(normalizer.normalize("R&D 2팀"), "알 앤드 디 두 팀");
+/// ```
+pub trait TtsRule {
+    /// Returns the name of this rule, as used by
+    /// `TtsNormalizer::remove_rule`.
+    fn name(&self) -> &'static str;
+
+    /// Rewrites the parts of `text` this rule handles, leaving the rest
+    /// unchanged.
+    fn apply(&self, text: &str) -> String;
+}
+
+/// Reads phone numbers such as 010-1234-5678 digit by digit, with pauses
+/// between the groups, using `number::verbalize_digits`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PhoneNumbers;
+
+impl TtsRule for PhoneNumbers {
+    fn name(&self) -> &'static str {
+        "phone-numbers"
+    }
+
+    fn apply(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '-')
+                .unwrap_or(rest.len());
+            let candidate = rest[..len].trim_end_matches('-');
+            if is_phone_number(candidate) {
+                result.push_str(&verbalize_digits(candidate));
+            } else {
+                result.push_str(candidate);
+            }
+            rest = &rest[candidate.len()..];
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+/// Reads times of day written with colons (10:30 → 열 시 삼십 분, 9:05:20 →
+/// 아홉 시 오 분 이십 초), using native Korean numerals for the hour.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Times;
+
+impl TtsRule for Times {
+    fn name(&self) -> &'static str {
+        "times"
+    }
+
+    fn apply(&self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit() && c != ':')
+                .unwrap_or(rest.len());
+            let candidate = rest[..len].trim_end_matches(':');
+            match read_time(candidate) {
+                Some(reading) => result.push_str(&reading),
+                None => result.push_str(candidate),
+            }
+            rest = &rest[candidate.len()..];
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+/// Reads years, months, and days written with digits (2024년 3월 5일), with
+/// the irregular month names 유월 and 시월.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Dates;
+
+impl TtsRule for Dates {
+    fn name(&self) -> &'static str {
+        "dates"
+    }
+
+    fn apply(&self, text: &str) -> String {
+        replace_numbers(text, |number, rest| {
+            let value = number.integer.filter(|_| !number.negative)?;
+            if rest.starts_with("월") {
+                let month = month_name(u32::try_from(value).ok()?)?;
+                Some((month.to_string(), "월".len()))
+            } else if rest.starts_with("년") || rest.starts_with("일") {
+                Some((format!("{} ", number.reading), 0))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Reads numbers before counters, using native Korean numerals for the
+/// counters that take them (3시 → 세 시, 2개 → 두 개, 20살 → 스무 살) and
+/// Sino-Korean numerals otherwise (3개월 → 삼 개월, 30분 → 삼십 분).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counters;
+
+impl TtsRule for Counters {
+    fn name(&self) -> &'static str {
+        "counters"
+    }
+
+    fn apply(&self, text: &str) -> String {
+        replace_numbers(text, |number, rest| {
+            let &(_, native) = COUNTERS
+                .iter()
+                .find(|(counter, _)| rest.starts_with(counter))?;
+            let reading = match number.integer {
+                Some(value) if native => u32::try_from(value)
+                    .ok()
+                    .and_then(to_native_korean_determiner)
+                    .unwrap_or_else(|| number.reading.clone()),
+                _ => number.reading.clone(),
+            };
+            Some((format!("{}{reading} ", number.sign()), 0))
+        })
+    }
+}
+
+/// Reads numbers followed by units of measurement, such as 3km (삼
+/// 킬로미터) and 50% (오십 퍼센트), with 영하 for temperatures below zero
+/// (-5℃ → 영하 오 도).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Units;
+
+impl TtsRule for Units {
+    fn name(&self) -> &'static str {
+        "units"
+    }
+
+    fn apply(&self, text: &str) -> String {
+        replace_numbers(text, |number, rest| {
+            let space = rest.len() - rest.trim_start_matches(' ').len();
+            let after_space = &rest[space..];
+            let &(unit, reading) = UNITS.iter().find(|(unit, _)| {
+                after_space
+                    .strip_prefix(unit)
+                    .is_some_and(|after| !after.starts_with(|c: char| c.is_ascii_alphabetic()))
+            })?;
+            let sign = if number.negative && TEMPERATURE_UNITS.contains(&unit) {
+                "영하 "
+            } else {
+                number.sign()
+            };
+            Some((
+                format!("{sign}{} {reading}", number.reading),
+                space + unit.len(),
+            ))
+        })
+    }
+}
+
+/// Reads all remaining numbers with Sino-Korean numerals (1,234 → 천이백삼십사,
+/// 3.14 → 삼 점 일사, -2 → 마이너스 이).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Numbers;
+
+impl TtsRule for Numbers {
+    fn name(&self) -> &'static str {
+        "numbers"
+    }
+
+    fn apply(&self, text: &str) -> String {
+        replace_numbers(text, |number, _| {
+            Some((format!("{}{}", number.sign(), number.reading), 0))
+        })
+    }
+}
+
+/// Spells out the remaining Latin letters, such as acronyms (KBS →
+/// 케이비에스), using `transform::spell_latin`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Acronyms;
+
+impl TtsRule for Acronyms {
+    fn name(&self) -> &'static str {
+        "acronyms"
+    }
+
+    fn apply(&self, text: &str) -> String {
+        spell_latin(text)
+    }
+}
+
+/// An ordered list of `TtsRule`s that rewrites mixed text into fully
+/// spelled-out Hangul for text-to-speech. Each rule is applied to the
+/// output of the one before it.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::tts::TtsNormalizer;
+///
+/// let mut normalizer = TtsNormalizer::new();
+/// assert_eq!(normalizer.normalize("3km를 달렸다"), "삼 킬로미터를 달렸다");
+///
+/// // Without the counters rule, numbers before counters are read as
+/// // Sino-Korean numerals
+/// assert!(normalizer.remove_rule("counters"));
+/// assert_eq!(normalizer.normalize("사과 2개"), "사과 이개");
+/// ```
+pub struct TtsNormalizer {
+    rules: Vec<Box<dyn TtsRule>>,
+}
+
+impl Default for TtsNormalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TtsNormalizer {
+    /// Creates a normalizer with the default rules: `PhoneNumbers`,
+    /// `Times`, `Dates`, `Units`, `Counters`, `Numbers`, and `Acronyms`, in
+    /// that order.
+    pub fn new() -> Self {
+        Self {
+            rules: vec![
+                Box::new(PhoneNumbers),
+                Box::new(Times),
+                Box::new(Dates),
+                Box::new(Units),
+                Box::new(Counters),
+                Box::new(Numbers),
+                Box::new(Acronyms),
+            ],
+        }
+    }
+
+    /// Creates a normalizer with no rules.
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Adds `rule` after the existing rules.
+    pub fn add_rule<R: TtsRule + 'static>(&mut self, rule: R) {
+        self.rules.push(Box::new(rule));
+    }
+
+    /// Adds `rule` at `index`, so that it runs before the rules after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of rules.
+    pub fn insert_rule<R: TtsRule + 'static>(&mut self, index: usize, rule: R) {
+        self.rules.insert(index, Box::new(rule));
+    }
+
+    /// Removes the rules named `name`, returning whether any were removed.
+    pub fn remove_rule(&mut self, name: &str) -> bool {
+        let len = self.rules.len();
+        self.rules.retain(|rule| rule.name() != name);
+        self.rules.len() != len
+    }
+
+    /// Returns the names of the rules, in the order they are applied.
+    pub fn rule_names(&self) -> Vec<&'static str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Applies each rule to `text` in order.
+    pub fn normalize(&self, text: &str) -> String {
+        self.rules
+            .iter()
+            .fold(text.to_string(), |text, rule| rule.apply(&text))
+    }
+}
+
+/// Rewrites mixed text into fully spelled-out Hangul for text-to-speech,
+/// using the default rules of `TtsNormalizer`.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::tts::normalize;
+///
+/// assert_eq!(
+///     normalize("2024년 6월 5일 오후 3시 30분"),
+///     "이천이십사 년 유월 오 일 오후 세 시 삼십 분"
+/// );
+/// assert_eq!(normalize("010-1234-5678로 연락"), "공일공 일이삼사 오육칠팔로 연락");
+/// assert_eq!(normalize("1,500원, 할인율 10%"), "천오백 원, 할인율 십 퍼센트");
+/// assert_eq!(normalize("KTX 2대"), "케이티엑스 두 대");
+/// ```
+pub fn normalize(text: &str) -> String {
+    TtsNormalizer::new().normalize(text)
+}
+
+// A number written with digits, as found by `replace_numbers`.
+struct NumberToken {
+    // Whether the number has a minus sign before it.
+    negative: bool,
+
+    // The value of the number, if it is a whole number that fits in a u64.
+    integer: Option<u64>,
+
+    // The number read with Sino-Korean numerals.
+    reading: String,
+}
+
+impl NumberToken {
+    // Returns how the sign of the number is read before its reading.
+    fn sign(&self) -> &'static str {
+        if self.negative { "마이너스 " } else { "" }
+    }
+}
+
+// Finds each number written with digits in `text`, optionally with commas
+// between groups of three digits, a decimal part, and a minus sign that
+// does not follow a letter or digit, and calls `f` with it and the text
+// after it. If `f` returns a replacement and the number of bytes after the
+// number that it replaces, the number (with its sign) and those bytes are
+// replaced.
+fn replace_numbers(
+    text: &str,
+    f: impl Fn(&NumberToken, &str) -> Option<(String, usize)>,
+) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let before = &rest[..start];
+        let unsigned = before.strip_suffix('-').filter(|unsigned| {
+            let previous = unsigned.chars().next_back().or(result.chars().next_back());
+            !previous.is_some_and(char::is_alphanumeric)
+        });
+        rest = &rest[start..];
+        let len = number_len(rest);
+        let mut token = parse_number(&rest[..len]);
+        token.negative = unsigned.is_some();
+        match f(&token, &rest[len..]) {
+            Some((replacement, consumed)) => {
+                result.push_str(unsigned.unwrap_or(before));
+                result.push_str(&replacement);
+                rest = &rest[len + consumed..];
+            }
+            None => {
+                result.push_str(before);
+                result.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+// Returns the length of the number at the start of `text`.
+fn number_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let digits_from = |i: usize| bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let mut len = digits_from(0);
+    while bytes.get(len) == Some(&b',') && digits_from(len + 1) == 3 {
+        len += 4;
+    }
+    if bytes.get(len) == Some(&b'.') && digits_from(len + 1) > 0 {
+        len += 1 + digits_from(len + 1);
+    }
+    len
+}
+
+fn parse_number(number: &str) -> NumberToken {
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let digits: String = whole.chars().filter(char::is_ascii_digit).collect();
+    let value = digits.parse::<u64>().ok();
+    let mut reading = match value {
+        Some(value) => to_sino_korean(value),
+        None => verbalize_digits(&digits),
+    };
+    if !fraction.is_empty() {
+        reading.push_str(" 점 ");
+        reading.extend(
+            fraction
+                .chars()
+                .map(|digit| to_sino_korean(digit as u64 - '0' as u64)),
+        );
+    }
+    NumberToken {
+        negative: false,
+        integer: value.filter(|_| fraction.is_empty()),
+        reading,
+    }
+}
+
+// Whether `text` is a phone number: three groups of digits separated by
+// hyphens, of two to four, three or four, and four digits.
+fn is_phone_number(text: &str) -> bool {
+    let groups: Vec<&str> = text.split('-').collect();
+    matches!(
+        groups.as_slice(),
+        [a, b, c] if (2..=4).contains(&a.len()) && (3..=4).contains(&b.len()) && c.len() == 4
+    )
+}
+
+// Reads `text` as a time of day, written as H:MM or H:MM:SS with an hour
+// of at most 24, or returns `None` if it is not one. Zero minutes and
+// seconds are not read.
+fn read_time(text: &str) -> Option<String> {
+    let parts: Vec<&str> = text.split(':').collect();
+    let (hour, minutes, seconds) = match parts.as_slice() {
+        [hour, minutes] => (*hour, *minutes, "00"),
+        [hour, minutes, seconds] => (*hour, *minutes, *seconds),
+        _ => return None,
+    };
+    if hour.is_empty() || hour.len() > 2 || minutes.len() != 2 || seconds.len() != 2 {
+        return None;
+    }
+    let hour: u32 = hour.parse().ok().filter(|hour| *hour <= 24)?;
+    let minutes: u64 = minutes.parse().ok().filter(|minutes| *minutes < 60)?;
+    let seconds: u64 = seconds.parse().ok().filter(|seconds| *seconds < 60)?;
+    let mut reading =
+        to_native_korean_determiner(hour).unwrap_or_else(|| to_sino_korean(hour.into())) + " 시";
+    if minutes > 0 {
+        reading += &format!(" {} 분", to_sino_korean(minutes));
+    }
+    if seconds > 0 {
+        reading += &format!(" {} 초", to_sino_korean(seconds));
+    }
+    Some(reading)
+}
+
+// Counters, longest first, and whether they take native Korean numerals.
+const COUNTERS: [(&str, bool); 20] = [
+    ("개월", false),
+    ("시간", true),
+    ("마리", true),
+    ("그루", true),
+    ("송이", true),
+    ("켤레", true),
+    ("개", true),
+    ("명", true),
+    ("살", true),
+    ("시", true),
+    ("권", true),
+    ("잔", true),
+    ("병", true),
+    ("장", true),
+    ("벌", true),
+    ("팀", true),
+    ("대", true),
+    ("분", false),
+    ("초", false),
+    ("원", false),
+];
+
+// Units of measurement and their readings, longest first among units that
+// share a prefix.
+const UNITS: [(&str, &str); 18] = [
+    ("km/h", "킬로미터 퍼 아워"),
+    ("km", "킬로미터"),
+    ("cm", "센티미터"),
+    ("mm", "밀리미터"),
+    ("m²", "제곱미터"),
+    ("m", "미터"),
+    ("kg", "킬로그램"),
+    ("mg", "밀리그램"),
+    ("g", "그램"),
+    ("ml", "밀리리터"),
+    ("mL", "밀리리터"),
+    ("L", "리터"),
+    ("GB", "기가바이트"),
+    ("MB", "메가바이트"),
+    ("%", "퍼센트"),
+    ("°C", "도"),
+    ("℃", "도"),
+    ("도", "도"),
+];
+
+// Units of temperature, read with 영하 instead of 마이너스 below zero.
+const TEMPERATURE_UNITS: [&str; 3] = ["°C", "℃", "도"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_apply_in_order() {
+        assert_eq!(
+            TtsNormalizer::new().rule_names(),
+            [
+                "phone-numbers",
+                "times",
+                "dates",
+                "units",
+                "counters",
+                "numbers",
+                "acronyms"
+            ]
+        );
+        assert_eq!(TtsNormalizer::empty().normalize("3km"), "3km");
+        assert_eq!(normalize("3개월 동안 2시간씩"), "삼 개월 동안 두 시간씩");
+        assert_eq!(normalize("100명"), "백 명");
+    }
+
+    #[test]
+    fn numbers_and_units() {
+        assert_eq!(normalize("3.05kg"), "삼 점 영오 킬로그램");
+        assert_eq!(normalize("1,000,000"), "백만");
+        assert_eq!(normalize("1,00"), "일,영");
+        assert_eq!(normalize("5 m 떨어진 3mx"), "오 미터 떨어진 삼엠엑스");
+        assert_eq!(
+            normalize("123456789012345678901"),
+            "일이삼사오육칠팔구공일이삼사오육칠팔구공일"
+        );
+        assert_eq!(normalize("1-2-3"), "일-이-삼");
+        // Numbers and units are always separated by a space
+        assert_eq!(normalize("5도"), "오 도");
+        assert_eq!(normalize("5℃"), "오 도");
+        // Minus signs
+        assert_eq!(
+            normalize("기온 -5℃, 체감 -10도"),
+            "기온 영하 오 도, 체감 영하 십 도"
+        );
+        assert_eq!(
+            normalize("-3.5kg, 값은 -2"),
+            "마이너스 삼 점 오 킬로그램, 값은 마이너스 이"
+        );
+        assert_eq!(normalize("a-1"), "에이-일");
+    }
+
+    #[test]
+    fn times_of_day() {
+        assert_eq!(normalize("10:30에 만나요"), "열 시 삼십 분에 만나요");
+        assert_eq!(normalize("9:00"), "아홉 시");
+        assert_eq!(normalize("0:05:20"), "영 시 오 분 이십 초");
+        assert_eq!(normalize("3:2"), "삼:이");
+        assert_eq!(normalize("24:00"), "스물네 시");
+    }
+}
@@ -0,0 +1,270 @@
+use crate::block::HangulBlock;
+use crate::jamo::{Character, Jamo};
+
+/// The state of a single jamo in a typing exercise.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum JamoStatus {
+    /// The jamo was typed correctly.
+    Correct,
+    /// A different character was typed in place of the jamo.
+    Incorrect,
+    /// The jamo has not been typed yet.
+    Untyped,
+}
+
+/// The result for one jamo of the target text, returned by
+/// `TypingSession::results`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct JamoResult {
+    /// The jamo (or non-Hangul character) expected at this position.
+    pub expected: char,
+
+    /// The character typed at this position, if any.
+    pub typed: Option<char>,
+
+    /// Whether `typed` matches `expected`.
+    pub status: JamoStatus,
+}
+
+/// Accuracy statistics for a typing exercise, returned by
+/// `TypingSession::stats`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct TypingStats {
+    /// The number of jamo pushed, including ones later removed with `pop`.
+    pub keystrokes: usize,
+
+    /// The number of pushed jamo that did not match the target, including
+    /// ones later corrected.
+    pub mistakes: usize,
+
+    /// The number of target jamo currently typed correctly.
+    pub correct: usize,
+
+    /// The number of target jamo currently typed incorrectly, plus any
+    /// jamo typed past the end of the target.
+    pub incorrect: usize,
+
+    /// The number of target jamo not yet typed.
+    pub remaining: usize,
+}
+
+impl TypingStats {
+    /// Returns the share of keystrokes that were not mistakes, from `0.0` to
+    /// `1.0`. Returns `1.0` if nothing has been typed.
+    pub fn accuracy(&self) -> f64 {
+        if self.keystrokes == 0 {
+            1.0
+        } else {
+            (self.keystrokes - self.mistakes) as f64 / self.keystrokes as f64
+        }
+    }
+}
+
+/// Tracks a typing exercise by comparing a stream of typed jamo against a
+/// target text, as in a Korean typing practice app.
+///
+/// The target is split into the jamo a typist enters on a Dubeolsik
+/// keyboard: syllables are split into their jamo, composite vowels and
+/// final clusters into their components (ㅘ → ㅗ, ㅏ), while double
+/// consonants such as ㄲ are kept whole. Typed characters are compared one
+/// jamo at a time, so mistakes are reported at the jamo level rather than
+/// per syllable.
+///
+/// **API:**
+/// ```rust
+/// use hangul_cd::typing::{JamoStatus, TypingSession};
+///
+/// let mut session = TypingSession::new("한글");
+/// for c in "ㅎㅏㄴㄱㅜ".chars() {
+///     session.push(c);
+/// }
+/// assert_eq!(session.position(), 5);
+/// assert_eq!(session.results()[4].status, JamoStatus::Incorrect);
+///
+/// // Correct the mistake and finish
+/// session.pop();
+/// session.push_str("ㅡㄹ");
+/// assert!(session.is_complete());
+///
+/// let stats = session.stats();
+/// assert_eq!(stats.mistakes, 1);
+/// assert_eq!(stats.correct, 6);
+/// assert_eq!(stats.accuracy(), 6.0 / 7.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypingSession {
+    target: Vec<char>,
+    typed: Vec<char>,
+    keystrokes: usize,
+    mistakes: usize,
+}
+
+impl TypingSession {
+    /// Creates a new `TypingSession` for the given target text.
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: keyed_jamo(target),
+            typed: Vec::new(),
+            keystrokes: 0,
+            mistakes: 0,
+        }
+    }
+
+    /// Returns the target text split into the jamo to be typed.
+    pub fn target(&self) -> &[char] {
+        &self.target
+    }
+
+    /// Pushes a typed character. Modern jamo are treated as their
+    /// compatibility equivalents, and precomposed syllables (e.g. committed
+    /// by an IME) are split into jamo as in the target.
+    pub fn push(&mut self, c: char) {
+        for jamo in keyed_jamo(&c.to_string()) {
+            if self.target.get(self.typed.len()) != Some(&jamo) {
+                self.mistakes += 1;
+            }
+            self.keystrokes += 1;
+            self.typed.push(jamo);
+        }
+    }
+
+    /// Pushes each character of `s` in order.
+    pub fn push_str(&mut self, s: &str) {
+        for c in s.chars() {
+            self.push(c);
+        }
+    }
+
+    /// Removes and returns the last typed jamo, like a backspace.
+    pub fn pop(&mut self) -> Option<char> {
+        self.typed.pop()
+    }
+
+    /// Returns the index of the next jamo to be typed in `target`.
+    pub fn position(&self) -> usize {
+        self.typed.len()
+    }
+
+    /// Returns `true` if the whole target has been typed without any
+    /// uncorrected mistakes.
+    pub fn is_complete(&self) -> bool {
+        self.typed == self.target
+    }
+
+    /// Returns the result for each jamo of the target, in order.
+    pub fn results(&self) -> Vec<JamoResult> {
+        self.target
+            .iter()
+            .enumerate()
+            .map(|(i, expected)| {
+                let typed = self.typed.get(i).copied();
+                let status = match typed {
+                    None => JamoStatus::Untyped,
+                    Some(t) if t == *expected => JamoStatus::Correct,
+                    Some(_) => JamoStatus::Incorrect,
+                };
+                JamoResult {
+                    expected: *expected,
+                    typed,
+                    status,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns accuracy statistics for the exercise so far.
+    pub fn stats(&self) -> TypingStats {
+        let mut stats = TypingStats {
+            keystrokes: self.keystrokes,
+            mistakes: self.mistakes,
+            incorrect: self.typed.len().saturating_sub(self.target.len()),
+            ..TypingStats::default()
+        };
+        for result in self.results() {
+            match result.status {
+                JamoStatus::Correct => stats.correct += 1,
+                JamoStatus::Incorrect => stats.incorrect += 1,
+                JamoStatus::Untyped => stats.remaining += 1,
+            }
+        }
+        stats
+    }
+}
+
+// Splits `s` into compatibility jamo as typed on a Dubeolsik keyboard,
+// leaving non-Hangul characters untouched.
+pub(crate) fn keyed_jamo(s: &str) -> Vec<char> {
+    let mut result = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        if let Ok(block) = HangulBlock::from_char(c) {
+            push_keyed_jamo(block.initial.jamo(), &mut result);
+            push_keyed_jamo(block.vowel.jamo(), &mut result);
+            if let Some(f) = block.final_jamo() {
+                push_keyed_jamo(f, &mut result);
+            }
+        } else if let Ok(Character::Hangul(jamo)) = Character::from_char(c) {
+            push_keyed_jamo(&jamo, &mut result);
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+// Composite vowels and final clusters are typed as two keys; double
+// consonants have their own (shifted) key.
+fn push_keyed_jamo(jamo: &Jamo, result: &mut Vec<char>) {
+    let (first, second) = match jamo {
+        Jamo::CompositeVowel(v) => v.decompose(),
+        Jamo::CompositeConsonant(c) if !c.is_valid_initial() => c.decompose(),
+        _ => {
+            result.push(jamo.char_compatibility());
+            return;
+        }
+    };
+    result.push(first.char_compatibility());
+    result.push(second.char_compatibility());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_is_split_into_keyed_jamo() {
+        let session = TypingSession::new("꽃값 1");
+        assert_eq!(
+            session.target(),
+            &['ㄲ', 'ㅗ', 'ㅊ', 'ㄱ', 'ㅏ', 'ㅂ', 'ㅅ', ' ', '1']
+        );
+    }
+
+    #[test]
+    fn committed_syllables_are_split() {
+        let mut session = TypingSession::new("안녕");
+        session.push('안');
+        session.push('\u{1102}'); // Modern ㄴ
+        assert_eq!(session.position(), 4);
+        assert_eq!(session.stats().mistakes, 0);
+        assert_eq!(session.stats().remaining, 2);
+    }
+
+    #[test]
+    fn typing_past_the_end_is_incorrect() {
+        let mut session = TypingSession::new("가");
+        session.push_str("ㄱㅏㅏ");
+        assert!(!session.is_complete());
+        let stats = session.stats();
+        assert_eq!(stats.correct, 2);
+        assert_eq!(stats.incorrect, 1);
+        assert_eq!(stats.mistakes, 1);
+    }
+
+    #[test]
+    fn empty_session() {
+        let session = TypingSession::new("");
+        assert!(session.is_complete());
+        assert_eq!(session.stats(), TypingStats::default());
+        assert_eq!(session.stats().accuracy(), 1.0);
+    }
+}
@@ -0,0 +1,137 @@
+use thiserror::Error;
+
+use crate::block::{
+    HangulBlock, HangulBlockDecompositionOptions, syllable_from_indices, syllable_indices,
+};
+use crate::jamo::{Choseong, JamoUnicodeType, Jongseong, Jungseong};
+use crate::string::{StringError, compose, decompose_with_options, normalize_hangul};
+
+/// An error returned by `roundtrip_all` for the first syllable that does not
+/// survive a round trip.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RoundtripError {
+    /// Occurs when a jamo triple, or its `HangulBlock`, does not convert to
+    /// the syllable at its place in Unicode order, or the syllable does not
+    /// convert back to the same block and indices. Holds the indices and the
+    /// expected syllable.
+    #[error("Jamo indices {0:?} do not convert to and from '{1}'")]
+    Indices((usize, usize, usize), char),
+
+    /// Occurs when the number of legal jamo triples is not the number of
+    /// modern syllables, 11,172. Holds the number of triples.
+    #[error("Found {0} jamo triples instead of 11172")]
+    TripleCount(usize),
+
+    /// Occurs when composing the jamo a syllable decomposes to does not give
+    /// the syllable back. Holds the syllable, its jamo, and the result.
+    #[error("'{0}' decomposed to \"{1}\" but composed back to \"{2}\"")]
+    Composition(char, String, String),
+
+    /// Occurs when composing or decomposing fails outright.
+    #[error("String error: {0}")]
+    StringError(#[from] StringError),
+}
+
+/// Checks that every legal combination of an initial, a vowel, and an
+/// optional final, and so every one of the 11,172 modern syllables,
+/// survives composition and decomposition. For each triple:
+///
+/// - the triple, taken in index order, and its `HangulBlock` convert to the
+///   syllable at the same place in Unicode order (가, 각, 갂, ...), which
+///   converts back to the same block and indices;
+/// - composing the syllable's compatibility jamo and conjoining jamo, with
+///   composite jamo such as ㅘ and ㄳ kept whole or split, gives the syllable
+///   back;
+/// - normalizing its conjoining jamo (NFD) with `normalize_hangul` gives the
+///   syllable back.
+///
+/// This is meant for test suites, including those of crates that wrap or
+/// reimplement parts of this one, to catch errors such as an off-by-one
+/// base codepoint. It returns the first failure found.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::verify::roundtrip_all;
+///
+/// assert_eq!(roundtrip_all(), Ok(()));
+/// ```
+pub fn roundtrip_all() -> Result<(), RoundtripError> {
+    let finals: Vec<Option<Jongseong>> = std::iter::once(None)
+        .chain(Jongseong::all().map(Some))
+        .collect();
+    // The expected syllables are counted from 가 rather than computed with
+    // the crate's own constants, so that a wrong base or count is caught
+    let mut count = 0;
+    for initial in Choseong::all() {
+        for vowel in Jungseong::all() {
+            for final_optional in &finals {
+                let expected = char::from_u32('가' as u32 + count as u32)
+                    .ok_or(RoundtripError::TripleCount(count))?;
+                roundtrip_triple(initial, vowel, *final_optional, expected)?;
+                count += 1;
+            }
+        }
+    }
+    if count != 11172 {
+        return Err(RoundtripError::TripleCount(count));
+    }
+    Ok(())
+}
+
+// Checks a single jamo triple as described in `roundtrip_all`.
+fn roundtrip_triple(
+    initial: Choseong,
+    vowel: Jungseong,
+    final_optional: Option<Jongseong>,
+    syllable: char,
+) -> Result<(), RoundtripError> {
+    let indices = (
+        initial.index(),
+        vowel.index(),
+        final_optional.as_ref().map_or(0, Jongseong::index),
+    );
+    let block = HangulBlock::from_parts(initial, vowel, final_optional);
+    if syllable_from_indices(indices.0, indices.1, indices.2) != Some(syllable)
+        || block.to_char() != syllable
+        || syllable_indices(syllable) != Some(indices)
+        || HangulBlock::from_char(syllable).as_ref() != Ok(&block)
+    {
+        return Err(RoundtripError::Indices(indices, syllable));
+    }
+
+    let text = syllable.to_string();
+    for modern in [false, true] {
+        for decompose_composites in [false, true] {
+            let options = HangulBlockDecompositionOptions {
+                decompose_composites,
+                jamo_era: if modern {
+                    JamoUnicodeType::Modern
+                } else {
+                    JamoUnicodeType::Compatibility
+                },
+            };
+            let jamo = decompose_with_options(&text, &options)?;
+            let composed = compose(&jamo);
+            if composed != text {
+                return Err(RoundtripError::Composition(syllable, jamo, composed));
+            }
+            if modern && !decompose_composites {
+                let normalized = normalize_hangul(&jamo).into_owned();
+                if normalized != text {
+                    return Err(RoundtripError::Composition(syllable, jamo, normalized));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_syllable_round_trips() {
+        assert_eq!(roundtrip_all(), Ok(()));
+    }
+}
@@ -0,0 +1,123 @@
+use wasm_bindgen::prelude::*;
+
+use crate::block::{HangulBlock, HangulBlockDecompositionOptions};
+use crate::jamo::JamoUnicodeType;
+use crate::romanize::{self, RomanizationSystem};
+use crate::string::StringComposer;
+use crate::word;
+
+/// A `wasm-bindgen` wrapper around `StringComposer`, exposed to JavaScript
+/// as `HangulComposer`. Web-based IMEs can push keystrokes one character at
+/// a time and read back the composed text after each push or pop.
+///
+/// **JavaScript API:**
+/// ```js
+/// const composer = new HangulComposer();
+/// for (const c of "ㅎㅏㄴㄱㅡㄹ") composer.pushChar(c);
+/// composer.asString(); // "한글"
+/// composer.pop();      // "ᄅ"
+/// composer.asString(); // "한그"
+/// ```
+#[wasm_bindgen(js_name = HangulComposer)]
+#[derive(Debug, Default)]
+pub struct WasmComposer {
+    inner: StringComposer,
+}
+
+#[wasm_bindgen(js_class = HangulComposer)]
+impl WasmComposer {
+    /// Creates a new, empty composer.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: StringComposer::new(),
+        }
+    }
+
+    /// Pushes a character to the composer. Throws if composition fails.
+    #[wasm_bindgen(js_name = pushChar)]
+    pub fn push_char(&mut self, c: char) -> Result<(), JsError> {
+        self.inner.push_char(c).map_err(to_js_error)
+    }
+
+    /// Pops the last character or jamo from the composer, returning
+    /// `undefined` if there is nothing to pop.
+    pub fn pop(&mut self) -> Result<Option<char>, JsError> {
+        self.inner.pop().map_err(to_js_error)
+    }
+
+    /// Returns the composed text, including the block currently being
+    /// composed.
+    #[wasm_bindgen(js_name = asString)]
+    pub fn as_string(&self) -> Result<String, JsError> {
+        self.inner.as_string().map_err(to_js_error)
+    }
+}
+
+/// Decomposes a single precomposed Hangul syllable into its jamo, returned
+/// as a string. If `modern` is `true`, conjoining (modern) jamo are returned;
+/// otherwise compatibility jamo are returned. Throws if `syllable` is not a
+/// precomposed Hangul syllable.
+#[wasm_bindgen(js_name = decomposeSyllable)]
+pub fn decompose_syllable(
+    syllable: char,
+    decompose_composites: bool,
+    modern: bool,
+) -> Result<String, JsError> {
+    let block = HangulBlock::from_char(syllable).map_err(to_js_error)?;
+    let options = HangulBlockDecompositionOptions {
+        decompose_composites,
+        jamo_era: if modern {
+            JamoUnicodeType::Modern
+        } else {
+            JamoUnicodeType::Compatibility
+        },
+    };
+    let jamo = block.decomposed_vec(&options).map_err(to_js_error)?;
+    Ok(jamo.into_iter().collect())
+}
+
+/// Writes Hangul text in Revised Romanization, as `romanize::romanize`
+/// does.
+#[wasm_bindgen]
+pub fn romanize(text: &str) -> String {
+    romanize::romanize(text, RomanizationSystem::RevisedRomanization)
+}
+
+/// Attaches the particle `josa` to `word` in the form that follows its
+/// last syllable, as `word::attach_josa` does.
+#[wasm_bindgen(js_name = attachJosa)]
+pub fn attach_josa(word: &str, josa: &str) -> String {
+    word::attach_josa(word, josa)
+}
+
+fn to_js_error(err: impl std::error::Error) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompose_syllable_in_both_eras() {
+        let decompose = |composites, modern| decompose_syllable('값', composites, modern).ok();
+        assert_eq!(decompose(true, false).as_deref(), Some("ㄱㅏㅂㅅ"));
+        assert_eq!(decompose(false, false).as_deref(), Some("ㄱㅏㅄ"));
+        assert_eq!(
+            decompose(false, true).as_deref(),
+            Some("\u{1100}\u{1161}\u{11B9}")
+        );
+    }
+
+    #[test]
+    fn romanize_revised() {
+        assert_eq!(romanize("한국어"), "hangugeo");
+    }
+
+    #[test]
+    fn attach_josa_by_batchim() {
+        assert_eq!(attach_josa("책", "를"), "책을");
+        assert_eq!(attach_josa("사과", "을"), "사과를");
+    }
+}
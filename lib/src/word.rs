@@ -1,8 +1,15 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
+use std::ops::Index;
+use std::slice::SliceIndex;
+use std::str::FromStr;
 
 use thiserror::Error;
 
-use crate::{block::*, jamo::*};
+use crate::{
+    block::*,
+    grammar::{FINAL_PIEUP, FINAL_RIEUL, FINAL_SSANGSIOS, a_eo_stem},
+    jamo::*,
+};
 
 /// A composer for a single Hangul word, made up of multiple syllable blocks.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -27,6 +34,11 @@ pub enum WordError {
     /// Tried to complete the current block, but it only contains one Jamo.
     #[error("Cannot complete current block; currently contains only one Jamo: {0:?}")]
     CannotCompleteCurrentBlock(Jamo),
+
+    /// Occurs when a block index is past the end of a `HangulWord`. Holds the
+    /// index and the number of blocks.
+    #[error("Block index {0} is out of range for a word of {1} blocks")]
+    BlockIndexOutOfRange(usize, usize),
 }
 
 /// A composer for a single Hangul word, made up of multiple syllable blocks.
@@ -67,10 +79,83 @@ pub enum WordError {
 /// );
 /// assert_eq!(composer.as_string().unwrap(), "안".to_string());
 /// ```
-#[derive(Debug)]
-pub struct HangulWordComposer {
-    prev_blocks: Vec<HangulBlock>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "SavedWordComposer",
+        into = "SavedWordComposer",
+        bound(
+            serialize = "R: CompositionRules + Clone",
+            deserialize = "R: CompositionRules + Default"
+        )
+    )
+)]
+pub struct HangulWordComposer<R = StandardRules> {
+    // The committed text followed by the syllables of `prev_blocks`, kept up
+    // to date as blocks are completed and popped so that `as_string` does
+    // not rebuild the word on every call.
+    text: String,
+    // The length of the committed text at the start of `text`.
+    committed_len: usize,
+    prev_blocks: Vec<BlockComposer>,
     cur_block: BlockComposer,
+    passthrough: bool,
+    double_tap: bool,
+    rules: R,
+}
+
+// The serialized form of a `HangulWordComposer`, which stores the committed
+// text without the syllables of the completed blocks, nor the rules, which
+// are restored with their `Default`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SavedWordComposer {
+    #[serde(default)]
+    committed: String,
+    prev_blocks: Vec<BlockComposer>,
+    cur_block: BlockComposer,
+    #[serde(default)]
+    passthrough: bool,
+    #[serde(default)]
+    double_tap: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<R: CompositionRules + Default> TryFrom<SavedWordComposer> for HangulWordComposer<R> {
+    type Error = WordError;
+
+    fn try_from(saved: SavedWordComposer) -> Result<Self, Self::Error> {
+        let committed_len = saved.committed.len();
+        let mut text = saved.committed;
+        for block in &saved.prev_blocks {
+            push_composer_text(&mut text, block)?;
+        }
+        Ok(HangulWordComposer {
+            text,
+            committed_len,
+            prev_blocks: saved.prev_blocks,
+            cur_block: saved.cur_block,
+            passthrough: saved.passthrough,
+            double_tap: saved.double_tap,
+            rules: R::default(),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R> From<HangulWordComposer<R>> for SavedWordComposer {
+    fn from(mut composer: HangulWordComposer<R>) -> Self {
+        composer.text.truncate(composer.committed_len);
+        SavedWordComposer {
+            committed: composer.text,
+            prev_blocks: composer.prev_blocks,
+            cur_block: composer.cur_block,
+            passthrough: composer.passthrough,
+            double_tap: composer.double_tap,
+        }
+    }
 }
 
 impl Default for HangulWordComposer {
@@ -97,11 +182,99 @@ impl HangulWordComposer {
     /// Creates a new, empty `HangulWordComposer`.
     pub fn new() -> Self {
         HangulWordComposer {
+            text: String::new(),
+            committed_len: 0,
+            prev_blocks: Vec::new(),
+            cur_block: BlockComposer::new(),
+            passthrough: false,
+            double_tap: false,
+            rules: StandardRules,
+        }
+    }
+
+    /// Creates a new, empty `HangulWordComposer` that passes non-Hangul
+    /// characters through instead of rejecting them. When a non-Hangul
+    /// character is pushed, the word so far is committed and the character
+    /// is appended to the output, and `WordPushResult::Continue` is
+    /// returned.
+    ///
+    /// Committed text is final: `pop` only removes jamo pushed after the
+    /// most recent non-Hangul character.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::word::{HangulWordComposer, WordPushResult};
+    ///
+    /// let mut composer = HangulWordComposer::with_passthrough();
+    /// for c in "ㄱㅏ1ㄴㅏ".chars() {
+    ///     assert_eq!(composer.push_char(c).unwrap(), WordPushResult::Continue);
+    /// }
+    /// assert_eq!(composer.as_string().unwrap(), "가1나");
+    /// ```
+    pub fn with_passthrough() -> Self {
+        HangulWordComposer {
+            passthrough: true,
+            ..Self::new()
+        }
+    }
+}
+
+impl<R: CompositionRules> HangulWordComposer<R> {
+    /// Creates a new, empty `HangulWordComposer` that combines jamo
+    /// according to `rules` instead of `StandardRules`; see
+    /// `CompositionRules` for an example.
+    pub fn with_rules(rules: R) -> Self {
+        HangulWordComposer {
+            text: String::new(),
+            committed_len: 0,
             prev_blocks: Vec::new(),
             cur_block: BlockComposer::new(),
+            passthrough: false,
+            double_tap: false,
+            rules,
         }
     }
 
+    /// Returns the rules this composer combines jamo with.
+    pub fn rules(&self) -> &R {
+        &self.rules
+    }
+
+    /// Returns `true` if this composer passes non-Hangul characters through
+    /// to its output.
+    pub fn is_passthrough(&self) -> bool {
+        self.passthrough
+    }
+
+    /// Sets whether a consonant typed twice in a row is doubled, as on
+    /// mobile keypads with no Shift key. With double tap, ㄱ, ㄷ, ㅂ, ㅅ, or
+    /// ㅈ typed twice at the start of a syllable gives ㄲ, ㄸ, ㅃ, ㅆ, or ㅉ
+    /// even if the rules do not allow it. Typed twice after a vowel, it
+    /// gives the double consonant as a final if the rules allow it there,
+    /// and otherwise at the start of the next syllable.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::word::HangulWordComposer;
+    ///
+    /// let mut composer = HangulWordComposer::new().with_double_tap(true);
+    /// for c in "ㄱㅏㄷㄷㅏㄱㄱ".chars() {
+    ///     composer.push_char(c).unwrap();
+    /// }
+    /// // ㄸ cannot be a final, but ㄲ can
+    /// assert_eq!(composer.as_string().unwrap(), "가딲");
+    /// ```
+    pub fn with_double_tap(mut self, double_tap: bool) -> Self {
+        self.double_tap = double_tap;
+        self
+    }
+
+    /// Returns `true` if this composer doubles a consonant typed twice in a
+    /// row; see `with_double_tap`.
+    pub fn is_double_tap(&self) -> bool {
+        self.double_tap
+    }
+
     /// Pushes a character into the `HangulWordComposer` if valid and returns a
     /// result indicating the outcome.
     ///
@@ -116,10 +289,15 @@ impl HangulWordComposer {
     /// If the character is Hangul but cannot form a valid syllable in either
     /// the current or a new block, `WordPushResult::InvalidHangul` is returned.
     ///
-    /// If the character is not Hangul, `WordPushResult::NonHangul` is returned.
+    /// If the character is not Hangul, `WordPushResult::NonHangul` is returned,
+    /// unless the composer was created with `with_passthrough`.
     pub fn push_char(&mut self, c: char) -> Result<WordPushResult, WordError> {
         match Character::from_char(c)? {
             Character::Hangul(jamo) => self.push(&jamo),
+            Character::NonHangul(_) if self.passthrough => {
+                self.commit_non_hangul(c)?;
+                Ok(WordPushResult::Continue)
+            }
             Character::NonHangul(_) => Ok(WordPushResult::NonHangul),
         }
     }
@@ -130,20 +308,25 @@ impl HangulWordComposer {
     /// valid Hangul syllable; otherwise, it completes the current block and
     /// creates a new block with the pushed character.
     pub fn push(&mut self, letter: &Jamo) -> Result<WordPushResult, WordError> {
-        match self.cur_block.push(letter) {
+        if self.double_tap && self.push_repeated(letter)? {
+            return Ok(WordPushResult::Continue);
+        }
+        match self.cur_block.push_with_rules(letter, &self.rules) {
             BlockPushResult::Success => Ok(WordPushResult::Continue),
             BlockPushResult::InvalidHangul => Ok(WordPushResult::InvalidHangul),
             BlockPushResult::NonHangul => Ok(WordPushResult::NonHangul),
-            BlockPushResult::StartNewBlockNoPop => match self.start_new_block(letter.clone()) {
+            // Clusters such as ㄳ can only end a block, never start one
+            BlockPushResult::StartNewBlockNoPop if !can_start_block(letter, &self.rules) => {
+                Ok(WordPushResult::InvalidHangul)
+            }
+            BlockPushResult::StartNewBlockNoPop => match self.start_new_block(*letter) {
+                Ok(_) => Ok(WordPushResult::Continue),
+                Err(e) => Err(e),
+            },
+            BlockPushResult::PopAndStartNewBlock => match self.pop_and_start_new_block(*letter) {
                 Ok(_) => Ok(WordPushResult::Continue),
                 Err(e) => Err(e),
             },
-            BlockPushResult::PopAndStartNewBlock => {
-                match self.pop_and_start_new_block(letter.clone()) {
-                    Ok(_) => Ok(WordPushResult::Continue),
-                    Err(e) => Err(e),
-                }
-            }
         }
     }
 
@@ -170,21 +353,48 @@ impl HangulWordComposer {
         }
     }
 
+    // Doubles `letter` if it was typed right after itself, as described in
+    // `with_double_tap`. Returns `false` if it was not.
+    fn push_repeated(&mut self, letter: &Jamo) -> Result<bool, WordError> {
+        let Jamo::Consonant(consonant) = letter else {
+            return Ok(false);
+        };
+        let Some(double) = consonant.combine_for_initial(consonant) else {
+            return Ok(false);
+        };
+        if self.cur_block.double_initial(letter) {
+            return Ok(true);
+        }
+        let double = Jamo::CompositeConsonant(double);
+        if !self.cur_block.ends_with_final(letter)
+            || allows_composite(&self.rules, JamoPosition::Final, &double)
+        {
+            return Ok(false);
+        }
+        self.cur_block.pop();
+        self.start_new_block(*letter)?;
+        Ok(self.cur_block.double_initial(letter))
+    }
+
     fn prev_block_to_cur(&mut self) -> Result<(), WordError> {
         if let Some(last_block) = self.prev_blocks.pop() {
-            self.cur_block = BlockComposer::from_composed_block(&last_block)?;
-            Ok(())
-        } else {
-            Ok(())
+            let mut last = String::new();
+            push_composer_text(&mut last, &last_block)?;
+            self.text.truncate(self.text.len() - last.len());
+            self.cur_block = last_block;
+            // The block may have lost its final to the next block when that
+            // block was started
+            self.cur_block.reset_state();
         }
+        Ok(())
     }
 
     fn pop_and_start_new_block(&mut self, letter: Jamo) -> Result<(), WordError> {
         match self.cur_block.pop_end_consonant() {
             Some(l) => {
                 self.complete_current_block()?;
-                self.cur_block.push(&l);
-                match self.cur_block.push(&letter) {
+                self.cur_block.push_with_rules(&l, &self.rules);
+                match self.cur_block.push_with_rules(&letter, &self.rules) {
                     BlockPushResult::Success => Ok(()),
                     other => Err(WordError::CouldNotStartNewBlock(
                         letter.char_compatibility(),
@@ -198,7 +408,7 @@ impl HangulWordComposer {
 
     fn start_new_block(&mut self, letter: Jamo) -> Result<(), WordError> {
         self.complete_current_block()?;
-        match self.cur_block.push(&letter) {
+        match self.cur_block.push_with_rules(&letter, &self.rules) {
             BlockPushResult::Success => Ok(()),
             other => Err(WordError::CouldNotStartNewBlock(
                 letter.char_compatibility(),
@@ -210,24 +420,42 @@ impl HangulWordComposer {
     /// Returns the composed string for the current Hangul word.
     /// This includes all completed syllable blocks and the current block,
     /// even if it is incomplete.
+    ///
+    /// The completed text is kept as a string while composing, so this only
+    /// copies it and appends the current block.
     pub fn as_string(&self) -> Result<String, WordError> {
-        let mut result = hangul_blocks_vec_to_string(&self.prev_blocks)?;
-        let cur_as_char = self.cur_block.block_as_string()?;
-        if let Some(c) = cur_as_char {
-            result.push(c);
-        }
+        let mut result = String::new();
+        self.push_to(&mut result)?;
         Ok(result)
     }
 
-    fn complete_current_block(&mut self) -> Result<(), WordError> {
-        match self.cur_block.try_as_complete_block()? {
-            BlockCompletionStatus::Complete(block) => {
-                self.prev_blocks.push(block);
-                self.cur_block = BlockComposer::new();
+    /// Appends the composed string for the current Hangul word to `out`, as
+    /// returned by `as_string`.
+    pub(crate) fn push_to(&self, out: &mut String) -> Result<(), WordError> {
+        out.push_str(&self.text);
+        push_composer_text(out, &self.cur_block)
+    }
+
+    fn commit_non_hangul(&mut self, c: char) -> Result<(), WordError> {
+        push_composer_text(&mut self.text, &self.cur_block)?;
+        self.text.push(c);
+        self.committed_len = self.text.len();
+        self.prev_blocks.clear();
+        self.cur_block = BlockComposer::new();
+        Ok(())
+    }
+
+    pub(crate) fn complete_current_block(&mut self) -> Result<(), WordError> {
+        match self.cur_block.combined_jamo()? {
+            [Some(_), Some(_), _] => {
+                push_composer_text(&mut self.text, &self.cur_block)?;
+                self.prev_blocks.push(std::mem::take(&mut self.cur_block));
                 Ok(())
             }
-            BlockCompletionStatus::Incomplete(c) => Err(WordError::CannotCompleteCurrentBlock(c)),
-            BlockCompletionStatus::Empty => {
+            [Some(c), None, _] | [None, Some(c), _] | [None, None, Some(c)] => {
+                Err(WordError::CannotCompleteCurrentBlock(c))
+            }
+            [None, None, None] => {
                 // Nothing to complete
                 Ok(())
             }
@@ -235,6 +463,609 @@ impl HangulWordComposer {
     }
 }
 
+/// A Hangul word as a sequence of complete syllable blocks, so that
+/// transformations can work on initials, vowels, and finals rather than on
+/// raw strings. Every block is a valid modern syllable.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::block::HangulBlock;
+/// use hangul_cd::jamo::Jongseong;
+/// use hangul_cd::word::HangulWord;
+///
+/// let mut word: HangulWord = "한국".parse().unwrap();
+/// assert_eq!(word.len(), 2);
+/// assert_eq!(word[1].to_char(), '국');
+///
+/// // Replace or remove the final consonant of a block
+/// word.set_final(0, Some(Jongseong::try_from('ㄹ').unwrap())).unwrap();
+/// word.set_final(1, None).unwrap();
+/// assert_eq!(word.to_string(), "할구");
+///
+/// word.push_block("어".parse::<HangulBlock>().unwrap());
+/// assert_eq!(word.slice(1..).unwrap().to_string(), "구어");
+/// assert_eq!(word.pop_block().unwrap().to_char(), '어');
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<HangulBlock>", into = "Vec<HangulBlock>")
+)]
+pub struct HangulWord {
+    blocks: Vec<HangulBlock>,
+}
+
+impl HangulWord {
+    /// Creates an empty `HangulWord`.
+    pub fn new() -> Self {
+        HangulWord { blocks: Vec::new() }
+    }
+
+    /// Creates a `HangulWord` from `blocks`.
+    pub fn from_blocks(blocks: Vec<HangulBlock>) -> Self {
+        HangulWord { blocks }
+    }
+
+    /// Returns the blocks of the word.
+    pub fn blocks(&self) -> &[HangulBlock] {
+        &self.blocks
+    }
+
+    /// Returns the blocks of the word, consuming it.
+    pub fn into_blocks(self) -> Vec<HangulBlock> {
+        self.blocks
+    }
+
+    /// Returns the number of blocks in the word.
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns whether the word has no blocks.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Returns an iterator over the blocks of the word.
+    pub fn iter(&self) -> std::slice::Iter<'_, HangulBlock> {
+        self.blocks.iter()
+    }
+
+    /// Returns the block at `index`, or `None` if it is out of range.
+    pub fn get(&self, index: usize) -> Option<&HangulBlock> {
+        self.blocks.get(index)
+    }
+
+    /// Returns the blocks in `range` as a new word, or `None` if the range
+    /// is out of bounds.
+    pub fn slice<R>(&self, range: R) -> Option<HangulWord>
+    where
+        R: SliceIndex<[HangulBlock], Output = [HangulBlock]>,
+    {
+        self.blocks.get(range).map(|blocks| HangulWord {
+            blocks: blocks.to_vec(),
+        })
+    }
+
+    /// Appends `block` to the end of the word.
+    pub fn push_block(&mut self, block: HangulBlock) {
+        self.blocks.push(block);
+    }
+
+    /// Removes and returns the last block of the word, or `None` if it is
+    /// empty.
+    pub fn pop_block(&mut self) -> Option<HangulBlock> {
+        self.blocks.pop()
+    }
+
+    /// Replaces the initial consonant of the block at `index`.
+    pub fn set_initial(&mut self, index: usize, initial: Choseong) -> Result<(), WordError> {
+        self.block_mut(index)?.initial = initial;
+        Ok(())
+    }
+
+    /// Replaces the vowel of the block at `index`.
+    pub fn set_vowel(&mut self, index: usize, vowel: Jungseong) -> Result<(), WordError> {
+        self.block_mut(index)?.vowel = vowel;
+        Ok(())
+    }
+
+    /// Replaces the final consonant of the block at `index`, or removes it if
+    /// `final_optional` is `None`.
+    pub fn set_final(
+        &mut self,
+        index: usize,
+        final_optional: Option<Jongseong>,
+    ) -> Result<(), WordError> {
+        self.block_mut(index)?.final_optional = final_optional;
+        Ok(())
+    }
+
+    /// Returns this word followed by `other`. If `liaison` is true, the
+    /// words are resyllabified at the seam as they are pronounced (연음) when
+    /// `other` begins with the silent ㅇ: the final consonant of this word
+    /// moves to the start of `other`, as in 먹 + 어요 → 머거요, or only the
+    /// second consonant of a cluster does, as in 닭 + 이 → 달기. A final ㅇ
+    /// does not move, and ㅎ is silent before a vowel, so 좋 + 아 gives 조아
+    /// and 않 + 아 gives 아나.
+    ///
+    /// This treats `other` as a particle or ending. Other sound changes,
+    /// such as the palatalization in 같이 → 가치 or the tensing in 넋이 →
+    /// 넉씨, are not applied.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use hangul_cd::word::HangulWord;
+    ///
+    /// let word: HangulWord = "옷".parse().unwrap();
+    /// let particle: HangulWord = "이".parse().unwrap();
+    /// assert_eq!(word.concat_with_liaison(&particle, true).to_string(), "오시");
+    /// assert_eq!(word.concat_with_liaison(&particle, false).to_string(), "옷이");
+    ///
+    /// let word: HangulWord = "읽".parse().unwrap();
+    /// let ending: HangulWord = "어요".parse().unwrap();
+    /// assert_eq!(word.concat_with_liaison(&ending, true).to_string(), "일거요");
+    /// ```
+    pub fn concat_with_liaison(&self, other: &HangulWord, liaison: bool) -> HangulWord {
+        let mut blocks = Vec::with_capacity(self.len() + other.len());
+        blocks.extend_from_slice(&self.blocks);
+        blocks.extend_from_slice(&other.blocks);
+        if liaison && !self.is_empty() {
+            link_blocks(&mut blocks[self.len() - 1..]);
+        }
+        HangulWord { blocks }
+    }
+
+    // Returns the block at `index` for the setters, which keep it valid.
+    fn block_mut(&mut self, index: usize) -> Result<&mut HangulBlock, WordError> {
+        let len = self.blocks.len();
+        self.blocks
+            .get_mut(index)
+            .ok_or(WordError::BlockIndexOutOfRange(index, len))
+    }
+}
+
+// Moves the final consonant of `blocks[0]` to the start of `blocks[1]` as
+// described in `HangulWord::concat_with_liaison`, if `blocks[1]` begins with
+// the silent ㅇ. Both blocks must be valid.
+fn link_blocks(blocks: &mut [HangulBlock]) {
+    const IEUNG: Jamo = Jamo::Consonant(JamoConsonantSingular::Ieung);
+    const HIEUT: Jamo = Jamo::Consonant(JamoConsonantSingular::Hieut);
+    let [prev, next, ..] = blocks else {
+        return;
+    };
+    let Some(final_consonant) = prev.final_optional else {
+        return;
+    };
+    if *next.initial.jamo() != IEUNG || *final_consonant.jamo() == IEUNG {
+        return;
+    }
+    // Infallible: a final that is not split off from a cluster can begin a
+    // syllable
+    let (mut kept, mut moved) = match split_jongseong(final_consonant) {
+        (kept, Some(moved)) => (Some(kept), moved),
+        (kept, None) => (None, final_to_initial(kept).unwrap()),
+    };
+    // The silent ㅎ leaves the consonant before it, if any, to move instead
+    if *moved.jamo() == HIEUT {
+        moved = match kept.take() {
+            // Infallible: only ㄴ and ㄹ are kept before a final ㅎ
+            Some(kept) => final_to_initial(kept).unwrap(),
+            None => next.initial,
+        };
+    }
+    prev.final_optional = kept;
+    next.initial = moved;
+}
+
+impl<I: SliceIndex<[HangulBlock]>> Index<I> for HangulWord {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.blocks[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a HangulWord {
+    type Item = &'a HangulBlock;
+    type IntoIter = std::slice::Iter<'a, HangulBlock>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.blocks.iter()
+    }
+}
+
+impl fmt::Display for HangulWord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.blocks
+            .iter()
+            .try_for_each(|block| write!(f, "{block}"))
+    }
+}
+
+impl FromStr for HangulWord {
+    type Err = WordError;
+
+    /// Parses a string made up only of precomposed Hangul syllables.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let blocks = s
+            .chars()
+            .map(HangulBlock::from_char)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(HangulWord { blocks })
+    }
+}
+
+impl From<Vec<HangulBlock>> for HangulWord {
+    fn from(blocks: Vec<HangulBlock>) -> Self {
+        HangulWord::from_blocks(blocks)
+    }
+}
+
+impl From<HangulWord> for Vec<HangulBlock> {
+    fn from(word: HangulWord) -> Self {
+        word.blocks
+    }
+}
+
+impl TryFrom<&str> for HangulWord {
+    type Error = WordError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+fn can_start_block<R: CompositionRules + ?Sized>(letter: &Jamo, rules: &R) -> bool {
+    match letter {
+        Jamo::Consonant(_) => true,
+        Jamo::CompositeConsonant(_) => allows_composite(rules, JamoPosition::Initial, letter),
+        Jamo::Vowel(_) | Jamo::CompositeVowel(_) => false,
+    }
+}
+
+// Appends the block in `composer` to `text` as `block_as_string` does, but
+// writing a lone jamo with no conjoining form, such as an initial ㅄ, as
+// compatibility jamo, and likewise blocks that other `CompositionRules`
+// allow but that are not modern syllables, such as ㅄ with ㅏ.
+fn push_composer_text(text: &mut String, composer: &BlockComposer) -> Result<(), WordError> {
+    match composer.combined_jamo()? {
+        [Some(initial), Some(vowel), final_optional] => {
+            match HangulBlock::try_new(initial, vowel, final_optional) {
+                Ok(block) => text.push(block.to_char()),
+                Err(_) => text.extend(
+                    [Some(initial), Some(vowel), final_optional]
+                        .iter()
+                        .flatten()
+                        .map(Jamo::char_compatibility),
+                ),
+            }
+        }
+        [Some(jamo), None, _] | [None, Some(jamo), _] | [None, None, Some(jamo)] => {
+            let position = match jamo {
+                Jamo::Consonant(_) | Jamo::CompositeConsonant(_) => JamoPosition::Initial,
+                Jamo::Vowel(_) | Jamo::CompositeVowel(_) => JamoPosition::Vowel,
+            };
+            text.push(
+                jamo.char_modern(position)
+                    .unwrap_or(jamo.char_compatibility()),
+            );
+        }
+        [None, None, None] => {}
+    }
+    Ok(())
+}
+
+/// Strips common verbal endings and particles (조사) from `word` to recover
+/// an approximate stem, so that different inflections of a word can be
+/// indexed under the same key: 먹었습니다 and 먹어요 both give 먹, and 학교에서
+/// gives 학교.
+///
+/// At most one ending is stripped, using a table of common endings, along
+/// with the past-tense 었/았 and future 겠 markers before a verbal ending.
+/// Particles that alternate with the preceding syllable, such as 이/가 and
+/// 을/를, are only stripped after a syllable they can follow. The result is
+/// approximate: irregular stems are not restored, the honorific 시 is kept
+/// (가세요 gives 가시), and a noun that happens to end like an ending (바다)
+/// is stripped too. Surrounding whitespace is trimmed, and a word that is
+/// entirely an ending is returned unchanged.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::word::stem;
+///
+/// assert_eq!(stem("먹었습니다"), "먹");
+/// assert_eq!(stem("먹어요"), "먹");
+/// assert_eq!(stem("갔어요"), "가");
+/// assert_eq!(stem("갑니다"), "가");
+/// assert_eq!(stem("학교에서"), "학교");
+/// assert_eq!(stem("책을"), "책");
+/// ```
+pub fn stem(word: &str) -> String {
+    let word = word.trim();
+    strip_verbal_ending(word)
+        .or_else(|| strip_ending(word, PARTICLES).map(str::to_string))
+        .unwrap_or_else(|| word.to_string())
+}
+
+/// The final consonant (받침) at the end of a word, as returned by
+/// `batchim_kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BatchimKind {
+    /// No final consonant, as in 사과, which takes particles such as 를, 가,
+    /// and 로.
+    None,
+
+    /// The final ㄹ, as in 서울, which takes 을 and 이 like other finals but
+    /// 로 rather than 으로 like a syllable without one.
+    Rieul,
+
+    /// Any other final consonant, including clusters with ㄹ such as ㄼ.
+    /// Holds the compatibility jamo it is pronounced as at the end of a word
+    /// (대표음), one of ㄱ, ㄴ, ㄷ, ㄹ, ㅁ, ㅂ, and ㅇ: 밖 gives ㄱ, 옷 gives ㄷ,
+    /// and 여덟 gives ㄹ.
+    Other(char),
+}
+
+impl BatchimKind {
+    /// Returns the compatibility jamo the final consonant is pronounced as at
+    /// the end of a word, or `None` if there is no final consonant.
+    pub fn representative_sound(&self) -> Option<char> {
+        match self {
+            BatchimKind::None => None,
+            BatchimKind::Rieul => Some('ㄹ'),
+            BatchimKind::Other(sound) => Some(*sound),
+        }
+    }
+}
+
+/// Returns whether the last character of `word` is a Hangul syllable with a
+/// final consonant (받침), or `None` if it is not a precomposed Hangul
+/// syllable or `word` is empty.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::word::has_batchim;
+///
+/// assert_eq!(has_batchim("책"), Some(true));
+/// assert_eq!(has_batchim("사과"), Some(false));
+/// assert_eq!(has_batchim("책!"), None);
+/// ```
+pub fn has_batchim(word: &str) -> Option<bool> {
+    batchim_kind(word).map(|kind| kind != BatchimKind::None)
+}
+
+/// Classifies the final consonant (받침) of the last character of `word`,
+/// for choosing particles such as 로/으로 and for pronunciation. Returns
+/// `None` if the last character is not a precomposed Hangul syllable or
+/// `word` is empty.
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::word::{BatchimKind, batchim_kind};
+///
+/// assert_eq!(batchim_kind("학교"), Some(BatchimKind::None));
+/// assert_eq!(batchim_kind("서울"), Some(BatchimKind::Rieul));
+/// assert_eq!(batchim_kind("부엌"), Some(BatchimKind::Other('ㄱ')));
+///
+/// // 로 follows no final or ㄹ, and 으로 any other final
+/// let particle = |word| match batchim_kind(word) {
+///     Some(BatchimKind::None | BatchimKind::Rieul) => "로",
+///     _ => "으로",
+/// };
+/// assert_eq!(particle("서울"), "로");
+/// assert_eq!(particle("여덟"), "으로");
+/// ```
+pub fn batchim_kind(word: &str) -> Option<BatchimKind> {
+    let block = HangulBlock::from_char(word.chars().next_back()?).ok()?;
+    let Some(final_consonant) = block.final_optional else {
+        return Some(BatchimKind::None);
+    };
+    let sound = match final_consonant.char_compatibility() {
+        'ㄹ' => return Some(BatchimKind::Rieul),
+        'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' => 'ㄱ',
+        'ㄴ' | 'ㄵ' | 'ㄶ' => 'ㄴ',
+        'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => 'ㄷ',
+        'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => 'ㄹ',
+        'ㅁ' | 'ㄻ' => 'ㅁ',
+        'ㅂ' | 'ㅍ' | 'ㄿ' | 'ㅄ' => 'ㅂ',
+        other => other,
+    };
+    Some(BatchimKind::Other(sound))
+}
+
+/// Attaches the particle (조사) `josa` to `word`, choosing the form that
+/// follows its last syllable: 을 or 를, 이 or 가, 은 or 는, 과 or 와, 아 or
+/// 야, 이나 or 나, 이랑 or 랑, 이에요 or 예요, and 으로 or 로 (which also
+/// follows ㄹ, as do 으로서, 으로써, and 으로부터). Either form may be
+/// given, or both separated by a slash as in `"을/를"`; other particles are
+/// attached unchanged.
+///
+/// If `word` does not end in a Hangul syllable, as with numbers and Latin
+/// letters, both forms are written with the second in parentheses, as in
+/// 을(를).
+///
+/// **Example:**
+/// ```rust
+/// use hangul_cd::word::attach_josa;
+///
+/// assert_eq!(attach_josa("책", "를"), "책을");
+/// assert_eq!(attach_josa("사과", "을/를"), "사과를");
+/// assert_eq!(attach_josa("서울", "으로"), "서울로");
+/// assert_eq!(attach_josa("집", "에서"), "집에서");
+/// assert_eq!(attach_josa("ABC", "이"), "ABC이(가)");
+/// ```
+pub fn attach_josa(word: &str, josa: &str) -> String {
+    let given = josa.split('/').next().unwrap_or(josa);
+    let Some(&(closed, open)) = JOSA_FORMS
+        .iter()
+        .find(|(closed, open)| given == *closed || given == *open)
+    else {
+        return format!("{word}{josa}");
+    };
+    let eu_ro = closed.starts_with("으로");
+    match batchim_kind(word) {
+        Some(BatchimKind::None) => format!("{word}{open}"),
+        Some(BatchimKind::Rieul) if eu_ro => format!("{word}{open}"),
+        Some(_) => format!("{word}{closed}"),
+        None => format!("{word}{closed}({open})"),
+    }
+}
+
+// Particles that alternate with the preceding syllable, as the form after a
+// final consonant and the form after a syllable without one.
+const JOSA_FORMS: &[(&str, &str)] = &[
+    ("을", "를"),
+    ("이", "가"),
+    ("은", "는"),
+    ("과", "와"),
+    ("아", "야"),
+    ("이나", "나"),
+    ("이랑", "랑"),
+    ("이에요", "예요"),
+    ("으로", "로"),
+    ("으로서", "로서"),
+    ("으로써", "로써"),
+    ("으로부터", "로부터"),
+];
+
+// The kind of syllable an ending can follow.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Follows {
+    Any,
+    // A syllable with a final consonant.
+    Closed,
+    // A syllable without a final consonant.
+    Open,
+    // A syllable without a final consonant or with ㄹ as its final.
+    OpenOrRieul,
+}
+
+// Verbal endings and the copula, longest first.
+pub(crate) const VERBAL_ENDINGS: &[(&str, Follows)] = &[
+    ("습니다", Follows::Closed),
+    ("습니까", Follows::Closed),
+    ("입니다", Follows::Any),
+    ("입니까", Follows::Any),
+    ("이에요", Follows::Closed),
+    ("으세요", Follows::Closed),
+    ("어요", Follows::Closed),
+    ("아요", Follows::Closed),
+    ("여요", Follows::Any),
+    ("예요", Follows::Open),
+    ("어서", Follows::Closed),
+    ("아서", Follows::Closed),
+    ("는데", Follows::Any),
+    ("는다", Follows::Any),
+    ("지요", Follows::Any),
+    ("이다", Follows::Closed),
+    ("죠", Follows::Any),
+    ("요", Follows::Open),
+    ("고", Follows::Any),
+    ("다", Follows::Any),
+];
+
+// Particles, longest first.
+pub(crate) const PARTICLES: &[(&str, Follows)] = &[
+    ("에서부터", Follows::Any),
+    ("으로부터", Follows::Closed),
+    ("로부터", Follows::OpenOrRieul),
+    ("에게서", Follows::Any),
+    ("한테서", Follows::Any),
+    ("에서는", Follows::Any),
+    ("까지", Follows::Any),
+    ("부터", Follows::Any),
+    ("에서", Follows::Any),
+    ("에게", Follows::Any),
+    ("한테", Follows::Any),
+    ("께서", Follows::Any),
+    ("으로", Follows::Closed),
+    ("처럼", Follows::Any),
+    ("보다", Follows::Any),
+    ("만큼", Follows::Any),
+    ("이랑", Follows::Closed),
+    ("에는", Follows::Any),
+    ("에도", Follows::Any),
+    ("은", Follows::Closed),
+    ("는", Follows::Open),
+    ("이", Follows::Closed),
+    ("가", Follows::Open),
+    ("을", Follows::Closed),
+    ("를", Follows::Open),
+    ("과", Follows::Closed),
+    ("와", Follows::Open),
+    ("랑", Follows::Open),
+    ("로", Follows::OpenOrRieul),
+    ("의", Follows::Any),
+    ("에", Follows::Any),
+    ("도", Follows::Any),
+    ("만", Follows::Any),
+];
+
+// Strips the first ending in `endings` that `word` ends with and that can
+// follow the syllable before it, returning the rest of `word`.
+fn strip_ending<'a>(word: &'a str, endings: &[(&str, Follows)]) -> Option<&'a str> {
+    endings.iter().find_map(|&(ending, follows)| {
+        let rest = word.strip_suffix(ending)?;
+        can_follow(rest, follows).then_some(rest)
+    })
+}
+
+// Returns whether an ending that can follow `follows` can be attached to
+// `rest`, which must end in a Hangul syllable.
+pub(crate) fn can_follow(rest: &str, follows: Follows) -> bool {
+    let Some((_, _, t)) = rest.chars().next_back().and_then(syllable_indices) else {
+        return false;
+    };
+    match follows {
+        Follows::Any => true,
+        Follows::Closed => t != 0,
+        Follows::Open => t == 0,
+        Follows::OpenOrRieul => t == 0 || t == FINAL_RIEUL,
+    }
+}
+
+fn strip_verbal_ending(word: &str) -> Option<String> {
+    if let Some(rest) = word
+        .strip_suffix("니다")
+        .or_else(|| word.strip_suffix("니까"))
+        && let Some(last) = rest.chars().next_back()
+        && let Some((l, v, FINAL_PIEUP)) = syllable_indices(last)
+        && !matches!(last, '입' | '습')
+    {
+        let head = &rest[..rest.len() - last.len_utf8()];
+        let open = syllable_from_indices(l, v, 0)?;
+        return Some(strip_pre_final(&format!("{head}{open}")));
+    }
+    let rest = strip_ending(word, VERBAL_ENDINGS)?;
+    let rest = strip_pre_final(rest);
+    Some(a_eo_stem(&rest).unwrap_or(rest))
+}
+
+// Strips the future 겠 and past 었/았/였 markers from a verb, restoring the
+// vowel of contracted past forms such as 갔 and 했.
+fn strip_pre_final(verb: &str) -> String {
+    let verb = verb
+        .strip_suffix('겠')
+        .filter(|v| !v.is_empty())
+        .unwrap_or(verb);
+    let Some(last) = verb.chars().next_back() else {
+        return verb.to_string();
+    };
+    let head = &verb[..verb.len() - last.len_utf8()];
+    match syllable_indices(last) {
+        Some((_, _, FINAL_SSANGSIOS)) if matches!(last, '었' | '았' | '였') && !head.is_empty() => {
+            head.to_string()
+        }
+        Some((l, v, FINAL_SSANGSIOS)) => {
+            let contracted = format!("{head}{}", syllable_from_indices(l, v, 0).unwrap_or(last));
+            a_eo_stem(&contracted).unwrap_or(contracted)
+        }
+        _ => verb.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -247,27 +1078,37 @@ mod tests {
         assert_eq!(composer.push_char('ㅏ'), Ok(WordPushResult::Continue));
         assert_eq!(composer.push_char('ㄴ'), Ok(WordPushResult::Continue),);
         assert_eq!(composer.push_char('ㅇ'), Ok(WordPushResult::Continue),);
+        let prev_blocks = |composer: &HangulWordComposer| {
+            composer
+                .prev_blocks
+                .iter()
+                .map(|block| match block.try_as_complete_block() {
+                    Ok(BlockCompletionStatus::Complete(block)) => block,
+                    other => panic!("{other:?}"),
+                })
+                .collect::<Vec<_>>()
+        };
         assert_eq!(
-            composer.prev_blocks,
+            prev_blocks(&composer),
             vec![HangulBlock {
-                initial: Jamo::Consonant(JamoConsonantSingular::Giyeok),
-                vowel: Jamo::Vowel(JamoVowelSingular::A),
-                final_optional: Some(Jamo::Consonant(JamoConsonantSingular::Nieun)),
+                initial: Choseong::try_from('ㄱ').unwrap(),
+                vowel: Jungseong::try_from('ㅏ').unwrap(),
+                final_optional: Some(Jongseong::try_from('ㄴ').unwrap()),
             }]
         );
         assert_eq!(composer.push_char('ㅛ'), Ok(WordPushResult::Continue));
         assert_eq!(composer.push_char('ㅉ'), Ok(WordPushResult::Continue),);
         assert_eq!(
-            composer.prev_blocks,
+            prev_blocks(&composer),
             vec![
                 HangulBlock {
-                    initial: Jamo::Consonant(JamoConsonantSingular::Giyeok),
-                    vowel: Jamo::Vowel(JamoVowelSingular::A),
-                    final_optional: Some(Jamo::Consonant(JamoConsonantSingular::Nieun)),
+                    initial: Choseong::try_from('ㄱ').unwrap(),
+                    vowel: Jungseong::try_from('ㅏ').unwrap(),
+                    final_optional: Some(Jongseong::try_from('ㄴ').unwrap()),
                 },
                 HangulBlock {
-                    initial: Jamo::Consonant(JamoConsonantSingular::Ieung),
-                    vowel: Jamo::Vowel(JamoVowelSingular::Yo),
+                    initial: Choseong::try_from('ㅇ').unwrap(),
+                    vowel: Jungseong::try_from('ㅛ').unwrap(),
                     final_optional: None,
                 }
             ]
@@ -443,4 +1284,329 @@ mod tests {
         let result_string = composer.as_string().unwrap();
         assert_eq!(result_string, "안".to_string());
     }
+
+    #[test]
+    fn cluster_cannot_start_new_block() {
+        let mut composer = HangulWordComposer::new();
+        for c in "ㄱㅏㄲ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        assert_eq!(composer.push_char('ㄳ'), Ok(WordPushResult::InvalidHangul));
+        assert_eq!(composer.as_string().unwrap(), "갂".to_string());
+    }
+
+    #[test]
+    fn test_passthrough() {
+        let mut composer = HangulWordComposer::new();
+        assert!(!composer.is_passthrough());
+        assert_eq!(composer.push_char('1'), Ok(WordPushResult::NonHangul));
+        assert_eq!(composer.as_string().unwrap(), "");
+
+        let mut composer = HangulWordComposer::with_passthrough();
+        for c in "ㄱ, ㅇㅏㄴ!".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        assert_eq!(composer.as_string().unwrap(), "\u{1100}, 안!");
+
+        // Text before the last non-Hangul character cannot be popped
+        assert_eq!(composer.pop(), Ok(None));
+        assert_eq!(composer.push_char('ㄱ'), Ok(WordPushResult::Continue));
+        assert_eq!(composer.pop().unwrap().unwrap().char_compatibility(), 'ㄱ');
+        assert_eq!(composer.as_string().unwrap(), "\u{1100}, 안!");
+    }
+
+    #[test]
+    fn popping_back_into_a_block_that_lost_its_final() {
+        // ㄴ is taken from 곤 as the initial of 나, then popped off again
+        let mut composer = HangulWordComposer::new();
+        for c in "ㄱㅗㄴㅏ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        assert_eq!(composer.pop().unwrap().unwrap().char_compatibility(), 'ㅏ');
+        assert_eq!(composer.pop().unwrap().unwrap().char_compatibility(), 'ㄴ');
+        assert_eq!(composer.push_char('ㄴ'), Ok(WordPushResult::Continue));
+        assert_eq!(composer.as_string().unwrap(), "곤");
+
+        let mut composer = HangulWordComposer::new();
+        for c in "ㄱㅗㄴㅏ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        composer.pop().unwrap();
+        composer.pop().unwrap();
+        assert_eq!(composer.push_char('ㅏ'), Ok(WordPushResult::Continue));
+        assert_eq!(composer.as_string().unwrap(), "과");
+    }
+
+    #[test]
+    fn completed_text_follows_pushes_and_pops() {
+        let mut composer = HangulWordComposer::with_passthrough();
+        for c in "ㅎㅏㄴㄱㅡㄹ ㅁㅏㄹㅆㅡㅁ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        assert_eq!(composer.as_string().unwrap(), "한글 말씀");
+        let mut popped = 0;
+        while composer.pop().unwrap().is_some() {
+            popped += 1;
+            if popped == 3 {
+                assert_eq!(composer.as_string().unwrap(), "한글 말");
+            }
+        }
+        assert_eq!(popped, 6);
+        assert_eq!(composer.as_string().unwrap(), "한글 ");
+        assert_eq!(composer.push_char('ㅇ'), Ok(WordPushResult::Continue));
+        assert_eq!(composer.push_char('ㅏ'), Ok(WordPushResult::Continue));
+        assert_eq!(composer.as_string().unwrap(), "한글 아");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_restores_composition_state() {
+        let mut composer = HangulWordComposer::new();
+        for c in "ㅇㅏㄴㄴㅕ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+
+        let saved = serde_json::to_string(&composer).unwrap();
+        let mut restored: HangulWordComposer = serde_json::from_str(&saved).unwrap();
+        assert_eq!(restored.as_string().unwrap(), "안녀".to_string());
+
+        // Composition continues exactly where it left off
+        assert_eq!(restored.push_char('ㅇ'), Ok(WordPushResult::Continue));
+        assert_eq!(restored.as_string().unwrap(), "안녕".to_string());
+        assert_eq!(restored.pop().unwrap().unwrap().char_compatibility(), 'ㅇ');
+        assert_eq!(restored.pop().unwrap().unwrap().char_compatibility(), 'ㅕ');
+        assert_eq!(restored.as_string().unwrap(), "안ᄂ".to_string());
+    }
+
+    #[test]
+    fn stem_groups_inflections() {
+        for word in [
+            "먹었습니다",
+            "먹어요",
+            "먹었어요",
+            "먹겠습니다",
+            "먹고",
+            "먹다",
+        ] {
+            assert_eq!(stem(word), "먹", "{word}");
+        }
+        for word in ["갑니다", "가요", "갔어요", "갔습니다"] {
+            assert_eq!(stem(word), "가", "{word}");
+        }
+        for word in ["공부합니다", "공부해요", "공부했어요"] {
+            assert_eq!(stem(word), "공부하", "{word}");
+        }
+        assert_eq!(stem("봤어요"), "보");
+        assert_eq!(stem("좋아요"), "좋");
+    }
+
+    #[test]
+    fn stem_strips_particles() {
+        assert_eq!(stem("학생이"), "학생");
+        assert_eq!(stem("학교가"), "학교");
+        assert_eq!(stem("나이"), "나이");
+        assert_eq!(stem("서울로"), "서울");
+        assert_eq!(stem("집으로"), "집");
+        assert_eq!(stem("친구에게서"), "친구");
+        assert_eq!(stem("학생입니다"), "학생");
+        assert_eq!(stem("학교예요"), "학교");
+        assert_eq!(stem(" 한글 "), "한글");
+        assert_eq!(stem("가"), "가");
+        assert_eq!(stem(""), "");
+    }
+
+    #[test]
+    fn hangul_word_edits_blocks() {
+        let mut word = HangulWord::try_from("닭고기").unwrap();
+        assert_eq!(&word[..2], HangulWord::from_str("닭고").unwrap().blocks());
+        assert_eq!(word.slice(2..4), None);
+        word.set_initial(2, Choseong::try_from('ㄲ').unwrap())
+            .unwrap();
+        word.set_vowel(1, Jungseong::try_from('ㅘ').unwrap())
+            .unwrap();
+        word.set_final(0, Some(Jongseong::try_from('ㄹ').unwrap()))
+            .unwrap();
+        assert_eq!(word.to_string(), "달과끼");
+        assert_eq!(
+            word.set_final(3, None),
+            Err(WordError::BlockIndexOutOfRange(3, 3))
+        );
+
+        // Non-syllables are rejected
+        assert!("한 글".parse::<HangulWord>().is_err());
+        assert!(HangulWord::from_str("").unwrap().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_word() {
+        let word: HangulWord = "값".parse().unwrap();
+        let json = serde_json::to_string(&word).unwrap();
+        assert_eq!(serde_json::from_str::<HangulWord>(&json).unwrap(), word);
+        // A vowel in place of the initial
+        let json = json
+            .replacen("Consonant", "Vowel", 1)
+            .replacen("Giyeok", "A", 1);
+        assert!(serde_json::from_str::<HangulWord>(&json).is_err());
+    }
+
+    #[test]
+    fn liaison_at_the_seam() {
+        let cases = [
+            ("먹", "어요", "머거요"),
+            ("닭", "이", "달기"),
+            ("없", "어", "업서"),
+            ("있", "어", "이써"),
+            ("강", "에", "강에"),
+            ("좋", "아", "조아"),
+            ("않", "아", "아나"),
+            ("싫", "어", "시러"),
+            ("책", "도", "책도"),
+            ("사과", "를", "사과를"),
+            ("", "이", "이"),
+            ("밥", "", "밥"),
+        ];
+        for (word, suffix, expected) in cases {
+            let word: HangulWord = word.parse().unwrap();
+            let suffix: HangulWord = suffix.parse().unwrap();
+            let linked = word.concat_with_liaison(&suffix, true);
+            assert_eq!(linked.to_string(), expected, "{word} + {suffix}");
+            assert_eq!(linked.len(), word.len() + suffix.len());
+        }
+    }
+
+    #[test]
+    fn batchim_classification() {
+        let cases = [
+            ("나무", BatchimKind::None),
+            ("물", BatchimKind::Rieul),
+            ("닭", BatchimKind::Other('ㄱ')),
+            ("앉", BatchimKind::Other('ㄴ')),
+            ("꽃", BatchimKind::Other('ㄷ')),
+            ("있", BatchimKind::Other('ㄷ')),
+            ("삶", BatchimKind::Other('ㅁ')),
+            ("값", BatchimKind::Other('ㅂ')),
+            ("강", BatchimKind::Other('ㅇ')),
+            ("핥", BatchimKind::Other('ㄹ')),
+        ];
+        for (word, kind) in cases {
+            assert_eq!(batchim_kind(word), Some(kind), "{word}");
+            assert_eq!(has_batchim(word), Some(kind != BatchimKind::None));
+        }
+        assert_eq!(BatchimKind::Rieul.representative_sound(), Some('ㄹ'));
+        assert_eq!(BatchimKind::None.representative_sound(), None);
+        for word in ["", "abc", "책 ", "ㄱ"] {
+            assert_eq!(batchim_kind(word), None, "{word:?}");
+        }
+    }
+
+    #[test]
+    fn attach_josa_chooses_the_form() {
+        let cases = [
+            ("책", "을", "책을"),
+            ("책", "를", "책을"),
+            ("나무", "이/가", "나무가"),
+            ("물", "은", "물은"),
+            ("물", "로", "물로"),
+            ("여덟", "로", "여덟으로"),
+            ("친구", "과", "친구와"),
+            ("학생", "예요", "학생이에요"),
+            ("학교", "에", "학교에"),
+            ("123", "를", "123을(를)"),
+            ("", "가", "이(가)"),
+        ];
+        for (word, josa, expected) in cases {
+            assert_eq!(attach_josa(word, josa), expected, "{word} + {josa}");
+        }
+    }
+
+    #[test]
+    fn double_tap_doubles_repeated_consonants() {
+        // Standard rules, without doubled consonants
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct NoDoubles;
+
+        impl CompositionRules for NoDoubles {
+            fn combine_initial(&self, _: char, _: char) -> Option<char> {
+                None
+            }
+
+            fn combine_vowel(&self, first: char, second: char) -> Option<char> {
+                StandardRules.combine_vowel(first, second)
+            }
+
+            fn combine_final(&self, first: char, second: char) -> Option<char> {
+                StandardRules
+                    .combine_final(first, second)
+                    .filter(|_| first != second)
+            }
+        }
+
+        let mut composer = HangulWordComposer::with_rules(NoDoubles).with_double_tap(true);
+        for c in "ㄱㄱㅏㄱㄱㅏ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        assert_eq!(composer.as_string().unwrap(), "까까");
+        // Whole double consonants still follow the rules
+        assert_eq!(composer.push_char('ㅆ'), Ok(WordPushResult::InvalidHangul));
+
+        // Only consonants typed one after the other are doubled
+        let mut composer = HangulWordComposer::new().with_double_tap(true);
+        for c in "ㄷㅏㄷㅗㄷㄷ".chars() {
+            composer.push_char(c).unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "다도\u{1104}");
+        composer.pop().unwrap();
+        assert_eq!(composer.as_string().unwrap(), "다도\u{1103}");
+    }
+
+    #[test]
+    fn custom_rules_allow_cluster_initials() {
+        // Standard rules, with ㅄ also allowed at the start of a syllable
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        struct BieupSiotInitial;
+
+        impl CompositionRules for BieupSiotInitial {
+            fn combine_initial(&self, first: char, second: char) -> Option<char> {
+                match (first, second) {
+                    ('ㅂ', 'ㅅ') => Some('ㅄ'),
+                    _ => StandardRules.combine_initial(first, second),
+                }
+            }
+
+            fn combine_vowel(&self, first: char, second: char) -> Option<char> {
+                StandardRules.combine_vowel(first, second)
+            }
+
+            fn combine_final(&self, first: char, second: char) -> Option<char> {
+                StandardRules.combine_final(first, second)
+            }
+        }
+
+        let mut composer = HangulWordComposer::with_rules(BieupSiotInitial);
+        for c in "ㅂㅅㅏㄹㄱㅏ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        // The block is not a modern syllable, so it is written as jamo
+        assert_eq!(composer.as_string().unwrap(), "ㅄㅏㄹ가");
+        for _ in 0..4 {
+            composer.pop().unwrap();
+        }
+        assert_eq!(composer.as_string().unwrap(), "ㅄ");
+        // Not within a word, where ㅂ and ㅅ form a final cluster
+        assert_eq!(composer.push_char('ㅓ'), Ok(WordPushResult::Continue));
+        for c in "ㅂㅅㅏ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        assert_eq!(composer.as_string().unwrap(), "ㅄㅓㅂ사");
+
+        // ㅄ pushed whole can start a block too
+        let mut composer = HangulWordComposer::with_rules(BieupSiotInitial);
+        for c in "ㅄㅣ".chars() {
+            assert_eq!(composer.push_char(c), Ok(WordPushResult::Continue));
+        }
+        assert_eq!(composer.as_string().unwrap(), "ㅄㅣ");
+        let mut standard = HangulWordComposer::new();
+        assert_eq!(standard.push_char('ㅄ'), Ok(WordPushResult::InvalidHangul));
+    }
 }
@@ -0,0 +1,69 @@
+//! Runs the `hangul` binary on standard input and files.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_hangul"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str], input: &str) -> String {
+    let output = run(args, input);
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn subcommands() {
+    assert_eq!(stdout(&["compose"], "ㅎㅏㄴㄱㅡㄹ\n"), "한글\n");
+    assert_eq!(stdout(&["compose"], "ㄱ ㅎㅏㄴ ㅏ"), "ㄱ 한 ㅏ");
+    assert_eq!(stdout(&["decompose"], "한글\n"), "ㅎㅏㄴㄱㅡㄹ\n");
+    assert_eq!(stdout(&["chosung"], "한글 OK\n"), "ㅎㄱ OK\n");
+    assert_eq!(
+        stdout(&["romanize"], "한국어\n안녕하세요\n"),
+        "hangugeo\nannyeonghaseyo\n"
+    );
+    assert_eq!(stdout(&["josa", "을/를"], "책\n사과\n"), "책을\n사과를\n");
+    assert!(!run(&["josa"], "책\n").status.success());
+}
+
+#[test]
+fn reads_files_in_order() {
+    let dir = std::env::temp_dir().join(format!("hangul-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let first = dir.join("first.txt");
+    let second = dir.join("second.txt");
+    std::fs::write(&first, "서울\n").unwrap();
+    std::fs::write(&second, "부산\n").unwrap();
+
+    let output = stdout(
+        &[
+            "romanize",
+            first.to_str().unwrap(),
+            second.to_str().unwrap(),
+        ],
+        "",
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(output, "seoul\nbusan\n");
+}
+
+#[test]
+fn rejects_unknown_commands() {
+    let output = run(&["transmogrify"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown command"));
+}